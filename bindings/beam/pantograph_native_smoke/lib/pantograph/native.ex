@@ -16,8 +16,12 @@ defmodule Pantograph.Native do
     {:workflow_remove_edge, 2},
     {:workflow_update_node_data, 3},
     {:workflow_validate, 1},
+    {:workflow_estimate, 2},
     {:executor_new, 2},
     {:executor_new_with_timeout, 3},
+    {:executor_new_sandboxed, 3},
+    {:executor_new_simulated, 3},
+    {:executor_new_with_limits, 3},
     {:inference_gateway_new, 2},
     {:executor_new_with_inference, 3},
     {:executor_new_with_inference_timeout, 4},
@@ -26,9 +30,18 @@ defmodule Pantograph.Native do
     {:executor_update_node_data, 3},
     {:executor_mark_modified, 2},
     {:executor_cache_stats, 1},
+    {:executor_set_deterministic_mode, 2},
+    {:executor_shutdown, 2},
+    {:executor_drain, 3},
     {:executor_get_graph_snapshot, 1},
+    {:executor_estimate, 2},
+    {:executor_get_graph_snapshot_with_revision, 1},
+    {:executor_replace_graph, 2},
     {:executor_set_input, 4},
     {:executor_get_output, 3},
+    {:log_sink_install, 2},
+    {:log_sink_set_level, 1},
+    {:log_sink_clear, 0},
     {:callback_respond, 2},
     {:callback_error, 2},
     {:orchestration_store_new, 0},
@@ -47,23 +60,39 @@ defmodule Pantograph.Native do
     {:node_registry_queryable_ports, 1},
     {:extensions_new, 0},
     {:extensions_setup, 2},
+    {:extensions_setup_from_file, 2},
     {:node_registry_query_port_options, 5},
     {:execute_orchestration, 4},
     {:execute_orchestration_with_inference, 5},
+    {:execute_orchestration_simulated, 5},
     {:orchestration_store_insert_data_graph, 3},
+    {:orchestration_recover_executions, 1},
+    {:orchestration_resume_execution, 3},
+    {:orchestration_resume_execution_with_inference, 4},
+    {:orchestration_discard_execution, 2},
     {:pumas_api_discover, 0},
     {:pumas_api_new, 1},
+    {:pumas_api_new_with_roots, 1},
     {:executor_set_pumas_api, 2},
     {:executor_set_kv_cache_store, 2},
     {:pumas_list_models, 1},
     {:pumas_search_models, 4},
     {:pumas_get_model, 2},
     {:pumas_rebuild_index, 1},
+    {:pumas_unused_models, 3},
+    {:pumas_gc, 4},
+    {:pumas_pin_model, 2},
+    {:pumas_unpin_model, 2},
     {:pumas_search_hf, 4},
     {:pumas_get_repo_files, 2},
     {:pumas_start_download, 2},
     {:pumas_get_download_progress, 2},
     {:pumas_cancel_download, 2},
+    {:pumas_start_verified_download, 2},
+    {:pumas_get_verified_download_progress, 2},
+    {:pumas_cancel_verified_download, 2},
+    {:pumas_inspect_download_url, 2},
+    {:pumas_set_download_bandwidth_limit, 2},
     {:pumas_import_model, 2},
     {:pumas_import_batch, 2},
     {:pumas_get_disk_space, 1},
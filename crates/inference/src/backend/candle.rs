@@ -97,9 +97,15 @@ impl InferenceBackend for CandleBackend {
 
     async fn start(
         &mut self,
-        _config: &BackendConfig,
+        config: &BackendConfig,
         _spawner: Arc<dyn ProcessSpawner>,
     ) -> Result<BackendStartOutcome, BackendError> {
+        if !config.lora_adapters.is_empty() {
+            return Err(BackendError::Config(
+                "Candle backend does not support LoRA adapters. Use llama.cpp with GGUF adapters instead.".to_string(),
+            ));
+        }
+
         // Candle runs in-process, so we don't need the spawner
         // The actual implementation would:
         // 1. Load the model from config.model_path or config.model_id
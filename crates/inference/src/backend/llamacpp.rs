@@ -189,10 +189,16 @@ impl InferenceBackend for LlamaCppBackend {
                 .mmproj_path
                 .as_ref()
                 .map(|p| p.to_string_lossy().to_string());
+            let requested_loras: Vec<String> = config
+                .lora_adapters
+                .iter()
+                .map(|adapter| adapter.path.to_string_lossy().to_string())
+                .collect();
 
             if self.server.matches_inference_runtime(
                 &model_path.to_string_lossy(),
                 mmproj_path.as_deref(),
+                &requested_loras,
                 &device_config,
                 config.port_override,
             ) {
@@ -202,11 +208,31 @@ impl InferenceBackend for LlamaCppBackend {
                 });
             }
 
+            // Restarting the same model for a new adapter keeps the adapters
+            // already loaded, so earlier selections stay switchable.
+            let mut lora_paths = if self.server.matches_inference_runtime(
+                &model_path.to_string_lossy(),
+                mmproj_path.as_deref(),
+                &[],
+                &device_config,
+                config.port_override,
+            ) {
+                self.server.loaded_lora_paths().to_vec()
+            } else {
+                Vec::new()
+            };
+            for path in requested_loras {
+                if !lora_paths.contains(&path) {
+                    lora_paths.push(path);
+                }
+            }
+
             self.server
                 .start_sidecar_inference(
                     spawner,
                     &model_path.to_string_lossy(),
                     mmproj_path.as_deref(),
+                    &lora_paths,
                     &device_config,
                     config.port_override,
                 )
@@ -519,6 +545,7 @@ mod tests {
             port: 8080,
             model_path: "/models/main.gguf".to_string(),
             mmproj_path: None,
+            lora_paths: Vec::new(),
             device: DeviceConfig {
                 device: "Vulkan0".to_string(),
                 gpu_layers: 40,
@@ -554,6 +581,7 @@ mod tests {
             port: 8080,
             model_path: "/models/main.gguf".to_string(),
             mmproj_path: Some("/models/vision.mmproj".to_string()),
+            lora_paths: Vec::new(),
             device: DeviceConfig {
                 device: "auto".to_string(),
                 gpu_layers: -1,
@@ -579,6 +607,7 @@ mod tests {
                 port: 11434,
                 model_path: "/models/main.gguf".to_string(),
                 mmproj_path: Some("/models/vision.mmproj".to_string()),
+                lora_paths: Vec::new(),
                 device: DeviceConfig {
                     device: "Vulkan0".to_string(),
                     gpu_layers: 40,
@@ -617,6 +646,7 @@ mod tests {
                 port: 11434,
                 model_path: "/models/main.gguf".to_string(),
                 mmproj_path: None,
+                lora_paths: Vec::new(),
                 device: DeviceConfig {
                     device: "Vulkan0".to_string(),
                     gpu_layers: 40,
@@ -652,6 +682,7 @@ mod tests {
                 port: 11434,
                 model_path: "/models/main.gguf".to_string(),
                 mmproj_path: None,
+                lora_paths: Vec::new(),
                 device: DeviceConfig {
                     device: "Vulkan0".to_string(),
                     gpu_layers: 40,
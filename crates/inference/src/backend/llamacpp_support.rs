@@ -188,13 +188,19 @@ pub fn kv_cache_model_fingerprint_for_mode(
     mode: &ServerMode,
     active_config: Option<&BackendConfig>,
 ) -> Result<ModelFingerprint, BackendError> {
-    let (model_path, mmproj_path, device) = match mode {
+    let (model_path, mmproj_path, lora_paths, device) = match mode {
         ServerMode::SidecarInference {
             model_path,
             mmproj_path,
+            lora_paths,
             device,
             ..
-        } => (model_path.as_str(), mmproj_path.as_deref(), device),
+        } => (
+            model_path.as_str(),
+            mmproj_path.as_deref(),
+            lora_paths,
+            device,
+        ),
         ServerMode::External { .. } => {
             return Err(BackendError::Inference(
                 "KV cache model fingerprint is not supported for external llama.cpp runtimes"
@@ -212,16 +218,23 @@ pub fn kv_cache_model_fingerprint_for_mode(
         .and_then(|config| config.context_size)
         .unwrap_or(defaults::CONTEXT_SIZE);
 
+    let mut config_hash = format!(
+        "llamacpp:{}:{}:{}:{}:{}",
+        model_path,
+        mmproj_path.unwrap_or("none"),
+        device.device,
+        device.gpu_layers,
+        context_size
+    );
+    // Loaded adapters change cached activations; runtimes without adapters
+    // keep their existing hash.
+    if !lora_paths.is_empty() {
+        config_hash.push_str(&format!(":lora={}", lora_paths.join(",")));
+    }
+
     Ok(ModelFingerprint {
         model_id: model_path.to_string(),
-        config_hash: format!(
-            "llamacpp:{}:{}:{}:{}:{}",
-            model_path,
-            mmproj_path.unwrap_or("none"),
-            device.device,
-            device.gpu_layers,
-            context_size
-        ),
+        config_hash,
     })
 }
 
@@ -129,6 +129,20 @@ pub struct BackendInfo {
     pub runtime_binary_id: Option<ManagedBinaryId>,
 }
 
+/// A LoRA adapter applied on top of the base model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoraAdapter {
+    /// Adapter file path (GGUF for llama.cpp)
+    pub path: std::path::PathBuf,
+    /// Adapter strength; 1.0 applies it fully
+    #[serde(default = "default_lora_scale")]
+    pub scale: f32,
+}
+
+fn default_lora_scale() -> f32 {
+    1.0
+}
+
 /// Configuration for starting a backend
 #[derive(Debug, Clone, Default)]
 pub struct BackendConfig {
@@ -144,6 +158,8 @@ pub struct BackendConfig {
     pub model_path: Option<std::path::PathBuf>,
     /// Vision projection file path (for llama.cpp mmproj)
     pub mmproj_path: Option<std::path::PathBuf>,
+    /// LoRA adapters to load alongside the model (llama.cpp only)
+    pub lora_adapters: Vec<LoraAdapter>,
     /// Model name (for Ollama, e.g., "llava:13b")
     pub model_name: Option<String>,
     /// HuggingFace model ID (for Candle)
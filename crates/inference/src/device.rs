@@ -194,6 +194,16 @@ pub async fn list_llamacpp_devices(app_data_dir: &Path) -> Result<Vec<DeviceInfo
     Ok(parse_llamacpp_device_listing(&output))
 }
 
+/// System RAM currently in use, in MiB.
+///
+/// Sampled before and after a model load to estimate its host memory cost;
+/// the difference also includes any unrelated allocations made meanwhile.
+pub fn system_used_memory_mb() -> u64 {
+    let mut system = sysinfo::System::new();
+    system.refresh_memory();
+    system.used_memory() / (1024 * 1024)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
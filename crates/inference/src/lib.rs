@@ -39,7 +39,7 @@ pub mod types;
 // Re-exports for convenience
 pub use backend::{
     BackendCapabilities, BackendConfig, BackendError, BackendFactory, BackendInfo, BackendRegistry,
-    ChatChunk, EmbeddingResult, InferenceBackend,
+    ChatChunk, EmbeddingResult, InferenceBackend, LoraAdapter,
 };
 
 #[cfg(feature = "backend-llamacpp")]
@@ -55,7 +55,9 @@ pub use backend::CandleBackend;
 pub use backend::PyTorchBackend;
 
 pub use config::{DeviceConfig, EmbeddingMemoryMode};
-pub use device::{list_llamacpp_devices, parse_llamacpp_device_listing, DeviceBackend};
+pub use device::{
+    list_llamacpp_devices, parse_llamacpp_device_listing, system_used_memory_mb, DeviceBackend,
+};
 pub use embedding_runtime::{DedicatedEmbeddingRuntimeManager, LlamaCppEmbeddingRuntime};
 pub use gateway::{
     EmbeddingRuntimePreparation, EmbeddingStartRequest, GatewayError, InferenceGateway,
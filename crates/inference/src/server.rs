@@ -128,6 +128,8 @@ pub enum ServerMode {
         port: u16,
         model_path: String,
         mmproj_path: Option<String>,
+        /// LoRA adapters loaded at startup, in llama-server adapter ID order
+        lora_paths: Vec<String>,
         device: DeviceConfig,
     },
     /// Sidecar running in embedding mode (for RAG indexing)
@@ -229,11 +231,16 @@ impl LlamaServer {
     ///
     /// If `mmproj_path` is `Some`, the `--mmproj` flag is passed to enable
     /// vision/multimodal support. For text-only LLMs, pass `None`.
+    ///
+    /// Each of `lora_paths` is loaded with `--lora` but left disabled; requests
+    /// enable adapters by ID and scale, so switching between loaded adapters
+    /// needs no restart.
     pub async fn start_sidecar_inference(
         &mut self,
         spawner: Arc<dyn ProcessSpawner>,
         model_path: &str,
         mmproj_path: Option<&str>,
+        lora_paths: &[String],
         device: &DeviceConfig,
         port_override: Option<u16>,
     ) -> Result<(), String> {
@@ -281,6 +288,14 @@ impl LlamaServer {
             args.push(mmproj.to_string());
         }
 
+        for lora in lora_paths {
+            args.push("--lora".to_string());
+            args.push(lora.clone());
+        }
+        if !lora_paths.is_empty() {
+            args.push("--lora-init-without-apply".to_string());
+        }
+
         // Add device selection if not "auto"
         if device.device != device_types::AUTO {
             args.push("--device".to_string());
@@ -306,6 +321,7 @@ impl LlamaServer {
             port,
             model_path: model_path.to_string(),
             mmproj_path: mmproj_path.map(|s| s.to_string()),
+            lora_paths: lora_paths.to_vec(),
             device: device.clone(),
         };
 
@@ -619,10 +635,13 @@ impl LlamaServer {
         matches!(self.mode, ServerMode::SidecarReranking { .. })
     }
 
+    /// Whether the running sidecar serves this model with every adapter in
+    /// `lora_paths` loaded.
     pub fn matches_inference_runtime(
         &self,
         model_path: &str,
         mmproj_path: Option<&str>,
+        lora_paths: &[String],
         device: &DeviceConfig,
         port_override: Option<u16>,
     ) -> bool {
@@ -634,15 +653,24 @@ impl LlamaServer {
                     port: active_port,
                     model_path: active_model_path,
                     mmproj_path: active_mmproj_path,
+                    lora_paths: active_lora_paths,
                     device: active_device,
-                    ..
                 } if active_model_path == model_path
                     && active_mmproj_path.as_deref() == mmproj_path
+                    && lora_paths.iter().all(|path| active_lora_paths.contains(path))
                     && active_device == device
                     && *active_port == expected_port
             )
     }
 
+    /// LoRA adapters loaded by the running inference sidecar.
+    pub fn loaded_lora_paths(&self) -> &[String] {
+        match &self.mode {
+            ServerMode::SidecarInference { lora_paths, .. } => lora_paths,
+            _ => &[],
+        }
+    }
+
     pub fn matches_embedding_runtime(
         &self,
         model_path: &str,
@@ -29,6 +29,7 @@ fn base_url_reflects_sidecar_port_override() {
             port: 18080,
             model_path: "/models/main.gguf".to_string(),
             mmproj_path: None,
+            lora_paths: Vec::new(),
             device: DeviceConfig {
                 device: "auto".to_string(),
                 gpu_layers: -1,
@@ -61,6 +62,7 @@ fn inference_runtime_matcher_requires_matching_port() {
             port: 11434,
             model_path: "/models/main.gguf".to_string(),
             mmproj_path: Some("/models/vision.mmproj".to_string()),
+            lora_paths: Vec::new(),
             device: device.clone(),
         },
         true,
@@ -69,13 +71,43 @@ fn inference_runtime_matcher_requires_matching_port() {
     assert!(server.matches_inference_runtime(
         "/models/main.gguf",
         Some("/models/vision.mmproj"),
+        &[],
         &device,
         Some(11434),
     ));
     assert!(!server.matches_inference_runtime(
         "/models/main.gguf",
         Some("/models/vision.mmproj"),
+        &[],
         &device,
         Some(18080),
     ));
 }
+
+#[test]
+fn inference_runtime_matcher_requires_loaded_lora_adapters() {
+    let mut server = LlamaServer::new();
+    let device = DeviceConfig {
+        device: "auto".to_string(),
+        gpu_layers: -1,
+    };
+    server.set_test_runtime_state(
+        ServerMode::SidecarInference {
+            port: 11434,
+            model_path: "/models/main.gguf".to_string(),
+            mmproj_path: None,
+            lora_paths: vec!["/loras/a.gguf".to_string(), "/loras/b.gguf".to_string()],
+            device: device.clone(),
+        },
+        true,
+    );
+
+    let matches = |loras: &[&str]| {
+        let loras: Vec<String> = loras.iter().map(|path| path.to_string()).collect();
+        server.matches_inference_runtime("/models/main.gguf", None, &loras, &device, Some(11434))
+    };
+    assert!(matches(&[]));
+    assert!(matches(&["/loras/b.gguf"]));
+    assert!(!matches(&["/loras/c.gguf"]));
+    assert_eq!(server.loaded_lora_paths().len(), 2);
+}
@@ -0,0 +1,310 @@
+//! Injectable time and randomness.
+//!
+//! Code that waits, reads the time, or draws random numbers during execution
+//! (the `delay` and `random` nodes, simulated latencies, result TTLs) goes
+//! through a [`Clock`] and an [`Rng`] instead of calling tokio, `Instant`, or
+//! the OS directly. Hosts install them in the executor extensions under
+//! [`extension_keys::CLOCK`] and [`extension_keys::RNG`]; without them,
+//! [`SystemClock`] and [`SystemRng`] are used. Waits on real processes, such
+//! as inference-server readiness, stay on wall time.
+//!
+//! Tests and replayed runs install a [`SimulatedClock`], which fast-forwards
+//! virtual time instead of sleeping, and a [`SeededRng`], which repeats the
+//! same sequence for the same seed.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let clock = Arc::new(SimulatedClock::new());
+//! let mut extensions = ExecutorExtensions::new();
+//! extensions.set(extension_keys::CLOCK, clock.clone() as Arc<dyn Clock>);
+//! extensions.set(extension_keys::RNG, Arc::new(SeededRng::new(42)) as Arc<dyn Rng>);
+//!
+//! // A 10 minute `delay` node now returns immediately and advances the clock.
+//! executor.demand(&"delay-1".to_string(), &core).await?;
+//! assert_eq!(clock.elapsed(), Duration::from_secs(600));
+//! ```
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use async_trait::async_trait;
+
+use crate::extensions::{extension_keys, ExecutorExtensions};
+
+/// Source of time for execution-time waits and timestamps.
+#[async_trait]
+pub trait Clock: Send + Sync {
+    /// Monotonic time, for deadlines and elapsed-time checks.
+    fn now(&self) -> Instant;
+
+    /// Wall-clock time, for timestamps that leave the process.
+    fn system_time(&self) -> SystemTime;
+
+    /// Wait until `duration` has passed on this clock.
+    async fn sleep(&self, duration: Duration);
+}
+
+/// Clock backed by the OS and the tokio timer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// Virtual clock that only moves when slept on or advanced.
+///
+/// `sleep` returns after yielding once and moves the clock to the sleeper's
+/// deadline if it is not already past it, so concurrent sleeps overlap as
+/// they would in real time: two parallel 1s sleeps advance the clock by 1s,
+/// two sequential ones by 2s.
+#[derive(Debug)]
+pub struct SimulatedClock {
+    origin: Instant,
+    system_origin: SystemTime,
+    elapsed: Mutex<Duration>,
+}
+
+impl SimulatedClock {
+    /// Start a clock at the current real time.
+    pub fn new() -> Self {
+        Self::starting_at(SystemTime::now())
+    }
+
+    /// Start a clock whose wall-clock time reads `system_origin`, so replayed
+    /// runs can reproduce the timestamps of the recorded run.
+    pub fn starting_at(system_origin: SystemTime) -> Self {
+        Self {
+            origin: Instant::now(),
+            system_origin,
+            elapsed: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap() += duration;
+    }
+
+    /// Virtual time passed since the clock was created.
+    pub fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap()
+    }
+}
+
+impl Default for SimulatedClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Clock for SimulatedClock {
+    fn now(&self) -> Instant {
+        self.origin + self.elapsed()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.system_origin + self.elapsed()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        let deadline = self.elapsed() + duration;
+        tokio::task::yield_now().await;
+        let mut elapsed = self.elapsed.lock().unwrap();
+        if *elapsed < deadline {
+            *elapsed = deadline;
+        }
+    }
+}
+
+/// Source of random numbers for execution-time draws.
+pub trait Rng: Send + Sync {
+    /// Next uniformly distributed 64-bit value.
+    fn next_u64(&self) -> u64;
+
+    /// Uniform float in `[0, 1)`.
+    fn next_f64(&self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform integer in `[low, high]`; `low` when the range is empty.
+    fn range_i64(&self, low: i64, high: i64) -> i64 {
+        if high <= low {
+            return low;
+        }
+        let span = high.wrapping_sub(low) as u64;
+        match span.checked_add(1) {
+            Some(size) => low.wrapping_add((self.next_u64() % size) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+}
+
+/// SplitMix64 generator that yields the same sequence for the same seed.
+///
+/// Draws are serialized through an atomic counter, so a run reproduces
+/// exactly as long as draws happen in the same order.
+#[derive(Debug)]
+pub struct SeededRng {
+    state: AtomicU64,
+}
+
+impl SeededRng {
+    const GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    /// Create a generator from `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+}
+
+impl Rng for SeededRng {
+    fn next_u64(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(Self::GAMMA, Ordering::Relaxed)
+            .wrapping_add(Self::GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Generator seeded from process entropy; not reproducible.
+#[derive(Debug)]
+pub struct SystemRng(SeededRng);
+
+impl SystemRng {
+    /// Create a generator with a fresh random seed.
+    pub fn new() -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos(),
+        );
+        Self(SeededRng::new(hasher.finish()))
+    }
+}
+
+impl Default for SystemRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rng for SystemRng {
+    fn next_u64(&self) -> u64 {
+        self.0.next_u64()
+    }
+}
+
+/// Clock installed under [`extension_keys::CLOCK`], or [`SystemClock`].
+pub fn clock(extensions: &ExecutorExtensions) -> Arc<dyn Clock> {
+    extensions
+        .get::<Arc<dyn Clock>>(extension_keys::CLOCK)
+        .cloned()
+        .unwrap_or_else(|| Arc::new(SystemClock))
+}
+
+/// Generator installed under [`extension_keys::RNG`], or a fresh
+/// [`SystemRng`].
+pub fn rng(extensions: &ExecutorExtensions) -> Arc<dyn Rng> {
+    extensions
+        .get::<Arc<dyn Rng>>(extension_keys::RNG)
+        .cloned()
+        .unwrap_or_else(|| Arc::new(SystemRng::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn simulated_sleep_fast_forwards() {
+        let clock = SimulatedClock::starting_at(SystemTime::UNIX_EPOCH);
+        let started = Instant::now();
+
+        clock.sleep(Duration::from_secs(3600)).await;
+        clock.advance(Duration::from_secs(1));
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(clock.elapsed(), Duration::from_secs(3601));
+        assert_eq!(
+            clock.system_time(),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(3601)
+        );
+    }
+
+    #[tokio::test]
+    async fn concurrent_simulated_sleeps_overlap() {
+        let clock = SimulatedClock::new();
+        let start = clock.now();
+
+        tokio::join!(
+            clock.sleep(Duration::from_secs(1)),
+            clock.sleep(Duration::from_secs(2))
+        );
+        clock.sleep(Duration::from_secs(1)).await;
+
+        assert_eq!(clock.now() - start, Duration::from_secs(3));
+    }
+
+    #[test]
+    fn seeded_rng_repeats_sequence() {
+        let draw = |seed| {
+            let rng = SeededRng::new(seed);
+            (0..4).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+    }
+
+    #[test]
+    fn range_and_float_draws_stay_in_bounds() {
+        let rng = SeededRng::new(1);
+        for _ in 0..1000 {
+            let value = rng.range_i64(-3, 3);
+            assert!((-3..=3).contains(&value));
+            let float = rng.next_f64();
+            assert!((0.0..1.0).contains(&float));
+        }
+        assert_eq!(rng.range_i64(5, 5), 5);
+        assert_eq!(rng.range_i64(5, 1), 5);
+    }
+
+    #[test]
+    fn extensions_override_defaults() {
+        let mut extensions = ExecutorExtensions::new();
+        let simulated = Arc::new(SimulatedClock::new());
+        simulated.advance(Duration::from_secs(10));
+        extensions.set(extension_keys::CLOCK, simulated.clone() as Arc<dyn Clock>);
+        extensions.set(
+            extension_keys::RNG,
+            Arc::new(SeededRng::new(3)) as Arc<dyn Rng>,
+        );
+
+        assert_eq!(clock(&extensions).now(), simulated.now());
+        assert_eq!(rng(&extensions).next_u64(), SeededRng::new(3).next_u64());
+    }
+}
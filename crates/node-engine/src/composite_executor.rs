@@ -63,6 +63,13 @@ impl TaskExecutor for CompositeTaskExecutor {
             .execute_task(task_id, inputs, context, extensions)
             .await
     }
+
+    async fn shutdown(&self) {
+        if let Some(ref host) = self.host {
+            host.shutdown().await;
+        }
+        self.core.shutdown().await;
+    }
 }
 
 #[cfg(test)]
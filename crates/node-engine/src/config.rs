@@ -0,0 +1,468 @@
+//! Extension configuration files.
+//!
+//! Hosts describe the dependencies nodes read from [`ExecutorExtensions`] in
+//! one TOML or JSON file instead of wiring each in code:
+//!
+//! ```toml
+//! pumas_library_path = "/opt/pumas"
+//!
+//! # Or several roots merged into one view, highest priority first:
+//! # [[pumas_library_roots]]
+//! # path = "/mnt/shared/pumas"
+//! # read_only = true
+//! # priority = 10
+//!
+//! [secrets]
+//! provider = "env"
+//! prefix = "PANTOGRAPH_SECRET_"
+//!
+//! [filesystem]
+//! root = "workspace"
+//! read_only = true
+//!
+//! [backends]
+//! ollama = "http://gpu-box:11434"
+//!
+//! [cache]
+//! kv_cache_dir = "cache/kv"
+//! kv_cache_policy = "memory_and_disk"
+//! ```
+//!
+//! [`ExtensionsConfig::build_extensions`] installs everything node-engine
+//! owns. Pumas library paths and roots are only recorded here;
+//! `workflow-nodes` opens the libraries because node-engine does not depend
+//! on them.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::extensions::{extension_keys, ExecutorExtensions};
+
+/// Errors loading or applying an extensions config.
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Cannot read '{path}': {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid config '{path}': {message}")]
+    Parse { path: PathBuf, message: String },
+
+    #[error("{0}")]
+    Unsupported(String),
+
+    #[error("Model library error: {0}")]
+    ModelLibrary(String),
+}
+
+/// Extension configuration loaded from a TOML or JSON file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExtensionsConfig {
+    /// Pumas library root; hosts fall back to `PUMAS_LIBRARY_PATH` and
+    /// discovery when unset.
+    pub pumas_library_path: Option<PathBuf>,
+    /// Several Pumas libraries merged into one view; excludes
+    /// `pumas_library_path`.
+    pub pumas_library_roots: Vec<LibraryRootConfig>,
+    pub secrets: SecretsConfig,
+    pub filesystem: FilesystemPolicy,
+    pub backends: BackendEndpoints,
+    pub cache: CacheConfig,
+}
+
+/// One root of a merged Pumas model library.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LibraryRootConfig {
+    pub path: PathBuf,
+    /// Never receives downloads, imports, or index rebuilds.
+    #[serde(default)]
+    pub read_only: bool,
+    /// Higher priorities win when roots hold the same model ID; ties keep
+    /// the configured order.
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// Where nodes look up credentials such as API keys.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum SecretsConfig {
+    /// No secrets provider is installed.
+    #[default]
+    None,
+    /// Environment variables named `{prefix}{NAME}`, with the secret name
+    /// uppercased.
+    Env {
+        #[serde(default)]
+        prefix: String,
+    },
+    /// A flat `name = "value"` TOML or JSON file, read once at startup.
+    File { path: PathBuf },
+}
+
+/// Restrictions on file I/O nodes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FilesystemPolicy {
+    /// Directory file paths must resolve within; overrides the executor's
+    /// project root when set.
+    pub root: Option<PathBuf>,
+    /// Reject `write-file`.
+    pub read_only: bool,
+}
+
+/// Base URLs of HTTP inference backends, keyed by backend name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BackendEndpoints(pub BTreeMap<String, String>);
+
+impl BackendEndpoints {
+    /// Backend name for `ollama-inference`.
+    pub const OLLAMA: &'static str = "ollama";
+    pub const DEFAULT_OLLAMA: &'static str = "http://localhost:11434";
+
+    pub fn get(&self, backend: &str) -> Option<&str> {
+        self.0.get(backend).map(String::as_str)
+    }
+
+    pub fn ollama(&self) -> &str {
+        self.get(Self::OLLAMA).unwrap_or(Self::DEFAULT_OLLAMA)
+    }
+}
+
+/// KV cache storage for the cache save/load/truncate nodes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Installs a KV cache store when set.
+    pub kv_cache_dir: Option<PathBuf>,
+    pub kv_cache_policy: KvCachePolicy,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KvCachePolicy {
+    MemoryOnly,
+    DiskOnly,
+    #[default]
+    MemoryAndDisk,
+}
+
+/// Credential lookup for nodes, installed under
+/// [`extension_keys::SECRETS_PROVIDER`] as `Arc<dyn SecretsProvider>`.
+pub trait SecretsProvider: Send + Sync {
+    /// Value of the secret `name`, or `None` when it is not configured.
+    fn secret(&self, name: &str) -> Option<String>;
+}
+
+/// Reads secrets from `{prefix}{NAME}` environment variables.
+pub struct EnvSecretsProvider {
+    prefix: String,
+}
+
+impl EnvSecretsProvider {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+}
+
+impl SecretsProvider for EnvSecretsProvider {
+    fn secret(&self, name: &str) -> Option<String> {
+        std::env::var(format!("{}{}", self.prefix, name.to_uppercase())).ok()
+    }
+}
+
+/// Fixed secrets, e.g. loaded from a file.
+pub struct StaticSecretsProvider {
+    secrets: HashMap<String, String>,
+}
+
+impl StaticSecretsProvider {
+    pub fn new(secrets: HashMap<String, String>) -> Self {
+        Self { secrets }
+    }
+}
+
+impl SecretsProvider for StaticSecretsProvider {
+    fn secret(&self, name: &str) -> Option<String> {
+        self.secrets.get(name).cloned()
+    }
+}
+
+/// Parse `content` as JSON when `path` ends in `.json`, TOML otherwise.
+fn parse_file<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, ConfigError> {
+    let content = std::fs::read_to_string(path).map_err(|source| ConfigError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let parsed = if path.extension().is_some_and(|ext| ext == "json") {
+        serde_json::from_str(&content).map_err(|e| e.to_string())
+    } else {
+        toml::from_str(&content).map_err(|e| e.to_string())
+    };
+    parsed.map_err(|message| ConfigError::Parse {
+        path: path.to_path_buf(),
+        message,
+    })
+}
+
+impl ExtensionsConfig {
+    /// Load a `.json` or TOML config, resolving relative paths against the
+    /// file's directory.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let mut config: Self = parse_file(path)?;
+        config.resolve_paths(path.parent().unwrap_or(Path::new(".")));
+        Ok(config)
+    }
+
+    /// Make relative paths relative to `base`, for configs embedded in
+    /// another file.
+    pub fn resolve_paths(&mut self, base: &Path) {
+        let secrets_file = match &mut self.secrets {
+            SecretsConfig::File { path } => Some(path),
+            _ => None,
+        };
+        let paths = [
+            self.pumas_library_path.as_mut(),
+            self.filesystem.root.as_mut(),
+            self.cache.kv_cache_dir.as_mut(),
+            secrets_file,
+        ];
+        let roots = self
+            .pumas_library_roots
+            .iter_mut()
+            .map(|root| &mut root.path);
+        for path in paths.into_iter().flatten().chain(roots) {
+            if path.is_relative() {
+                *path = base.join(&*path);
+            }
+        }
+    }
+
+    /// Fresh extensions with everything this config describes.
+    pub fn build_extensions(&self) -> Result<ExecutorExtensions, ConfigError> {
+        let mut extensions = ExecutorExtensions::new();
+        self.apply_to(&mut extensions)?;
+        Ok(extensions)
+    }
+
+    /// Install the configured secrets provider, filesystem policy, backend
+    /// endpoints, and KV cache store, replacing existing values.
+    pub fn apply_to(&self, extensions: &mut ExecutorExtensions) -> Result<(), ConfigError> {
+        if self.pumas_library_path.is_some() && !self.pumas_library_roots.is_empty() {
+            return Err(ConfigError::Unsupported(
+                "set either pumas_library_path or pumas_library_roots, not both".to_string(),
+            ));
+        }
+        let secrets: Option<Arc<dyn SecretsProvider>> = match &self.secrets {
+            SecretsConfig::None => None,
+            SecretsConfig::Env { prefix } => Some(Arc::new(EnvSecretsProvider::new(prefix))),
+            SecretsConfig::File { path } => {
+                Some(Arc::new(StaticSecretsProvider::new(parse_file(path)?)))
+            }
+        };
+        if let Some(secrets) = secrets {
+            extensions.set(extension_keys::SECRETS_PROVIDER, secrets);
+        }
+        extensions.set(
+            extension_keys::FILESYSTEM_POLICY,
+            Arc::new(self.filesystem.clone()),
+        );
+        extensions.set(
+            extension_keys::BACKEND_ENDPOINTS,
+            Arc::new(self.backends.clone()),
+        );
+        if let Some(dir) = &self.cache.kv_cache_dir {
+            self.install_kv_cache(dir, extensions)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "inference-nodes")]
+    fn install_kv_cache(
+        &self,
+        dir: &Path,
+        extensions: &mut ExecutorExtensions,
+    ) -> Result<(), ConfigError> {
+        use inference::kv_cache::{KvCacheStore, StoragePolicy};
+
+        let policy = match self.cache.kv_cache_policy {
+            KvCachePolicy::MemoryOnly => StoragePolicy::MemoryOnly,
+            KvCachePolicy::DiskOnly => StoragePolicy::DiskOnly,
+            KvCachePolicy::MemoryAndDisk => StoragePolicy::MemoryAndDisk,
+        };
+        extensions.set(
+            extension_keys::KV_CACHE_STORE,
+            Arc::new(KvCacheStore::new(dir.to_path_buf(), policy)),
+        );
+        Ok(())
+    }
+
+    #[cfg(not(feature = "inference-nodes"))]
+    fn install_kv_cache(
+        &self,
+        _dir: &Path,
+        _extensions: &mut ExecutorExtensions,
+    ) -> Result<(), ConfigError> {
+        Err(ConfigError::Unsupported(
+            "cache.kv_cache_dir requires the node-engine `inference-nodes` feature".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toml_and_json_configs_load_with_resolved_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let toml_path = dir.path().join("extensions.toml");
+        std::fs::write(
+            &toml_path,
+            r#"
+            pumas_library_path = "pumas"
+
+            [secrets]
+            provider = "file"
+            path = "secrets.json"
+
+            [filesystem]
+            root = "/srv/data"
+            read_only = true
+
+            [backends]
+            ollama = "http://gpu-box:11434"
+            "#,
+        )
+        .unwrap();
+        let json_path = dir.path().join("extensions.json");
+        std::fs::write(
+            &json_path,
+            r#"{
+                "pumas_library_path": "pumas",
+                "secrets": { "provider": "file", "path": "secrets.json" },
+                "filesystem": { "root": "/srv/data", "read_only": true },
+                "backends": { "ollama": "http://gpu-box:11434" }
+            }"#,
+        )
+        .unwrap();
+
+        let config = ExtensionsConfig::load(&toml_path).unwrap();
+        assert_eq!(config, ExtensionsConfig::load(&json_path).unwrap());
+        assert_eq!(config.pumas_library_path, Some(dir.path().join("pumas")));
+        assert_eq!(
+            config.secrets,
+            SecretsConfig::File {
+                path: dir.path().join("secrets.json")
+            }
+        );
+        assert_eq!(config.filesystem.root, Some(PathBuf::from("/srv/data")));
+        assert_eq!(config.backends.ollama(), "http://gpu-box:11434");
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("extensions.toml");
+        std::fs::write(&path, "pumas_path = \"/opt/pumas\"\n").unwrap();
+
+        assert!(matches!(
+            ExtensionsConfig::load(&path),
+            Err(ConfigError::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn build_extensions_installs_configured_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let secrets_path = dir.path().join("secrets.toml");
+        std::fs::write(&secrets_path, "openai_api_key = \"sk-test\"\n").unwrap();
+        let config = ExtensionsConfig {
+            secrets: SecretsConfig::File { path: secrets_path },
+            filesystem: FilesystemPolicy {
+                root: None,
+                read_only: true,
+            },
+            ..ExtensionsConfig::default()
+        };
+
+        let extensions = config.build_extensions().unwrap();
+        let secrets = extensions
+            .get::<Arc<dyn SecretsProvider>>(extension_keys::SECRETS_PROVIDER)
+            .unwrap();
+        assert_eq!(secrets.secret("openai_api_key").as_deref(), Some("sk-test"));
+        assert_eq!(secrets.secret("missing"), None);
+        assert!(
+            extensions
+                .get::<Arc<FilesystemPolicy>>(extension_keys::FILESYSTEM_POLICY)
+                .unwrap()
+                .read_only
+        );
+        assert_eq!(
+            extensions
+                .get::<Arc<BackendEndpoints>>(extension_keys::BACKEND_ENDPOINTS)
+                .unwrap()
+                .ollama(),
+            BackendEndpoints::DEFAULT_OLLAMA
+        );
+    }
+
+    #[test]
+    fn library_roots_resolve_and_exclude_a_single_path() {
+        let mut config: ExtensionsConfig = toml::from_str(
+            r#"
+            [[pumas_library_roots]]
+            path = "/mnt/shared"
+            read_only = true
+            priority = 10
+
+            [[pumas_library_roots]]
+            path = "local"
+            "#,
+        )
+        .unwrap();
+        config.resolve_paths(Path::new("/etc/pantograph"));
+
+        assert_eq!(
+            config.pumas_library_roots,
+            vec![
+                LibraryRootConfig {
+                    path: PathBuf::from("/mnt/shared"),
+                    read_only: true,
+                    priority: 10,
+                },
+                LibraryRootConfig {
+                    path: PathBuf::from("/etc/pantograph/local"),
+                    read_only: false,
+                    priority: 0,
+                },
+            ]
+        );
+        assert!(config.build_extensions().is_ok());
+
+        config.pumas_library_path = Some(PathBuf::from("/opt/pumas"));
+        assert!(matches!(
+            config.build_extensions(),
+            Err(ConfigError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn env_secrets_use_the_uppercased_name() {
+        std::env::set_var("NODE_ENGINE_CONFIG_TEST_API_KEY", "from-env");
+        let provider = EnvSecretsProvider::new("NODE_ENGINE_CONFIG_TEST_");
+        assert_eq!(provider.secret("api_key").as_deref(), Some("from-env"));
+    }
+}
@@ -0,0 +1,507 @@
+//! Binding-agnostic conformance suite for data-graph execution.
+//!
+//! Every host (Rustler NIF, UniFFI, Tauri) runs data graphs through its own
+//! glue: it maps graph inputs onto nodes, picks the terminal nodes to demand,
+//! collects their outputs, and translates errors and events into its own
+//! types. The vectors in `conformance/vectors/` pin the expected behavior of
+//! that glue as JSON: a graph, its inputs, mocked node behavior per node type,
+//! and the expected outputs, error kind, executed nodes, and events.
+//!
+//! A binding implements [`ConformanceHarness`] by running a graph the way its
+//! host does, using the [`ConformanceTaskExecutor`] it is handed so nodes never
+//! touch models or callbacks. [`check_suite`] then runs every vector through
+//! the harness and reports where the host deviates.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::TaskExecutor;
+use crate::error::{NodeEngineError, Result};
+use crate::extensions::ExecutorExtensions;
+use crate::types::WorkflowGraph;
+
+/// Vectors shipped with the engine, as `(name, json)` pairs.
+const BUILTIN_VECTORS: &[(&str, &str)] = &[
+    (
+        "chain_outputs",
+        include_str!("../conformance/vectors/chain_outputs.json"),
+    ),
+    (
+        "terminal_fan_out",
+        include_str!("../conformance/vectors/terminal_fan_out.json"),
+    ),
+    (
+        "graph_input_unconnected_port",
+        include_str!("../conformance/vectors/graph_input_unconnected_port.json"),
+    ),
+    (
+        "ambiguous_graph_input",
+        include_str!("../conformance/vectors/ambiguous_graph_input.json"),
+    ),
+    (
+        "terminal_output_aliases",
+        include_str!("../conformance/vectors/terminal_output_aliases.json"),
+    ),
+    (
+        "edge_wins_over_graph_input",
+        include_str!("../conformance/vectors/edge_wins_over_graph_input.json"),
+    ),
+    (
+        "no_terminal_nodes",
+        include_str!("../conformance/vectors/no_terminal_nodes.json"),
+    ),
+    (
+        "failing_upstream",
+        include_str!("../conformance/vectors/failing_upstream.json"),
+    ),
+    (
+        "panicking_node",
+        include_str!("../conformance/vectors/panicking_node.json"),
+    ),
+    (
+        "empty_graph",
+        include_str!("../conformance/vectors/empty_graph.json"),
+    ),
+];
+
+/// One conformance test vector.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConformanceVector {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub graph: WorkflowGraph,
+    /// Graph inputs keyed by port name, as a `DataGraphExecutor` receives them.
+    #[serde(default)]
+    pub inputs: HashMap<String, serde_json::Value>,
+    /// Mocked behavior keyed by node type.
+    #[serde(default)]
+    pub mocks: HashMap<String, MockNode>,
+    pub expected: ConformanceExpectation,
+}
+
+/// Mocked behavior for every node of one type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockNode {
+    /// Input ports the node reads; only these are echoed.
+    #[serde(default)]
+    pub inputs: Vec<String>,
+    #[serde(flatten)]
+    pub behavior: MockBehavior,
+}
+
+/// What a mocked node does when executed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "behavior", rename_all = "snake_case")]
+pub enum MockBehavior {
+    /// Return each declared input port that has a value. Like built-in nodes,
+    /// a value in node data (`_data`) wins over a connected input.
+    Echo,
+    /// Return fixed outputs.
+    Constant {
+        outputs: HashMap<String, serde_json::Value>,
+    },
+    /// Fail with `ExecutionFailed`.
+    Fail { message: String },
+    /// Panic inside the task.
+    Panic { message: String },
+}
+
+/// Expected observation for a vector. Every field is optional so vectors
+/// only pin what they are about.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConformanceExpectation {
+    /// Outputs that must be present with these values. Hosts may add more.
+    #[serde(default)]
+    pub outputs: HashMap<String, serde_json::Value>,
+    /// Output keys that must not be present.
+    #[serde(default)]
+    pub absent_outputs: Vec<String>,
+    /// Expected error kind (see [`NodeEngineError::kind`]); `None` expects
+    /// success.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Node IDs the task executor must have run, in any order.
+    #[serde(default)]
+    pub executed: Option<Vec<String>>,
+    /// Events that must be published in this order, possibly interleaved with
+    /// others.
+    #[serde(default)]
+    pub events: Vec<ConformanceEvent>,
+}
+
+/// An event as a host publishes it: its serialized `type` and `taskId`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConformanceEvent {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+}
+
+/// What a host produced for one vector.
+#[derive(Debug, Clone)]
+pub struct ConformanceObservation {
+    /// Graph outputs, or the kind label of the error the host surfaced.
+    pub result: std::result::Result<HashMap<String, serde_json::Value>, String>,
+    /// Event payloads as the host publishes them to its callers.
+    pub events: Vec<serde_json::Value>,
+}
+
+/// A host's data-graph execution path under test.
+#[async_trait]
+pub trait ConformanceHarness: Send + Sync {
+    /// Host label used in reports, e.g. `nif`.
+    fn host(&self) -> &str;
+
+    /// Run `graph` with `inputs` the way the host runs a data graph,
+    /// executing every node with `task_executor`.
+    async fn run(
+        &self,
+        graph: &WorkflowGraph,
+        inputs: &HashMap<String, serde_json::Value>,
+        task_executor: Arc<dyn TaskExecutor>,
+    ) -> ConformanceObservation;
+}
+
+/// Result of running one vector through one harness.
+#[derive(Debug, Clone)]
+pub struct ConformanceReport {
+    pub host: String,
+    pub vector: String,
+    pub mismatches: Vec<String>,
+}
+
+impl ConformanceReport {
+    pub fn passed(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl std::fmt::Display for ConformanceReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.host, self.vector)?;
+        if self.passed() {
+            return write!(f, ": ok");
+        }
+        for mismatch in &self.mismatches {
+            write!(f, "\n  - {}", mismatch)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse the vectors shipped with the engine.
+pub fn builtin_vectors() -> Vec<ConformanceVector> {
+    BUILTIN_VECTORS
+        .iter()
+        .map(|(name, json)| {
+            serde_json::from_str(json)
+                .unwrap_or_else(|e| panic!("conformance vector '{}' is invalid: {}", name, e))
+        })
+        .collect()
+}
+
+/// Run one vector through a harness.
+pub async fn run_vector(
+    harness: &dyn ConformanceHarness,
+    vector: &ConformanceVector,
+) -> ConformanceReport {
+    let task_executor = Arc::new(ConformanceTaskExecutor::new(vector));
+    let observation = harness
+        .run(&vector.graph, &vector.inputs, task_executor.clone())
+        .await;
+    ConformanceReport {
+        host: harness.host().to_string(),
+        vector: vector.name.clone(),
+        mismatches: vector
+            .expected
+            .mismatches(&observation, &task_executor.executed()),
+    }
+}
+
+/// Run every builtin vector through a harness.
+///
+/// Vectors named in `known_divergences` are expected to fail for this host;
+/// one that passes is reported too, so the list shrinks as hosts converge.
+pub async fn check_suite(
+    harness: &dyn ConformanceHarness,
+    known_divergences: &[&str],
+) -> std::result::Result<(), String> {
+    let mut failures = Vec::new();
+    for vector in builtin_vectors() {
+        let report = run_vector(harness, &vector).await;
+        let known = known_divergences.contains(&vector.name.as_str());
+        match (report.passed(), known) {
+            (false, false) => failures.push(report.to_string()),
+            (true, true) => failures.push(format!(
+                "[{}] {}: passes but is listed as a known divergence",
+                report.host, report.vector
+            )),
+            _ => {}
+        }
+    }
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n"))
+    }
+}
+
+impl ConformanceExpectation {
+    fn mismatches(&self, observation: &ConformanceObservation, executed: &[String]) -> Vec<String> {
+        let mut mismatches = Vec::new();
+
+        match (&observation.result, &self.error) {
+            (Ok(_), Some(kind)) => {
+                mismatches.push(format!("expected error '{}', got success", kind));
+            }
+            (Err(kind), None) => mismatches.push(format!("unexpected error '{}'", kind)),
+            (Err(actual), Some(kind)) if actual != kind => {
+                mismatches.push(format!("expected error '{}', got '{}'", kind, actual));
+            }
+            _ => {}
+        }
+
+        if let Ok(outputs) = &observation.result {
+            let mut keys: Vec<&String> = self.outputs.keys().collect();
+            keys.sort();
+            for key in keys {
+                let expected = &self.outputs[key];
+                match outputs.get(key) {
+                    Some(actual) if actual == expected => {}
+                    Some(actual) => mismatches.push(format!(
+                        "output '{}' is {}, expected {}",
+                        key, actual, expected
+                    )),
+                    None => mismatches.push(format!("output '{}' is missing", key)),
+                }
+            }
+            for key in &self.absent_outputs {
+                if outputs.contains_key(key) {
+                    mismatches.push(format!("output '{}' should be absent", key));
+                }
+            }
+        }
+
+        if let Some(expected) = &self.executed {
+            let expected: HashSet<&String> = expected.iter().collect();
+            let actual: HashSet<&String> = executed.iter().collect();
+            if expected != actual {
+                let mut actual: Vec<&String> = actual.into_iter().collect();
+                actual.sort();
+                mismatches.push(format!(
+                    "executed {:?}, expected {:?}",
+                    actual,
+                    self.executed.as_deref().unwrap_or_default()
+                ));
+            }
+        }
+
+        let published: Vec<ConformanceEvent> = observation
+            .events
+            .iter()
+            .filter_map(|event| serde_json::from_value(event.clone()).ok())
+            .collect();
+        let mut remaining = published.iter();
+        for expected in &self.events {
+            if !remaining.any(|event| event_matches(expected, event)) {
+                mismatches.push(format!(
+                    "event '{}'{} not published in order",
+                    expected.event_type,
+                    expected
+                        .task_id
+                        .as_deref()
+                        .map(|id| format!(" for '{}'", id))
+                        .unwrap_or_default()
+                ));
+                break;
+            }
+        }
+
+        mismatches
+    }
+}
+
+fn event_matches(expected: &ConformanceEvent, event: &ConformanceEvent) -> bool {
+    expected.event_type == event.event_type
+        && (expected.task_id.is_none() || expected.task_id == event.task_id)
+}
+
+/// Task executor that runs a vector's mocks and records which nodes ran.
+pub struct ConformanceTaskExecutor {
+    node_types: HashMap<String, String>,
+    mocks: HashMap<String, MockNode>,
+    executed: Mutex<Vec<String>>,
+}
+
+impl ConformanceTaskExecutor {
+    pub fn new(vector: &ConformanceVector) -> Self {
+        Self {
+            node_types: vector
+                .graph
+                .nodes
+                .iter()
+                .map(|node| (node.id.clone(), node.node_type.clone()))
+                .collect(),
+            mocks: vector.mocks.clone(),
+            executed: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Node IDs executed so far, in execution order.
+    pub fn executed(&self) -> Vec<String> {
+        self.executed
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+#[async_trait]
+impl TaskExecutor for ConformanceTaskExecutor {
+    async fn execute_task(
+        &self,
+        task_id: &str,
+        inputs: HashMap<String, serde_json::Value>,
+        _context: &graph_flow::Context,
+        _extensions: &ExecutorExtensions,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        self.executed
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(task_id.to_string());
+
+        let node_type = self.node_types.get(task_id).ok_or_else(|| {
+            NodeEngineError::failed(format!("Node '{}' is not in the vector graph", task_id))
+        })?;
+        let mock = self.mocks.get(node_type).ok_or_else(|| {
+            NodeEngineError::failed(format!("No mock for node type '{}'", node_type))
+        })?;
+
+        match &mock.behavior {
+            MockBehavior::Echo => Ok(mock
+                .inputs
+                .iter()
+                .filter_map(|port| {
+                    inputs
+                        .get("_data")
+                        .and_then(|data| data.get(port))
+                        .or_else(|| inputs.get(port))
+                        .map(|value| (port.clone(), value.clone()))
+                })
+                .collect()),
+            MockBehavior::Constant { outputs } => Ok(outputs.clone()),
+            MockBehavior::Fail { message } => Err(NodeEngineError::failed(message.clone())),
+            MockBehavior::Panic { message } => panic!("{}", message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vector(name: &str) -> ConformanceVector {
+        builtin_vectors()
+            .into_iter()
+            .find(|vector| vector.name == name)
+            .expect("builtin vector")
+    }
+
+    async fn run(
+        executor: &ConformanceTaskExecutor,
+        task_id: &str,
+        inputs: HashMap<String, serde_json::Value>,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        executor
+            .execute_task(
+                task_id,
+                inputs,
+                &graph_flow::Context::new(),
+                &ExecutorExtensions::new(),
+            )
+            .await
+    }
+
+    #[test]
+    fn builtin_vectors_parse_with_unique_names() {
+        let vectors = builtin_vectors();
+        assert_eq!(vectors.len(), BUILTIN_VECTORS.len());
+        for ((name, _), vector) in BUILTIN_VECTORS.iter().zip(&vectors) {
+            assert_eq!(*name, vector.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn echo_prefers_node_data_over_connected_inputs() {
+        let executor = ConformanceTaskExecutor::new(&vector("edge_wins_over_graph_input"));
+
+        let outputs = run(
+            &executor,
+            "sink",
+            HashMap::from([
+                ("text".to_string(), serde_json::json!("from edge")),
+                (
+                    "_data".to_string(),
+                    serde_json::json!({"text": "from data"}),
+                ),
+            ]),
+        )
+        .await
+        .expect("echo");
+
+        assert_eq!(outputs["text"], "from data");
+        assert_eq!(executor.executed(), vec!["sink".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn fail_mock_returns_execution_failed() {
+        let executor = ConformanceTaskExecutor::new(&vector("failing_upstream"));
+
+        let error = run(&executor, "bad", HashMap::new())
+            .await
+            .expect_err("fail mock");
+
+        assert_eq!(error.kind(), "execution_failed");
+    }
+
+    #[test]
+    fn expectation_reports_missing_outputs_errors_and_events() {
+        let expected = vector("chain_outputs").expected;
+        let observation = ConformanceObservation {
+            result: Ok(HashMap::from([(
+                "sink.text".to_string(),
+                serde_json::json!("other"),
+            )])),
+            events: vec![serde_json::json!({"type": "taskCompleted", "taskId": "sink"})],
+        };
+
+        let mismatches = expected.mismatches(&observation, &["sink".to_string()]);
+
+        assert_eq!(mismatches.len(), 3, "{:?}", mismatches);
+        assert!(mismatches[0].starts_with("output 'sink.text' is \"other\""));
+        assert!(mismatches[1].starts_with("executed"));
+        assert!(mismatches[2].starts_with("event 'taskCompleted' for 'src'"));
+    }
+
+    #[test]
+    fn expectation_accepts_matching_error_kind() {
+        let expected = vector("failing_upstream").expected;
+        let observation = ConformanceObservation {
+            result: Err("execution_failed".to_string()),
+            events: Vec::new(),
+        };
+
+        assert!(expected
+            .mismatches(&observation, &["bad".to_string()])
+            .is_empty());
+    }
+}
@@ -12,23 +12,33 @@ use async_trait::async_trait;
 #[cfg(feature = "inference-nodes")]
 use inference::InferenceGateway;
 
+use crate::config::FilesystemPolicy;
 use crate::engine::TaskExecutor;
 use crate::error::{NodeEngineError, Result};
 use crate::events::EventSink;
 use crate::extensions::ExecutorExtensions;
+use crate::model_usage::ModelUsageLog;
 
+mod array_nodes;
 #[cfg(feature = "audio-nodes")]
 mod audio_nodes;
+#[cfg(feature = "inference-nodes")]
+mod benchmark_nodes;
+mod clock_nodes;
 mod dependency_preflight;
 mod file_io;
 #[cfg(feature = "inference-nodes")]
 mod inference_nodes;
+mod json_schema;
 #[cfg(feature = "inference-nodes")]
 mod kv_cache;
 #[cfg(feature = "inference-nodes")]
 mod llamacpp_nodes;
+#[cfg(feature = "mock-nodes")]
+mod mock_nodes;
 mod model_nodes;
 mod ollama;
+mod predicate;
 mod processing_nodes;
 mod pure_nodes;
 #[cfg(feature = "pytorch-nodes")]
@@ -36,14 +46,21 @@ mod pytorch_nodes;
 #[cfg(feature = "inference-nodes")]
 mod retrieval_nodes;
 mod settings;
+pub(crate) use array_nodes::*;
 #[cfg(feature = "audio-nodes")]
 pub(crate) use audio_nodes::*;
+#[cfg(feature = "inference-nodes")]
+pub(crate) use benchmark_nodes::*;
+pub(crate) use clock_nodes::*;
 pub(crate) use dependency_preflight::*;
 pub(crate) use file_io::*;
 #[cfg(feature = "inference-nodes")]
 pub(crate) use inference_nodes::*;
+pub(crate) use json_schema::*;
 #[cfg(feature = "inference-nodes")]
 pub(crate) use llamacpp_nodes::*;
+#[cfg(feature = "mock-nodes")]
+pub(crate) use mock_nodes::*;
 pub(crate) use model_nodes::*;
 pub(crate) use ollama::*;
 pub(crate) use processing_nodes::*;
@@ -88,6 +105,8 @@ pub struct CoreTaskExecutor {
     event_sink: Option<Arc<dyn EventSink>>,
     /// Execution ID for event correlation.
     execution_id: Option<String>,
+    /// Latest pass of each `throttle` node, shared across executions.
+    throttles: ThrottleSlots,
 }
 
 impl CoreTaskExecutor {
@@ -99,6 +118,7 @@ impl CoreTaskExecutor {
             gateway: None,
             event_sink: None,
             execution_id: None,
+            throttles: ThrottleSlots::default(),
         }
     }
 
@@ -126,6 +146,31 @@ impl CoreTaskExecutor {
         self.execution_id = Some(id);
         self
     }
+
+    /// Root for file I/O nodes: the configured filesystem policy's root,
+    /// falling back to the project root.
+    fn file_root<'a>(&'a self, extensions: &'a ExecutorExtensions) -> Option<&'a PathBuf> {
+        filesystem_policy(extensions)
+            .and_then(|policy| policy.root.as_ref())
+            .or(self.project_root.as_ref())
+    }
+}
+
+fn filesystem_policy(extensions: &ExecutorExtensions) -> Option<&FilesystemPolicy> {
+    extensions
+        .get::<Arc<FilesystemPolicy>>(crate::extension_keys::FILESYSTEM_POLICY)
+        .map(Arc::as_ref)
+}
+
+/// Record the selected model in the installed usage log, if any.
+fn record_model_use(outputs: &HashMap<String, serde_json::Value>, extensions: &ExecutorExtensions) {
+    let Some(usage) = extensions.get::<Arc<ModelUsageLog>>(crate::extension_keys::MODEL_USAGE)
+    else {
+        return;
+    };
+    if let Some(model_id) = outputs.get("model_id").and_then(|value| value.as_str()) {
+        usage.record_use(model_id);
+    }
 }
 
 impl Default for CoreTaskExecutor {
@@ -168,7 +213,6 @@ impl TaskExecutor for CoreTaskExecutor {
         extensions: &ExecutorExtensions,
     ) -> Result<HashMap<String, serde_json::Value>> {
         let node_type = resolve_node_type(task_id, &inputs);
-        let _ = extensions;
 
         log::debug!(
             "CoreTaskExecutor: executing '{}' (type '{}')",
@@ -198,27 +242,46 @@ impl TaskExecutor for CoreTaskExecutor {
 
             // Model/provider nodes
             "model-provider" => execute_model_provider(&inputs),
-            "puma-lib" => execute_puma_lib(&inputs),
+            "puma-lib" => {
+                execute_puma_lib(&inputs).inspect(|outputs| record_model_use(outputs, extensions))
+            }
 
             // Control flow nodes
             "conditional" => execute_conditional(&inputs),
             "merge" => execute_merge(&inputs),
+            "delay" => execute_delay(&inputs, extensions).await,
+            "throttle" => execute_throttle(task_id, &inputs, extensions, &self.throttles).await,
 
             // Processing nodes
             "validator" => execute_validator(&inputs),
             "json-filter" => execute_json_filter(&inputs),
+            "json-schema-validate" => execute_json_schema_validate(&inputs),
+            "random" => execute_random(&inputs, extensions),
+
+            // Array nodes
+            "aggregate" => execute_aggregate(&inputs),
+            "sort" => execute_sort(&inputs),
+            "filter" => execute_filter(&inputs),
+            "join" => execute_join(&inputs),
             "expand-settings" => execute_expand_settings(&inputs),
 
             // File I/O nodes
-            "read-file" => execute_read_file(self.project_root.as_ref(), &inputs).await,
-            "write-file" => execute_write_file(self.project_root.as_ref(), &inputs).await,
+            "read-file" => execute_read_file(self.file_root(extensions), &inputs).await,
+            "write-file" => {
+                if filesystem_policy(extensions).is_some_and(|policy| policy.read_only) {
+                    return Err(NodeEngineError::ExecutionFailed(
+                        "write-file is disabled by the read-only filesystem policy".to_string(),
+                    ));
+                }
+                execute_write_file(self.file_root(extensions), &inputs).await
+            }
 
             // Interaction nodes
             "human-input" => execute_human_input(&inputs),
             "tool-executor" => execute_tool_executor(&inputs),
 
             // Pure HTTP inference
-            "ollama-inference" => execute_ollama_inference(&inputs).await,
+            "ollama-inference" => execute_ollama_inference(&inputs, extensions).await,
 
             // Gateway-backed inference nodes (require `inference-nodes` feature)
             #[cfg(feature = "inference-nodes")]
@@ -257,6 +320,19 @@ impl TaskExecutor for CoreTaskExecutor {
             "vision-analysis" => execute_vision_analysis(self.gateway.as_ref(), &inputs).await,
             #[cfg(feature = "inference-nodes")]
             "unload-model" => execute_unload_model(self.gateway.as_ref(), &inputs).await,
+            #[cfg(feature = "inference-nodes")]
+            "model-benchmark" => {
+                let exec_id = self.execution_id.as_deref().unwrap_or("unknown");
+                execute_model_benchmark(
+                    self.gateway.as_ref(),
+                    &inputs,
+                    task_id,
+                    self.event_sink.as_ref(),
+                    exec_id,
+                    extensions,
+                )
+                .await
+            }
 
             // KV cache operations (require inference-nodes feature)
             #[cfg(feature = "inference-nodes")]
@@ -304,6 +380,14 @@ impl TaskExecutor for CoreTaskExecutor {
                 execute_audio_generation(&inputs, resolved_model_ref).await
             }
 
+            // Mock backends for examples and tests (require `mock-nodes` feature)
+            #[cfg(feature = "mock-nodes")]
+            "mock-llm" => execute_mock_llm(&inputs, extensions).await,
+            #[cfg(feature = "mock-nodes")]
+            "mock-embedding" => execute_mock_embedding(&inputs, extensions).await,
+            #[cfg(feature = "mock-nodes")]
+            "mock-http" => execute_mock_http(&inputs, extensions).await,
+
             // Unknown — signal that this node requires a host-specific executor
             _ => Err(NodeEngineError::ExecutionFailed(format!(
                 "Node type '{}' requires host-specific executor",
@@ -311,6 +395,15 @@ impl TaskExecutor for CoreTaskExecutor {
             ))),
         }
     }
+
+    /// Stop the inference gateway so its server process exits with the
+    /// executor. A later llama.cpp demand restarts it.
+    async fn shutdown(&self) {
+        #[cfg(feature = "inference-nodes")]
+        if let Some(gateway) = &self.gateway {
+            gateway.stop().await;
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
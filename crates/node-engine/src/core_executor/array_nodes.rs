@@ -0,0 +1,400 @@
+use std::collections::HashMap;
+
+use super::predicate::Predicate;
+use super::processing_nodes::extract_json_path;
+use crate::error::{NodeEngineError, Result};
+
+/// Read a JSON array input, accepting JSON-encoded strings from text ports.
+pub(crate) fn read_array_input(
+    inputs: &HashMap<String, serde_json::Value>,
+    port: &str,
+) -> Result<Vec<serde_json::Value>> {
+    let value = inputs
+        .get(port)
+        .ok_or_else(|| NodeEngineError::ExecutionFailed(format!("Missing {} input", port)))?;
+
+    let parsed;
+    let value = match value {
+        serde_json::Value::String(raw) => {
+            parsed = serde_json::from_str::<serde_json::Value>(raw).map_err(|_| {
+                NodeEngineError::InvalidInputType {
+                    port: port.to_string(),
+                    expected: "JSON array".to_string(),
+                }
+            })?;
+            &parsed
+        }
+        other => other,
+    };
+
+    value
+        .as_array()
+        .cloned()
+        .ok_or_else(|| NodeEngineError::InvalidInputType {
+            port: port.to_string(),
+            expected: "JSON array".to_string(),
+        })
+}
+
+/// Read a string setting from node data.
+pub(crate) fn data_str<'a>(
+    inputs: &'a HashMap<String, serde_json::Value>,
+    key: &str,
+) -> Option<&'a str> {
+    inputs
+        .get("_data")
+        .and_then(|d| d.get(key))
+        .and_then(|v| v.as_str())
+}
+
+/// Resolve a field on an array element.
+///
+/// Paths use `json-filter` notation; a leading JSONPath root (`$` or `$.`) is
+/// accepted and ignored so paths copied from JSONPath tooling keep working.
+/// An empty path selects the element itself. Missing fields resolve to `None`.
+pub(crate) fn field_value(item: &serde_json::Value, path: &str) -> Option<serde_json::Value> {
+    let path = path.trim();
+    let path = path
+        .strip_prefix("$.")
+        .or_else(|| path.strip_prefix('$'))
+        .unwrap_or(path);
+    let (value, found) = extract_json_path(item, path);
+    found.then_some(value)
+}
+
+/// Coerce a JSON value to a finite number, accepting numeric strings.
+pub(crate) fn numeric_value(value: &serde_json::Value) -> Option<f64> {
+    let number = match value {
+        serde_json::Value::Number(n) => n.as_f64()?,
+        serde_json::Value::String(s) => s.trim().parse::<f64>().ok()?,
+        _ => return None,
+    };
+    number.is_finite().then_some(number)
+}
+
+/// Render a JSON value as display text: strings without quotes, everything
+/// else as compact JSON.
+pub(crate) fn display_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+pub(crate) fn execute_aggregate(
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<HashMap<String, serde_json::Value>> {
+    let items = read_array_input(inputs, "items")?;
+    let operation = data_str(inputs, "operation").unwrap_or("count");
+    let field = data_str(inputs, "field").unwrap_or("");
+
+    // Field values for every element that has the field; nulls are treated as
+    // missing so sparse records do not skew numeric aggregates.
+    let values: Vec<serde_json::Value> = items
+        .iter()
+        .filter_map(|item| field_value(item, field))
+        .filter(|value| !value.is_null())
+        .collect();
+
+    let result = match operation {
+        "count" => serde_json::json!(values.len()),
+        "sum" => serde_json::json!(numeric_values(&values, operation)?.iter().sum::<f64>()),
+        "avg" => {
+            let numbers = numeric_values(&values, operation)?;
+            if numbers.is_empty() {
+                serde_json::Value::Null
+            } else {
+                serde_json::json!(numbers.iter().sum::<f64>() / numbers.len() as f64)
+            }
+        }
+        "min" => extreme_value(&values, std::cmp::Ordering::Less)?,
+        "max" => extreme_value(&values, std::cmp::Ordering::Greater)?,
+        "distinct" => {
+            let mut distinct: Vec<serde_json::Value> = Vec::new();
+            for value in values.iter() {
+                if !distinct.contains(value) {
+                    distinct.push(value.clone());
+                }
+            }
+            serde_json::json!(distinct)
+        }
+        "group-by" => group_by(&items, field),
+        "concat" => {
+            let separator = data_str(inputs, "separator").unwrap_or(", ");
+            let parts: Vec<String> = values.iter().map(display_value).collect();
+            serde_json::json!(parts.join(separator))
+        }
+        other => {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Unknown aggregate operation '{}'",
+                other
+            )))
+        }
+    };
+
+    let mut outputs = HashMap::new();
+    outputs.insert("result".to_string(), result);
+    outputs.insert("count".to_string(), serde_json::json!(items.len()));
+    Ok(outputs)
+}
+
+fn numeric_values(values: &[serde_json::Value], operation: &str) -> Result<Vec<f64>> {
+    values
+        .iter()
+        .map(|value| {
+            numeric_value(value).ok_or_else(|| {
+                NodeEngineError::ExecutionFailed(format!(
+                    "Aggregate '{}' expects numeric values, got {}",
+                    operation, value
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Pick the minimum or maximum value. All-numeric inputs compare numerically,
+/// all-string inputs compare lexicographically; mixed inputs are rejected.
+fn extreme_value(
+    values: &[serde_json::Value],
+    keep: std::cmp::Ordering,
+) -> Result<serde_json::Value> {
+    if values.iter().all(|v| v.is_number()) {
+        let best = values
+            .iter()
+            .filter_map(|v| v.as_f64().map(|n| (n, v)))
+            .reduce(|best, next| {
+                if next.0.partial_cmp(&best.0) == Some(keep) {
+                    next
+                } else {
+                    best
+                }
+            });
+        return Ok(best.map(|(_, v)| v.clone()).unwrap_or_default());
+    }
+
+    if values.iter().all(|v| v.is_string()) {
+        let best = values
+            .iter()
+            .filter_map(|v| v.as_str())
+            .reduce(|best, next| if next.cmp(best) == keep { next } else { best });
+        return Ok(best.map(|s| serde_json::json!(s)).unwrap_or_default());
+    }
+
+    Err(NodeEngineError::ExecutionFailed(
+        "Aggregate min/max expects all numbers or all strings".to_string(),
+    ))
+}
+
+/// Group elements by a field, preserving first-seen key order.
+///
+/// Emits `[{ "key": <value>, "items": [...], "count": n }]` so non-string keys
+/// survive intact. Elements without the field are grouped under `null`.
+fn group_by(items: &[serde_json::Value], field: &str) -> serde_json::Value {
+    let mut groups: Vec<(serde_json::Value, Vec<serde_json::Value>)> = Vec::new();
+    for item in items {
+        let key = field_value(item, field).unwrap_or(serde_json::Value::Null);
+        match groups.iter().position(|(existing, _)| *existing == key) {
+            Some(index) => groups[index].1.push(item.clone()),
+            None => groups.push((key, vec![item.clone()])),
+        }
+    }
+
+    serde_json::Value::Array(
+        groups
+            .into_iter()
+            .map(|(key, members)| {
+                serde_json::json!({
+                    "key": key,
+                    "count": members.len(),
+                    "items": members,
+                })
+            })
+            .collect(),
+    )
+}
+
+pub(crate) fn execute_sort(
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<HashMap<String, serde_json::Value>> {
+    let mut items = read_array_input(inputs, "items")?;
+    let field = data_str(inputs, "field").unwrap_or("");
+    let descending = match data_str(inputs, "order").unwrap_or("asc") {
+        "asc" => false,
+        "desc" => true,
+        other => {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Unknown sort order '{}'",
+                other
+            )))
+        }
+    };
+    let numeric = match data_str(inputs, "compare").unwrap_or("lexicographic") {
+        "lexicographic" => false,
+        "numeric" => true,
+        other => {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Unknown sort comparison '{}'",
+                other
+            )))
+        }
+    };
+
+    // Elements whose key is missing or not comparable in the selected mode sort
+    // last in both directions; the sort is stable so ties keep input order.
+    items.sort_by(|a, b| {
+        let key_a = sort_key(a, field, numeric);
+        let key_b = sort_key(b, field, numeric);
+        match (key_a, key_b) {
+            (Some(x), Some(y)) => {
+                let ordering = x.compare(&y);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    });
+
+    let mut outputs = HashMap::new();
+    outputs.insert("count".to_string(), serde_json::json!(items.len()));
+    outputs.insert("items".to_string(), serde_json::Value::Array(items));
+    Ok(outputs)
+}
+
+enum SortKey {
+    Number(f64),
+    Text(String),
+}
+
+impl SortKey {
+    fn compare(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.total_cmp(b),
+            (Self::Text(a), Self::Text(b)) => a.cmp(b),
+            _ => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+fn sort_key(item: &serde_json::Value, field: &str, numeric: bool) -> Option<SortKey> {
+    let value = field_value(item, field).filter(|value| !value.is_null())?;
+    if numeric {
+        numeric_value(&value).map(SortKey::Number)
+    } else {
+        Some(SortKey::Text(display_value(&value)))
+    }
+}
+
+pub(crate) fn execute_filter(
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<HashMap<String, serde_json::Value>> {
+    let items = read_array_input(inputs, "items")?;
+    let expression = data_str(inputs, "expression")
+        .filter(|e| !e.trim().is_empty())
+        .ok_or_else(|| NodeEngineError::ExecutionFailed("Missing filter expression".to_string()))?;
+    let predicate = Predicate::parse(expression)?;
+
+    let (matched, rejected): (Vec<_>, Vec<_>) =
+        items.into_iter().partition(|item| predicate.matches(item));
+
+    let mut outputs = HashMap::new();
+    outputs.insert("count".to_string(), serde_json::json!(matched.len()));
+    outputs.insert("items".to_string(), serde_json::Value::Array(matched));
+    outputs.insert("rejected".to_string(), serde_json::Value::Array(rejected));
+    Ok(outputs)
+}
+
+pub(crate) fn execute_join(
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<HashMap<String, serde_json::Value>> {
+    let left = read_array_input(inputs, "left")?;
+    let right = read_array_input(inputs, "right")?;
+    let mode = data_str(inputs, "mode").unwrap_or("zip");
+
+    let merged: Vec<serde_json::Value> = match mode {
+        "zip" => left
+            .iter()
+            .zip(right.iter())
+            .map(|(l, r)| merge_pair(l, Some(r)))
+            .collect(),
+        "inner" | "left" => {
+            let shared_key = data_str(inputs, "key").unwrap_or("");
+            let left_key = data_str(inputs, "left_key").unwrap_or(shared_key);
+            let right_key = data_str(inputs, "right_key").unwrap_or(shared_key);
+            if left_key.is_empty() || right_key.is_empty() {
+                return Err(NodeEngineError::ExecutionFailed(format!(
+                    "Join mode '{}' requires a key field",
+                    mode
+                )));
+            }
+            join_on_key(&left, &right, left_key, right_key, mode == "left")
+        }
+        other => {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Unknown join mode '{}'",
+                other
+            )))
+        }
+    };
+
+    let mut outputs = HashMap::new();
+    outputs.insert("count".to_string(), serde_json::json!(merged.len()));
+    outputs.insert("items".to_string(), serde_json::Value::Array(merged));
+    Ok(outputs)
+}
+
+/// Merge a left element with its right counterpart.
+///
+/// Two objects merge into one object with right-hand fields winning on
+/// conflict. Any other pairing is wrapped as `{ "left": .., "right": .. }` so
+/// scalar arrays (e.g. reranker scores) can still be correlated.
+fn merge_pair(left: &serde_json::Value, right: Option<&serde_json::Value>) -> serde_json::Value {
+    match (left, right) {
+        (serde_json::Value::Object(l), Some(serde_json::Value::Object(r))) => {
+            let mut merged = l.clone();
+            for (key, value) in r {
+                merged.insert(key.clone(), value.clone());
+            }
+            serde_json::Value::Object(merged)
+        }
+        (serde_json::Value::Object(_), None) => left.clone(),
+        (l, r) => serde_json::json!({
+            "left": l,
+            "right": r.cloned().unwrap_or(serde_json::Value::Null),
+        }),
+    }
+}
+
+/// Hash-join two arrays on key fields, preserving left order and emitting one
+/// row per matching right element. Elements missing the key never match.
+fn join_on_key(
+    left: &[serde_json::Value],
+    right: &[serde_json::Value],
+    left_key: &str,
+    right_key: &str,
+    keep_unmatched: bool,
+) -> Vec<serde_json::Value> {
+    let mut index: HashMap<String, Vec<&serde_json::Value>> = HashMap::new();
+    for item in right {
+        if let Some(key) = field_value(item, right_key).filter(|k| !k.is_null()) {
+            index.entry(key.to_string()).or_default().push(item);
+        }
+    }
+
+    let mut merged = Vec::new();
+    for item in left {
+        let matches = field_value(item, left_key)
+            .filter(|k| !k.is_null())
+            .and_then(|key| index.get(&key.to_string()));
+        match matches {
+            Some(rows) => merged.extend(rows.iter().map(|row| merge_pair(item, Some(*row)))),
+            None if keep_unmatched => merged.push(merge_pair(item, None)),
+            None => {}
+        }
+    }
+    merged
+}
@@ -0,0 +1,347 @@
+use super::super::*;
+
+fn aggregate_inputs(
+    items: serde_json::Value,
+    data: serde_json::Value,
+) -> HashMap<String, serde_json::Value> {
+    let mut inputs = HashMap::new();
+    inputs.insert("items".to_string(), items);
+    inputs.insert("_data".to_string(), data);
+    inputs
+}
+
+fn scored_docs() -> serde_json::Value {
+    serde_json::json!([
+        {"id": "a", "topic": "rust", "score": 0.9},
+        {"id": "b", "topic": "python", "score": 0.4},
+        {"id": "c", "topic": "rust", "score": 0.5},
+        {"id": "d", "topic": "go"}
+    ])
+}
+
+#[test]
+fn test_aggregate_count_defaults_to_all_items() {
+    let inputs = aggregate_inputs(scored_docs(), serde_json::json!({}));
+    let result = execute_aggregate(&inputs).unwrap();
+    assert_eq!(result["result"], 4);
+    assert_eq!(result["count"], 4);
+}
+
+#[test]
+fn test_aggregate_count_field_skips_missing_values() {
+    let inputs = aggregate_inputs(
+        scored_docs(),
+        serde_json::json!({"operation": "count", "field": "score"}),
+    );
+    let result = execute_aggregate(&inputs).unwrap();
+    assert_eq!(result["result"], 3);
+}
+
+#[test]
+fn test_aggregate_sum_and_avg() {
+    let sum = execute_aggregate(&aggregate_inputs(
+        serde_json::json!([{"n": 1}, {"n": "2.5"}, {"n": null}]),
+        serde_json::json!({"operation": "sum", "field": "n"}),
+    ))
+    .unwrap();
+    assert_eq!(sum["result"], 3.5);
+
+    let avg = execute_aggregate(&aggregate_inputs(
+        serde_json::json!([2, 4, 9]),
+        serde_json::json!({"operation": "avg"}),
+    ))
+    .unwrap();
+    assert_eq!(avg["result"], 5.0);
+}
+
+#[test]
+fn test_aggregate_avg_of_empty_is_null() {
+    let result = execute_aggregate(&aggregate_inputs(
+        serde_json::json!([]),
+        serde_json::json!({"operation": "avg"}),
+    ))
+    .unwrap();
+    assert!(result["result"].is_null());
+}
+
+#[test]
+fn test_aggregate_sum_rejects_non_numeric_values() {
+    let error = execute_aggregate(&aggregate_inputs(
+        serde_json::json!(["one", 2]),
+        serde_json::json!({"operation": "sum"}),
+    ))
+    .expect_err("non-numeric values should fail");
+    assert!(error.to_string().contains("expects numeric values"));
+}
+
+#[test]
+fn test_aggregate_min_max() {
+    let max = execute_aggregate(&aggregate_inputs(
+        scored_docs(),
+        serde_json::json!({"operation": "max", "field": "$.score"}),
+    ))
+    .unwrap();
+    assert_eq!(max["result"], 0.9);
+
+    let min = execute_aggregate(&aggregate_inputs(
+        scored_docs(),
+        serde_json::json!({"operation": "min", "field": "topic"}),
+    ))
+    .unwrap();
+    assert_eq!(min["result"], "go");
+}
+
+#[test]
+fn test_aggregate_distinct_preserves_first_seen_order() {
+    let result = execute_aggregate(&aggregate_inputs(
+        scored_docs(),
+        serde_json::json!({"operation": "distinct", "field": "topic"}),
+    ))
+    .unwrap();
+    assert_eq!(
+        result["result"],
+        serde_json::json!(["rust", "python", "go"])
+    );
+}
+
+#[test]
+fn test_aggregate_group_by() {
+    let result = execute_aggregate(&aggregate_inputs(
+        scored_docs(),
+        serde_json::json!({"operation": "group-by", "field": "topic"}),
+    ))
+    .unwrap();
+    let groups = result["result"].as_array().unwrap();
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[0]["key"], "rust");
+    assert_eq!(groups[0]["count"], 2);
+    assert_eq!(groups[0]["items"][1]["id"], "c");
+}
+
+#[test]
+fn test_aggregate_concat_with_separator() {
+    let result = execute_aggregate(&aggregate_inputs(
+        scored_docs(),
+        serde_json::json!({"operation": "concat", "field": "id", "separator": " | "}),
+    ))
+    .unwrap();
+    assert_eq!(result["result"], "a | b | c | d");
+}
+
+#[test]
+fn test_aggregate_accepts_json_string_items() {
+    let result = execute_aggregate(&aggregate_inputs(
+        serde_json::json!("[1, 2, 3]"),
+        serde_json::json!({"operation": "sum"}),
+    ))
+    .unwrap();
+    assert_eq!(result["result"], 6.0);
+}
+
+#[test]
+fn test_aggregate_rejects_unknown_operation() {
+    let error = execute_aggregate(&aggregate_inputs(
+        serde_json::json!([]),
+        serde_json::json!({"operation": "median"}),
+    ))
+    .expect_err("unknown operation should fail");
+    assert!(error.to_string().contains("median"));
+}
+
+fn sort_inputs(data: serde_json::Value) -> HashMap<String, serde_json::Value> {
+    aggregate_inputs(scored_docs(), data)
+}
+
+fn ids(value: &serde_json::Value) -> Vec<&str> {
+    value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["id"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_sort_numeric_desc_puts_missing_last() {
+    let result = execute_sort(&sort_inputs(serde_json::json!({
+        "field": "score",
+        "order": "desc",
+        "compare": "numeric"
+    })))
+    .unwrap();
+    assert_eq!(ids(&result["items"]), vec!["a", "c", "b", "d"]);
+    assert_eq!(result["count"], 4);
+}
+
+#[test]
+fn test_sort_lexicographic_is_stable() {
+    let result = execute_sort(&sort_inputs(serde_json::json!({"field": "$.topic"}))).unwrap();
+    assert_eq!(ids(&result["items"]), vec!["d", "b", "a", "c"]);
+}
+
+#[test]
+fn test_sort_numeric_vs_lexicographic() {
+    let items = serde_json::json!([{"n": 10}, {"n": 9}, {"n": "100"}]);
+    let numeric = execute_sort(&aggregate_inputs(
+        items.clone(),
+        serde_json::json!({"field": "n", "compare": "numeric"}),
+    ))
+    .unwrap();
+    assert_eq!(
+        numeric["items"],
+        serde_json::json!([{"n": 9}, {"n": 10}, {"n": "100"}])
+    );
+
+    let lexicographic =
+        execute_sort(&aggregate_inputs(items, serde_json::json!({"field": "n"}))).unwrap();
+    assert_eq!(
+        lexicographic["items"],
+        serde_json::json!([{"n": 10}, {"n": "100"}, {"n": 9}])
+    );
+}
+
+#[test]
+fn test_sort_rejects_unknown_order() {
+    let error = execute_sort(&sort_inputs(serde_json::json!({"order": "sideways"})))
+        .expect_err("unknown order should fail");
+    assert!(error.to_string().contains("sideways"));
+}
+
+#[test]
+fn test_filter_partitions_items() {
+    let result = execute_filter(&sort_inputs(serde_json::json!({
+        "expression": "score >= 0.5 && topic == 'rust'"
+    })))
+    .unwrap();
+    assert_eq!(ids(&result["items"]), vec!["a", "c"]);
+    assert_eq!(ids(&result["rejected"]), vec!["b", "d"]);
+    assert_eq!(result["count"], 2);
+}
+
+#[test]
+fn test_filter_or_not_and_missing_fields() {
+    let result = execute_filter(&sort_inputs(serde_json::json!({
+        "expression": "!(score) || topic == \"python\""
+    })))
+    .unwrap();
+    assert_eq!(ids(&result["items"]), vec!["b", "d"]);
+}
+
+#[test]
+fn test_filter_contains_and_element_reference() {
+    let result = execute_filter(&aggregate_inputs(
+        serde_json::json!(["alpha", "beta", "gamma"]),
+        serde_json::json!({"expression": "@ contains 'ta' or @ == 'alpha'"}),
+    ))
+    .unwrap();
+    assert_eq!(result["items"], serde_json::json!(["alpha", "beta"]));
+}
+
+#[test]
+fn test_filter_requires_expression() {
+    let error = execute_filter(&sort_inputs(serde_json::json!({"expression": "  "})))
+        .expect_err("expression is required");
+    assert!(error.to_string().contains("Missing filter expression"));
+}
+
+#[test]
+fn test_filter_rejects_invalid_expression() {
+    let error = execute_filter(&sort_inputs(serde_json::json!({"expression": "score >="})))
+        .expect_err("incomplete expression should fail");
+    assert!(error.to_string().contains("Invalid filter expression"));
+}
+
+#[test]
+fn test_predicate_parse_precedence() {
+    use super::super::predicate::Predicate;
+
+    let predicate = Predicate::parse("a == 1 || b == 2 && c == 3").unwrap();
+    assert!(matches!(predicate, Predicate::Or(_, _)));
+    assert!(predicate.matches(&serde_json::json!({"a": 1.0})));
+    assert!(!predicate.matches(&serde_json::json!({"b": 2})));
+    assert!(predicate.matches(&serde_json::json!({"b": 2, "c": 3})));
+}
+
+fn join_inputs(
+    left: serde_json::Value,
+    right: serde_json::Value,
+    data: serde_json::Value,
+) -> HashMap<String, serde_json::Value> {
+    let mut inputs = HashMap::new();
+    inputs.insert("left".to_string(), left);
+    inputs.insert("right".to_string(), right);
+    inputs.insert("_data".to_string(), data);
+    inputs
+}
+
+#[test]
+fn test_join_zip_pairs_scalars_and_truncates() {
+    let result = execute_join(&join_inputs(
+        serde_json::json!(["doc a", "doc b", "doc c"]),
+        serde_json::json!([0.9, 0.2]),
+        serde_json::json!({}),
+    ))
+    .unwrap();
+    assert_eq!(
+        result["items"],
+        serde_json::json!([
+            {"left": "doc a", "right": 0.9},
+            {"left": "doc b", "right": 0.2}
+        ])
+    );
+    assert_eq!(result["count"], 2);
+}
+
+#[test]
+fn test_join_zip_merges_objects() {
+    let result = execute_join(&join_inputs(
+        serde_json::json!([{"id": "a", "text": "alpha"}]),
+        serde_json::json!([{"score": 0.7}]),
+        serde_json::json!({"mode": "zip"}),
+    ))
+    .unwrap();
+    assert_eq!(
+        result["items"],
+        serde_json::json!([{"id": "a", "text": "alpha", "score": 0.7}])
+    );
+}
+
+#[test]
+fn test_join_inner_on_key() {
+    let result = execute_join(&join_inputs(
+        serde_json::json!([{"id": 1, "text": "one"}, {"id": 2, "text": "two"}]),
+        serde_json::json!([{"doc_id": 2, "score": 0.8}, {"doc_id": 3, "score": 0.1}]),
+        serde_json::json!({"mode": "inner", "left_key": "id", "right_key": "doc_id"}),
+    ))
+    .unwrap();
+    assert_eq!(
+        result["items"],
+        serde_json::json!([{"id": 2, "doc_id": 2, "text": "two", "score": 0.8}])
+    );
+}
+
+#[test]
+fn test_join_left_keeps_unmatched_and_duplicates_matches() {
+    let result = execute_join(&join_inputs(
+        serde_json::json!([{"k": "x"}, {"k": "y"}]),
+        serde_json::json!([{"k": "x", "v": 1}, {"k": "x", "v": 2}]),
+        serde_json::json!({"mode": "left", "key": "k"}),
+    ))
+    .unwrap();
+    assert_eq!(
+        result["items"],
+        serde_json::json!([{"k": "x", "v": 1}, {"k": "x", "v": 2}, {"k": "y"}])
+    );
+    assert_eq!(result["count"], 3);
+}
+
+#[test]
+fn test_join_key_modes_require_key() {
+    let error = execute_join(&join_inputs(
+        serde_json::json!([]),
+        serde_json::json!([]),
+        serde_json::json!({"mode": "inner"}),
+    ))
+    .expect_err("key is required");
+    assert!(error.to_string().contains("requires a key field"));
+}
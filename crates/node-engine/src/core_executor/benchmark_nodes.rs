@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use inference::InferenceGateway;
+use pantograph_runtime_identity::canonical_runtime_backend_key;
+
+use crate::error::{NodeEngineError, Result};
+use crate::events::EventSink;
+use crate::extensions::{extension_keys, ExecutorExtensions};
+use crate::model_benchmark::{
+    ModelBenchmarkResult, ModelBenchmarkStore, STANDARD_BENCHMARK_PROMPTS,
+};
+use crate::model_usage::now_ms;
+
+use super::{require_gateway, resolve_gguf_path};
+
+const DEFAULT_BENCHMARK_MAX_TOKENS: u64 = 128;
+const BENCHMARK_LOAD_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Timing of one benchmark prompt.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PromptTiming {
+    pub(crate) time_to_first_token: Duration,
+    /// Time from the first generated token to the end of the response.
+    pub(crate) generation: Duration,
+    pub(crate) tokens: u64,
+}
+
+pub(crate) async fn execute_model_benchmark(
+    gateway: Option<&Arc<InferenceGateway>>,
+    inputs: &HashMap<String, serde_json::Value>,
+    task_id: &str,
+    event_sink: Option<&Arc<dyn EventSink>>,
+    execution_id: &str,
+    extensions: &ExecutorExtensions,
+) -> Result<HashMap<String, serde_json::Value>> {
+    use futures_util::StreamExt;
+
+    let gw = require_gateway(gateway)?;
+
+    let model_path_raw = inputs
+        .get("model_path")
+        .and_then(|m| m.as_str())
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .ok_or_else(|| {
+            NodeEngineError::ExecutionFailed(
+                "Missing model_path input. Connect a Puma-Lib node.".to_string(),
+            )
+        })?;
+    let model_id = inputs
+        .get("model_id")
+        .and_then(|m| m.as_str())
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .map(str::to_string);
+    let prompts = parse_benchmark_prompts(inputs)?;
+    let max_tokens = inputs
+        .get("max_tokens")
+        .and_then(|m| m.as_u64())
+        .filter(|m| *m > 0)
+        .unwrap_or(DEFAULT_BENCHMARK_MAX_TOKENS);
+
+    if let Some(backend) = inputs
+        .get("backend")
+        .and_then(|b| b.as_str())
+        .map(str::trim)
+        .filter(|b| !b.is_empty())
+    {
+        let current = gw.current_backend_name().await;
+        if canonical_runtime_backend_key(&current) != canonical_runtime_backend_key(backend) {
+            gw.switch_backend(backend).await.map_err(|e| {
+                NodeEngineError::ExecutionFailed(format!(
+                    "Failed to switch to backend '{}': {}",
+                    backend, e
+                ))
+            })?;
+        }
+    }
+    let backend_name = gw.current_backend_name().await;
+    let backend_key = canonical_runtime_backend_key(&backend_name);
+
+    let mut config = inference::BackendConfig {
+        device: Some("auto".to_string()),
+        gpu_layers: Some(-1),
+        embedding_mode: false,
+        ..Default::default()
+    };
+    match backend_key.as_str() {
+        "ollama" => config.model_name = Some(model_path_raw.to_string()),
+        "llama_cpp" => {
+            config.model_path = Some(PathBuf::from(resolve_gguf_path(model_path_raw)?));
+        }
+        _ => config.model_path = Some(PathBuf::from(model_path_raw)),
+    }
+
+    // Load from a stopped runtime so load time and memory growth belong to
+    // this model rather than whatever was loaded before.
+    gw.stop().await;
+    let baseline_memory_mb = inference::system_used_memory_mb();
+    log::info!(
+        "ModelBenchmark: loading '{}' on backend '{}'",
+        model_path_raw,
+        backend_name
+    );
+    let load_started = Instant::now();
+    gw.start(&config).await.map_err(|e| {
+        NodeEngineError::ExecutionFailed(format!(
+            "Failed to start backend '{}' for benchmark: {}",
+            backend_name, e
+        ))
+    })?;
+    while !gw.is_ready().await {
+        if load_started.elapsed() > BENCHMARK_LOAD_TIMEOUT {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Timeout waiting for backend '{}' to load the benchmark model",
+                backend_name
+            )));
+        }
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+    let load_ms = load_started.elapsed().as_millis() as u64;
+    let memory_mb = inference::system_used_memory_mb().saturating_sub(baseline_memory_mb);
+
+    let mut timings = Vec::with_capacity(prompts.len());
+    for (index, prompt) in prompts.iter().enumerate() {
+        let request = serde_json::json!({
+            "model": "benchmark",
+            "messages": [{ "role": "user", "content": prompt }],
+            "max_tokens": max_tokens,
+            "temperature": 0.0,
+            "stream": true
+        });
+
+        let sent = Instant::now();
+        let mut stream = gw
+            .chat_completion_stream(request.to_string())
+            .await
+            .map_err(|e| {
+                NodeEngineError::ExecutionFailed(format!("Benchmark request failed: {}", e))
+            })?;
+        let mut first_token: Option<Instant> = None;
+        let mut tokens = 0u64;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| {
+                NodeEngineError::ExecutionFailed(format!("Benchmark stream failed: {}", e))
+            })?;
+            if chunk.content.as_deref().is_some_and(|c| !c.is_empty()) {
+                first_token.get_or_insert_with(Instant::now);
+                tokens += 1;
+            }
+            if chunk.done {
+                break;
+            }
+        }
+        let finished = Instant::now();
+        let first_token = first_token.unwrap_or(finished);
+        timings.push(PromptTiming {
+            time_to_first_token: first_token - sent,
+            generation: finished - first_token,
+            tokens,
+        });
+
+        if let Some(sink) = event_sink {
+            let _ = sink.send(crate::WorkflowEvent::task_progress(
+                task_id,
+                execution_id,
+                (index + 1) as f32 / prompts.len() as f32,
+                Some(format!("Benchmark prompt {}/{}", index + 1, prompts.len())),
+            ));
+        }
+    }
+
+    let (tokens_per_second, time_to_first_token_ms) = summarize_prompt_timings(&timings);
+    let result = ModelBenchmarkResult {
+        backend: backend_key,
+        model_path: model_path_raw.to_string(),
+        model_id: model_id.clone(),
+        prompt_count: timings.len(),
+        tokens_per_second,
+        time_to_first_token_ms,
+        memory_mb,
+        load_ms,
+        measured_at_ms: now_ms(),
+    };
+    log::info!(
+        "ModelBenchmark: '{}' on '{}': {:.1} tok/s, {:.0} ms to first token, {} MiB",
+        result.model_path,
+        result.backend,
+        result.tokens_per_second,
+        result.time_to_first_token_ms,
+        result.memory_mb
+    );
+
+    let mut stored = false;
+    if let (Some(model_id), Some(store)) = (
+        model_id.as_deref(),
+        extensions.get::<Arc<dyn ModelBenchmarkStore>>(extension_keys::MODEL_BENCHMARK_STORE),
+    ) {
+        match store.store_benchmark(model_id, &result).await {
+            Ok(()) => stored = true,
+            Err(error) => log::warn!(
+                "ModelBenchmark: failed to store result for '{}': {}",
+                model_id,
+                error
+            ),
+        }
+    }
+
+    let mut outputs = HashMap::new();
+    outputs.insert(
+        "tokens_per_second".to_string(),
+        serde_json::json!(result.tokens_per_second),
+    );
+    outputs.insert(
+        "time_to_first_token_ms".to_string(),
+        serde_json::json!(result.time_to_first_token_ms),
+    );
+    outputs.insert("memory_mb".to_string(), serde_json::json!(result.memory_mb));
+    outputs.insert("stored".to_string(), serde_json::json!(stored));
+    outputs.insert(
+        "benchmark".to_string(),
+        serde_json::to_value(&result).unwrap_or(serde_json::Value::Null),
+    );
+    Ok(outputs)
+}
+
+/// Read the `prompts` input: one prompt or an array of prompts. Falls back
+/// to [`STANDARD_BENCHMARK_PROMPTS`] when unset or empty.
+pub(crate) fn parse_benchmark_prompts(
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<Vec<String>> {
+    let prompts = match inputs.get("prompts") {
+        None | Some(serde_json::Value::Null) => Vec::new(),
+        Some(serde_json::Value::String(prompt)) => vec![prompt.clone()],
+        Some(serde_json::Value::Array(values)) => values
+            .iter()
+            .map(|value| {
+                value.as_str().map(str::to_string).ok_or_else(|| {
+                    NodeEngineError::ExecutionFailed(format!("Invalid benchmark prompt: {}", value))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?,
+        Some(other) => {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Invalid prompts input: {}",
+                other
+            )))
+        }
+    };
+    let prompts = prompts
+        .into_iter()
+        .filter(|prompt| !prompt.trim().is_empty())
+        .collect::<Vec<_>>();
+
+    if prompts.is_empty() {
+        Ok(STANDARD_BENCHMARK_PROMPTS
+            .iter()
+            .map(|prompt| prompt.to_string())
+            .collect())
+    } else {
+        Ok(prompts)
+    }
+}
+
+/// Overall tokens per second and mean time to first token in milliseconds.
+///
+/// Throughput counts tokens after the first one of each prompt over the
+/// generation time, so prompt processing only shows up in time to first token.
+pub(crate) fn summarize_prompt_timings(timings: &[PromptTiming]) -> (f64, f64) {
+    if timings.is_empty() {
+        return (0.0, 0.0);
+    }
+
+    let generated_tokens: u64 = timings
+        .iter()
+        .map(|timing| timing.tokens.saturating_sub(1))
+        .sum();
+    let generation_secs: f64 = timings
+        .iter()
+        .map(|timing| timing.generation.as_secs_f64())
+        .sum();
+    let tokens_per_second = if generation_secs > 0.0 {
+        generated_tokens as f64 / generation_secs
+    } else {
+        0.0
+    };
+    let time_to_first_token_ms = timings
+        .iter()
+        .map(|timing| timing.time_to_first_token.as_secs_f64() * 1000.0)
+        .sum::<f64>()
+        / timings.len() as f64;
+
+    (tokens_per_second, time_to_first_token_ms)
+}
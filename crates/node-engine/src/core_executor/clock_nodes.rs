@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::clock::{self, Rng, SeededRng};
+use crate::error::{NodeEngineError, Result};
+use crate::extensions::ExecutorExtensions;
+
+use super::read_optional_input_value;
+
+/// Read a number from an input port or node data, parsing numeric strings.
+pub(crate) fn read_optional_number(
+    inputs: &HashMap<String, serde_json::Value>,
+    key: &str,
+) -> Option<f64> {
+    read_optional_input_value(inputs, key).and_then(|value| match value {
+        serde_json::Value::String(raw) => raw.trim().parse().ok(),
+        other => other.as_f64(),
+    })
+}
+
+/// Pass `value` through after waiting `duration_ms` on the installed clock.
+pub(crate) async fn execute_delay(
+    inputs: &HashMap<String, serde_json::Value>,
+    extensions: &ExecutorExtensions,
+) -> Result<HashMap<String, serde_json::Value>> {
+    let duration_ms = read_optional_number(inputs, "duration_ms").unwrap_or(0.0);
+    let duration = Duration::try_from_secs_f64(duration_ms / 1000.0).map_err(|_| {
+        NodeEngineError::InvalidInputType {
+            port: "duration_ms".to_string(),
+            expected: "non-negative number of milliseconds".to_string(),
+        }
+    })?;
+    if !duration.is_zero() {
+        clock::clock(extensions).sleep(duration).await;
+    }
+
+    let value = inputs
+        .get("value")
+        .cloned()
+        .unwrap_or(serde_json::Value::Null);
+    Ok(HashMap::from([("value".to_string(), value)]))
+}
+
+/// Clock time of each throttle node's latest pass, keyed by node ID.
+#[derive(Debug, Default)]
+pub(crate) struct ThrottleSlots(Mutex<HashMap<String, Instant>>);
+
+/// Pass `value` through once `interval_ms` has elapsed on the installed clock
+/// since this node's previous pass.
+///
+/// The pass slot is reserved before waiting, so concurrent demands of the
+/// same node are spaced out instead of all waking at the same instant.
+pub(crate) async fn execute_throttle(
+    task_id: &str,
+    inputs: &HashMap<String, serde_json::Value>,
+    extensions: &ExecutorExtensions,
+    slots: &ThrottleSlots,
+) -> Result<HashMap<String, serde_json::Value>> {
+    let interval_ms = read_optional_number(inputs, "interval_ms").unwrap_or(0.0);
+    let interval = Duration::try_from_secs_f64(interval_ms / 1000.0).map_err(|_| {
+        NodeEngineError::InvalidInputType {
+            port: "interval_ms".to_string(),
+            expected: "non-negative number of milliseconds".to_string(),
+        }
+    })?;
+
+    let clock = clock::clock(extensions);
+    let now = clock.now();
+    let slot = {
+        let mut passes = slots.0.lock().unwrap_or_else(|e| e.into_inner());
+        let slot = passes
+            .get(task_id)
+            .map_or(now, |previous| (*previous + interval).max(now));
+        passes.insert(task_id.to_string(), slot);
+        slot
+    };
+    let wait = slot.saturating_duration_since(now);
+    if !wait.is_zero() {
+        clock.sleep(wait).await;
+    }
+
+    let value = inputs
+        .get("value")
+        .cloned()
+        .unwrap_or(serde_json::Value::Null);
+    Ok(HashMap::from([("value".to_string(), value)]))
+}
+
+/// Draw a number from a fixed `seed`, or from the installed generator.
+///
+/// `mode` is `float` (default, `[min, max)`, defaults `0` and `1`) or
+/// `integer` (`[min, max]` inclusive, defaults `0` and `100`).
+pub(crate) fn execute_random(
+    inputs: &HashMap<String, serde_json::Value>,
+    extensions: &ExecutorExtensions,
+) -> Result<HashMap<String, serde_json::Value>> {
+    let seeded;
+    let shared;
+    let rng: &dyn Rng = match read_optional_input_value(inputs, "seed").and_then(|s| s.as_i64()) {
+        Some(seed) if seed >= 0 => {
+            seeded = SeededRng::new(seed as u64);
+            &seeded
+        }
+        _ => {
+            shared = clock::rng(extensions);
+            shared.as_ref()
+        }
+    };
+
+    let mode = read_optional_input_value(inputs, "mode")
+        .and_then(|mode| mode.as_str().map(str::to_string))
+        .unwrap_or_else(|| "float".to_string());
+    let value = match mode.as_str() {
+        "float" => {
+            let min = read_optional_number(inputs, "min").unwrap_or(0.0);
+            let max = read_optional_number(inputs, "max").unwrap_or(1.0);
+            if !(min.is_finite() && max.is_finite()) || max < min {
+                return Err(NodeEngineError::ExecutionFailed(format!(
+                    "Invalid random range [{}, {})",
+                    min, max
+                )));
+            }
+            serde_json::json!(min + rng.next_f64() * (max - min))
+        }
+        "integer" => {
+            let min = read_optional_number(inputs, "min").unwrap_or(0.0).ceil() as i64;
+            let max = read_optional_number(inputs, "max").unwrap_or(100.0).floor() as i64;
+            if max < min {
+                return Err(NodeEngineError::ExecutionFailed(format!(
+                    "Invalid random range [{}, {}]",
+                    min, max
+                )));
+            }
+            serde_json::json!(rng.range_i64(min, max))
+        }
+        other => {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Unknown random mode '{}'",
+                other
+            )))
+        }
+    };
+    Ok(HashMap::from([("value".to_string(), value)]))
+}
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::super::*;
+use crate::clock::{Clock, Rng, SeededRng, SimulatedClock};
+use crate::extensions::extension_keys;
+
+fn simulated_extensions(clock: &Arc<SimulatedClock>, seed: u64) -> ExecutorExtensions {
+    let mut extensions = ExecutorExtensions::new();
+    extensions.set(extension_keys::CLOCK, clock.clone() as Arc<dyn Clock>);
+    extensions.set(
+        extension_keys::RNG,
+        Arc::new(SeededRng::new(seed)) as Arc<dyn Rng>,
+    );
+    extensions
+}
+
+fn node_inputs(data: serde_json::Value) -> HashMap<String, serde_json::Value> {
+    HashMap::from([("_data".to_string(), data)])
+}
+
+#[tokio::test]
+async fn test_delay_fast_forwards_simulated_clock() {
+    let clock = Arc::new(SimulatedClock::new());
+    let extensions = simulated_extensions(&clock, 0);
+    let mut inputs = node_inputs(serde_json::json!({"duration_ms": 600_000}));
+    inputs.insert("value".to_string(), serde_json::json!({"answer": 42}));
+
+    let started = std::time::Instant::now();
+    let result = execute_delay(&inputs, &extensions).await.unwrap();
+
+    assert!(started.elapsed() < Duration::from_secs(1));
+    assert_eq!(clock.elapsed(), Duration::from_secs(600));
+    assert_eq!(result["value"], serde_json::json!({"answer": 42}));
+}
+
+#[tokio::test]
+async fn test_delay_port_overrides_node_data() {
+    let clock = Arc::new(SimulatedClock::new());
+    let extensions = simulated_extensions(&clock, 0);
+    let mut inputs = node_inputs(serde_json::json!({"duration_ms": 5_000}));
+    inputs.insert("duration_ms".to_string(), serde_json::json!("250"));
+
+    execute_delay(&inputs, &extensions).await.unwrap();
+    assert_eq!(clock.elapsed(), Duration::from_millis(250));
+}
+
+#[tokio::test]
+async fn test_delay_rejects_negative_duration() {
+    let inputs = node_inputs(serde_json::json!({"duration_ms": -1}));
+    let error = execute_delay(&inputs, &ExecutorExtensions::new())
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), "invalid_input_type");
+}
+
+#[tokio::test]
+async fn test_throttle_spaces_passes_of_the_same_node() {
+    let clock = Arc::new(SimulatedClock::new());
+    let extensions = simulated_extensions(&clock, 0);
+    let slots = ThrottleSlots::default();
+    let mut inputs = node_inputs(serde_json::json!({"interval_ms": 1_000}));
+    inputs.insert("value".to_string(), serde_json::json!("request"));
+
+    let result = execute_throttle("throttle-1", &inputs, &extensions, &slots)
+        .await
+        .unwrap();
+    assert_eq!(result["value"], serde_json::json!("request"));
+    assert_eq!(clock.elapsed(), Duration::ZERO);
+
+    execute_throttle("throttle-1", &inputs, &extensions, &slots)
+        .await
+        .unwrap();
+    assert_eq!(clock.elapsed(), Duration::from_secs(1));
+
+    execute_throttle("throttle-2", &inputs, &extensions, &slots)
+        .await
+        .unwrap();
+    assert_eq!(clock.elapsed(), Duration::from_secs(1));
+
+    clock.advance(Duration::from_secs(5));
+    execute_throttle("throttle-1", &inputs, &extensions, &slots)
+        .await
+        .unwrap();
+    assert_eq!(clock.elapsed(), Duration::from_secs(6));
+}
+
+#[tokio::test]
+async fn test_throttle_rejects_negative_interval() {
+    let inputs = node_inputs(serde_json::json!({"interval_ms": -5}));
+    let error = execute_throttle(
+        "throttle-1",
+        &inputs,
+        &ExecutorExtensions::new(),
+        &ThrottleSlots::default(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(error.kind(), "invalid_input_type");
+}
+
+#[test]
+fn test_random_repeats_with_installed_generator_seed() {
+    let clock = Arc::new(SimulatedClock::new());
+    let draw = || {
+        let extensions = simulated_extensions(&clock, 9);
+        let inputs = node_inputs(serde_json::json!({"mode": "integer", "min": 1, "max": 6}));
+        (0..5)
+            .map(|_| execute_random(&inputs, &extensions).unwrap()["value"].clone())
+            .collect::<Vec<_>>()
+    };
+
+    let first = draw();
+    assert_eq!(first, draw());
+    assert!(first
+        .iter()
+        .all(|value| (1..=6).contains(&value.as_i64().unwrap())));
+}
+
+#[test]
+fn test_random_seed_input_wins_over_installed_generator() {
+    let clock = Arc::new(SimulatedClock::new());
+    let mut inputs = node_inputs(serde_json::json!({"min": 10, "max": 20}));
+    inputs.insert("seed".to_string(), serde_json::json!(5));
+
+    let a = execute_random(&inputs, &simulated_extensions(&clock, 1)).unwrap();
+    let b = execute_random(&inputs, &simulated_extensions(&clock, 2)).unwrap();
+
+    assert_eq!(a["value"], b["value"]);
+    let value = a["value"].as_f64().unwrap();
+    assert!((10.0..20.0).contains(&value));
+}
+
+#[test]
+fn test_random_rejects_unknown_mode_and_inverted_range() {
+    let extensions = ExecutorExtensions::new();
+    let error = execute_random(
+        &node_inputs(serde_json::json!({"mode": "gaussian"})),
+        &extensions,
+    )
+    .unwrap_err();
+    assert!(error.to_string().contains("Unknown random mode"));
+
+    let error = execute_random(
+        &node_inputs(serde_json::json!({"mode": "integer", "min": 5, "max": 1})),
+        &extensions,
+    )
+    .unwrap_err();
+    assert!(error.to_string().contains("Invalid random range"));
+}
@@ -23,6 +23,7 @@ pub(crate) fn require_gateway(
 /// Resolve a model path that may be a directory to the actual `.gguf` file inside.
 ///
 /// pumas-library stores directory paths; llama.cpp needs the `.gguf` file.
+/// Multimodal projectors stored alongside the model are skipped.
 #[cfg(feature = "inference-nodes")]
 pub(crate) fn resolve_gguf_path(path: &str) -> Result<String> {
     let p = std::path::Path::new(path);
@@ -35,11 +36,11 @@ pub(crate) fn resolve_gguf_path(path: &str) -> Result<String> {
                 ))
             })?
             .filter_map(|entry| entry.ok())
-            .find(|entry| {
-                entry
-                    .path()
-                    .extension()
+            .map(|entry| entry.path())
+            .find(|path| {
+                path.extension()
                     .is_some_and(|ext| ext.eq_ignore_ascii_case("gguf"))
+                    && !crate::mmproj::is_mmproj_file(path)
             })
             .ok_or_else(|| {
                 NodeEngineError::ExecutionFailed(format!(
@@ -47,7 +48,7 @@ pub(crate) fn resolve_gguf_path(path: &str) -> Result<String> {
                     path
                 ))
             })?;
-        Ok(gguf.path().to_string_lossy().into_owned())
+        Ok(gguf.to_string_lossy().into_owned())
     } else {
         Ok(path.to_string())
     }
@@ -169,3 +169,106 @@ fn test_parse_reranker_documents_input_accepts_json_string_alias() {
     let documents = parse_reranker_documents_input(&inputs).expect("documents_json should parse");
     assert_eq!(documents, vec!["alpha", "beta"]);
 }
+
+#[cfg(feature = "inference-nodes")]
+#[test]
+fn test_parse_lora_adapters_accepts_paths_and_scaled_objects() {
+    let mut inputs = HashMap::new();
+    inputs.insert(
+        "lora".to_string(),
+        serde_json::json!([
+            "/loras/style.gguf",
+            {"path": "/loras/tone.gguf", "scale": 0.5},
+            " "
+        ]),
+    );
+
+    let adapters = parse_lora_adapters(&inputs).unwrap();
+    assert_eq!(adapters.len(), 2);
+    assert_eq!(
+        adapters[0].path,
+        std::path::PathBuf::from("/loras/style.gguf")
+    );
+    assert_eq!(adapters[0].scale, 1.0);
+    assert_eq!(adapters[1].scale, 0.5);
+    assert!(parse_lora_adapters(&HashMap::new()).unwrap().is_empty());
+}
+
+#[cfg(feature = "inference-nodes")]
+#[test]
+fn test_lora_request_scales_disables_unrequested_adapters() {
+    let loaded = vec![
+        LoadedLoraAdapter {
+            id: 0,
+            path: "/loras/style.gguf".to_string(),
+        },
+        LoadedLoraAdapter {
+            id: 1,
+            path: "/loras/tone.gguf".to_string(),
+        },
+    ];
+    let requested = vec![inference::LoraAdapter {
+        path: "/loras/tone.gguf".into(),
+        scale: 0.5,
+    }];
+
+    assert_eq!(
+        lora_request_scales(&loaded, &requested).unwrap(),
+        serde_json::json!([{"id": 0, "scale": 0.0}, {"id": 1, "scale": 0.5}])
+    );
+
+    let missing = vec![inference::LoraAdapter {
+        path: "/loras/other.gguf".into(),
+        scale: 1.0,
+    }];
+    assert!(lora_request_scales(&loaded, &missing).is_err());
+}
+
+#[cfg(feature = "inference-nodes")]
+#[test]
+fn test_parse_benchmark_prompts_defaults_to_standard_set() {
+    assert_eq!(
+        parse_benchmark_prompts(&HashMap::new()).unwrap(),
+        crate::STANDARD_BENCHMARK_PROMPTS
+            .iter()
+            .map(|prompt| prompt.to_string())
+            .collect::<Vec<_>>()
+    );
+
+    let mut inputs = HashMap::new();
+    inputs.insert(
+        "prompts".to_string(),
+        serde_json::json!(["one", " ", "two"]),
+    );
+    assert_eq!(
+        parse_benchmark_prompts(&inputs).unwrap(),
+        vec!["one", "two"]
+    );
+
+    inputs.insert("prompts".to_string(), serde_json::json!([1, 2]));
+    assert!(parse_benchmark_prompts(&inputs).is_err());
+}
+
+#[cfg(feature = "inference-nodes")]
+#[test]
+fn test_summarize_prompt_timings_excludes_first_token_from_throughput() {
+    use std::time::Duration;
+
+    let timings = [
+        PromptTiming {
+            time_to_first_token: Duration::from_millis(100),
+            generation: Duration::from_secs(1),
+            tokens: 21,
+        },
+        PromptTiming {
+            time_to_first_token: Duration::from_millis(300),
+            generation: Duration::from_secs(3),
+            tokens: 61,
+        },
+    ];
+
+    let (tokens_per_second, time_to_first_token_ms) = summarize_prompt_timings(&timings);
+    assert!((tokens_per_second - 20.0).abs() < 1e-9);
+    assert!((time_to_first_token_ms - 200.0).abs() < 1e-9);
+    assert_eq!(summarize_prompt_timings(&[]), (0.0, 0.0));
+}
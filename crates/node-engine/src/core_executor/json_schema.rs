@@ -0,0 +1,444 @@
+use std::collections::HashMap;
+
+use crate::error::{NodeEngineError, Result};
+
+/// A single schema violation reported by the `json-schema-validate` node.
+///
+/// `path` uses the same dot/bracket notation accepted by `json-filter`, so a
+/// failing location can be fed straight back into a filter node. The root
+/// value is reported as an empty path.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SchemaViolation {
+    pub path: String,
+    pub keyword: &'static str,
+    pub message: String,
+}
+
+impl SchemaViolation {
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "path": self.path,
+            "keyword": self.keyword,
+            "message": self.message,
+        })
+    }
+}
+
+pub(crate) fn execute_json_schema_validate(
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<HashMap<String, serde_json::Value>> {
+    let json = inputs
+        .get("json")
+        .ok_or_else(|| NodeEngineError::ExecutionFailed("Missing json input".to_string()))?;
+
+    // A connected `schema` port overrides the schema stored in node data.
+    let schema = inputs
+        .get("schema")
+        .filter(|s| !s.is_null())
+        .or_else(|| inputs.get("_data").and_then(|d| d.get("schema")))
+        .ok_or_else(|| NodeEngineError::ExecutionFailed("Missing schema input".to_string()))?;
+    let schema = parse_schema_value(schema)?;
+
+    let violations = validate_json_schema(json, &schema)?;
+    let errors: Vec<serde_json::Value> = violations.iter().map(SchemaViolation::to_json).collect();
+
+    let mut outputs = HashMap::new();
+    outputs.insert(
+        "valid".to_string(),
+        serde_json::json!(violations.is_empty()),
+    );
+    outputs.insert("errors".to_string(), serde_json::json!(errors));
+    outputs.insert("error_count".to_string(), serde_json::json!(errors.len()));
+    Ok(outputs)
+}
+
+/// Accept schemas supplied either as JSON values or as JSON-encoded strings
+/// (node data edited in the UI arrives as text).
+fn parse_schema_value(schema: &serde_json::Value) -> Result<serde_json::Value> {
+    match schema {
+        serde_json::Value::String(raw) => serde_json::from_str(raw).map_err(|e| {
+            NodeEngineError::ExecutionFailed(format!("Schema is not valid JSON: {}", e))
+        }),
+        other => Ok(other.clone()),
+    }
+}
+
+/// Validate `value` against a JSON Schema subset.
+///
+/// Supported keywords: `type`, `enum`, `const`, `properties`, `required`,
+/// `additionalProperties`, `items`, `minItems`, `maxItems`, `uniqueItems`,
+/// `minLength`, `maxLength`, `minimum`, `maximum`, `exclusiveMinimum`,
+/// `exclusiveMaximum`, `multipleOf`, `allOf`, `anyOf`, `oneOf`, and `not`.
+/// Unknown keywords are ignored, matching JSON Schema's open-vocabulary rule.
+/// Returns an error only when the schema itself is malformed.
+pub(crate) fn validate_json_schema(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+) -> Result<Vec<SchemaViolation>> {
+    let mut violations = Vec::new();
+    validate_at(value, schema, "", &mut violations)?;
+    Ok(violations)
+}
+
+fn validate_at(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    path: &str,
+    out: &mut Vec<SchemaViolation>,
+) -> Result<()> {
+    let schema = match schema {
+        serde_json::Value::Bool(true) => return Ok(()),
+        serde_json::Value::Bool(false) => {
+            out.push(violation(path, "false", "No value is allowed here"));
+            return Ok(());
+        }
+        serde_json::Value::Object(map) => map,
+        other => {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Invalid schema at '{}': expected object or boolean, got {}",
+                path,
+                json_type_name(other)
+            )))
+        }
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            serde_json::Value::String(t) => vec![t.as_str()],
+            serde_json::Value::Array(types) => types.iter().filter_map(|t| t.as_str()).collect(),
+            _ => {
+                return Err(NodeEngineError::ExecutionFailed(format!(
+                    "Invalid schema at '{}': 'type' must be a string or array",
+                    path
+                )))
+            }
+        };
+        if !allowed.iter().any(|t| matches_type(value, t)) {
+            out.push(violation(
+                path,
+                "type",
+                format!(
+                    "Expected {}, got {}",
+                    allowed.join(" or "),
+                    json_type_name(value)
+                ),
+            ));
+            // Remaining keywords assume the declared type; stop here to avoid
+            // cascading noise for the same location.
+            return Ok(());
+        }
+    }
+
+    if let Some(options) = schema.get("enum").and_then(|e| e.as_array()) {
+        if !options.iter().any(|option| json_equal(option, value)) {
+            out.push(violation(
+                path,
+                "enum",
+                "Value is not one of the allowed options",
+            ));
+        }
+    }
+
+    if let Some(expected) = schema.get("const") {
+        if !json_equal(expected, value) {
+            out.push(violation(
+                path,
+                "const",
+                format!("Expected constant {}", expected),
+            ));
+        }
+    }
+
+    match value {
+        serde_json::Value::Object(object) => {
+            validate_object(object, schema, path, out)?;
+        }
+        serde_json::Value::Array(items) => {
+            validate_array(items, schema, path, out)?;
+        }
+        serde_json::Value::String(s) => {
+            let length = s.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(|m| m.as_u64()) {
+                if length < min {
+                    out.push(violation(
+                        path,
+                        "minLength",
+                        format!("String is shorter than {} characters", min),
+                    ));
+                }
+            }
+            if let Some(max) = schema.get("maxLength").and_then(|m| m.as_u64()) {
+                if length > max {
+                    out.push(violation(
+                        path,
+                        "maxLength",
+                        format!("String is longer than {} characters", max),
+                    ));
+                }
+            }
+        }
+        serde_json::Value::Number(n) => {
+            if let Some(number) = n.as_f64() {
+                validate_number(number, schema, path, out);
+            }
+        }
+        _ => {}
+    }
+
+    if let Some(all) = schema.get("allOf").and_then(|a| a.as_array()) {
+        for sub in all {
+            validate_at(value, sub, path, out)?;
+        }
+    }
+
+    if let Some(any) = schema.get("anyOf").and_then(|a| a.as_array()) {
+        let mut matched = false;
+        for sub in any {
+            if schema_matches(value, sub, path)? {
+                matched = true;
+                break;
+            }
+        }
+        if !matched {
+            out.push(violation(
+                path,
+                "anyOf",
+                "Value does not match any of the allowed schemas",
+            ));
+        }
+    }
+
+    if let Some(one) = schema.get("oneOf").and_then(|a| a.as_array()) {
+        let mut matches = 0;
+        for sub in one {
+            if schema_matches(value, sub, path)? {
+                matches += 1;
+            }
+        }
+        if matches != 1 {
+            out.push(violation(
+                path,
+                "oneOf",
+                format!("Value matches {} schemas, expected exactly one", matches),
+            ));
+        }
+    }
+
+    if let Some(not) = schema.get("not") {
+        if schema_matches(value, not, path)? {
+            out.push(violation(
+                path,
+                "not",
+                "Value matches a schema it must not match",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_object(
+    object: &serde_json::Map<String, serde_json::Value>,
+    schema: &serde_json::Map<String, serde_json::Value>,
+    path: &str,
+    out: &mut Vec<SchemaViolation>,
+) -> Result<()> {
+    if let Some(required) = schema.get("required").and_then(|r| r.as_array()) {
+        for key in required.iter().filter_map(|k| k.as_str()) {
+            if !object.contains_key(key) {
+                out.push(violation(
+                    &child_field_path(path, key),
+                    "required",
+                    format!("Missing required property '{}'", key),
+                ));
+            }
+        }
+    }
+
+    let properties = schema.get("properties").and_then(|p| p.as_object());
+    for (key, child) in object {
+        let child_path = child_field_path(path, key);
+        if let Some(child_schema) = properties.and_then(|p| p.get(key)) {
+            validate_at(child, child_schema, &child_path, out)?;
+            continue;
+        }
+        match schema.get("additionalProperties") {
+            Some(serde_json::Value::Bool(false)) => out.push(violation(
+                &child_path,
+                "additionalProperties",
+                format!("Property '{}' is not allowed", key),
+            )),
+            Some(additional) => validate_at(child, additional, &child_path, out)?,
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_array(
+    items: &[serde_json::Value],
+    schema: &serde_json::Map<String, serde_json::Value>,
+    path: &str,
+    out: &mut Vec<SchemaViolation>,
+) -> Result<()> {
+    let length = items.len() as u64;
+    if let Some(min) = schema.get("minItems").and_then(|m| m.as_u64()) {
+        if length < min {
+            out.push(violation(
+                path,
+                "minItems",
+                format!("Array has fewer than {} items", min),
+            ));
+        }
+    }
+    if let Some(max) = schema.get("maxItems").and_then(|m| m.as_u64()) {
+        if length > max {
+            out.push(violation(
+                path,
+                "maxItems",
+                format!("Array has more than {} items", max),
+            ));
+        }
+    }
+    if schema.get("uniqueItems").and_then(|u| u.as_bool()) == Some(true) {
+        let duplicate = items
+            .iter()
+            .enumerate()
+            .any(|(i, a)| items[i + 1..].iter().any(|b| json_equal(a, b)));
+        if duplicate {
+            out.push(violation(path, "uniqueItems", "Array items are not unique"));
+        }
+    }
+    if let Some(item_schema) = schema.get("items") {
+        for (index, item) in items.iter().enumerate() {
+            validate_at(item, item_schema, &format!("{}[{}]", path, index), out)?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_number(
+    number: f64,
+    schema: &serde_json::Map<String, serde_json::Value>,
+    path: &str,
+    out: &mut Vec<SchemaViolation>,
+) {
+    if let Some(min) = schema.get("minimum").and_then(|m| m.as_f64()) {
+        if number < min {
+            out.push(violation(
+                path,
+                "minimum",
+                format!("Value is less than {}", min),
+            ));
+        }
+    }
+    if let Some(max) = schema.get("maximum").and_then(|m| m.as_f64()) {
+        if number > max {
+            out.push(violation(
+                path,
+                "maximum",
+                format!("Value is greater than {}", max),
+            ));
+        }
+    }
+    if let Some(min) = schema.get("exclusiveMinimum").and_then(|m| m.as_f64()) {
+        if number <= min {
+            out.push(violation(
+                path,
+                "exclusiveMinimum",
+                format!("Value must be greater than {}", min),
+            ));
+        }
+    }
+    if let Some(max) = schema.get("exclusiveMaximum").and_then(|m| m.as_f64()) {
+        if number >= max {
+            out.push(violation(
+                path,
+                "exclusiveMaximum",
+                format!("Value must be less than {}", max),
+            ));
+        }
+    }
+    if let Some(divisor) = schema.get("multipleOf").and_then(|m| m.as_f64()) {
+        if divisor > 0.0 {
+            let quotient = number / divisor;
+            if (quotient - quotient.round()).abs() > 1e-9 {
+                out.push(violation(
+                    path,
+                    "multipleOf",
+                    format!("Value is not a multiple of {}", divisor),
+                ));
+            }
+        }
+    }
+}
+
+fn schema_matches(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    path: &str,
+) -> Result<bool> {
+    let mut scratch = Vec::new();
+    validate_at(value, schema, path, &mut scratch)?;
+    Ok(scratch.is_empty())
+}
+
+fn matches_type(value: &serde_json::Value, expected: &str) -> bool {
+    match expected {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value
+            .as_f64()
+            .is_some_and(|n| value.is_i64() || value.is_u64() || n.fract() == 0.0),
+        _ => false,
+    }
+}
+
+/// Compare JSON values with numeric equality (`1` equals `1.0`), as required
+/// by `enum`, `const`, and `uniqueItems`.
+fn json_equal(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    match (a, b) {
+        (serde_json::Value::Number(x), serde_json::Value::Number(y)) => x.as_f64() == y.as_f64(),
+        (serde_json::Value::Array(x), serde_json::Value::Array(y)) => {
+            x.len() == y.len() && x.iter().zip(y).all(|(a, b)| json_equal(a, b))
+        }
+        (serde_json::Value::Object(x), serde_json::Value::Object(y)) => {
+            x.len() == y.len()
+                && x.iter()
+                    .all(|(key, a)| y.get(key).is_some_and(|b| json_equal(a, b)))
+        }
+        _ => a == b,
+    }
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
+fn child_field_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", parent, key)
+    }
+}
+
+fn violation(path: &str, keyword: &'static str, message: impl Into<String>) -> SchemaViolation {
+    SchemaViolation {
+        path: path.to_string(),
+        keyword,
+        message: message.into(),
+    }
+}
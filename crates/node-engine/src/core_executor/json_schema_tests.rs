@@ -0,0 +1,128 @@
+use super::super::*;
+
+fn schema_inputs(
+    json: serde_json::Value,
+    schema: serde_json::Value,
+) -> HashMap<String, serde_json::Value> {
+    let mut inputs = HashMap::new();
+    inputs.insert("json".to_string(), json);
+    inputs.insert("_data".to_string(), serde_json::json!({ "schema": schema }));
+    inputs
+}
+
+#[test]
+fn test_json_schema_validate_accepts_matching_object() {
+    let inputs = schema_inputs(
+        serde_json::json!({"name": "doc", "score": 0.8}),
+        serde_json::json!({
+            "type": "object",
+            "required": ["name", "score"],
+            "properties": {
+                "name": {"type": "string", "minLength": 1},
+                "score": {"type": "number", "minimum": 0, "maximum": 1}
+            }
+        }),
+    );
+    let result = execute_json_schema_validate(&inputs).unwrap();
+    assert_eq!(result["valid"], true);
+    assert_eq!(result["errors"], serde_json::json!([]));
+    assert_eq!(result["error_count"], 0);
+}
+
+#[test]
+fn test_json_schema_validate_reports_error_paths() {
+    let inputs = schema_inputs(
+        serde_json::json!({"items": [{"id": 1}, {"id": "two"}], "extra": true}),
+        serde_json::json!({
+            "type": "object",
+            "required": ["items", "title"],
+            "additionalProperties": false,
+            "properties": {
+                "items": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {"id": {"type": "integer"}}
+                    }
+                }
+            }
+        }),
+    );
+    let result = execute_json_schema_validate(&inputs).unwrap();
+    assert_eq!(result["valid"], false);
+    assert_eq!(result["error_count"], 3);
+
+    let errors = result["errors"].as_array().unwrap();
+    let paths: Vec<(&str, &str)> = errors
+        .iter()
+        .map(|e| (e["path"].as_str().unwrap(), e["keyword"].as_str().unwrap()))
+        .collect();
+    assert!(paths.contains(&("title", "required")));
+    assert!(paths.contains(&("items[1].id", "type")));
+    assert!(paths.contains(&("extra", "additionalProperties")));
+}
+
+#[test]
+fn test_json_schema_validate_schema_port_overrides_node_data() {
+    let mut inputs = schema_inputs(serde_json::json!(5), serde_json::json!({"type": "string"}));
+    inputs.insert(
+        "schema".to_string(),
+        serde_json::json!({"type": "integer", "multipleOf": 5}),
+    );
+    let result = execute_json_schema_validate(&inputs).unwrap();
+    assert_eq!(result["valid"], true);
+}
+
+#[test]
+fn test_json_schema_validate_accepts_schema_string() {
+    let inputs = schema_inputs(
+        serde_json::json!("b"),
+        serde_json::json!(r#"{"enum": ["a", "b"]}"#),
+    );
+    let result = execute_json_schema_validate(&inputs).unwrap();
+    assert_eq!(result["valid"], true);
+}
+
+#[test]
+fn test_json_schema_validate_combinators() {
+    let schema = serde_json::json!({
+        "oneOf": [{"type": "string"}, {"type": "number", "minimum": 10}],
+        "not": {"const": "forbidden"}
+    });
+
+    let ok = validate_json_schema(&serde_json::json!(12), &schema).unwrap();
+    assert!(ok.is_empty());
+
+    let not_violation = validate_json_schema(&serde_json::json!("forbidden"), &schema).unwrap();
+    assert_eq!(not_violation.len(), 1);
+    assert_eq!(not_violation[0].keyword, "not");
+
+    let one_of_violation = validate_json_schema(&serde_json::json!(3), &schema).unwrap();
+    assert_eq!(one_of_violation.len(), 1);
+    assert_eq!(one_of_violation[0].keyword, "oneOf");
+}
+
+#[test]
+fn test_json_schema_validate_unique_items_uses_numeric_equality() {
+    let schema = serde_json::json!({"type": "array", "uniqueItems": true});
+    let violations = validate_json_schema(&serde_json::json!([1, 1.0]), &schema).unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].keyword, "uniqueItems");
+}
+
+#[test]
+fn test_json_schema_validate_missing_schema_errors() {
+    let mut inputs = HashMap::new();
+    inputs.insert("json".to_string(), serde_json::json!({}));
+    let error = execute_json_schema_validate(&inputs).expect_err("schema is required");
+    assert!(error.to_string().contains("Missing schema"));
+}
+
+#[test]
+fn test_json_schema_validate_malformed_schema_errors() {
+    let inputs = schema_inputs(serde_json::json!({}), serde_json::json!({"type": 7}));
+    let error = execute_json_schema_validate(&inputs).expect_err("schema is malformed");
+    assert!(error
+        .to_string()
+        .contains("'type' must be a string or array"));
+}
@@ -1,8 +1,9 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use inference::InferenceGateway;
+use inference::{InferenceGateway, LoraAdapter};
+use serde::Deserialize;
 
 use crate::error::{NodeEngineError, Result};
 use crate::events::EventSink;
@@ -42,6 +43,11 @@ pub(crate) async fn execute_llamacpp_inference(
         })?;
 
     let model_path = resolve_gguf_path(model_path_raw)?;
+    let mmproj_path = inputs
+        .get("mmproj_path")
+        .and_then(|m| m.as_str())
+        .map(str::trim)
+        .filter(|m| !m.is_empty());
     let system_prompt = inputs.get("system_prompt").and_then(|s| s.as_str());
     let temperature = inputs
         .get("temperature")
@@ -52,13 +58,35 @@ pub(crate) async fn execute_llamacpp_inference(
         .and_then(|m| m.as_i64())
         .unwrap_or(512);
 
+    let lora_adapters = parse_lora_adapters(inputs)?;
+
     // Read model-specific inference settings
     let extra_settings = build_extra_settings(inputs);
 
-    // Ensure gateway is ready before sending completion requests.
-    if !gw.is_ready().await {
+    let client = reqwest::Client::new();
+
+    // Ensure gateway is ready before sending completion requests. Adapters the
+    // running server has not loaded also need a (re)start; the backend keeps
+    // the adapters it already has so earlier selections stay switchable.
+    let mut needs_start = !gw.is_ready().await;
+    if !needs_start && !lora_adapters.is_empty() {
+        needs_start = match gw.base_url().await {
+            Some(base_url) => {
+                let loaded = fetch_lora_adapters(&client, &base_url).await?;
+                lora_adapters.iter().any(|adapter| {
+                    !loaded
+                        .iter()
+                        .any(|lora| Path::new(&lora.path) == adapter.path)
+                })
+            }
+            None => true,
+        };
+    }
+    if needs_start {
         let mut config = inference::BackendConfig {
             model_path: Some(PathBuf::from(&model_path)),
+            mmproj_path: mmproj_path.map(PathBuf::from),
+            lora_adapters: lora_adapters.clone(),
             device: Some("auto".to_string()),
             gpu_layers: Some(-1),
             embedding_mode: false,
@@ -84,7 +112,8 @@ pub(crate) async fn execute_llamacpp_inference(
             NodeEngineError::ExecutionFailed(format!("Failed to start llama.cpp server: {}", e))
         })?;
 
-        // Wait for readiness with timeout
+        // Wait for readiness on wall time: the server is a real process, so a
+        // simulated clock must not expire the deadline early.
         let deadline = std::time::Instant::now() + std::time::Duration::from_secs(60);
         while !gw.is_ready().await {
             if std::time::Instant::now() > deadline {
@@ -130,8 +159,11 @@ pub(crate) async fn execute_llamacpp_inference(
         request_body["id_slot"] = serde_json::json!(0);
         request_body["cache_prompt"] = serde_json::json!(true);
     }
+    if !lora_adapters.is_empty() {
+        let loaded = fetch_lora_adapters(&client, &base_url).await?;
+        request_body["lora"] = lora_request_scales(&loaded, &lora_adapters)?;
+    }
 
-    let client = reqwest::Client::new();
     let url = format!("{}/completion", base_url);
 
     log::debug!(
@@ -257,6 +289,99 @@ pub(crate) async fn execute_llamacpp_inference(
     Ok(outputs)
 }
 
+/// Parse the `lora` input: one adapter path, an array of paths, or
+/// `{ "path", "scale" }` objects. Library adapter directories resolve to the
+/// `.gguf` file inside.
+pub(crate) fn parse_lora_adapters(
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<Vec<LoraAdapter>> {
+    let values = match inputs.get("lora") {
+        None | Some(serde_json::Value::Null) => return Ok(Vec::new()),
+        Some(serde_json::Value::Array(values)) => values.clone(),
+        Some(value) => vec![value.clone()],
+    };
+
+    let mut adapters = Vec::with_capacity(values.len());
+    for value in &values {
+        let (path, scale) = match value {
+            serde_json::Value::String(path) => (path.as_str(), 1.0),
+            serde_json::Value::Object(object) => (
+                object
+                    .get("path")
+                    .and_then(|p| p.as_str())
+                    .unwrap_or_default(),
+                object.get("scale").and_then(|s| s.as_f64()).unwrap_or(1.0) as f32,
+            ),
+            other => {
+                return Err(NodeEngineError::ExecutionFailed(format!(
+                    "Invalid lora input: {}",
+                    other
+                )))
+            }
+        };
+        let path = path.trim();
+        if path.is_empty() {
+            continue;
+        }
+        adapters.push(LoraAdapter {
+            path: PathBuf::from(resolve_gguf_path(path)?),
+            scale,
+        });
+    }
+    Ok(adapters)
+}
+
+/// An adapter loaded by llama-server, as listed by `GET /lora-adapters`.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct LoadedLoraAdapter {
+    pub id: u64,
+    pub path: String,
+}
+
+async fn fetch_lora_adapters(
+    client: &reqwest::Client,
+    base_url: &str,
+) -> Result<Vec<LoadedLoraAdapter>> {
+    let url = format!("{}/lora-adapters", base_url);
+    let response = client.get(&url).send().await.map_err(|e| {
+        NodeEngineError::ExecutionFailed(format!("Failed to list LoRA adapters at {}: {}", url, e))
+    })?;
+    response.json().await.map_err(|e| {
+        NodeEngineError::ExecutionFailed(format!("Failed to parse LoRA adapter list: {}", e))
+    })
+}
+
+/// Per-request `lora` field for `/completion`: requested adapters at their
+/// scale, every other loaded adapter disabled.
+pub(crate) fn lora_request_scales(
+    loaded: &[LoadedLoraAdapter],
+    requested: &[LoraAdapter],
+) -> Result<serde_json::Value> {
+    if let Some(missing) = requested.iter().find(|adapter| {
+        !loaded
+            .iter()
+            .any(|lora| Path::new(&lora.path) == adapter.path)
+    }) {
+        return Err(NodeEngineError::ExecutionFailed(format!(
+            "LoRA adapter '{}' is not loaded by the llama.cpp server",
+            missing.path.display()
+        )));
+    }
+
+    Ok(serde_json::Value::Array(
+        loaded
+            .iter()
+            .map(|lora| {
+                let scale = requested
+                    .iter()
+                    .find(|adapter| Path::new(&lora.path) == adapter.path)
+                    .map_or(0.0, |adapter| adapter.scale);
+                serde_json::json!({ "id": lora.id, "scale": scale })
+            })
+            .collect(),
+    ))
+}
+
 /// Parse a llama.cpp `/completion` SSE data line into a content token.
 ///
 /// llama.cpp streams `data: {"content": "token", ...}` per line.
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::clock::{self, Rng, SeededRng};
+use crate::error::{NodeEngineError, Result};
+use crate::extensions::ExecutorExtensions;
+
+use super::{read_optional_input_value, read_optional_number};
+
+const DEFAULT_LLM_RESPONSE: &str = "Mock response to: {{prompt}}";
+const DEFAULT_EMBEDDING_DIMENSIONS: usize = 8;
+const MAX_EMBEDDING_DIMENSIONS: usize = 4096;
+
+/// Fill `{{name}}` placeholders from inputs, falling back to node data.
+///
+/// Strings are inserted as-is, other values as JSON, and missing values as
+/// empty strings. An unclosed `{{` is kept literally.
+pub(crate) fn render_mock_template(
+    template: &str,
+    inputs: &HashMap<String, serde_json::Value>,
+) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        rendered.push_str(&rest[..start]);
+        let name = rest[start + 2..start + 2 + len].trim();
+        match read_optional_input_value(inputs, name) {
+            Some(serde_json::Value::String(value)) => rendered.push_str(&value),
+            Some(serde_json::Value::Null) | None => {}
+            Some(value) => rendered.push_str(&value.to_string()),
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// Wait `latency_ms` on the installed clock, like `delay`.
+async fn simulate_latency(
+    inputs: &HashMap<String, serde_json::Value>,
+    extensions: &ExecutorExtensions,
+) -> Result<()> {
+    let latency_ms = read_optional_number(inputs, "latency_ms").unwrap_or(0.0);
+    let latency = Duration::try_from_secs_f64(latency_ms / 1000.0).map_err(|_| {
+        NodeEngineError::InvalidInputType {
+            port: "latency_ms".to_string(),
+            expected: "non-negative number of milliseconds".to_string(),
+        }
+    })?;
+    if !latency.is_zero() {
+        clock::clock(extensions).sleep(latency).await;
+    }
+    Ok(())
+}
+
+/// Answer a prompt with a canned, templated response.
+///
+/// `responses` maps exact prompts to replies; other prompts get the
+/// `response` template. `tool_calls` is returned as given.
+pub(crate) async fn execute_mock_llm(
+    inputs: &HashMap<String, serde_json::Value>,
+    extensions: &ExecutorExtensions,
+) -> Result<HashMap<String, serde_json::Value>> {
+    simulate_latency(inputs, extensions).await?;
+
+    let prompt = read_optional_input_value(inputs, "prompt");
+    let canned = prompt.as_ref().and_then(|prompt| {
+        let prompt = prompt.as_str()?;
+        read_optional_input_value(inputs, "responses")?
+            .get(prompt)?
+            .as_str()
+            .map(str::to_string)
+    });
+    let template = canned.unwrap_or_else(|| {
+        read_optional_input_value(inputs, "response")
+            .and_then(|response| response.as_str().map(str::to_string))
+            .unwrap_or_else(|| DEFAULT_LLM_RESPONSE.to_string())
+    });
+    let response = render_mock_template(&template, inputs);
+
+    let tool_calls = match read_optional_input_value(inputs, "tool_calls") {
+        Some(serde_json::Value::Array(calls)) => calls,
+        Some(serde_json::Value::Null) | None => Vec::new(),
+        Some(_) => {
+            return Err(NodeEngineError::InvalidInputType {
+                port: "tool_calls".to_string(),
+                expected: "array of tool calls".to_string(),
+            })
+        }
+    };
+
+    Ok(HashMap::from([
+        ("response".to_string(), serde_json::json!(response)),
+        (
+            "has_tool_calls".to_string(),
+            serde_json::json!(!tool_calls.is_empty()),
+        ),
+        ("tool_calls".to_string(), serde_json::json!(tool_calls)),
+    ]))
+}
+
+/// Embed text as a unit vector derived from a hash of the text.
+///
+/// The same text always yields the same vector, so similarity search over
+/// mock embeddings is repeatable. A fixed `embedding` in node data is
+/// returned instead when set.
+pub(crate) async fn execute_mock_embedding(
+    inputs: &HashMap<String, serde_json::Value>,
+    extensions: &ExecutorExtensions,
+) -> Result<HashMap<String, serde_json::Value>> {
+    simulate_latency(inputs, extensions).await?;
+
+    let embedding = match read_optional_input_value(inputs, "embedding") {
+        Some(serde_json::Value::Array(values)) => values
+            .iter()
+            .map(serde_json::Value::as_f64)
+            .collect::<Option<Vec<f64>>>()
+            .ok_or_else(|| NodeEngineError::InvalidInputType {
+                port: "embedding".to_string(),
+                expected: "array of numbers".to_string(),
+            })?,
+        _ => {
+            let dimensions = read_optional_number(inputs, "dimensions")
+                .map_or(DEFAULT_EMBEDDING_DIMENSIONS as f64, f64::trunc);
+            if !(1.0..=MAX_EMBEDDING_DIMENSIONS as f64).contains(&dimensions) {
+                return Err(NodeEngineError::ExecutionFailed(format!(
+                    "Mock embedding dimensions must be between 1 and {}",
+                    MAX_EMBEDDING_DIMENSIONS
+                )));
+            }
+            let text = match read_optional_input_value(inputs, "text") {
+                Some(serde_json::Value::String(text)) => text,
+                Some(value) => value.to_string(),
+                None => return Err(NodeEngineError::MissingInput("text".to_string())),
+            };
+            hashed_unit_vector(&text, dimensions as usize)
+        }
+    };
+
+    let dimensions = embedding.len();
+    Ok(HashMap::from([
+        ("embedding".to_string(), serde_json::json!(embedding)),
+        (
+            "metadata".to_string(),
+            serde_json::json!({
+                "backend": "mock",
+                "model": "mock-embedding",
+                "vector_length": dimensions,
+            }),
+        ),
+    ]))
+}
+
+fn hashed_unit_vector(text: &str, dimensions: usize) -> Vec<f64> {
+    // FNV-1a, so vectors stay stable across platforms and releases.
+    let seed = text.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    let rng = SeededRng::new(seed);
+    let vector: Vec<f64> = (0..dimensions)
+        .map(|_| rng.next_f64() * 2.0 - 1.0)
+        .collect();
+    let norm = vector.iter().map(|v| v * v).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return vector;
+    }
+    vector.into_iter().map(|v| v / norm).collect()
+}
+
+/// Return a canned HTTP response without touching the network.
+///
+/// String bodies are templates over the request inputs (`url`, `method`,
+/// `body`); JSON bodies are returned unchanged.
+pub(crate) async fn execute_mock_http(
+    inputs: &HashMap<String, serde_json::Value>,
+    extensions: &ExecutorExtensions,
+) -> Result<HashMap<String, serde_json::Value>> {
+    simulate_latency(inputs, extensions).await?;
+
+    let status = read_optional_number(inputs, "status").unwrap_or(200.0);
+    if !(100.0..=599.0).contains(&status) || status.fract() != 0.0 {
+        return Err(NodeEngineError::InvalidInputType {
+            port: "status".to_string(),
+            expected: "HTTP status code between 100 and 599".to_string(),
+        });
+    }
+    let status = status as u16;
+
+    let body = match read_optional_input_value(inputs, "response_body") {
+        Some(serde_json::Value::String(template)) => {
+            serde_json::json!(render_mock_template(&template, inputs))
+        }
+        Some(body) => body,
+        None => serde_json::json!(""),
+    };
+    let headers = match read_optional_input_value(inputs, "headers") {
+        Some(serde_json::Value::Object(headers)) => serde_json::Value::Object(headers),
+        Some(serde_json::Value::Null) | None => serde_json::json!({}),
+        Some(_) => {
+            return Err(NodeEngineError::InvalidInputType {
+                port: "headers".to_string(),
+                expected: "object of header names to values".to_string(),
+            })
+        }
+    };
+
+    Ok(HashMap::from([
+        ("status".to_string(), serde_json::json!(status)),
+        (
+            "ok".to_string(),
+            serde_json::json!((200..300).contains(&status)),
+        ),
+        ("body".to_string(), body),
+        ("headers".to_string(), headers),
+    ]))
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::super::*;
+use crate::clock::{Clock, SimulatedClock};
+use crate::extensions::extension_keys;
+
+fn node_inputs(data: serde_json::Value) -> HashMap<String, serde_json::Value> {
+    HashMap::from([("_data".to_string(), data)])
+}
+
+#[test]
+fn test_template_fills_inputs_and_node_data() {
+    let mut inputs = node_inputs(serde_json::json!({"name": "Ada"}));
+    inputs.insert("count".to_string(), serde_json::json!(3));
+
+    assert_eq!(
+        render_mock_template("{{ name }} has {{count}} {{missing}}items {{open", &inputs),
+        "Ada has 3 items {{open"
+    );
+}
+
+#[tokio::test]
+async fn test_mock_llm_prefers_canned_reply_then_template() {
+    let data = serde_json::json!({
+        "response": "Echo: {{prompt}}",
+        "responses": {"ping": "pong"},
+    });
+    let mut inputs = node_inputs(data);
+    inputs.insert("prompt".to_string(), serde_json::json!("ping"));
+    let result = execute_mock_llm(&inputs, &ExecutorExtensions::new())
+        .await
+        .unwrap();
+    assert_eq!(result["response"], "pong");
+    assert_eq!(result["has_tool_calls"], false);
+
+    inputs.insert("prompt".to_string(), serde_json::json!("hello"));
+    let result = execute_mock_llm(&inputs, &ExecutorExtensions::new())
+        .await
+        .unwrap();
+    assert_eq!(result["response"], "Echo: hello");
+}
+
+#[tokio::test]
+async fn test_mock_llm_latency_runs_on_installed_clock() {
+    let clock = Arc::new(SimulatedClock::new());
+    let mut extensions = ExecutorExtensions::new();
+    extensions.set(extension_keys::CLOCK, clock.clone() as Arc<dyn Clock>);
+    let mut inputs = node_inputs(serde_json::json!({
+        "latency_ms": 1500,
+        "tool_calls": [{"name": "search", "arguments": {"q": "rust"}}],
+    }));
+    inputs.insert("prompt".to_string(), serde_json::json!("hi"));
+
+    let result = execute_mock_llm(&inputs, &extensions).await.unwrap();
+    assert_eq!(clock.elapsed(), Duration::from_millis(1500));
+    assert_eq!(result["response"], "Mock response to: hi");
+    assert_eq!(result["has_tool_calls"], true);
+}
+
+#[tokio::test]
+async fn test_mock_embedding_is_repeatable_unit_vector() {
+    let embed = |text: &str| {
+        let mut inputs = node_inputs(serde_json::json!({"dimensions": 16}));
+        inputs.insert("text".to_string(), serde_json::json!(text));
+        async move {
+            execute_mock_embedding(&inputs, &ExecutorExtensions::new())
+                .await
+                .unwrap()
+        }
+    };
+    let first = embed("hello").await;
+    let vector: Vec<f64> = serde_json::from_value(first["embedding"].clone()).unwrap();
+    let norm = vector.iter().map(|v| v * v).sum::<f64>().sqrt();
+
+    assert_eq!(vector.len(), 16);
+    assert!((norm - 1.0).abs() < 1e-9);
+    assert_eq!(embed("hello").await["embedding"], first["embedding"]);
+    assert_ne!(embed("world").await["embedding"], first["embedding"]);
+    assert_eq!(first["metadata"]["vector_length"], 16);
+}
+
+#[tokio::test]
+async fn test_mock_embedding_rejects_out_of_range_dimensions() {
+    let mut inputs = node_inputs(serde_json::json!({"dimensions": 0}));
+    inputs.insert("text".to_string(), serde_json::json!("hello"));
+    let error = execute_mock_embedding(&inputs, &ExecutorExtensions::new())
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), "execution_failed");
+}
+
+#[tokio::test]
+async fn test_mock_http_renders_body_and_reports_status() {
+    let mut inputs = node_inputs(serde_json::json!({
+        "status": 404,
+        "response_body": "no route for {{url}}",
+        "headers": {"content-type": "text/plain"},
+    }));
+    inputs.insert("url".to_string(), serde_json::json!("/missing"));
+
+    let result = execute_mock_http(&inputs, &ExecutorExtensions::new())
+        .await
+        .unwrap();
+    assert_eq!(result["status"], 404);
+    assert_eq!(result["ok"], false);
+    assert_eq!(result["body"], "no route for /missing");
+    assert_eq!(result["headers"]["content-type"], "text/plain");
+
+    let inputs = node_inputs(serde_json::json!({"response_body": {"items": [1, 2]}}));
+    let result = execute_mock_http(&inputs, &ExecutorExtensions::new())
+        .await
+        .unwrap();
+    assert_eq!(result["status"], 200);
+    assert_eq!(result["body"], serde_json::json!({"items": [1, 2]}));
+}
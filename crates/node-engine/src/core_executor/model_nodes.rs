@@ -126,6 +126,18 @@ pub(crate) fn execute_puma_lib(
         );
     }
 
+    // Vision models need their projector; prefer the library's stored pairing
+    // and fall back to the naming convention next to the model file.
+    let mmproj_path = data_string(inputs, "mmproj_path", "mmprojPath").or_else(|| {
+        Some(model_path)
+            .filter(|path| !path.is_empty())
+            .and_then(|path| crate::mmproj::find_mmproj(std::path::Path::new(path)))
+            .map(|path| path.to_string_lossy().into_owned())
+    });
+    if let Some(mmproj_path) = mmproj_path {
+        outputs.insert("mmproj_path".to_string(), serde_json::json!(mmproj_path));
+    }
+
     log::debug!("PumaLib: providing model path '{}'", model_path);
     Ok(outputs)
 }
@@ -1,11 +1,15 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
+use crate::config::BackendEndpoints;
 use crate::error::{NodeEngineError, Result};
+use crate::extensions::{extension_keys, ExecutorExtensions};
 
 use super::{build_extra_settings, build_model_ref_v2};
 
 pub(crate) async fn execute_ollama_inference(
     inputs: &HashMap<String, serde_json::Value>,
+    extensions: &ExecutorExtensions,
 ) -> Result<HashMap<String, serde_json::Value>> {
     let prompt = inputs
         .get("prompt")
@@ -54,7 +58,12 @@ pub(crate) async fn execute_ollama_inference(
     }
 
     let client = reqwest::Client::new();
-    let url = "http://localhost:11434/api/generate";
+    let base_url = extensions
+        .get::<Arc<BackendEndpoints>>(extension_keys::BACKEND_ENDPOINTS)
+        .map_or(BackendEndpoints::DEFAULT_OLLAMA, |endpoints| {
+            endpoints.ollama()
+        });
+    let url = format!("{}/api/generate", base_url.trim_end_matches('/'));
 
     log::debug!(
         "OllamaInference: sending request to {} with model '{}'",
@@ -63,7 +72,7 @@ pub(crate) async fn execute_ollama_inference(
     );
 
     let http_response = client
-        .post(url)
+        .post(&url)
         .json(&request_body)
         .send()
         .await
@@ -0,0 +1,345 @@
+use super::array_nodes::field_value;
+use crate::error::{NodeEngineError, Result};
+
+/// A parsed `filter` predicate that can be evaluated against many elements.
+///
+/// Grammar (lowest to highest precedence):
+///
+/// ```text
+/// expr    := and ( "||" and )*
+/// and     := unary ( "&&" unary )*
+/// unary   := "!" unary | compare
+/// compare := operand ( ( "==" | "!=" | "<" | "<=" | ">" | ">=" | "contains" ) operand )?
+/// operand := "(" expr ")" | number | string | "true" | "false" | "null" | path
+/// ```
+///
+/// Paths use `json-filter` notation relative to the current element
+/// (`score`, `meta.tags[0]`); `@` refers to the element itself. Missing paths
+/// evaluate to `null`. Strings may be single- or double-quoted.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Predicate {
+    Or(Box<Predicate>, Box<Predicate>),
+    And(Box<Predicate>, Box<Predicate>),
+    Not(Box<Predicate>),
+    Compare(Box<Predicate>, CompareOp, Box<Predicate>),
+    Literal(serde_json::Value),
+    Path(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    Not,
+    And,
+    Or,
+    Op(CompareOp),
+    Literal(serde_json::Value),
+    Path(String),
+}
+
+impl Predicate {
+    /// Parse a predicate expression.
+    pub(crate) fn parse(source: &str) -> Result<Self> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let predicate = parser.parse_or()?;
+        if parser.pos != parser.tokens.len() {
+            return Err(invalid(source, "unexpected trailing tokens"));
+        }
+        Ok(predicate)
+    }
+
+    /// Evaluate the predicate against an element and return its truthiness.
+    pub(crate) fn matches(&self, item: &serde_json::Value) -> bool {
+        is_truthy(&self.evaluate(item))
+    }
+
+    fn evaluate(&self, item: &serde_json::Value) -> serde_json::Value {
+        match self {
+            Self::Or(left, right) => serde_json::json!(left.matches(item) || right.matches(item)),
+            Self::And(left, right) => serde_json::json!(left.matches(item) && right.matches(item)),
+            Self::Not(inner) => serde_json::json!(!inner.matches(item)),
+            Self::Compare(left, op, right) => {
+                serde_json::json!(compare(&left.evaluate(item), *op, &right.evaluate(item)))
+            }
+            Self::Literal(value) => value.clone(),
+            Self::Path(path) => {
+                let path = path.strip_prefix('@').unwrap_or(path);
+                let path = path.strip_prefix('.').unwrap_or(path);
+                field_value(item, path).unwrap_or(serde_json::Value::Null)
+            }
+        }
+    }
+}
+
+fn compare(left: &serde_json::Value, op: CompareOp, right: &serde_json::Value) -> bool {
+    use serde_json::Value;
+
+    match op {
+        CompareOp::Eq => values_equal(left, right),
+        CompareOp::Ne => !values_equal(left, right),
+        CompareOp::Contains => match (left, right) {
+            (Value::String(haystack), Value::String(needle)) => haystack.contains(needle.as_str()),
+            (Value::Array(items), needle) => items.iter().any(|item| values_equal(item, needle)),
+            (Value::Object(map), Value::String(key)) => map.contains_key(key),
+            _ => false,
+        },
+        CompareOp::Lt | CompareOp::Le | CompareOp::Gt | CompareOp::Ge => {
+            let ordering = match (left, right) {
+                (Value::Number(a), Value::Number(b)) => a
+                    .as_f64()
+                    .zip(b.as_f64())
+                    .and_then(|(a, b)| a.partial_cmp(&b)),
+                (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+                _ => None,
+            };
+            let Some(ordering) = ordering else {
+                return false;
+            };
+            match op {
+                CompareOp::Lt => ordering.is_lt(),
+                CompareOp::Le => ordering.is_le(),
+                CompareOp::Gt => ordering.is_gt(),
+                _ => ordering.is_ge(),
+            }
+        }
+    }
+}
+
+fn values_equal(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    match (a, b) {
+        (serde_json::Value::Number(x), serde_json::Value::Number(y)) => x.as_f64() == y.as_f64(),
+        _ => a == b,
+    }
+}
+
+fn is_truthy(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Null => false,
+        serde_json::Value::Bool(b) => *b,
+        serde_json::Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        serde_json::Value::String(s) => !s.is_empty(),
+        serde_json::Value::Array(items) => !items.is_empty(),
+        serde_json::Value::Object(map) => !map.is_empty(),
+    }
+}
+
+fn invalid(source: &str, reason: &str) -> NodeEngineError {
+    NodeEngineError::ExecutionFailed(format!(
+        "Invalid filter expression '{}': {}",
+        source, reason
+    ))
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '&' if next == Some('&') => {
+                tokens.push(Token::And);
+                i += 2;
+            }
+            '|' if next == Some('|') => {
+                tokens.push(Token::Or);
+                i += 2;
+            }
+            '=' if next == Some('=') => {
+                tokens.push(Token::Op(CompareOp::Eq));
+                i += 2;
+            }
+            '!' if next == Some('=') => {
+                tokens.push(Token::Op(CompareOp::Ne));
+                i += 2;
+            }
+            '!' => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            '<' | '>' => {
+                let inclusive = next == Some('=');
+                tokens.push(Token::Op(match (c, inclusive) {
+                    ('<', false) => CompareOp::Lt,
+                    ('<', true) => CompareOp::Le,
+                    ('>', false) => CompareOp::Gt,
+                    _ => CompareOp::Ge,
+                }));
+                i += if inclusive { 2 } else { 1 };
+            }
+            '"' | '\'' => {
+                let quote = c;
+                let mut text = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(invalid(source, "unterminated string")),
+                        Some('\\') => {
+                            if let Some(escaped) = chars.get(i + 1) {
+                                text.push(*escaped);
+                            }
+                            i += 2;
+                        }
+                        Some(ch) if *ch == quote => {
+                            i += 1;
+                            break;
+                        }
+                        Some(ch) => {
+                            text.push(*ch);
+                            i += 1;
+                        }
+                    }
+                }
+                tokens.push(Token::Literal(serde_json::Value::String(text)));
+            }
+            c if c.is_ascii_digit()
+                || (c == '-' && next.is_some_and(|n| n.is_ascii_digit() || n == '.')) =>
+            {
+                let start = i;
+                i += 1;
+                while i < chars.len()
+                    && (chars[i].is_ascii_digit()
+                        || matches!(chars[i], '.' | 'e' | 'E' | '+' | '-'))
+                {
+                    i += 1;
+                }
+                let raw: String = chars[start..i].iter().collect();
+                let number: f64 = raw
+                    .parse()
+                    .map_err(|_| invalid(source, &format!("invalid number '{}'", raw)))?;
+                tokens.push(Token::Literal(serde_json::json!(number)));
+            }
+            c if c == '@' || c == '$' || c == '_' || c.is_alphabetic() => {
+                let start = i;
+                while i < chars.len() && is_path_char(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                tokens.push(match word.as_str() {
+                    "true" => Token::Literal(serde_json::Value::Bool(true)),
+                    "false" => Token::Literal(serde_json::Value::Bool(false)),
+                    "null" => Token::Literal(serde_json::Value::Null),
+                    "contains" => Token::Op(CompareOp::Contains),
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => Token::Path(word),
+                });
+            }
+            other => {
+                return Err(invalid(
+                    source,
+                    &format!("unexpected character '{}'", other),
+                ))
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn is_path_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '.' | '[' | ']' | '@' | '$')
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Predicate> {
+        let mut left = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            let right = self.parse_and()?;
+            left = Predicate::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<Predicate> {
+        let mut left = self.parse_unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            let right = self.parse_unary()?;
+            left = Predicate::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Predicate> {
+        if self.peek() == Some(&Token::Not) {
+            self.pos += 1;
+            return Ok(Predicate::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_compare()
+    }
+
+    fn parse_compare(&mut self) -> Result<Predicate> {
+        let left = self.parse_operand()?;
+        if let Some(Token::Op(op)) = self.peek().cloned() {
+            self.pos += 1;
+            let right = self.parse_operand()?;
+            return Ok(Predicate::Compare(Box::new(left), op, Box::new(right)));
+        }
+        Ok(left)
+    }
+
+    fn parse_operand(&mut self) -> Result<Predicate> {
+        match self.advance() {
+            Some(Token::LParen) => {
+                let inner = self.parse_or()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err(NodeEngineError::ExecutionFailed(
+                        "Invalid filter expression: missing ')'".to_string(),
+                    )),
+                }
+            }
+            Some(Token::Literal(value)) => Ok(Predicate::Literal(value)),
+            Some(Token::Path(path)) => Ok(Predicate::Path(path)),
+            Some(other) => Err(NodeEngineError::ExecutionFailed(format!(
+                "Invalid filter expression: unexpected token {:?}",
+                other
+            ))),
+            None => Err(NodeEngineError::ExecutionFailed(
+                "Invalid filter expression: unexpected end of expression".to_string(),
+            )),
+        }
+    }
+}
@@ -92,7 +92,7 @@ pub(crate) fn execute_json_filter(
 ///
 /// Supports object field access (`field.subfield`), array indexing (`[0]`),
 /// and combinations (`field[0].subfield`).
-fn extract_json_path(json: &serde_json::Value, path: &str) -> (serde_json::Value, bool) {
+pub(crate) fn extract_json_path(json: &serde_json::Value, path: &str) -> (serde_json::Value, bool) {
     if path.is_empty() {
         return (json.clone(), true);
     }
@@ -151,6 +151,7 @@ pub(crate) async fn execute_reranker(
             NodeEngineError::ExecutionFailed(format!("Failed to start reranking server: {}", e))
         })?;
 
+        // Wall time, like the llama.cpp readiness wait: the server is real.
         let deadline = std::time::Instant::now() + std::time::Duration::from_secs(60);
         while !gw.is_ready().await {
             if std::time::Instant::now() > deadline {
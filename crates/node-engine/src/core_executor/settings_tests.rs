@@ -305,6 +305,39 @@ async fn test_execute_write_file_rejects_traversal() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_filesystem_policy_sets_root_and_blocks_writes() {
+    let root = tempdir().unwrap();
+    std::fs::write(root.path().join("note.txt"), "hello").unwrap();
+    let mut extensions = ExecutorExtensions::new();
+    extensions.set(
+        crate::extension_keys::FILESYSTEM_POLICY,
+        Arc::new(crate::config::FilesystemPolicy {
+            root: Some(root.path().to_path_buf()),
+            read_only: true,
+        }),
+    );
+    let executor = CoreTaskExecutor::new();
+    let context = graph_flow::Context::new();
+
+    let mut read_inputs = HashMap::new();
+    read_inputs.insert("path".to_string(), serde_json::json!("note.txt"));
+    let read = executor
+        .execute_task("read-file-1", read_inputs, &context, &extensions)
+        .await
+        .unwrap();
+    assert_eq!(read["content"], serde_json::json!("hello"));
+
+    let mut write_inputs = HashMap::new();
+    write_inputs.insert("path".to_string(), serde_json::json!("out.txt"));
+    write_inputs.insert("content".to_string(), serde_json::json!("blocked"));
+    let write = executor
+        .execute_task("write-file-1", write_inputs, &context, &extensions)
+        .await;
+    assert!(write.is_err());
+    assert!(!root.path().join("out.txt").exists());
+}
+
 #[test]
 fn test_read_optional_input_bool_aliases_parses_data_field() {
     let mut inputs = HashMap::new();
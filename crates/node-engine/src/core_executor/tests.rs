@@ -278,6 +278,32 @@ fn test_model_provider() {
     assert_eq!(result["model_name"], "phi-3");
 }
 
+#[tokio::test]
+async fn test_puma_lib_records_model_use() {
+    let usage = Arc::new(ModelUsageLog::new());
+    let mut extensions = ExecutorExtensions::new();
+    extensions.set(crate::extension_keys::MODEL_USAGE, usage.clone());
+    let mut inputs = HashMap::new();
+    inputs.insert(
+        "_data".to_string(),
+        serde_json::json!({"modelPath": "/models/test.gguf", "model_id": "llm/example/test"}),
+    );
+
+    CoreTaskExecutor::new()
+        .execute_task(
+            "puma-lib-1",
+            inputs,
+            &graph_flow::Context::new(),
+            &extensions,
+        )
+        .await
+        .unwrap();
+
+    let entry = usage.entry("llm/example/test").unwrap();
+    assert_eq!(entry.use_count, 1);
+    assert!(entry.last_used_ms.is_some());
+}
+
 #[test]
 fn test_puma_lib() {
     let mut inputs = HashMap::new();
@@ -360,6 +386,37 @@ fn test_puma_lib_missing_inference_settings_defaults_to_empty_array() {
     let result = execute_puma_lib(&inputs).unwrap();
     assert_eq!(result["model_path"], "/models/test.gguf");
     assert_eq!(result["inference_settings"], serde_json::json!([]));
+    assert!(!result.contains_key("mmproj_path"));
+}
+
+#[test]
+fn test_puma_lib_pairs_mmproj_beside_gguf_model() {
+    let dir = tempfile::tempdir().unwrap();
+    let model = dir.path().join("llava-7b-q4.gguf");
+    let mmproj = dir.path().join("llava-7b-mmproj-f16.gguf");
+    std::fs::write(&model, b"gguf").unwrap();
+    std::fs::write(&mmproj, b"gguf").unwrap();
+
+    let mut inputs = HashMap::new();
+    inputs.insert(
+        "_data".to_string(),
+        serde_json::json!({"modelPath": model.to_string_lossy()}),
+    );
+    let result = execute_puma_lib(&inputs).unwrap();
+    assert_eq!(
+        result["mmproj_path"],
+        serde_json::json!(mmproj.to_string_lossy())
+    );
+
+    inputs.insert(
+        "_data".to_string(),
+        serde_json::json!({
+            "modelPath": model.to_string_lossy(),
+            "mmproj_path": "/models/stored.mmproj"
+        }),
+    );
+    let result = execute_puma_lib(&inputs).unwrap();
+    assert_eq!(result["mmproj_path"], "/models/stored.mmproj");
 }
 
 #[test]
@@ -403,6 +460,19 @@ fn test_tool_executor_is_disabled() {
 #[path = "settings_tests.rs"]
 mod settings_tests;
 
+#[path = "json_schema_tests.rs"]
+mod json_schema_tests;
+
+#[path = "array_nodes_tests.rs"]
+mod array_nodes_tests;
+
+#[path = "clock_nodes_tests.rs"]
+mod clock_nodes_tests;
+
+#[cfg(feature = "mock-nodes")]
+#[path = "mock_nodes_tests.rs"]
+mod mock_nodes_tests;
+
 #[test]
 fn test_execute_vector_output_missing_vector_returns_null() {
     let inputs = HashMap::new();
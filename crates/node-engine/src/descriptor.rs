@@ -6,9 +6,12 @@
 //! This creates a single source of truth for node definitions - the task
 //! implementation defines both its behavior AND its metadata.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{ExecutionMode, NodeCategory, PortDataType};
+use crate::ui_hints::{NodeUiHints, PortUiHints};
 
 /// Trait for tasks that can describe their metadata
 ///
@@ -19,7 +22,7 @@ use crate::types::{ExecutionMode, NodeCategory, PortDataType};
 /// # Example
 ///
 /// ```ignore
-/// use node_engine::{TaskDescriptor, TaskMetadata, PortMetadata};
+/// use node_engine::{NodeDocs, NodeExample, NodeUiHints, TaskDescriptor, TaskMetadata, PortMetadata};
 /// use node_engine::{NodeCategory, ExecutionMode, PortDataType};
 ///
 /// impl TaskDescriptor for MyTask {
@@ -36,6 +39,14 @@ use crate::types::{ExecutionMode, NodeCategory, PortDataType};
 ///                 PortMetadata::optional("output", "Output", PortDataType::String),
 ///             ],
 ///             execution_mode: ExecutionMode::Reactive,
+///             docs: NodeDocs::new("Uppercases `input`.")
+///                 .example(
+///                     NodeExample::new("Shout")
+///                         .input("input", json!("hi"))
+///                         .output("output", json!("HI")),
+///                 )
+///                 .link("Guide", "https://example.com/my-task"),
+///                 ui: NodeUiHints::icon("sparkles"),
 ///         }
 ///     }
 /// }
@@ -68,6 +79,121 @@ pub struct TaskMetadata {
     pub outputs: Vec<PortMetadata>,
     /// Execution mode
     pub execution_mode: ExecutionMode,
+    /// Long-form help, examples, and links for editor help panels
+    #[serde(default, skip_serializing_if = "NodeDocs::is_empty")]
+    pub docs: NodeDocs,
+    /// Icon and color overrides; empty fields resolve from the category
+    #[serde(default)]
+    pub ui: NodeUiHints,
+}
+
+impl TaskMetadata {
+    /// Fill empty node and port UI hints from the category and data types.
+    pub fn resolve_ui_hints(&mut self) {
+        self.ui.resolve(self.category);
+        for port in self.inputs.iter_mut().chain(self.outputs.iter_mut()) {
+            if port.ui.is_unset() {
+                port.ui = PortUiHints::for_data_type(port.data_type);
+            }
+        }
+    }
+}
+
+/// Long-form documentation for a node type.
+///
+/// Travels with the descriptor so every host's editor renders the same help
+/// panel and can autofill a node from one of its examples.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeDocs {
+    /// Markdown body: behavior, node data settings, and edge cases
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<NodeExample>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<NodeLink>,
+}
+
+impl NodeDocs {
+    /// Create docs with a Markdown body.
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Add an example.
+    pub fn example(mut self, example: NodeExample) -> Self {
+        self.examples.push(example);
+        self
+    }
+
+    /// Add a link.
+    pub fn link(mut self, label: impl Into<String>, url: impl Into<String>) -> Self {
+        self.links.push(NodeLink {
+            label: label.into(),
+            url: url.into(),
+        });
+        self
+    }
+
+    /// Whether there is nothing to show.
+    pub fn is_empty(&self) -> bool {
+        self.body.is_empty() && self.examples.is_empty() && self.links.is_empty()
+    }
+}
+
+/// One worked example: node data and port inputs, and the outputs they give.
+///
+/// `data` and `inputs` are what an editor writes into a new node when the
+/// example is used for autofill.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeExample {
+    pub title: String,
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub data: serde_json::Value,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub inputs: BTreeMap<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub outputs: BTreeMap<String, serde_json::Value>,
+}
+
+impl NodeExample {
+    /// Create an example with no data, inputs, or outputs.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the node data.
+    pub fn data(mut self, data: serde_json::Value) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Set an input port value.
+    pub fn input(mut self, port: impl Into<String>, value: serde_json::Value) -> Self {
+        self.inputs.insert(port.into(), value);
+        self
+    }
+
+    /// Set an expected output port value.
+    pub fn output(mut self, port: impl Into<String>, value: serde_json::Value) -> Self {
+        self.outputs.insert(port.into(), value);
+        self
+    }
+}
+
+/// A labelled link to further reading.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeLink {
+    pub label: String,
+    pub url: String,
 }
 
 /// A function pointer that produces a `TaskMetadata`.
@@ -79,6 +205,33 @@ pub struct DescriptorFn(pub fn() -> TaskMetadata);
 
 inventory::collect!(DescriptorFn);
 
+/// Declares a node type whose outputs cannot be reproduced from its inputs
+/// (e.g. it runs shell commands or reads the clock).
+///
+/// Deterministic execution mode refuses to run these nodes. Submit with
+/// `inventory::submit!(node_engine::NondeterministicNode("process"));`.
+pub struct NondeterministicNode(pub &'static str);
+
+inventory::collect!(NondeterministicNode);
+
+/// Declares the expected per-run cost of a node type for pre-run estimates.
+///
+/// Node types without an annotation are estimated as free, local, and fast,
+/// passing their input tokens through. Submit with
+/// `inventory::submit!(node_engine::NodeCostAnnotation { .. });`.
+pub struct NodeCostAnnotation {
+    pub node_type: &'static str,
+    /// External API or model-server requests made per run.
+    pub api_calls: u32,
+    pub min_wall_time_ms: u64,
+    pub max_wall_time_ms: u64,
+    /// Tokens generated per run when no `max_tokens` input is set; `None` for
+    /// nodes that do not generate text.
+    pub default_output_tokens: Option<u64>,
+}
+
+inventory::collect!(NodeCostAnnotation);
+
 /// Metadata for a port (input or output)
 ///
 /// Describes a single port on a node, including its data type
@@ -96,6 +249,9 @@ pub struct PortMetadata {
     pub required: bool,
     /// Whether multiple connections are allowed
     pub multiple: bool,
+    /// Color and shape for the port handle
+    #[serde(default)]
+    pub ui: PortUiHints,
 }
 
 impl PortMetadata {
@@ -113,6 +269,7 @@ impl PortMetadata {
             data_type,
             required,
             multiple,
+            ui: PortUiHints::for_data_type(data_type),
         }
     }
 
@@ -186,10 +343,34 @@ mod tests {
                 PortDataType::String,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::default(),
+            ui: NodeUiHints::default(),
         };
 
         let json = serde_json::to_string(&metadata).unwrap();
         assert!(json.contains("test-task"));
         assert!(json.contains("nodeType")); // camelCase
+        assert!(!json.contains("docs"));
+    }
+
+    #[test]
+    fn test_node_docs_round_trip() {
+        let docs = NodeDocs::new("Sorts **items**.")
+            .example(
+                NodeExample::new("Descending")
+                    .data(serde_json::json!({"order": "desc"}))
+                    .input("items", serde_json::json!([1, 3, 2]))
+                    .output("items", serde_json::json!([3, 2, 1])),
+            )
+            .link("Guide", "https://example.com/sort");
+
+        let json = serde_json::to_value(&docs).unwrap();
+        assert_eq!(
+            json["examples"][0]["inputs"]["items"],
+            serde_json::json!([1, 3, 2])
+        );
+        assert_eq!(json["links"][0]["url"], "https://example.com/sort");
+        assert_eq!(serde_json::from_value::<NodeDocs>(json).unwrap(), docs);
+        assert!(NodeDocs::default().is_empty());
     }
 }
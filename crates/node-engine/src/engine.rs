@@ -39,9 +39,10 @@ use async_trait::async_trait;
 use graph_flow::Context;
 use tokio::sync::RwLock;
 
-use crate::error::Result;
+use crate::error::{NodeEngineError, Result};
 use crate::events::{EventSink, WorkflowEvent};
 use crate::extensions::ExecutorExtensions;
+use crate::limits::GraphLimits;
 use crate::types::{NodeId, WorkflowGraph};
 
 pub(super) type NodeOutputMap = HashMap<String, serde_json::Value>;
@@ -78,7 +79,9 @@ impl<'a> DemandRuntimeContext<'a> {
     }
 }
 
+mod cache_export;
 mod dependency_inputs;
+mod determinism;
 mod execution_core;
 mod execution_events;
 mod graph_events;
@@ -88,15 +91,21 @@ mod multi_demand;
 mod node_preparation;
 mod output_cache;
 mod session_state;
+mod shutdown;
 mod single_demand;
 mod workflow_execution_session;
 
+pub use cache_export::{
+    hash_task_inputs, DemandCacheEntry, DemandCacheExport, DEMAND_CACHE_EXPORT_FORMAT_VERSION,
+};
+pub use determinism::DeterministicMode;
 pub use session_state::{
     GraphMemoryImpactSummary, NodeMemoryCompatibility, NodeMemoryCompatibilitySnapshot,
     NodeMemoryIdentity, NodeMemoryIndirectStateReference, NodeMemoryRestoreStrategy,
     NodeMemorySnapshot, NodeMemoryStatus, WorkflowExecutionSessionCheckpointSummary,
     WorkflowExecutionSessionResidencyState,
 };
+pub use shutdown::{ExecutorDrainReport, ExecutorShutdownReport};
 
 /// Trait for executing a single node/task
 ///
@@ -121,6 +130,13 @@ pub trait TaskExecutor: Send + Sync {
         context: &Context,
         extensions: &ExecutorExtensions,
     ) -> Result<HashMap<String, serde_json::Value>>;
+
+    /// Release backend resources this executor holds, such as inference
+    /// server processes
+    ///
+    /// Called by [`WorkflowExecutor::shutdown`] after every running demand has
+    /// been cancelled. The default does nothing.
+    async fn shutdown(&self) {}
 }
 
 /// Cached output for a node with its version
@@ -145,6 +161,14 @@ pub struct DemandEngine {
     /// Last resolved task inputs captured at execution time for node-memory
     /// projection and inspection.
     last_inputs: HashMap<NodeId, serde_json::Value>,
+    /// Outputs imported from another executor's demand-cache export, keyed
+    /// by node and input hash. Consulted before executing a node.
+    warm_cache: cache_export::WarmCache,
+    /// Nodes whose last execution panicked. Cleared when the node next
+    /// completes successfully.
+    poisoned: HashSet<NodeId>,
+    /// Pins seeds and temperatures and orders scheduling when set.
+    deterministic: Option<DeterministicMode>,
     /// Global version counter (for marking external changes)
     global_version: u64,
     /// Execution ID for events
@@ -158,6 +182,9 @@ impl DemandEngine {
             versions: HashMap::new(),
             cache: HashMap::new(),
             last_inputs: HashMap::new(),
+            warm_cache: HashMap::new(),
+            poisoned: HashSet::new(),
+            deterministic: None,
             global_version: 0,
             execution_id: execution_id.into(),
         }
@@ -215,6 +242,22 @@ impl DemandEngine {
             .fold(0u64, |acc, v| acc.wrapping_add(*v))
     }
 
+    /// Enable or disable deterministic execution mode.
+    ///
+    /// Changing the mode clears the cache, since cached outputs were produced
+    /// under different input pinning.
+    pub fn set_deterministic_mode(&mut self, mode: Option<DeterministicMode>) {
+        if self.deterministic != mode {
+            self.deterministic = mode;
+            self.clear_cache();
+        }
+    }
+
+    /// Current deterministic execution mode, if enabled.
+    pub fn deterministic_mode(&self) -> Option<DeterministicMode> {
+        self.deterministic
+    }
+
     /// Clear the entire cache
     pub fn clear_cache(&mut self) {
         self.cache.clear();
@@ -232,9 +275,47 @@ impl DemandEngine {
             cached_nodes: self.cache.len(),
             total_versions: self.versions.len(),
             global_version: self.global_version,
+            poisoned_nodes: self.poisoned_nodes(),
         }
     }
 
+    /// Nodes whose last execution panicked, sorted by ID.
+    pub fn poisoned_nodes(&self) -> Vec<NodeId> {
+        let mut nodes: Vec<NodeId> = self.poisoned.iter().cloned().collect();
+        nodes.sort();
+        nodes
+    }
+
+    /// Export cached node outputs keyed by a portable input hash.
+    ///
+    /// Nodes without a recorded input snapshot are skipped. Imported warm
+    /// entries that have not been demanded yet are carried through.
+    pub fn export_cache(&self) -> DemandCacheExport {
+        cache_export::build_export(&self.cache, &self.last_inputs, &self.warm_cache)
+    }
+
+    /// Import entries from a demand-cache export to warm this engine.
+    ///
+    /// Imported outputs are reused when a node's resolved inputs hash to an
+    /// imported entry; the node is then not executed. Returns the number of
+    /// entries imported.
+    pub fn import_cache(&mut self, export: DemandCacheExport) -> Result<usize> {
+        cache_export::import_entries(&mut self.warm_cache, export)
+    }
+
+    /// Write [`DemandEngine::export_cache`] to a JSON file and return the
+    /// number of exported entries.
+    pub fn export_cache_to_file(&self, path: &std::path::Path) -> Result<usize> {
+        let export = self.export_cache();
+        export.write_to_file(path)?;
+        Ok(export.entries.len())
+    }
+
+    /// Read a demand-cache export file and import it.
+    pub fn import_cache_from_file(&mut self, path: &std::path::Path) -> Result<usize> {
+        self.import_cache(DemandCacheExport::read_from_file(path)?)
+    }
+
     fn reconcile_isolated_run(&mut self, base: &Self, isolated: &Self) {
         reconcile_changed_node_entries(&mut self.versions, &base.versions, &isolated.versions);
         reconcile_changed_node_entries(&mut self.cache, &base.cache, &isolated.cache);
@@ -398,6 +479,8 @@ pub struct CacheStats {
     pub total_versions: usize,
     /// Global version counter
     pub global_version: u64,
+    /// Nodes whose last execution panicked
+    pub poisoned_nodes: Vec<NodeId>,
 }
 
 /// Wrapper for executing workflows with graph-flow
@@ -416,10 +499,14 @@ pub struct WorkflowExecutor {
     /// Phase 6 session-state scaffold for workflow execution session residency and
     /// checkpoint integration.
     session_state: Arc<session_state::WorkflowExecutorSessionState>,
+    /// Cancellation and in-flight demand tracking for `shutdown`
+    shutdown: Arc<shutdown::ExecutorShutdownState>,
     /// Execution ID
     execution_id: String,
     /// Typed extensions for non-serializable dependencies (API clients, etc.)
     extensions: ExecutorExtensions,
+    /// Size and complexity bounds enforced on graph mutations
+    graph_limits: GraphLimits,
 }
 
 impl WorkflowExecutor {
@@ -436,8 +523,10 @@ impl WorkflowExecutor {
             event_sink,
             graph: Arc::new(RwLock::new(graph)),
             session_state: Arc::new(session_state::WorkflowExecutorSessionState::new()),
+            shutdown: Arc::new(shutdown::ExecutorShutdownState::new()),
             execution_id,
             extensions: ExecutorExtensions::new(),
+            graph_limits: GraphLimits::default(),
         }
     }
 
@@ -471,6 +560,21 @@ impl WorkflowExecutor {
         &mut self.extensions
     }
 
+    /// Graph size and complexity limits; unlimited by default
+    pub fn graph_limits(&self) -> GraphLimits {
+        self.graph_limits
+    }
+
+    /// Enforce `limits` on the current graph and every later mutation.
+    ///
+    /// Fails without changing the limits when the current graph already
+    /// exceeds them, so hosts call this right after loading a graph.
+    pub async fn set_graph_limits(&mut self, limits: GraphLimits) -> Result<()> {
+        limits.check_graph(&*self.graph.read().await)?;
+        self.graph_limits = limits;
+        Ok(())
+    }
+
     /// Get a reference to the workflow graph
     pub fn graph(&self) -> &Arc<RwLock<WorkflowGraph>> {
         &self.graph
@@ -665,7 +769,11 @@ impl WorkflowExecutor {
         node_id: &NodeId,
         executor: &dyn TaskExecutor,
     ) -> Result<HashMap<String, serde_json::Value>> {
-        single_demand::demand_with_executor(self, node_id, executor).await
+        let _in_flight = self.shutdown.begin_demand()?;
+        tokio::select! {
+            result = single_demand::demand_with_executor(self, node_id, executor) => result,
+            _ = self.shutdown.cancelled() => Err(NodeEngineError::Cancelled),
+        }
     }
 
     /// Demand outputs from multiple nodes
@@ -674,7 +782,54 @@ impl WorkflowExecutor {
         node_ids: &[NodeId],
         executor: &dyn TaskExecutor,
     ) -> Result<HashMap<NodeId, HashMap<String, serde_json::Value>>> {
-        multi_demand::demand_multiple_with_executor(self, node_ids, executor).await
+        let _in_flight = self.shutdown.begin_demand()?;
+        tokio::select! {
+            result = multi_demand::demand_multiple_with_executor(self, node_ids, executor) => result,
+            _ = self.shutdown.cancelled() => Err(NodeEngineError::Cancelled),
+        }
+    }
+
+    /// Shut the executor down
+    ///
+    /// Cancels every running demand and waits for them to unwind, flushes the
+    /// event sink, writes the demand cache to `checkpoint_path` (marking the
+    /// bound workflow execution session's checkpoint available), then calls
+    /// [`TaskExecutor::shutdown`] on `task_executor` so backend processes stop
+    /// before this returns. Later demands fail with
+    /// [`NodeEngineError::Cancelled`]; calling it again only waits for
+    /// cancellation to finish.
+    pub async fn shutdown(
+        &self,
+        task_executor: Option<&dyn TaskExecutor>,
+        checkpoint_path: Option<&std::path::Path>,
+    ) -> ExecutorShutdownReport {
+        shutdown::shutdown_executor(self, task_executor, checkpoint_path).await
+    }
+
+    /// Whether [`shutdown`](Self::shutdown) has been called
+    pub fn is_shut_down(&self) -> bool {
+        self.shutdown.is_shutting_down()
+    }
+
+    /// Drain the executor ahead of a deploy or restart
+    ///
+    /// New demands fail with [`NodeEngineError::Cancelled`] at once, while
+    /// running demands get up to `timeout` to finish. Whatever is still
+    /// running then is cancelled, and the executor is shut down as by
+    /// [`shutdown`](Self::shutdown), so `checkpoint_path` receives every
+    /// output completed before the drain ended.
+    pub async fn drain(
+        &self,
+        timeout: std::time::Duration,
+        task_executor: Option<&dyn TaskExecutor>,
+        checkpoint_path: Option<&std::path::Path>,
+    ) -> ExecutorDrainReport {
+        shutdown::drain_executor(self, timeout, task_executor, checkpoint_path).await
+    }
+
+    /// Whether [`drain`](Self::drain) has been called
+    pub fn is_draining(&self) -> bool {
+        self.shutdown.is_draining()
     }
 
     /// Mark a node as modified (e.g., user changed its data)
@@ -701,15 +856,20 @@ impl WorkflowExecutor {
     }
 
     /// Add a new node to the graph
-    pub async fn add_node(&self, node: crate::types::GraphNode) {
-        graph_state::add_node(self, node).await;
+    ///
+    /// Fails with [`NodeEngineError::GraphLimitExceeded`] when the node would
+    /// exceed the executor's node limit.
+    pub async fn add_node(&self, node: crate::types::GraphNode) -> Result<()> {
+        graph_state::add_node(self, node).await
     }
 
     /// Add a new edge to the graph
     ///
     /// This marks the target node as modified since its inputs changed.
-    pub async fn add_edge(&self, edge: crate::types::GraphEdge) {
-        graph_state::add_edge(self, edge).await;
+    /// Fails with [`NodeEngineError::GraphLimitExceeded`] when the edge would
+    /// exceed the executor's edge limit.
+    pub async fn add_edge(&self, edge: crate::types::GraphEdge) -> Result<()> {
+        graph_state::add_edge(self, edge).await
     }
 
     /// Remove an edge from the graph
@@ -731,11 +891,53 @@ impl WorkflowExecutor {
         graph_state::restore_graph_snapshot(self, graph).await;
     }
 
+    /// Replace the graph with one supplied by a caller
+    ///
+    /// Like [`Self::restore_graph_snapshot`], but checks the new graph
+    /// against the executor's limits first and keeps the current graph when
+    /// it exceeds them. Use this for graphs that did not come from
+    /// [`Self::get_graph_snapshot`].
+    pub async fn replace_graph(&self, graph: WorkflowGraph) -> Result<()> {
+        self.graph_limits.check_graph(&graph)?;
+        graph_state::restore_graph_snapshot(self, graph).await;
+        Ok(())
+    }
+
+    /// Enable or disable deterministic execution mode for later demands.
+    pub async fn set_deterministic_mode(&self, mode: Option<DeterministicMode>) {
+        self.demand_engine
+            .write()
+            .await
+            .set_deterministic_mode(mode);
+    }
+
+    /// Current deterministic execution mode, if enabled.
+    pub async fn deterministic_mode(&self) -> Option<DeterministicMode> {
+        self.demand_engine.read().await.deterministic_mode()
+    }
+
     /// Get cache statistics
     pub async fn cache_stats(&self) -> CacheStats {
         let engine = self.demand_engine.read().await;
         engine.cache_stats()
     }
+
+    /// Export the demand cache to a JSON file for warming another executor
+    ///
+    /// Returns the number of exported entries.
+    pub async fn export_demand_cache(&self, path: &std::path::Path) -> Result<usize> {
+        let engine = self.demand_engine.read().await;
+        engine.export_cache_to_file(path)
+    }
+
+    /// Import a demand-cache export file written by another executor
+    ///
+    /// Returns the number of imported entries.
+    pub async fn import_demand_cache(&self, path: &std::path::Path) -> Result<usize> {
+        let export = DemandCacheExport::read_from_file(path)?;
+        let mut engine = self.demand_engine.write().await;
+        engine.import_cache(export)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::CachedOutput;
+use crate::error::{NodeEngineError, Result};
+use crate::types::NodeId;
+
+/// Current on-disk format version for exported demand caches.
+pub const DEMAND_CACHE_EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Input keys injected per executor instance that must not affect the
+/// portable input hash.
+const HASH_EXCLUDED_INPUT_KEYS: &[&str] = &["_node_memory"];
+
+/// One portable demand-cache entry.
+///
+/// `input_hash` is a content hash of the node's resolved task inputs
+/// (including static node data), so it stays valid across executor instances
+/// and processes, unlike the in-memory version counters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DemandCacheEntry {
+    /// Node the outputs belong to
+    pub node_id: NodeId,
+    /// Content hash of the resolved task inputs
+    pub input_hash: String,
+    /// Output map produced for those inputs
+    pub outputs: serde_json::Value,
+}
+
+/// Portable snapshot of demand-cache outputs that can be written to a file
+/// and imported into another executor to warm it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DemandCacheExport {
+    /// Format version; imports reject versions they do not understand
+    pub format_version: u32,
+    /// Entries sorted by node id then input hash
+    pub entries: Vec<DemandCacheEntry>,
+}
+
+impl DemandCacheExport {
+    /// Write the export as pretty-printed JSON.
+    pub fn write_to_file(&self, path: &Path) -> Result<()> {
+        let bytes = serde_json::to_vec_pretty(self)?;
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    /// Read an export previously written by [`DemandCacheExport::write_to_file`].
+    pub fn read_from_file(path: &Path) -> Result<Self> {
+        let bytes = std::fs::read(path)?;
+        let export: Self = serde_json::from_slice(&bytes)?;
+        Ok(export)
+    }
+}
+
+/// Warmed outputs imported from another executor, keyed by node id and then
+/// by input hash.
+pub(super) type WarmCache = HashMap<NodeId, HashMap<String, serde_json::Value>>;
+
+/// Hash resolved task inputs with FNV-1a over canonical JSON.
+///
+/// `serde_json` object maps are key-sorted, so the serialized form is stable
+/// regardless of insertion order.
+pub fn hash_task_inputs(inputs: &HashMap<String, serde_json::Value>) -> String {
+    let canonical: serde_json::Map<String, serde_json::Value> = inputs
+        .iter()
+        .filter(|(key, _)| !HASH_EXCLUDED_INPUT_KEYS.contains(&key.as_str()))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    let bytes = serde_json::Value::Object(canonical).to_string();
+
+    let mut digest = FNV64_OFFSET_BASIS;
+    digest = fnv1a64_update(digest, b"v1");
+    digest = fnv1a64_update(digest, bytes.as_bytes());
+    format!("{:016x}", digest)
+}
+
+pub(super) fn build_export(
+    cache: &HashMap<NodeId, CachedOutput>,
+    last_inputs: &HashMap<NodeId, serde_json::Value>,
+    warm_cache: &WarmCache,
+) -> DemandCacheExport {
+    let mut entries: HashMap<(NodeId, String), serde_json::Value> = HashMap::new();
+
+    // Imported entries carry through so re-exporting a warmed executor does
+    // not drop outputs it has not demanded yet.
+    for (node_id, by_hash) in warm_cache {
+        for (input_hash, outputs) in by_hash {
+            entries.insert((node_id.clone(), input_hash.clone()), outputs.clone());
+        }
+    }
+
+    for (node_id, cached) in cache {
+        let Some(serde_json::Value::Object(inputs)) = last_inputs.get(node_id) else {
+            continue;
+        };
+        let inputs: HashMap<String, serde_json::Value> = inputs
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        entries.insert(
+            (node_id.clone(), hash_task_inputs(&inputs)),
+            cached.value.clone(),
+        );
+    }
+
+    let mut entries: Vec<DemandCacheEntry> = entries
+        .into_iter()
+        .map(|((node_id, input_hash), outputs)| DemandCacheEntry {
+            node_id,
+            input_hash,
+            outputs,
+        })
+        .collect();
+    entries.sort_by(|a, b| (&a.node_id, &a.input_hash).cmp(&(&b.node_id, &b.input_hash)));
+
+    DemandCacheExport {
+        format_version: DEMAND_CACHE_EXPORT_FORMAT_VERSION,
+        entries,
+    }
+}
+
+pub(super) fn import_entries(
+    warm_cache: &mut WarmCache,
+    export: DemandCacheExport,
+) -> Result<usize> {
+    if export.format_version != DEMAND_CACHE_EXPORT_FORMAT_VERSION {
+        return Err(NodeEngineError::ExecutionFailed(format!(
+            "Unsupported demand cache export format version {} (expected {})",
+            export.format_version, DEMAND_CACHE_EXPORT_FORMAT_VERSION
+        )));
+    }
+
+    let mut imported = 0;
+    for entry in export.entries {
+        if !entry.outputs.is_object() {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Demand cache entry for node '{}' has non-object outputs",
+                entry.node_id
+            )));
+        }
+        warm_cache
+            .entry(entry.node_id)
+            .or_default()
+            .insert(entry.input_hash, entry.outputs);
+        imported += 1;
+    }
+    Ok(imported)
+}
+
+/// Look up warmed outputs for a node whose resolved inputs hash to an
+/// imported entry. Skips hashing entirely when nothing was imported for the
+/// node.
+pub(super) fn resolve_warm_output(
+    warm_cache: &WarmCache,
+    node_id: &NodeId,
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<Option<HashMap<String, serde_json::Value>>> {
+    let Some(by_hash) = warm_cache.get(node_id) else {
+        return Ok(None);
+    };
+    let input_hash = hash_task_inputs(inputs);
+    let Some(outputs) = by_hash.get(&input_hash) else {
+        log::debug!(
+            "Warm cache miss for node '{}' (input hash {})",
+            node_id,
+            input_hash
+        );
+        return Ok(None);
+    };
+
+    log::debug!(
+        "Warm cache hit for node '{}' (input hash {})",
+        node_id,
+        input_hash
+    );
+    Ok(Some(serde_json::from_value(outputs.clone())?))
+}
+
+const FNV64_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV64_PRIME: u64 = 0x100000001b3;
+
+fn fnv1a64_update(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(FNV64_PRIME);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(pairs: &[(&str, serde_json::Value)]) -> HashMap<String, serde_json::Value> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn hash_task_inputs_is_order_independent_and_ignores_node_memory() {
+        let a = inputs(&[
+            ("prompt", serde_json::json!("hi")),
+            ("_data", serde_json::json!({"b": 1, "a": 2})),
+        ]);
+        let mut b = inputs(&[
+            ("_data", serde_json::json!({"a": 2, "b": 1})),
+            ("prompt", serde_json::json!("hi")),
+        ]);
+        b.insert(
+            "_node_memory".to_string(),
+            serde_json::json!({"status": "ready"}),
+        );
+
+        assert_eq!(hash_task_inputs(&a), hash_task_inputs(&b));
+        assert_ne!(
+            hash_task_inputs(&a),
+            hash_task_inputs(&inputs(&[("prompt", serde_json::json!("bye"))]))
+        );
+    }
+
+    #[test]
+    fn build_export_hashes_last_inputs_and_skips_nodes_without_inputs() {
+        let cache = HashMap::from([
+            (
+                "a".to_string(),
+                CachedOutput {
+                    version: 1,
+                    value: serde_json::json!({"out": 1}),
+                },
+            ),
+            (
+                "b".to_string(),
+                CachedOutput {
+                    version: 1,
+                    value: serde_json::json!({"out": 2}),
+                },
+            ),
+        ]);
+        let last_inputs = HashMap::from([("a".to_string(), serde_json::json!({"x": 1}))]);
+
+        let export = build_export(&cache, &last_inputs, &WarmCache::new());
+
+        assert_eq!(export.format_version, DEMAND_CACHE_EXPORT_FORMAT_VERSION);
+        assert_eq!(
+            export.entries,
+            vec![DemandCacheEntry {
+                node_id: "a".to_string(),
+                input_hash: hash_task_inputs(&inputs(&[("x", serde_json::json!(1))])),
+                outputs: serde_json::json!({"out": 1}),
+            }]
+        );
+    }
+
+    #[test]
+    fn import_then_resolve_round_trips_outputs() {
+        let node_inputs = inputs(&[("x", serde_json::json!(1))]);
+        let export = DemandCacheExport {
+            format_version: DEMAND_CACHE_EXPORT_FORMAT_VERSION,
+            entries: vec![DemandCacheEntry {
+                node_id: "a".to_string(),
+                input_hash: hash_task_inputs(&node_inputs),
+                outputs: serde_json::json!({"out": "warm"}),
+            }],
+        };
+        let mut warm = WarmCache::new();
+
+        assert_eq!(import_entries(&mut warm, export).expect("import"), 1);
+
+        let hit = resolve_warm_output(&warm, &"a".to_string(), &node_inputs).expect("resolve");
+        assert_eq!(
+            hit,
+            Some(HashMap::from([(
+                "out".to_string(),
+                serde_json::json!("warm")
+            )]))
+        );
+        let miss = resolve_warm_output(
+            &warm,
+            &"a".to_string(),
+            &inputs(&[("x", serde_json::json!(2))]),
+        )
+        .expect("resolve");
+        assert_eq!(miss, None);
+    }
+
+    #[test]
+    fn import_rejects_unknown_format_version() {
+        let mut warm = WarmCache::new();
+        let result = import_entries(
+            &mut warm,
+            DemandCacheExport {
+                format_version: 99,
+                entries: Vec::new(),
+            },
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn export_file_round_trip() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("cache.json");
+        let export = DemandCacheExport {
+            format_version: DEMAND_CACHE_EXPORT_FORMAT_VERSION,
+            entries: vec![DemandCacheEntry {
+                node_id: "a".to_string(),
+                input_hash: "0123456789abcdef".to_string(),
+                outputs: serde_json::json!({"out": 1}),
+            }],
+        };
+
+        export.write_to_file(&path).expect("write");
+        let loaded = DemandCacheExport::read_from_file(&path).expect("read");
+
+        assert_eq!(loaded, export);
+    }
+}
@@ -55,7 +55,7 @@ fn merge_model_path_context(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{GraphEdge, GraphNode, WorkflowGraph};
+    use crate::types::{GraphEdge, GraphNode, GraphPorts, WorkflowGraph};
 
     #[test]
     fn resolve_dependency_inputs_maps_edges_by_port() {
@@ -84,6 +84,7 @@ mod tests {
                 target_handle: "input".to_string(),
             }],
             groups: Vec::new(),
+            ports: GraphPorts::default(),
         };
 
         let dependency_outputs = HashMap::from([(
@@ -123,6 +124,7 @@ mod tests {
                 target_handle: "model_path".to_string(),
             }],
             groups: Vec::new(),
+            ports: GraphPorts::default(),
         };
 
         let dependency_outputs = HashMap::from([(
@@ -0,0 +1,159 @@
+//! Deterministic execution mode.
+//!
+//! When a [`DeterministicMode`] is set on the demand engine, node inputs are
+//! pinned before execution so repeated runs of a graph produce the same
+//! outputs: nodes with a `seed` input get the mode's seed unless a fixed
+//! non-negative seed is already set, and nodes with a `temperature` input run
+//! at temperature 0. Nodes declared through
+//! [`NondeterministicNode`](crate::descriptor::NondeterministicNode) are
+//! rejected, and dependencies are demanded in sorted ID order.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::descriptor::{DescriptorFn, NondeterministicNode};
+use crate::error::{NodeEngineError, Result};
+
+const SEED_PORT: &str = "seed";
+const TEMPERATURE_PORT: &str = "temperature";
+
+/// Settings for deterministic execution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeterministicMode {
+    /// Seed given to every node with a `seed` input that has no fixed seed.
+    pub seed: i64,
+}
+
+/// Determinism-relevant traits of a node type, derived from its descriptor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct NodeDeterminismTraits {
+    pub(super) nondeterministic: bool,
+    pub(super) has_seed: bool,
+    pub(super) has_temperature: bool,
+}
+
+struct DeterminismIndex {
+    nondeterministic: HashSet<&'static str>,
+    seeded: HashSet<String>,
+    tempered: HashSet<String>,
+}
+
+fn index() -> &'static DeterminismIndex {
+    static INDEX: OnceLock<DeterminismIndex> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut seeded = HashSet::new();
+        let mut tempered = HashSet::new();
+        for descriptor in inventory::iter::<DescriptorFn> {
+            let metadata = (descriptor.0)();
+            for port in &metadata.inputs {
+                match port.id.as_str() {
+                    SEED_PORT => {
+                        seeded.insert(metadata.node_type.clone());
+                    }
+                    TEMPERATURE_PORT => {
+                        tempered.insert(metadata.node_type.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+        DeterminismIndex {
+            nondeterministic: inventory::iter::<NondeterministicNode>
+                .into_iter()
+                .map(|node| node.0)
+                .collect(),
+            seeded,
+            tempered,
+        }
+    })
+}
+
+/// Look up a node type's traits in the linked descriptor inventory.
+pub(super) fn node_traits(node_type: &str) -> NodeDeterminismTraits {
+    let index = index();
+    NodeDeterminismTraits {
+        nondeterministic: index.nondeterministic.contains(node_type),
+        has_seed: index.seeded.contains(node_type),
+        has_temperature: index.tempered.contains(node_type),
+    }
+}
+
+/// Pin a node's inputs for deterministic execution.
+pub(super) fn apply_deterministic_inputs(
+    mode: DeterministicMode,
+    node_id: &str,
+    node_type: &str,
+    traits: NodeDeterminismTraits,
+    inputs: &mut HashMap<String, serde_json::Value>,
+) -> Result<()> {
+    if traits.nondeterministic {
+        return Err(NodeEngineError::failed(format!(
+            "Node '{}' of type '{}' is nondeterministic and cannot run in deterministic mode",
+            node_id, node_type
+        )));
+    }
+
+    let has_fixed_seed = inputs
+        .get(SEED_PORT)
+        .and_then(|seed| seed.as_i64())
+        .is_some_and(|seed| seed >= 0);
+    if traits.has_seed && !has_fixed_seed {
+        inputs.insert(SEED_PORT.to_string(), serde_json::json!(mode.seed));
+    }
+    if traits.has_temperature {
+        inputs.insert(TEMPERATURE_PORT.to_string(), serde_json::json!(0.0));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    inventory::submit!(NondeterministicNode("determinism-test-clock"));
+
+    #[test]
+    fn inventory_declarations_mark_nodes_nondeterministic() {
+        assert!(node_traits("determinism-test-clock").nondeterministic);
+        assert_eq!(node_traits("unknown"), NodeDeterminismTraits::default());
+    }
+
+    #[test]
+    fn nondeterministic_nodes_are_rejected() {
+        let error = apply_deterministic_inputs(
+            DeterministicMode::default(),
+            "clock-1",
+            "determinism-test-clock",
+            node_traits("determinism-test-clock"),
+            &mut HashMap::new(),
+        )
+        .expect_err("nondeterministic node rejected");
+
+        assert!(error.to_string().contains("clock-1"));
+    }
+
+    #[test]
+    fn seeds_and_temperature_are_pinned() {
+        let traits = NodeDeterminismTraits {
+            nondeterministic: false,
+            has_seed: true,
+            has_temperature: true,
+        };
+        let mode = DeterministicMode { seed: 42 };
+
+        let mut random_seed = HashMap::from([
+            ("seed".to_string(), serde_json::json!(-1)),
+            ("temperature".to_string(), serde_json::json!(0.8)),
+        ]);
+        apply_deterministic_inputs(mode, "gen-1", "gen", traits, &mut random_seed).expect("apply");
+        assert_eq!(random_seed["seed"], 42);
+        assert_eq!(random_seed["temperature"], 0.0);
+
+        let mut fixed_seed = HashMap::from([("seed".to_string(), serde_json::json!(7))]);
+        apply_deterministic_inputs(mode, "gen-1", "gen", traits, &mut fixed_seed).expect("apply");
+        assert_eq!(fixed_seed["seed"], 7);
+    }
+}
@@ -78,6 +78,24 @@ impl<'a> DemandExecutionCore<'a> {
                     return Err(NodeEngineError::waiting_for_input(node_id.clone(), prompt));
                 }
 
+                if let Some(mode) = self.engine.deterministic {
+                    let node_type = self
+                        .runtime
+                        .graph
+                        .find_node(node_id)
+                        .map(|node| node.node_type.clone())
+                        .unwrap_or_else(|| {
+                            crate::core_executor::resolve_node_type(node_id, &inputs)
+                        });
+                    super::determinism::apply_deterministic_inputs(
+                        mode,
+                        node_id,
+                        &node_type,
+                        super::determinism::node_traits(&node_type),
+                        &mut inputs,
+                    )?;
+                }
+
                 super::execution_events::emit_task_started(
                     self.runtime.event_sink,
                     node_id.clone(),
@@ -85,30 +103,43 @@ impl<'a> DemandExecutionCore<'a> {
                 );
 
                 let task_inputs = inputs.clone();
-                let outputs = match self
-                    .runtime
-                    .executor
-                    .execute_task(
+                let warm_outputs = super::cache_export::resolve_warm_output(
+                    &self.engine.warm_cache,
+                    node_id,
+                    &task_inputs,
+                )?;
+                let outputs = if let Some(outputs) = warm_outputs {
+                    outputs
+                } else {
+                    match crate::panic_isolation::execute_task_contained(
+                        self.runtime.executor,
                         node_id,
                         inputs,
                         self.runtime.context,
                         self.runtime.extensions,
                     )
                     .await
-                {
-                    Ok(outputs) => outputs,
-                    Err(NodeEngineError::WaitingForInput { task_id, prompt }) => {
-                        super::execution_events::emit_waiting_for_input(
-                            self.runtime.event_sink,
-                            self.runtime.graph.id.clone(),
-                            self.engine.execution_id.clone(),
-                            task_id.clone(),
-                            prompt.clone(),
-                        );
-                        return Err(NodeEngineError::WaitingForInput { task_id, prompt });
+                    {
+                        Ok(outputs) => outputs,
+                        Err(NodeEngineError::WaitingForInput { task_id, prompt }) => {
+                            super::execution_events::emit_waiting_for_input(
+                                self.runtime.event_sink,
+                                self.runtime.graph.id.clone(),
+                                self.engine.execution_id.clone(),
+                                task_id.clone(),
+                                prompt.clone(),
+                            );
+                            return Err(NodeEngineError::WaitingForInput { task_id, prompt });
+                        }
+                        Err(error) => {
+                            if matches!(error, NodeEngineError::NodeFailure(_)) {
+                                self.engine.poisoned.insert(node_id.clone());
+                            }
+                            return Err(error);
+                        }
                     }
-                    Err(error) => return Err(error),
                 };
+                self.engine.poisoned.remove(node_id);
 
                 super::execution_events::emit_task_completed(
                     self.runtime.event_sink,
@@ -142,8 +173,13 @@ impl<'a> DemandExecutionCore<'a> {
     ) -> super::DemandFuture<'b, super::MultiNodeOutputMap> {
         Box::pin(async move {
             let mut dependency_outputs = HashMap::new();
+            let mut dependencies = self.runtime.graph.get_dependencies(node_id);
+            if self.engine.deterministic.is_some() {
+                dependencies.sort();
+                dependencies.dedup();
+            }
 
-            for dep_id in self.runtime.graph.get_dependencies(node_id) {
+            for dep_id in dependencies {
                 let dep_outputs = self.run_node(&dep_id).await?;
                 dependency_outputs.insert(dep_id, dep_outputs);
             }
@@ -1,4 +1,5 @@
 use crate::error::{NodeEngineError, Result};
+use crate::limits;
 use crate::types::{GraphEdge, GraphNode, NodeId, WorkflowGraph};
 
 use super::{graph_events, WorkflowExecutor};
@@ -24,22 +25,30 @@ pub(super) async fn update_node_data(
     Ok(())
 }
 
-pub(super) async fn add_node(executor: &WorkflowExecutor, node: GraphNode) {
+pub(super) async fn add_node(executor: &WorkflowExecutor, node: GraphNode) -> Result<()> {
     let node_id = node.id.clone();
     let mut graph = executor.graph.write().await;
+    executor
+        .graph_limits
+        .check_node_count(limits::node_count(&graph) + 1)?;
     graph.nodes.push(node);
     let workflow_id = graph.id.clone();
     drop(graph);
     executor.emit_graph_modified(workflow_id, vec![node_id], None);
+    Ok(())
 }
 
-pub(super) async fn add_edge(executor: &WorkflowExecutor, edge: GraphEdge) {
+pub(super) async fn add_edge(executor: &WorkflowExecutor, edge: GraphEdge) -> Result<()> {
     let target = edge.target.clone();
     {
         let mut graph = executor.graph.write().await;
+        executor
+            .graph_limits
+            .check_edge_count(limits::edge_count(&graph) + 1)?;
         graph.edges.push(edge);
     }
     executor.mark_modified(&target).await;
+    Ok(())
 }
 
 pub(super) async fn remove_edge(executor: &WorkflowExecutor, edge_id: &str) {
@@ -213,7 +213,6 @@ impl DemandExecutionBudget {
         Self::new(2)
     }
 
-    #[cfg(test)]
     fn sequential() -> Self {
         Self::new(1)
     }
@@ -627,6 +626,12 @@ async fn execute_plan_with_budget(
     budget: DemandExecutionBudget,
     runtime: super::DemandRuntimeContext<'_>,
 ) -> Result<HashMap<NodeId, HashMap<String, serde_json::Value>>> {
+    // Parallel windows interleave task events nondeterministically.
+    let budget = if engine.deterministic_mode().is_some() {
+        DemandExecutionBudget::sequential()
+    } else {
+        budget
+    };
     DemandMultipleCoordinator::new(engine, plan, budget, runtime)
         .run()
         .await
@@ -11,7 +11,7 @@ use crate::engine::{DemandRuntimeContext, WorkflowExecutor};
 use crate::error::NodeEngineError;
 use crate::events::{EventSink, NullEventSink};
 use crate::extensions::ExecutorExtensions;
-use crate::types::{GraphEdge, GraphNode, WorkflowGraph};
+use crate::types::{GraphEdge, GraphNode, GraphPorts, WorkflowGraph};
 
 use super::{
     demand_multiple_with_default_budget, demand_multiple_with_explicit_budget,
@@ -72,6 +72,7 @@ fn make_linear_graph() -> WorkflowGraph {
             },
         ],
         groups: Vec::new(),
+        ports: GraphPorts::default(),
     }
 }
 
@@ -140,6 +141,7 @@ fn make_disjoint_branches_graph() -> WorkflowGraph {
             },
         ],
         groups: Vec::new(),
+        ports: GraphPorts::default(),
     }
 }
 
@@ -184,6 +186,7 @@ fn make_shared_dependency_graph() -> WorkflowGraph {
             },
         ],
         groups: Vec::new(),
+        ports: GraphPorts::default(),
     }
 }
 
@@ -207,6 +210,7 @@ fn make_parallel_roots_graph() -> WorkflowGraph {
         ],
         edges: Vec::new(),
         groups: Vec::new(),
+        ports: GraphPorts::default(),
     }
 }
 
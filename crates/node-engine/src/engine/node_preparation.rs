@@ -4,6 +4,7 @@ use crate::core_executor::{
     human_input_auto_accept, human_input_default_value, human_input_prompt,
     human_input_response_value,
 };
+use crate::orchestration::data_graph::GRAPH_INPUTS_DATA_KEY;
 use crate::types::{NodeId, WorkflowGraph};
 
 pub(super) fn prepare_node_inputs(
@@ -17,11 +18,26 @@ pub(super) fn prepare_node_inputs(
         inputs.insert("_data".to_string(), node.data.clone());
     }
 
+    inject_graph_inputs(&node.data, inputs);
+
     inject_kv_cache_input_from_node_memory(inputs);
 
     unresolved_human_input_prompt(&node.node_type, inputs)
 }
 
+/// Graph inputs bound by a data-graph run are port inputs; edges win.
+fn inject_graph_inputs(data: &serde_json::Value, inputs: &mut HashMap<String, serde_json::Value>) {
+    let Some(bound) = data
+        .get(GRAPH_INPUTS_DATA_KEY)
+        .and_then(|value| value.as_object())
+    else {
+        return;
+    };
+    for (port, value) in bound {
+        inputs.entry(port.clone()).or_insert_with(|| value.clone());
+    }
+}
+
 fn inject_kv_cache_input_from_node_memory(inputs: &mut HashMap<String, serde_json::Value>) {
     if inputs.contains_key("kv_cache_in") {
         return;
@@ -99,7 +115,7 @@ fn unresolved_human_input_prompt(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{GraphNode, WorkflowGraph};
+    use crate::types::{GraphNode, GraphPorts, WorkflowGraph};
 
     #[test]
     fn prepare_node_inputs_injects_static_node_data() {
@@ -114,6 +130,7 @@ mod tests {
             }],
             edges: Vec::new(),
             groups: Vec::new(),
+            ports: GraphPorts::default(),
         };
         let mut inputs = HashMap::new();
 
@@ -126,6 +143,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prepare_node_inputs_delivers_bound_graph_inputs_without_overriding_edges() {
+        let graph = WorkflowGraph {
+            id: "workflow".to_string(),
+            name: "Workflow".to_string(),
+            nodes: vec![GraphNode {
+                id: "validator".to_string(),
+                node_type: "json-schema-validate".to_string(),
+                data: serde_json::json!({
+                    "_graph_inputs": {"json": "{}", "schema": "{\"type\": \"object\"}"}
+                }),
+                position: (0.0, 0.0),
+            }],
+            edges: Vec::new(),
+            groups: Vec::new(),
+            ports: GraphPorts::default(),
+        };
+        let mut inputs = HashMap::from([("schema".to_string(), serde_json::json!("from edge"))]);
+
+        prepare_node_inputs(&graph, &"validator".to_string(), &mut inputs);
+
+        assert_eq!(inputs.get("json"), Some(&serde_json::json!("{}")));
+        assert_eq!(inputs.get("schema"), Some(&serde_json::json!("from edge")));
+    }
+
     #[test]
     fn prepare_node_inputs_returns_wait_prompt_for_unanswered_human_input() {
         let graph = WorkflowGraph {
@@ -141,6 +183,7 @@ mod tests {
             }],
             edges: Vec::new(),
             groups: Vec::new(),
+            ports: GraphPorts::default(),
         };
         let mut inputs = HashMap::new();
 
@@ -165,6 +208,7 @@ mod tests {
             }],
             edges: Vec::new(),
             groups: Vec::new(),
+            ports: GraphPorts::default(),
         };
         let mut inputs =
             HashMap::from([("user_response".to_string(), serde_json::json!("approved"))]);
@@ -187,6 +231,7 @@ mod tests {
             }],
             edges: Vec::new(),
             groups: Vec::new(),
+            ports: GraphPorts::default(),
         };
         let mut inputs = HashMap::from([(
             "_node_memory".to_string(),
@@ -252,6 +297,7 @@ mod tests {
             }],
             edges: Vec::new(),
             groups: Vec::new(),
+            ports: GraphPorts::default(),
         };
         let explicit_handle = serde_json::json!({
             "cache_id": "explicit-cache",
@@ -315,6 +361,7 @@ mod tests {
             }],
             edges: Vec::new(),
             groups: Vec::new(),
+            ports: GraphPorts::default(),
         };
         let mut inputs = HashMap::from([(
             "_node_memory".to_string(),
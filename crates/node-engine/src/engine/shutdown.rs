@@ -0,0 +1,288 @@
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::sync::Notify;
+
+use super::{TaskExecutor, WorkflowExecutor};
+use crate::error::{NodeEngineError, Result};
+
+/// Outcome of [`WorkflowExecutor::shutdown`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ExecutorShutdownReport {
+    /// The executor had already been shut down; nothing else was done.
+    pub already_shut_down: bool,
+    /// Demands that were running when shutdown began and were cancelled.
+    pub cancelled_demands: usize,
+    /// Error returned by the event sink's flush, if any.
+    pub event_flush_error: Option<String>,
+    /// Demand-cache entries written to the checkpoint path.
+    pub checkpoint_entries: Option<usize>,
+    /// Error from writing the checkpoint, if any.
+    pub checkpoint_error: Option<String>,
+    /// Bound workflow execution session marked as having a checkpoint.
+    pub checkpointed_session_id: Option<String>,
+}
+
+/// Outcome of [`WorkflowExecutor::drain`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ExecutorDrainReport {
+    /// Demands running when the drain began.
+    pub running_at_start: usize,
+    /// Of those, demands that finished before the timeout.
+    pub finished: usize,
+    /// The timeout elapsed with demands still running; they were cancelled.
+    pub timed_out: bool,
+    /// The shutdown that ended the drain.
+    pub shutdown: ExecutorShutdownReport,
+}
+
+/// Cancellation and in-flight demand tracking for one executor.
+pub(crate) struct ExecutorShutdownState {
+    draining: AtomicBool,
+    shutting_down: AtomicBool,
+    cancelled: Notify,
+    in_flight: AtomicUsize,
+    idle: Notify,
+}
+
+/// Counts one running demand until dropped.
+pub(crate) struct InFlightDemand<'a> {
+    state: &'a ExecutorShutdownState,
+}
+
+impl ExecutorShutdownState {
+    pub(crate) fn new() -> Self {
+        Self {
+            draining: AtomicBool::new(false),
+            shutting_down: AtomicBool::new(false),
+            cancelled: Notify::new(),
+            in_flight: AtomicUsize::new(0),
+            idle: Notify::new(),
+        }
+    }
+
+    pub(crate) fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    /// Register a demand, or fail with [`NodeEngineError::Cancelled`] once
+    /// a drain or shutdown has begun.
+    pub(crate) fn begin_demand(&self) -> Result<InFlightDemand<'_>> {
+        // Count first so a concurrent `cancel` either sees this demand or
+        // this demand sees the flag.
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        let demand = InFlightDemand { state: self };
+        if self.is_draining() || self.is_shutting_down() {
+            return Err(NodeEngineError::Cancelled);
+        }
+        Ok(demand)
+    }
+
+    /// Reject new demands while letting running ones finish. Returns the
+    /// number of running demands.
+    pub(crate) fn stop_accepting(&self) -> usize {
+        self.draining.store(true, Ordering::SeqCst);
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Start shutdown and wake every running demand. Returns `None` if
+    /// shutdown had already begun, otherwise the number of running demands.
+    pub(crate) fn cancel(&self) -> Option<usize> {
+        if self.shutting_down.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+        let running = self.in_flight.load(Ordering::SeqCst);
+        self.cancelled.notify_waiters();
+        Some(running)
+    }
+
+    /// Resolve once shutdown has begun.
+    pub(crate) async fn cancelled(&self) {
+        loop {
+            // `notify_waiters` only wakes futures that already exist, so
+            // create it before checking the flag.
+            let notified = self.cancelled.notified();
+            if self.is_shutting_down() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Resolve once no demand is running.
+    pub(crate) async fn wait_idle(&self) {
+        loop {
+            let notified = self.idle.notified();
+            if self.in_flight.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+impl Drop for InFlightDemand<'_> {
+    fn drop(&mut self) {
+        if self.state.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.state.idle.notify_waiters();
+        }
+    }
+}
+
+pub(super) async fn shutdown_executor(
+    workflow_executor: &WorkflowExecutor,
+    task_executor: Option<&dyn TaskExecutor>,
+    checkpoint_path: Option<&Path>,
+) -> ExecutorShutdownReport {
+    let state = &workflow_executor.shutdown;
+    let Some(cancelled_demands) = state.cancel() else {
+        state.wait_idle().await;
+        return ExecutorShutdownReport {
+            already_shut_down: true,
+            ..Default::default()
+        };
+    };
+    state.wait_idle().await;
+
+    let mut report = ExecutorShutdownReport {
+        cancelled_demands,
+        ..Default::default()
+    };
+
+    if let Err(error) = workflow_executor.event_sink.flush() {
+        log::warn!(
+            "Executor '{}': failed to flush events on shutdown: {}",
+            workflow_executor.execution_id,
+            error
+        );
+        report.event_flush_error = Some(error.message);
+    }
+
+    if let Some(path) = checkpoint_path {
+        match workflow_executor.export_demand_cache(path).await {
+            Ok(entries) => {
+                report.checkpoint_entries = Some(entries);
+                if let Some(session_id) = workflow_executor
+                    .bound_workflow_execution_session_id()
+                    .await
+                {
+                    workflow_executor
+                        .mark_workflow_execution_session_checkpoint_available(&session_id)
+                        .await;
+                    report.checkpointed_session_id = Some(session_id);
+                }
+            }
+            Err(error) => {
+                log::warn!(
+                    "Executor '{}': failed to write shutdown checkpoint {:?}: {}",
+                    workflow_executor.execution_id,
+                    path,
+                    error
+                );
+                report.checkpoint_error = Some(error.to_string());
+            }
+        }
+    }
+
+    if let Some(task_executor) = task_executor {
+        task_executor.shutdown().await;
+    }
+
+    log::info!(
+        "Executor '{}' shut down ({} demand(s) cancelled)",
+        workflow_executor.execution_id,
+        cancelled_demands
+    );
+    report
+}
+
+pub(super) async fn drain_executor(
+    workflow_executor: &WorkflowExecutor,
+    timeout: Duration,
+    task_executor: Option<&dyn TaskExecutor>,
+    checkpoint_path: Option<&Path>,
+) -> ExecutorDrainReport {
+    let state = &workflow_executor.shutdown;
+    let running_at_start = state.stop_accepting();
+    let timed_out = tokio::time::timeout(timeout, state.wait_idle())
+        .await
+        .is_err();
+    if timed_out {
+        log::warn!(
+            "Executor '{}': drain timed out after {:?}; cancelling remaining demands",
+            workflow_executor.execution_id,
+            timeout
+        );
+    }
+
+    let shutdown = shutdown_executor(workflow_executor, task_executor, checkpoint_path).await;
+    ExecutorDrainReport {
+        running_at_start,
+        finished: running_at_start.saturating_sub(shutdown.cancelled_demands),
+        timed_out,
+        shutdown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn begin_demand_is_rejected_after_cancel() {
+        let state = ExecutorShutdownState::new();
+        let demand = state.begin_demand().expect("demand before shutdown");
+
+        assert_eq!(state.cancel(), Some(1));
+        assert_eq!(state.cancel(), None);
+        assert!(matches!(
+            state.begin_demand().map(|_| ()),
+            Err(NodeEngineError::Cancelled)
+        ));
+
+        drop(demand);
+        assert_eq!(state.in_flight.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn stop_accepting_rejects_new_demands_without_cancelling() {
+        let state = ExecutorShutdownState::new();
+        let demand = state.begin_demand().expect("demand before drain");
+
+        assert_eq!(state.stop_accepting(), 1);
+        assert!(!state.is_shutting_down());
+        assert!(matches!(
+            state.begin_demand().map(|_| ()),
+            Err(NodeEngineError::Cancelled)
+        ));
+
+        drop(demand);
+        assert_eq!(state.cancel(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn wait_idle_resolves_when_last_demand_finishes() {
+        let state = std::sync::Arc::new(ExecutorShutdownState::new());
+        let waiter_state = state.clone();
+        let demand_state = state.clone();
+
+        let demand = tokio::spawn(async move {
+            let _demand = demand_state.begin_demand().expect("demand");
+            demand_state.cancelled().await;
+        });
+        while state.in_flight.load(Ordering::SeqCst) == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        state.cancel();
+        waiter_state.wait_idle().await;
+        demand.await.expect("demand task");
+        assert_eq!(state.in_flight.load(Ordering::SeqCst), 0);
+    }
+}
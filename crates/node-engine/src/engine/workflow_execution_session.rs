@@ -522,6 +522,7 @@ mod tests {
                 },
             ],
             groups: Vec::new(),
+            ports: crate::types::GraphPorts::default(),
         }
     }
 
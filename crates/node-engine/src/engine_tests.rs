@@ -1,7 +1,7 @@
 use super::*;
 use crate::error::NodeEngineError;
 use crate::events::{NullEventSink, VecEventSink};
-use crate::types::{GraphEdge, GraphNode};
+use crate::types::{GraphEdge, GraphNode, GraphPorts};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
 
@@ -262,6 +262,26 @@ impl FailingExecutor {
     }
 }
 
+/// Panics on `panic_on` until disarmed, passing through like
+/// `CountingExecutor` otherwise.
+struct PanickingExecutor {
+    panic_on: String,
+    armed: std::sync::atomic::AtomicBool,
+}
+
+impl PanickingExecutor {
+    fn new(panic_on: impl Into<String>) -> Self {
+        Self {
+            panic_on: panic_on.into(),
+            armed: std::sync::atomic::AtomicBool::new(true),
+        }
+    }
+
+    fn disarm(&self) {
+        self.armed.store(false, Ordering::SeqCst);
+    }
+}
+
 struct WaitingExecutor {
     wait_on: String,
     execution_log: Mutex<Vec<String>>,
@@ -425,6 +445,25 @@ impl TaskExecutor for FailingExecutor {
     }
 }
 
+#[async_trait]
+impl TaskExecutor for PanickingExecutor {
+    async fn execute_task(
+        &self,
+        task_id: &str,
+        _inputs: HashMap<String, serde_json::Value>,
+        _context: &Context,
+        _extensions: &ExecutorExtensions,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        if task_id == self.panic_on && self.armed.load(Ordering::SeqCst) {
+            panic!("forced panic at {task_id}");
+        }
+        Ok(HashMap::from([(
+            "out".to_string(),
+            serde_json::json!({ "task": task_id }),
+        )]))
+    }
+}
+
 #[async_trait]
 impl TaskExecutor for WaitingExecutor {
     async fn execute_task(
@@ -462,8 +501,12 @@ mod cache_state;
 mod demand;
 #[path = "engine_tests/human_input.rs"]
 mod human_input;
+#[path = "engine_tests/limits.rs"]
+mod limits;
 #[path = "engine_tests/multi_demand.rs"]
 mod multi_demand;
+#[path = "engine_tests/shutdown.rs"]
+mod shutdown;
 #[path = "engine_tests/snapshot.rs"]
 mod snapshot;
 #[path = "engine_tests/workflow_events.rs"]
@@ -162,3 +162,93 @@ async fn test_demand_events() {
     assert_eq!(started_count, 3);
     assert_eq!(completed_count, 3);
 }
+
+#[tokio::test]
+async fn test_demand_contains_task_panic_and_marks_node_poisoned() {
+    let graph = make_linear_graph();
+    let mut engine = DemandEngine::new("test");
+    let executor = PanickingExecutor::new("b");
+    let context = Context::new();
+    let event_sink = NullEventSink;
+    let extensions = ExecutorExtensions::new();
+
+    let error = engine
+        .demand(
+            &"c".to_string(),
+            &graph,
+            &executor,
+            &context,
+            &event_sink,
+            &extensions,
+        )
+        .await
+        .expect_err("panic surfaces as an error");
+
+    assert!(matches!(
+        error,
+        NodeEngineError::NodeFailure(crate::error::NodeFailure {
+            ref node_id,
+            kind: crate::error::NodeFailureKind::Panic,
+            ref message,
+        }) if node_id == "b" && message == "forced panic at b"
+    ));
+    assert_eq!(engine.cache_stats().poisoned_nodes, vec!["b".to_string()]);
+
+    // The engine stays usable: the same demand succeeds once the node
+    // stops panicking, and the poison mark is cleared.
+    executor.disarm();
+    engine
+        .demand(
+            &"c".to_string(),
+            &graph,
+            &executor,
+            &context,
+            &event_sink,
+            &extensions,
+        )
+        .await
+        .expect("demand after panic");
+    assert!(engine.cache_stats().poisoned_nodes.is_empty());
+}
+
+#[tokio::test]
+async fn test_deterministic_mode_rejects_nondeterministic_nodes() {
+    let mut graph = make_linear_graph();
+    graph.nodes[1].node_type = "determinism-test-clock".to_string();
+    let mut engine = DemandEngine::new("test");
+    let executor = CountingExecutor::new();
+    let context = Context::new();
+    let event_sink = NullEventSink;
+    let extensions = ExecutorExtensions::new();
+
+    engine
+        .demand(
+            &"c".to_string(),
+            &graph,
+            &executor,
+            &context,
+            &event_sink,
+            &extensions,
+        )
+        .await
+        .expect("nondeterministic nodes run outside deterministic mode");
+    assert_eq!(engine.cache_stats().cached_nodes, 3);
+
+    engine.set_deterministic_mode(Some(DeterministicMode { seed: 7 }));
+    assert_eq!(engine.cache_stats().cached_nodes, 0);
+
+    let error = engine
+        .demand(
+            &"c".to_string(),
+            &graph,
+            &executor,
+            &context,
+            &event_sink,
+            &extensions,
+        )
+        .await
+        .expect_err("deterministic mode refuses the clock node");
+
+    assert!(error.to_string().contains("nondeterministic"));
+    assert_eq!(executor.count(), 4);
+}
@@ -16,6 +16,7 @@ async fn test_workflow_executor_human_input_emits_waiting_for_input() {
         }],
         edges: Vec::new(),
         groups: Vec::new(),
+        ports: GraphPorts::default(),
     };
     let event_sink = Arc::new(VecEventSink::new());
     let workflow_executor = WorkflowExecutor::new("exec_human_input", graph, event_sink.clone());
@@ -68,6 +69,7 @@ async fn test_workflow_executor_human_input_continues_with_response() {
         }],
         edges: Vec::new(),
         groups: Vec::new(),
+        ports: GraphPorts::default(),
     };
     let event_sink = Arc::new(VecEventSink::new());
     let workflow_executor = WorkflowExecutor::new("exec_human_input", graph, event_sink.clone());
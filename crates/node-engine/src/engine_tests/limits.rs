@@ -0,0 +1,72 @@
+use super::*;
+use crate::limits::{GraphLimitError, GraphLimits};
+
+fn linear_executor() -> WorkflowExecutor {
+    WorkflowExecutor::new("exec_1", make_linear_graph(), Arc::new(NullEventSink))
+}
+
+fn shortcut_edge() -> GraphEdge {
+    GraphEdge {
+        id: "e3".to_string(),
+        source: "a".to_string(),
+        source_handle: "out".to_string(),
+        target: "c".to_string(),
+        target_handle: "in".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_add_node_and_edge_stop_at_graph_limits() {
+    let mut executor = linear_executor();
+    executor
+        .set_graph_limits(GraphLimits {
+            max_nodes: Some(3),
+            max_edges: Some(2),
+            ..GraphLimits::default()
+        })
+        .await
+        .unwrap();
+
+    let error = executor
+        .add_node(GraphNode {
+            id: "d".to_string(),
+            node_type: "process".to_string(),
+            data: serde_json::Value::Null,
+            position: (300.0, 0.0),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        NodeEngineError::GraphLimitExceeded(GraphLimitError::MaxNodes { count: 4, limit: 3 })
+    ));
+
+    let error = executor.add_edge(shortcut_edge()).await.unwrap_err();
+    assert_eq!(error.kind(), "graph_limit_exceeded");
+
+    let graph = executor.get_graph_snapshot().await;
+    assert_eq!((graph.nodes.len(), graph.edges.len()), (3, 2));
+}
+
+#[tokio::test]
+async fn test_limits_are_checked_against_loaded_and_replacement_graphs() {
+    let mut executor = linear_executor();
+    let too_small = GraphLimits {
+        max_nodes: Some(2),
+        ..GraphLimits::default()
+    };
+    assert!(executor.set_graph_limits(too_small).await.is_err());
+    assert_eq!(executor.graph_limits(), GraphLimits::unlimited());
+
+    executor
+        .set_graph_limits(GraphLimits {
+            max_edges: Some(2),
+            ..GraphLimits::default()
+        })
+        .await
+        .unwrap();
+    let mut bigger = make_linear_graph();
+    bigger.edges.push(shortcut_edge());
+    assert!(executor.replace_graph(bigger).await.is_err());
+    assert_eq!(executor.get_graph_snapshot().await.edges.len(), 2);
+}
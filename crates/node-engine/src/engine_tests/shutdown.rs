@@ -0,0 +1,211 @@
+use super::*;
+
+/// Never finishes a task; counts shutdown calls.
+struct HangingExecutor {
+    started: tokio::sync::Notify,
+    shutdowns: AtomicUsize,
+}
+
+#[async_trait]
+impl TaskExecutor for HangingExecutor {
+    async fn execute_task(
+        &self,
+        _task_id: &str,
+        _inputs: HashMap<String, serde_json::Value>,
+        _context: &Context,
+        _extensions: &ExecutorExtensions,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        self.started.notify_one();
+        std::future::pending().await
+    }
+
+    async fn shutdown(&self) {
+        self.shutdowns.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn test_shutdown_cancels_in_flight_demand() {
+    let workflow_executor = Arc::new(WorkflowExecutor::new(
+        "exec_1",
+        make_linear_graph(),
+        Arc::new(NullEventSink),
+    ));
+    let task_executor = Arc::new(HangingExecutor {
+        started: tokio::sync::Notify::new(),
+        shutdowns: AtomicUsize::new(0),
+    });
+
+    let demand = tokio::spawn({
+        let workflow_executor = workflow_executor.clone();
+        let task_executor = task_executor.clone();
+        async move {
+            workflow_executor
+                .demand(&"c".to_string(), task_executor.as_ref())
+                .await
+        }
+    });
+    task_executor.started.notified().await;
+
+    let report = workflow_executor
+        .shutdown(Some(task_executor.as_ref()), None)
+        .await;
+
+    assert_eq!(report.cancelled_demands, 1);
+    assert!(!report.already_shut_down);
+    assert!(matches!(
+        demand.await.expect("demand task"),
+        Err(NodeEngineError::Cancelled)
+    ));
+    assert_eq!(task_executor.shutdowns.load(Ordering::SeqCst), 1);
+    assert!(workflow_executor.is_shut_down());
+
+    let rejected = workflow_executor
+        .demand(&"a".to_string(), &CountingExecutor::new())
+        .await;
+    assert!(matches!(rejected, Err(NodeEngineError::Cancelled)));
+
+    let again = workflow_executor
+        .shutdown(Some(task_executor.as_ref()), None)
+        .await;
+    assert!(again.already_shut_down);
+    assert_eq!(task_executor.shutdowns.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_shutdown_writes_checkpoint_for_bound_session() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let path = dir.path().join("checkpoint.json");
+    let workflow_executor =
+        WorkflowExecutor::new("exec_1", make_linear_graph(), Arc::new(NullEventSink));
+    workflow_executor
+        .bind_workflow_execution_session("session-1")
+        .await;
+    workflow_executor
+        .demand(&"c".to_string(), &CountingExecutor::new())
+        .await
+        .expect("demand");
+
+    let report = workflow_executor.shutdown(None, Some(&path)).await;
+
+    assert_eq!(report.cancelled_demands, 0);
+    assert_eq!(report.checkpoint_entries, Some(3));
+    assert_eq!(report.checkpointed_session_id.as_deref(), Some("session-1"));
+    assert!(path.exists());
+    assert!(
+        workflow_executor
+            .workflow_execution_session_checkpoint_summary("session-1")
+            .await
+            .checkpoint_available
+    );
+}
+
+/// Finishes each task once released.
+struct GatedExecutor {
+    started: tokio::sync::Notify,
+    release: tokio::sync::Notify,
+}
+
+#[async_trait]
+impl TaskExecutor for GatedExecutor {
+    async fn execute_task(
+        &self,
+        task_id: &str,
+        _inputs: HashMap<String, serde_json::Value>,
+        _context: &Context,
+        _extensions: &ExecutorExtensions,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        self.started.notify_one();
+        self.release.notified().await;
+        Ok(HashMap::from([(
+            "out".to_string(),
+            serde_json::json!({ "task": task_id }),
+        )]))
+    }
+}
+
+#[tokio::test]
+async fn test_drain_lets_running_demand_finish() {
+    let workflow_executor = Arc::new(WorkflowExecutor::new(
+        "exec_1",
+        make_linear_graph(),
+        Arc::new(NullEventSink),
+    ));
+    let task_executor = Arc::new(GatedExecutor {
+        started: tokio::sync::Notify::new(),
+        release: tokio::sync::Notify::new(),
+    });
+
+    let demand = tokio::spawn({
+        let workflow_executor = workflow_executor.clone();
+        let task_executor = task_executor.clone();
+        async move {
+            workflow_executor
+                .demand(&"a".to_string(), task_executor.as_ref())
+                .await
+        }
+    });
+    task_executor.started.notified().await;
+
+    let (report, rejected) = tokio::join!(
+        workflow_executor.drain(std::time::Duration::from_secs(5), None, None),
+        async {
+            let rejected = workflow_executor
+                .demand(&"b".to_string(), &CountingExecutor::new())
+                .await;
+            task_executor.release.notify_one();
+            rejected
+        }
+    );
+
+    assert!(matches!(rejected, Err(NodeEngineError::Cancelled)));
+    assert!(demand.await.expect("demand task").is_ok());
+    assert_eq!(report.running_at_start, 1);
+    assert_eq!(report.finished, 1);
+    assert!(!report.timed_out);
+    assert_eq!(report.shutdown.cancelled_demands, 0);
+    assert!(workflow_executor.is_draining());
+    assert!(workflow_executor.is_shut_down());
+}
+
+#[tokio::test]
+async fn test_drain_cancels_demands_past_the_timeout() {
+    let workflow_executor = Arc::new(WorkflowExecutor::new(
+        "exec_1",
+        make_linear_graph(),
+        Arc::new(NullEventSink),
+    ));
+    let task_executor = Arc::new(HangingExecutor {
+        started: tokio::sync::Notify::new(),
+        shutdowns: AtomicUsize::new(0),
+    });
+
+    let demand = tokio::spawn({
+        let workflow_executor = workflow_executor.clone();
+        let task_executor = task_executor.clone();
+        async move {
+            workflow_executor
+                .demand(&"c".to_string(), task_executor.as_ref())
+                .await
+        }
+    });
+    task_executor.started.notified().await;
+
+    let report = workflow_executor
+        .drain(
+            std::time::Duration::from_millis(20),
+            Some(task_executor.as_ref()),
+            None,
+        )
+        .await;
+
+    assert!(report.timed_out);
+    assert_eq!(report.running_at_start, 1);
+    assert_eq!(report.finished, 0);
+    assert_eq!(report.shutdown.cancelled_demands, 1);
+    assert!(matches!(
+        demand.await.expect("demand task"),
+        Err(NodeEngineError::Cancelled)
+    ));
+    assert_eq!(task_executor.shutdowns.load(Ordering::SeqCst), 1);
+}
@@ -19,7 +19,8 @@ async fn test_workflow_executor_snapshot() {
             data: serde_json::Value::Null,
             position: (300.0, 0.0),
         })
-        .await;
+        .await
+        .unwrap();
 
     // Verify node was added
     let updated = workflow_executor.get_graph_snapshot().await;
@@ -1,5 +1,6 @@
 //! Error types for the node engine
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Result type alias using NodeEngineError
@@ -58,6 +59,53 @@ pub enum NodeEngineError {
     /// I/O error
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
+
+    /// A node failed in a way the executor contained (e.g. a panic)
+    #[error("{0}")]
+    NodeFailure(NodeFailure),
+
+    /// A graph load or mutation would exceed a configured graph limit
+    #[error("Graph limit exceeded: {0}")]
+    GraphLimitExceeded(crate::limits::GraphLimitError),
+}
+
+/// Kind of contained node failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeFailureKind {
+    /// The task panicked; the panic was caught before it could unwind into
+    /// the host.
+    Panic,
+}
+
+impl NodeFailureKind {
+    /// Snake-case label used in serialized failures and binding errors.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Panic => "panic",
+        }
+    }
+}
+
+/// Structured failure of a single node.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeFailure {
+    pub node_id: String,
+    pub kind: NodeFailureKind,
+    pub message: String,
+}
+
+impl std::fmt::Display for NodeFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Node '{}' failed ({}): {}",
+            self.node_id,
+            self.kind.as_str(),
+            self.message
+        )
+    }
 }
 
 impl NodeEngineError {
@@ -74,8 +122,37 @@ impl NodeEngineError {
         }
     }
 
+    /// Create a contained-panic failure for a node.
+    pub fn panicked(node_id: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::NodeFailure(NodeFailure {
+            node_id: node_id.into(),
+            kind: NodeFailureKind::Panic,
+            message: message.into(),
+        })
+    }
+
     /// Create from a graph-flow error
     pub fn from_graph_flow(err: graph_flow::GraphError) -> Self {
         Self::GraphFlow(err.to_string())
     }
+
+    /// Snake-case label of the error variant, independent of its message.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::GraphFlow(_) => "graph_flow",
+            Self::MissingInput(_) => "missing_input",
+            Self::InvalidInputType { .. } => "invalid_input_type",
+            Self::ExecutionFailed(_) => "execution_failed",
+            Self::ContextNotFound(_) => "context_not_found",
+            Self::Serialization(_) => "serialization",
+            Self::Compression(_) => "compression",
+            Self::Cancelled => "cancelled",
+            Self::WaitingForInput { .. } => "waiting_for_input",
+            Self::Gateway(_) => "gateway",
+            Self::Rag(_) => "rag",
+            Self::Io(_) => "io",
+            Self::NodeFailure(_) => "node_failure",
+            Self::GraphLimitExceeded(_) => "graph_limit_exceeded",
+        }
+    }
 }
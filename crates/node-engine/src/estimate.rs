@@ -0,0 +1,339 @@
+//! Pre-run cost estimation.
+//!
+//! [`estimate`] walks a workflow graph in dependency order and predicts, per
+//! node and in total, how many tokens flow in and out, how many external API
+//! calls are made, and how long the run takes. Costs come from
+//! [`NodeCostAnnotation`] declarations; token counts use the
+//! [`estimate_tokens`] heuristic on text in node data and run inputs, and
+//! flow along edges from upstream predictions.
+//!
+//! Estimates are ranges, not promises: the total wall-time minimum assumes
+//! full parallelism along the critical path, and the maximum assumes every
+//! node runs sequentially at its slowest.
+
+use std::collections::{HashMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::descriptor::NodeCostAnnotation;
+use crate::error::{NodeEngineError, Result};
+use crate::types::{NodeId, WorkflowGraph};
+
+const MAX_TOKENS_PORT: &str = "max_tokens";
+
+/// Inclusive range of predicted values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimateRange {
+    pub min: u64,
+    pub max: u64,
+}
+
+/// Predicted cost of one node.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeEstimate {
+    pub node_id: NodeId,
+    pub node_type: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub api_calls: u32,
+    pub wall_time_ms: EstimateRange,
+    /// Whether the node type declares a [`NodeCostAnnotation`].
+    pub annotated: bool,
+}
+
+/// Predicted cost of a whole run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionEstimate {
+    /// Per-node estimates in execution order.
+    pub nodes: Vec<NodeEstimate>,
+    /// Tokens consumed by annotated nodes. Unannotated nodes pass text
+    /// through rather than bill for it, so they are left out.
+    pub total_input_tokens: u64,
+    /// Tokens produced by annotated nodes.
+    pub total_output_tokens: u64,
+    pub total_api_calls: u32,
+    pub wall_time_ms: EstimateRange,
+}
+
+/// Rough token count of a text (about four characters per token).
+pub fn estimate_tokens(text: &str) -> u64 {
+    (text.chars().count() as u64).div_ceil(4)
+}
+
+/// Token count of every string inside a JSON value.
+fn value_tokens(value: &serde_json::Value) -> u64 {
+    match value {
+        serde_json::Value::String(text) => estimate_tokens(text),
+        serde_json::Value::Array(items) => items.iter().map(value_tokens).sum(),
+        serde_json::Value::Object(fields) => fields.values().map(value_tokens).sum(),
+        _ => 0,
+    }
+}
+
+fn annotations() -> HashMap<&'static str, &'static NodeCostAnnotation> {
+    inventory::iter::<NodeCostAnnotation>
+        .into_iter()
+        .map(|annotation| (annotation.node_type, annotation))
+        .collect()
+}
+
+/// Node IDs in dependency order, or an error if the graph has a cycle.
+fn execution_order(graph: &WorkflowGraph) -> Result<Vec<NodeId>> {
+    let mut in_degree: HashMap<&str, usize> = graph
+        .nodes
+        .iter()
+        .map(|node| (node.id.as_str(), 0))
+        .collect();
+    for edge in &graph.edges {
+        if let Some(degree) = in_degree.get_mut(edge.target.as_str()) {
+            *degree += 1;
+        }
+    }
+
+    let mut ready: VecDeque<&str> = graph
+        .nodes
+        .iter()
+        .map(|node| node.id.as_str())
+        .filter(|id| in_degree[id] == 0)
+        .collect();
+    let mut order = Vec::with_capacity(graph.nodes.len());
+    while let Some(node_id) = ready.pop_front() {
+        order.push(node_id.to_string());
+        for edge in graph.outgoing_edges(node_id) {
+            if let Some(degree) = in_degree.get_mut(edge.target.as_str()) {
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.push_back(edge.target.as_str());
+                }
+            }
+        }
+    }
+
+    if order.len() != graph.nodes.len() {
+        return Err(NodeEngineError::failed(format!(
+            "Cannot estimate workflow '{}': graph contains a cycle",
+            graph.id
+        )));
+    }
+    Ok(order)
+}
+
+/// Estimate the cost of running every node of `graph`.
+///
+/// `inputs` holds run inputs keyed `"{node_id}.{port}"`; they count toward
+/// the receiving node's input tokens, and a `max_tokens` input (or node data
+/// field) caps a generating node's output tokens.
+pub fn estimate(
+    graph: &WorkflowGraph,
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<ExecutionEstimate> {
+    let annotations = annotations();
+    let mut result = ExecutionEstimate::default();
+    let mut output_tokens: HashMap<NodeId, u64> = HashMap::new();
+    let mut finish_min_ms: HashMap<NodeId, u64> = HashMap::new();
+
+    for node_id in execution_order(graph)? {
+        let Some(node) = graph.find_node(&node_id) else {
+            continue;
+        };
+        let prefix = format!("{}.", node_id);
+        let run_inputs = inputs
+            .iter()
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, value)| (&key[prefix.len()..], value));
+
+        let mut input_tokens = value_tokens(&node.data);
+        let mut max_tokens = node.data.get(MAX_TOKENS_PORT).and_then(|v| v.as_u64());
+        for (port, value) in run_inputs {
+            input_tokens += value_tokens(value);
+            if port == MAX_TOKENS_PORT {
+                max_tokens = value.as_u64().or(max_tokens);
+            }
+        }
+        let mut start_min_ms = 0;
+        for dependency in graph.get_dependencies(&node_id) {
+            input_tokens += output_tokens.get(&dependency).copied().unwrap_or(0);
+            start_min_ms = start_min_ms.max(finish_min_ms.get(&dependency).copied().unwrap_or(0));
+        }
+
+        let annotation = annotations.get(node.node_type.as_str());
+        let (produced_tokens, api_calls, wall_time_ms) = match annotation {
+            Some(annotation) => (
+                annotation
+                    .default_output_tokens
+                    .map(|default| max_tokens.unwrap_or(default))
+                    .unwrap_or(0),
+                annotation.api_calls,
+                EstimateRange {
+                    min: annotation.min_wall_time_ms,
+                    max: annotation.max_wall_time_ms,
+                },
+            ),
+            None => (input_tokens, 0, EstimateRange::default()),
+        };
+
+        output_tokens.insert(node_id.clone(), produced_tokens);
+        finish_min_ms.insert(node_id.clone(), start_min_ms + wall_time_ms.min);
+
+        if annotation.is_some() {
+            result.total_input_tokens += input_tokens;
+            result.total_output_tokens += produced_tokens;
+        }
+        result.total_api_calls += api_calls;
+        result.wall_time_ms.max += wall_time_ms.max;
+        result.nodes.push(NodeEstimate {
+            node_id,
+            node_type: node.node_type.clone(),
+            input_tokens,
+            output_tokens: produced_tokens,
+            api_calls,
+            wall_time_ms,
+            annotated: annotation.is_some(),
+        });
+    }
+
+    result.wall_time_ms.min = finish_min_ms.values().copied().max().unwrap_or(0);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GraphEdge, GraphNode};
+
+    inventory::submit!(NodeCostAnnotation {
+        node_type: "estimate-test-llm",
+        api_calls: 1,
+        min_wall_time_ms: 100,
+        max_wall_time_ms: 1_000,
+        default_output_tokens: Some(256),
+    });
+
+    fn node(id: &str, node_type: &str, data: serde_json::Value) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            node_type: node_type.to_string(),
+            data,
+            position: (0.0, 0.0),
+        }
+    }
+
+    fn edge(source: &str, target: &str) -> GraphEdge {
+        GraphEdge {
+            id: format!("{}-{}", source, target),
+            source: source.to_string(),
+            source_handle: "out".to_string(),
+            target: target.to_string(),
+            target_handle: "in".to_string(),
+        }
+    }
+
+    fn graph() -> WorkflowGraph {
+        let mut graph = WorkflowGraph::new("estimate", "Estimate");
+        graph.nodes = vec![
+            node(
+                "prompt",
+                "text-input",
+                serde_json::json!({ "text": "abcdefgh" }),
+            ),
+            node("draft", "estimate-test-llm", serde_json::json!({})),
+            node(
+                "review",
+                "estimate-test-llm",
+                serde_json::json!({ "max_tokens": 64 }),
+            ),
+        ];
+        graph.edges = vec![edge("prompt", "draft"), edge("prompt", "review")];
+        graph
+    }
+
+    #[test]
+    fn tokens_flow_along_edges_and_respect_max_tokens() {
+        let estimate = estimate(&graph(), &HashMap::new()).expect("estimate");
+
+        assert_eq!(estimate.nodes[0].node_id, "prompt");
+        assert_eq!(estimate.nodes[0].output_tokens, 2);
+        let draft = estimate
+            .nodes
+            .iter()
+            .find(|n| n.node_id == "draft")
+            .unwrap();
+        assert_eq!(draft.input_tokens, 2);
+        assert_eq!(draft.output_tokens, 256);
+        let review = estimate
+            .nodes
+            .iter()
+            .find(|n| n.node_id == "review")
+            .unwrap();
+        assert_eq!(review.output_tokens, 64);
+        assert_eq!(estimate.total_api_calls, 2);
+        // The prompt passes its text through; only the two generating nodes bill.
+        assert_eq!(estimate.total_input_tokens, 4);
+        assert_eq!(estimate.total_output_tokens, 320);
+    }
+
+    #[test]
+    fn pass_through_nodes_are_not_counted_twice() {
+        let mut graph = WorkflowGraph::new("chain", "Chain");
+        graph.nodes = vec![
+            node(
+                "prompt",
+                "text-input",
+                serde_json::json!({ "text": "abcdefgh" }),
+            ),
+            node("llm", "estimate-test-llm", serde_json::json!({})),
+            node("out", "text-output", serde_json::json!({})),
+        ];
+        graph.edges = vec![edge("prompt", "llm"), edge("llm", "out")];
+
+        let estimate = estimate(&graph, &HashMap::new()).expect("estimate");
+
+        assert_eq!(estimate.total_input_tokens, 2);
+        assert_eq!(estimate.total_output_tokens, 256);
+    }
+
+    #[test]
+    fn wall_time_spans_critical_path_to_sequential_run() {
+        let estimate = estimate(&graph(), &HashMap::new()).expect("estimate");
+
+        assert_eq!(
+            estimate.wall_time_ms,
+            EstimateRange {
+                min: 100,
+                max: 2_000
+            }
+        );
+    }
+
+    #[test]
+    fn run_inputs_count_toward_tokens_and_override_max_tokens() {
+        let inputs = HashMap::from([
+            (
+                "draft.context".to_string(),
+                serde_json::json!("abcd".repeat(10)),
+            ),
+            ("draft.max_tokens".to_string(), serde_json::json!(32)),
+        ]);
+
+        let estimate = estimate(&graph(), &inputs).expect("estimate");
+        let draft = estimate
+            .nodes
+            .iter()
+            .find(|n| n.node_id == "draft")
+            .unwrap();
+
+        assert_eq!(draft.input_tokens, 12);
+        assert_eq!(draft.output_tokens, 32);
+    }
+
+    #[test]
+    fn cycles_are_rejected() {
+        let mut graph = graph();
+        graph.edges.push(edge("draft", "prompt"));
+
+        assert!(estimate(&graph, &HashMap::new()).is_err());
+    }
+}
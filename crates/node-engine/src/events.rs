@@ -5,6 +5,7 @@
 //! single catch-all file.
 
 mod contract;
+mod ring_buffer;
 mod sinks;
 
 #[cfg(test)]
@@ -15,6 +16,7 @@ pub use contract::{
     KvCacheEventAction, KvCacheEventOutcome, KvCacheExecutionDiagnostics, TaskProgressDetail,
     WorkflowEvent,
 };
+pub use ring_buffer::{EventReplay, EventRingBuffer, SequencedEvent};
 pub use sinks::{
     BroadcastEventSink, CallbackEventSink, CompositeEventSink, EventError, EventSink,
     NullEventSink, VecEventSink,
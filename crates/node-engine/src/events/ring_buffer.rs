@@ -0,0 +1,119 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use super::{EventError, EventSink, WorkflowEvent};
+
+/// A workflow event with its position in an [`EventRingBuffer`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SequencedEvent {
+    /// Monotonic ID, starting at 1, used as a resume cursor.
+    pub id: u64,
+    /// `executionId` of the event, when it has one.
+    pub execution_id: Option<String>,
+    pub event: WorkflowEvent,
+}
+
+/// Retained events after a cursor plus a live subscription that continues
+/// exactly where they end.
+pub struct EventReplay {
+    pub events: Vec<SequencedEvent>,
+    /// Whether events after the cursor were already evicted.
+    pub truncated: bool,
+    pub receiver: tokio::sync::broadcast::Receiver<SequencedEvent>,
+}
+
+struct RingState {
+    events: VecDeque<SequencedEvent>,
+    next_id: u64,
+}
+
+/// Event sink that keeps the most recent events in a bounded buffer and
+/// broadcasts them to live subscribers.
+///
+/// Transports use it to let clients reconnect with the last event ID they saw
+/// and resume without gaps, as long as the events are still retained.
+pub struct EventRingBuffer {
+    capacity: usize,
+    state: Mutex<RingState>,
+    sender: tokio::sync::broadcast::Sender<SequencedEvent>,
+}
+
+impl EventRingBuffer {
+    /// Create a buffer retaining up to `capacity` events (at least one).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        let (sender, _) = tokio::sync::broadcast::channel(capacity);
+        Self {
+            capacity,
+            state: Mutex::new(RingState {
+                events: VecDeque::with_capacity(capacity),
+                next_id: 1,
+            }),
+            sender,
+        }
+    }
+
+    /// Subscribe to events sent from now on.
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<SequencedEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Retained events with an ID above `last_event_id`, optionally limited
+    /// to one execution, together with a subscription for later events.
+    pub fn replay_after(&self, execution_id: Option<&str>, last_event_id: u64) -> EventReplay {
+        let state = self.state.lock().unwrap();
+        let receiver = self.sender.subscribe();
+        let truncated = state
+            .events
+            .front()
+            .is_some_and(|oldest| oldest.id > last_event_id + 1);
+        let events = state
+            .events
+            .iter()
+            .filter(|event| event.id > last_event_id)
+            .filter(|event| execution_id.is_none() || event.execution_id.as_deref() == execution_id)
+            .cloned()
+            .collect();
+        EventReplay {
+            events,
+            truncated,
+            receiver,
+        }
+    }
+
+    /// ID of the most recent event, or 0 if none was sent.
+    pub fn latest_id(&self) -> u64 {
+        self.state.lock().unwrap().next_id - 1
+    }
+}
+
+fn event_execution_id(event: &WorkflowEvent) -> Option<String> {
+    serde_json::to_value(event)
+        .ok()?
+        .get("executionId")?
+        .as_str()
+        .map(str::to_string)
+}
+
+impl EventSink for EventRingBuffer {
+    fn send(&self, event: WorkflowEvent) -> Result<(), EventError> {
+        let mut state = self.state.lock().unwrap();
+        let sequenced = SequencedEvent {
+            id: state.next_id,
+            execution_id: event_execution_id(&event),
+            event,
+        };
+        state.next_id += 1;
+        if state.events.len() == self.capacity {
+            state.events.pop_front();
+        }
+        state.events.push_back(sequenced.clone());
+        // Broadcast under the lock so `replay_after` never misses or repeats
+        // an event between its snapshot and its subscription.
+        let _ = self.sender.send(sequenced);
+        Ok(())
+    }
+}
@@ -9,6 +9,14 @@ pub trait EventSink: Send + Sync {
     ///
     /// Returns an error if the event could not be sent.
     fn send(&self, event: WorkflowEvent) -> Result<(), EventError>;
+
+    /// Deliver any events still buffered by this sink.
+    ///
+    /// Called when an executor shuts down. Sinks that deliver on `send`
+    /// keep the default no-op.
+    fn flush(&self) -> Result<(), EventError> {
+        Ok(())
+    }
 }
 
 /// Error when sending events fails.
@@ -181,4 +189,18 @@ impl EventSink for CompositeEventSink {
             None => Ok(()),
         }
     }
+
+    fn flush(&self) -> Result<(), EventError> {
+        let mut last_error = None;
+        for sink in &self.sinks {
+            if let Err(error) = sink.flush() {
+                last_error = Some(error);
+            }
+        }
+
+        match last_error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use super::{
-    BroadcastEventSink, CallbackEventSink, CompositeEventSink, EventSink, KvCacheEventAction,
-    KvCacheEventOutcome, NullEventSink, TaskProgressDetail, VecEventSink, WorkflowEvent,
+    BroadcastEventSink, CallbackEventSink, CompositeEventSink, EventRingBuffer, EventSink,
+    KvCacheEventAction, KvCacheEventOutcome, NullEventSink, TaskProgressDetail, VecEventSink,
+    WorkflowEvent,
 };
 
 #[test]
@@ -110,6 +111,48 @@ fn test_composite_empty() {
         .unwrap();
 }
 
+#[test]
+fn test_ring_buffer_replays_after_cursor_for_one_execution() {
+    let buffer = EventRingBuffer::new(8);
+    for execution_id in ["exec1", "exec2", "exec1", "exec1"] {
+        buffer
+            .send(WorkflowEvent::task_progress(
+                "task1",
+                execution_id,
+                0.5,
+                None,
+            ))
+            .unwrap();
+    }
+
+    let replay = buffer.replay_after(Some("exec1"), 1);
+    let ids: Vec<u64> = replay.events.iter().map(|event| event.id).collect();
+    assert_eq!(ids, vec![3, 4]);
+    assert!(!replay.truncated);
+    assert_eq!(buffer.latest_id(), 4);
+}
+
+#[test]
+fn test_ring_buffer_reports_evicted_events_and_continues_live() {
+    let buffer = EventRingBuffer::new(2);
+    for _ in 0..4 {
+        buffer
+            .send(WorkflowEvent::task_progress("task1", "exec1", 0.5, None))
+            .unwrap();
+    }
+
+    let mut replay = buffer.replay_after(None, 0);
+    assert!(replay.truncated);
+    assert_eq!(replay.events.len(), 2);
+
+    buffer
+        .send(WorkflowEvent::task_progress("task1", "exec1", 1.0, None))
+        .unwrap();
+    let live = replay.receiver.try_recv().unwrap();
+    assert_eq!(live.id, 5);
+    assert_eq!(live.execution_id.as_deref(), Some("exec1"));
+}
+
 #[test]
 fn test_workflow_cancelled_now_sets_timestamp() {
     let event = WorkflowEvent::WorkflowCancelled {
@@ -72,12 +72,42 @@ pub mod extension_keys {
     /// Key for `Arc<pumas_library::PumasApi>` — model library access.
     pub const PUMAS_API: &str = "pumas_api";
 
+    /// Key for `Arc<workflow_nodes::ModelLibraryRoots>` — merged view over
+    /// several model libraries. Hosts that set it also set [`PUMAS_API`] to
+    /// the primary root.
+    pub const PUMAS_LIBRARY_ROOTS: &str = "pumas_library_roots";
+
+    /// Key for `Arc<crate::model_usage::ModelUsageLog>` — records a model use
+    /// whenever a `puma-lib` node executes.
+    pub const MODEL_USAGE: &str = "model_usage";
+
+    /// Key for `Arc<dyn crate::model_benchmark::ModelBenchmarkStore>` — stores
+    /// `model-benchmark` results in the model library.
+    pub const MODEL_BENCHMARK_STORE: &str = "model_benchmark_store";
+
     /// Key for `Arc<inference::kv_cache::KvCacheStore>` — KV cache storage.
     pub const KV_CACHE_STORE: &str = "kv_cache_store";
 
     /// Key for `Arc<dyn crate::model_dependencies::ModelDependencyResolver>` —
     /// host-provided model dependency resolver.
     pub const MODEL_DEPENDENCY_RESOLVER: &str = "model_dependency_resolver";
+
+    /// Key for `Arc<dyn crate::config::SecretsProvider>` — credential lookup.
+    pub const SECRETS_PROVIDER: &str = "secrets_provider";
+
+    /// Key for `Arc<crate::config::FilesystemPolicy>` — file I/O node limits.
+    pub const FILESYSTEM_POLICY: &str = "filesystem_policy";
+
+    /// Key for `Arc<crate::config::BackendEndpoints>` — HTTP backend base URLs.
+    pub const BACKEND_ENDPOINTS: &str = "backend_endpoints";
+
+    /// Key for `Arc<dyn crate::clock::Clock>` — time source for waits,
+    /// deadlines, and TTLs.
+    pub const CLOCK: &str = "clock";
+
+    /// Key for `Arc<dyn crate::clock::Rng>` — random source for execution-time
+    /// draws.
+    pub const RNG: &str = "rng";
 }
 
 #[cfg(test)]
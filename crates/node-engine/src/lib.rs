@@ -31,7 +31,7 @@
 //! # Example
 //!
 //! ```ignore
-//! use node_engine::{TaskDescriptor, TaskMetadata, PortMetadata, PortDataType, NodeCategory};
+//! use node_engine::{TaskDescriptor, TaskMetadata, NodeDocs, NodeUiHints, PortMetadata, PortDataType, NodeCategory};
 //!
 //! impl TaskDescriptor for MyTask {
 //!     fn descriptor() -> TaskMetadata {
@@ -47,52 +47,78 @@
 //!                 PortMetadata::optional("output", "Output", PortDataType::String),
 //!             ],
 //!             execution_mode: ExecutionMode::Reactive,
+//!             docs: NodeDocs::default(),
+//!             ui: NodeUiHints::default(),
 //!         }
 //!     }
 //! }
 //! ```
 
 pub mod builder;
+pub mod clock;
 pub mod composite_executor;
+pub mod config;
+#[cfg(any(test, feature = "conformance"))]
+pub mod conformance;
 pub mod core_executor;
 pub mod descriptor;
 pub mod engine;
 pub mod error;
+pub mod estimate;
 pub mod events;
 pub mod extensions;
 pub mod groups;
+pub mod limits;
+pub mod log_sink;
+pub mod mmproj;
+pub mod model_benchmark;
 pub mod model_dependencies;
+pub mod model_usage;
 pub mod orchestration;
+mod panic_isolation;
 pub mod path_validation;
 pub mod port_options;
 pub mod registry;
+pub mod sandbox;
+pub mod simulation;
 pub mod tasks;
 pub mod types;
+pub mod ui_hints;
 pub mod undo;
 pub mod validation;
 
 // Re-export core and composite executors
+pub use clock::{Clock, Rng, SeededRng, SimulatedClock, SystemClock, SystemRng};
 pub use composite_executor::CompositeTaskExecutor;
 pub use core_executor::{resolve_node_type, CoreTaskExecutor};
 
 // Re-export key types from engine
 pub use engine::{
-    CacheStats, CachedOutput, DemandEngine, GraphMemoryImpactSummary, NodeMemoryCompatibility,
-    NodeMemoryCompatibilitySnapshot, NodeMemoryIdentity, NodeMemorySnapshot, NodeMemoryStatus,
-    TaskExecutor, WorkflowExecutionSessionCheckpointSummary,
-    WorkflowExecutionSessionResidencyState, WorkflowExecutor,
+    hash_task_inputs, CacheStats, CachedOutput, DemandCacheEntry, DemandCacheExport, DemandEngine,
+    DeterministicMode, ExecutorDrainReport, ExecutorShutdownReport, GraphMemoryImpactSummary,
+    NodeMemoryCompatibility, NodeMemoryCompatibilitySnapshot, NodeMemoryIdentity,
+    NodeMemorySnapshot, NodeMemoryStatus, TaskExecutor, WorkflowExecutionSessionCheckpointSummary,
+    WorkflowExecutionSessionResidencyState, WorkflowExecutor, DEMAND_CACHE_EXPORT_FORMAT_VERSION,
 };
-pub use error::{NodeEngineError, Result};
+pub use error::{NodeEngineError, NodeFailure, NodeFailureKind, Result};
+pub use estimate::{estimate, estimate_tokens, EstimateRange, ExecutionEstimate, NodeEstimate};
 pub use events::{
-    BroadcastEventSink, CallbackEventSink, CompositeEventSink, EventError, EventSink,
-    KvCacheEventAction, KvCacheEventOutcome, KvCacheExecutionDiagnostics, NullEventSink,
-    TaskProgressDetail, VecEventSink, WorkflowEvent,
+    BroadcastEventSink, CallbackEventSink, CompositeEventSink, EventError, EventReplay,
+    EventRingBuffer, EventSink, KvCacheEventAction, KvCacheEventOutcome,
+    KvCacheExecutionDiagnostics, NullEventSink, SequencedEvent, TaskProgressDetail, VecEventSink,
+    WorkflowEvent,
 };
 pub use extensions::{extension_keys, ExecutorExtensions};
+pub use limits::{GraphLimitError, GraphLimits};
+pub use log_sink::{
+    clear_log_sink, install_log_sink, set_log_sink_level, LogLevel, LogRecord, LogSink,
+    LogSinkError,
+};
 pub use types::{
-    EdgeId, ExecutionMode, GraphEdge, GraphNode, NodeCategory, NodeDefinition, NodeId,
-    PortDataType, PortDefinition, PortId, WorkflowGraph,
+    EdgeId, ExecutionMode, GraphEdge, GraphNode, GraphPort, GraphPorts, NodeCategory,
+    NodeDefinition, NodeId, PortDataType, PortDefinition, PortId, WorkflowGraph,
 };
+pub use ui_hints::{NodeUiHints, PortShape, PortUiHints};
 pub use undo::UndoStack;
 
 // Re-export group types
@@ -101,7 +127,15 @@ pub use groups::{
 };
 
 // Re-export descriptor types
-pub use descriptor::{DescriptorFn, PortMetadata, TaskDescriptor, TaskMetadata};
+pub use descriptor::{
+    DescriptorFn, NodeCostAnnotation, NodeDocs, NodeExample, NodeLink, NondeterministicNode,
+    PortMetadata, TaskDescriptor, TaskMetadata,
+};
+pub use mmproj::find_mmproj;
+pub use model_benchmark::{
+    benchmark_tokens_per_second, ModelBenchmarkResult, ModelBenchmarkStore,
+    STANDARD_BENCHMARK_PROMPTS,
+};
 pub use model_dependencies::{
     DependencyOverrideFieldsV1, DependencyOverridePatchV1, DependencyOverrideScope,
     DependencyState, DependencyValidationError, DependencyValidationErrorScope,
@@ -109,8 +143,17 @@ pub use model_dependencies::{
     ModelDependencyInstallResult, ModelDependencyRequest, ModelDependencyRequirement,
     ModelDependencyRequirements, ModelDependencyResolver, ModelDependencyStatus, ModelRefV2,
 };
+pub use model_usage::{referenced_model_ids, ModelUsageEntry, ModelUsageLog, UnusedModel};
 pub use path_validation::resolve_path_within_root;
 
+// Re-export sandbox types
+pub use sandbox::{
+    run_sandbox_worker, SandboxPolicy, SandboxProfile, SandboxWorkerCommand, SandboxedTaskExecutor,
+};
+
+// Re-export simulation types
+pub use simulation::{SimulatedTaskExecutor, SimulationConfig};
+
 // Re-export port options types
 pub use port_options::{
     PortOption, PortOptionsProvider, PortOptionsQuery, PortOptionsResult, PortQueryFn,
@@ -127,11 +170,12 @@ pub use registry::{
 
 // Re-export orchestration types
 pub use orchestration::{
-    ConditionConfig, DataGraphConfig, DataGraphExecutor, LoopConfig, NodeExecutionResult,
+    execute_data_graph, ConditionConfig, DataGraphConfig, DataGraphExecutor, DataGraphRun,
+    LoopConfig, NodeExecutionResult, OrchestrationCheckpoint, OrchestrationCheckpointStore,
     OrchestrationContext, OrchestrationEdge, OrchestrationEdgeId, OrchestrationEvent,
     OrchestrationExecutor, OrchestrationGraph, OrchestrationGraphId, OrchestrationGraphMetadata,
     OrchestrationNode, OrchestrationNodeId, OrchestrationNodeType, OrchestrationResult,
-    OrchestrationStore,
+    OrchestrationStore, ResumableExecution, ORCHESTRATION_CHECKPOINT_FORMAT_VERSION,
 };
 
 // Re-export builder types
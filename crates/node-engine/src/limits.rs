@@ -0,0 +1,406 @@
+//! Hard limits on graph size and complexity.
+//!
+//! Servers that accept user-submitted graphs bound their worst case with
+//! [`GraphLimits`]: node and edge counts, how deeply groups nest, and how
+//! many iterations any orchestration loop may run. Limits are checked when a
+//! graph is loaded ([`crate::WorkflowExecutor::set_graph_limits`],
+//! [`crate::WorkflowExecutor::replace_graph`],
+//! [`crate::OrchestrationExecutor::with_graph_limits`]) and again by every
+//! executor mutation that could grow it, so a graph can never get past a
+//! limit one edit at a time.
+//!
+//! Violations are [`GraphLimitError`]s, carried by
+//! [`NodeEngineError::GraphLimitExceeded`] so hosts can report which limit
+//! was hit and by how much.
+//!
+//! Counts include the nodes and edges inside groups. Every limit is `None`
+//! (unlimited) by default.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::error::NodeEngineError;
+use crate::orchestration::{LoopConfig, OrchestrationGraph, OrchestrationNodeType};
+use crate::types::WorkflowGraph;
+
+/// Configurable upper bounds on graph size and complexity.
+///
+/// Hosts pass limits as camelCase JSON (`{"maxNodes": 500}`), like the other
+/// graph DTOs; unknown fields are rejected so a misspelled limit is not
+/// silently ignored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct GraphLimits {
+    /// Nodes in a graph, including nodes inside groups.
+    pub max_nodes: Option<usize>,
+    /// Edges in a graph, including edges inside groups.
+    pub max_edges: Option<usize>,
+    /// Levels of groups nested inside groups; a top-level group is depth 1.
+    pub max_nesting_depth: Option<usize>,
+    /// Iterations of any orchestration loop. Loops declaring a higher
+    /// `maxIterations` are rejected up front; unbounded loops fail when they
+    /// start an iteration past it.
+    pub max_loop_iterations: Option<u32>,
+}
+
+/// A graph that exceeds one of its [`GraphLimits`].
+///
+/// Serializes with a snake_case `kind` tag and camelCase fields, like the
+/// other graph DTOs hosts receive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[serde(
+    tag = "kind",
+    rename_all = "snake_case",
+    rename_all_fields = "camelCase"
+)]
+pub enum GraphLimitError {
+    #[error("Graph has {count} nodes, above the limit of {limit}")]
+    MaxNodes { count: usize, limit: usize },
+
+    #[error("Graph has {count} edges, above the limit of {limit}")]
+    MaxEdges { count: usize, limit: usize },
+
+    #[error("Group '{group_id}' is nested {depth} deep, above the limit of {limit}")]
+    MaxNestingDepth {
+        group_id: String,
+        depth: usize,
+        limit: usize,
+    },
+
+    /// `iterations` is the loop's declared bound, or the iteration it was
+    /// stopped at when it has none.
+    #[error("Loop '{node_id}' reaches {iterations} iterations, above the limit of {limit}")]
+    MaxLoopIterations {
+        node_id: String,
+        iterations: u32,
+        limit: u32,
+    },
+}
+
+impl From<GraphLimitError> for NodeEngineError {
+    fn from(error: GraphLimitError) -> Self {
+        Self::GraphLimitExceeded(error)
+    }
+}
+
+impl GraphLimits {
+    /// Limits that accept any graph.
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// Whether no limit is set.
+    pub fn is_unlimited(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Check a data graph against every limit.
+    pub fn check_graph(&self, graph: &WorkflowGraph) -> Result<(), GraphLimitError> {
+        self.check_node_count(node_count(graph))?;
+        self.check_edge_count(edge_count(graph))?;
+        if let Some(limit) = self.max_nesting_depth {
+            if let Some((group_id, depth)) = deepest_group(graph) {
+                if depth > limit {
+                    return Err(GraphLimitError::MaxNestingDepth {
+                        group_id,
+                        depth,
+                        limit,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check an orchestration graph's node and edge counts and the declared
+    /// iteration bound of each loop node.
+    pub fn check_orchestration(&self, graph: &OrchestrationGraph) -> Result<(), GraphLimitError> {
+        self.check_node_count(graph.nodes.len())?;
+        self.check_edge_count(graph.edges.len())?;
+        for node in &graph.nodes {
+            if node.node_type != OrchestrationNodeType::Loop {
+                continue;
+            }
+            let config: LoopConfig =
+                serde_json::from_value(node.config.clone()).unwrap_or_default();
+            if config.max_iterations > 0 {
+                self.check_loop_iteration(&node.id, config.max_iterations)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that loop `node_id` may run its `iteration`th iteration.
+    pub fn check_loop_iteration(
+        &self,
+        node_id: &str,
+        iteration: u32,
+    ) -> Result<(), GraphLimitError> {
+        match self.max_loop_iterations {
+            Some(limit) if iteration > limit => Err(GraphLimitError::MaxLoopIterations {
+                node_id: node_id.to_string(),
+                iterations: iteration,
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that a graph may hold `count` nodes.
+    pub fn check_node_count(&self, count: usize) -> Result<(), GraphLimitError> {
+        match self.max_nodes {
+            Some(limit) if count > limit => Err(GraphLimitError::MaxNodes { count, limit }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that a graph may hold `count` edges.
+    pub fn check_edge_count(&self, count: usize) -> Result<(), GraphLimitError> {
+        match self.max_edges {
+            Some(limit) if count > limit => Err(GraphLimitError::MaxEdges { count, limit }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Nodes in `graph`, including nodes inside groups.
+pub fn node_count(graph: &WorkflowGraph) -> usize {
+    graph.nodes.len() + graph.groups.iter().map(|g| g.nodes.len()).sum::<usize>()
+}
+
+/// Edges in `graph`, including edges inside groups.
+pub fn edge_count(graph: &WorkflowGraph) -> usize {
+    graph.edges.len() + graph.groups.iter().map(|g| g.edges.len()).sum::<usize>()
+}
+
+/// The most deeply nested group and its depth.
+///
+/// A group contains another when one of its nodes carries the other group's
+/// ID, which is how a collapsed group is placed inside its parent. A
+/// containment cycle counts as one level per group on it.
+fn deepest_group(graph: &WorkflowGraph) -> Option<(String, usize)> {
+    let parents: HashMap<&str, &str> = graph
+        .groups
+        .iter()
+        .flat_map(|parent| {
+            parent
+                .nodes
+                .iter()
+                .filter(|node| graph.find_group(&node.id).is_some())
+                .map(move |node| (node.id.as_str(), parent.id.as_str()))
+        })
+        .collect();
+
+    graph
+        .groups
+        .iter()
+        .map(|group| {
+            let mut depth = 1;
+            let mut current = group.id.as_str();
+            while let Some(parent) = parents.get(current) {
+                if depth > graph.groups.len() {
+                    break;
+                }
+                depth += 1;
+                current = parent;
+            }
+            (group.id.clone(), depth)
+        })
+        .max_by_key(|(_, depth)| *depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::NodeGroup;
+    use crate::orchestration::{OrchestrationEdge, OrchestrationNode};
+    use crate::types::{GraphEdge, GraphNode};
+
+    fn node(id: &str) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            node_type: "text-input".to_string(),
+            data: serde_json::json!({}),
+            position: (0.0, 0.0),
+        }
+    }
+
+    fn edge(id: &str, source: &str, target: &str) -> GraphEdge {
+        GraphEdge {
+            id: id.to_string(),
+            source: source.to_string(),
+            source_handle: "text".to_string(),
+            target: target.to_string(),
+            target_handle: "text".to_string(),
+        }
+    }
+
+    #[test]
+    fn counts_include_group_contents() {
+        let mut graph = WorkflowGraph::new("g", "Graph");
+        graph.nodes = vec![node("a"), node("b")];
+        graph.edges = vec![edge("e1", "a", "b")];
+        graph.groups = vec![NodeGroup::new("grp", "Group")
+            .with_nodes(vec![node("c"), node("d")])
+            .with_edges(vec![edge("e2", "c", "d")])];
+
+        let limits = GraphLimits {
+            max_nodes: Some(3),
+            ..GraphLimits::default()
+        };
+        assert_eq!(
+            limits.check_graph(&graph),
+            Err(GraphLimitError::MaxNodes { count: 4, limit: 3 })
+        );
+
+        let limits = GraphLimits {
+            max_edges: Some(1),
+            ..GraphLimits::default()
+        };
+        assert_eq!(
+            limits.check_graph(&graph),
+            Err(GraphLimitError::MaxEdges { count: 2, limit: 1 })
+        );
+        assert!(GraphLimits::unlimited().check_graph(&graph).is_ok());
+    }
+
+    #[test]
+    fn nesting_depth_follows_groups_placed_in_groups() {
+        let mut graph = WorkflowGraph::new("g", "Graph");
+        graph.nodes = vec![node("outer")];
+        graph.groups = vec![
+            NodeGroup::new("outer", "Outer").with_nodes(vec![node("middle")]),
+            NodeGroup::new("middle", "Middle").with_nodes(vec![node("inner")]),
+            NodeGroup::new("inner", "Inner").with_nodes(vec![node("leaf")]),
+        ];
+
+        let limits = GraphLimits {
+            max_nesting_depth: Some(2),
+            ..GraphLimits::default()
+        };
+        assert_eq!(
+            limits.check_graph(&graph),
+            Err(GraphLimitError::MaxNestingDepth {
+                group_id: "inner".to_string(),
+                depth: 3,
+                limit: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn loops_may_not_declare_a_bound_above_the_limit() {
+        let mut graph = OrchestrationGraph::new("o", "Orchestration");
+        let bounded = OrchestrationNode::with_config(
+            "bounded",
+            OrchestrationNodeType::Loop,
+            (0.0, 0.0),
+            serde_json::json!({"maxIterations": 5}),
+        );
+        let unbounded = OrchestrationNode::with_config(
+            "unbounded",
+            OrchestrationNodeType::Loop,
+            (0.0, 0.0),
+            serde_json::json!({"maxIterations": 0}),
+        );
+        graph.nodes = vec![bounded];
+
+        let limits = GraphLimits {
+            max_loop_iterations: Some(5),
+            ..GraphLimits::default()
+        };
+        assert!(limits.check_orchestration(&graph).is_ok());
+
+        graph.nodes.push(unbounded);
+        assert!(limits.check_orchestration(&graph).is_ok());
+        assert!(limits.check_loop_iteration("unbounded", 5).is_ok());
+        assert_eq!(
+            limits.check_loop_iteration("unbounded", 6).unwrap_err(),
+            GraphLimitError::MaxLoopIterations {
+                node_id: "unbounded".to_string(),
+                iterations: 6,
+                limit: 5,
+            }
+        );
+
+        graph.nodes[0].config = serde_json::json!({"maxIterations": 6});
+        let error = limits.check_orchestration(&graph).unwrap_err();
+        assert_eq!(
+            error,
+            GraphLimitError::MaxLoopIterations {
+                node_id: "bounded".to_string(),
+                iterations: 6,
+                limit: 5,
+            }
+        );
+        assert!(error.to_string().contains("reaches 6 iterations"));
+
+        graph.nodes.pop();
+        graph.edges.push(OrchestrationEdge::new(
+            "e",
+            "bounded",
+            "iteration",
+            "bounded",
+            "input",
+        ));
+        let limits = GraphLimits {
+            max_edges: Some(0),
+            ..GraphLimits::default()
+        };
+        assert!(limits.check_orchestration(&graph).is_err());
+    }
+
+    #[test]
+    fn limits_deserialize_from_camel_case() {
+        let limits: GraphLimits = serde_json::from_value(serde_json::json!({
+            "maxNodes": 500,
+            "maxNestingDepth": 8,
+            "maxLoopIterations": 100,
+        }))
+        .unwrap();
+        assert_eq!(
+            limits,
+            GraphLimits {
+                max_nodes: Some(500),
+                max_edges: None,
+                max_nesting_depth: Some(8),
+                max_loop_iterations: Some(100),
+            }
+        );
+
+        assert!(
+            serde_json::from_value::<GraphLimits>(serde_json::json!({"max_nodes": 500})).is_err()
+        );
+    }
+
+    #[test]
+    fn errors_serialize_with_kind_tag() {
+        let json = serde_json::to_value(GraphLimitError::MaxNodes { count: 5, limit: 4 }).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"kind": "max_nodes", "count": 5, "limit": 4})
+        );
+
+        let json = serde_json::to_value(GraphLimitError::MaxNestingDepth {
+            group_id: "inner".to_string(),
+            depth: 3,
+            limit: 2,
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"kind": "max_nesting_depth", "groupId": "inner", "depth": 3, "limit": 2})
+        );
+
+        let json = serde_json::to_value(GraphLimitError::MaxLoopIterations {
+            node_id: "retry".to_string(),
+            iterations: 6,
+            limit: 5,
+        })
+        .unwrap();
+        assert_eq!(json["nodeId"], "retry");
+        assert!(json.get("node_id").is_none());
+    }
+}
@@ -0,0 +1,265 @@
+//! Host log forwarding.
+//!
+//! Engine crates log through the `log` facade. Embedding hosts whose stderr
+//! is not observed (the BEAM, Python notebooks) install a [`LogSink`] so
+//! records are forwarded to the host's own logger instead. The bridge is a
+//! process-global `log::Log` implementation: the first install claims the
+//! global logger slot, later installs only swap the sink and level.
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+/// Severity of a forwarded log record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// Lowercase label used in serialized records and host APIs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warn => "warn",
+            Self::Info => "info",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+        }
+    }
+
+    /// Parse a case-insensitive level label (`warning` is accepted for `warn`).
+    pub fn parse(label: &str) -> Option<Self> {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "error" => Some(Self::Error),
+            "warn" | "warning" => Some(Self::Warn),
+            "info" => Some(Self::Info),
+            "debug" => Some(Self::Debug),
+            "trace" => Some(Self::Trace),
+            _ => None,
+        }
+    }
+
+    fn to_filter(self) -> log::LevelFilter {
+        match self {
+            Self::Error => log::LevelFilter::Error,
+            Self::Warn => log::LevelFilter::Warn,
+            Self::Info => log::LevelFilter::Info,
+            Self::Debug => log::LevelFilter::Debug,
+            Self::Trace => log::LevelFilter::Trace,
+        }
+    }
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => Self::Error,
+            log::Level::Warn => Self::Warn,
+            log::Level::Info => Self::Info,
+            log::Level::Debug => Self::Debug,
+            log::Level::Trace => Self::Trace,
+        }
+    }
+}
+
+/// A log record forwarded to a [`LogSink`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogRecord {
+    pub level: LogLevel,
+    /// Log target, usually the emitting module path (`node_engine::engine`)
+    pub target: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    pub occurred_at_ms: u64,
+}
+
+/// Destination for forwarded log records.
+///
+/// Implementations are called synchronously on the logging thread, so they
+/// should hand records off (channel, buffer) rather than block. Records
+/// logged from inside a sink are dropped to avoid recursion.
+pub trait LogSink: Send + Sync {
+    fn log(&self, record: LogRecord);
+}
+
+/// Error installing the host log bridge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogSinkError {
+    pub message: String,
+}
+
+impl std::fmt::Display for LogSinkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Log sink error: {}", self.message)
+    }
+}
+
+impl std::error::Error for LogSinkError {}
+
+struct LogBridge {
+    sink: RwLock<Option<Arc<dyn LogSink>>>,
+}
+
+static BRIDGE: LogBridge = LogBridge {
+    sink: RwLock::new(None),
+};
+static BRIDGE_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static FORWARDING: Cell<bool> = const { Cell::new(false) };
+}
+
+impl log::Log for LogBridge {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) || FORWARDING.with(Cell::get) {
+            return;
+        }
+        let Some(sink) = self.sink.read().ok().and_then(|sink| sink.clone()) else {
+            return;
+        };
+
+        FORWARDING.with(|forwarding| forwarding.set(true));
+        sink.log(LogRecord {
+            level: record.level().into(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+            module_path: record.module_path().map(str::to_string),
+            file: record.file().map(str::to_string),
+            line: record.line(),
+            occurred_at_ms: crate::events::unix_timestamp_ms(),
+        });
+        FORWARDING.with(|forwarding| forwarding.set(false));
+    }
+
+    fn flush(&self) {}
+}
+
+/// Forward `log` records at or above `max_level` to `sink`.
+///
+/// Replaces any previously installed sink. Fails only when another global
+/// logger (for example `env_logger`) already owns the process logger slot.
+pub fn install_log_sink(sink: Arc<dyn LogSink>, max_level: LogLevel) -> Result<(), LogSinkError> {
+    if !BRIDGE_INSTALLED.load(Ordering::Acquire) {
+        log::set_logger(&BRIDGE).map_err(|_| LogSinkError {
+            message: "another global logger is already installed".to_string(),
+        })?;
+        BRIDGE_INSTALLED.store(true, Ordering::Release);
+    }
+
+    *BRIDGE.sink.write().unwrap() = Some(sink);
+    log::set_max_level(max_level.to_filter());
+    Ok(())
+}
+
+/// Change the forwarding level of the installed sink.
+pub fn set_log_sink_level(max_level: LogLevel) {
+    if BRIDGE_INSTALLED.load(Ordering::Acquire) {
+        log::set_max_level(max_level.to_filter());
+    }
+}
+
+/// Stop forwarding records. The bridge keeps the global logger slot, so a
+/// later [`install_log_sink`] call succeeds.
+pub fn clear_log_sink() {
+    if BRIDGE_INSTALLED.load(Ordering::Acquire) {
+        *BRIDGE.sink.write().unwrap() = None;
+        log::set_max_level(log::LevelFilter::Off);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct CollectingSink {
+        records: Mutex<Vec<LogRecord>>,
+    }
+
+    impl LogSink for CollectingSink {
+        fn log(&self, record: LogRecord) {
+            // Logging from inside a sink must not recurse.
+            log::error!("sink re-entry");
+            self.records.lock().unwrap().push(record);
+        }
+    }
+
+    #[test]
+    fn parse_accepts_labels_case_insensitively() {
+        assert_eq!(LogLevel::parse("WARNING"), Some(LogLevel::Warn));
+        assert_eq!(LogLevel::parse(" debug "), Some(LogLevel::Debug));
+        assert_eq!(LogLevel::parse("verbose"), None);
+        assert_eq!(LogLevel::Info.as_str(), "info");
+    }
+
+    #[test]
+    fn record_serializes_with_lowercase_level() {
+        let record = LogRecord {
+            level: LogLevel::Warn,
+            target: "node_engine".to_string(),
+            message: "hi".to_string(),
+            module_path: None,
+            file: None,
+            line: Some(3),
+            occurred_at_ms: 1,
+        };
+        let value = serde_json::to_value(&record).unwrap();
+
+        assert_eq!(value["level"], "warn");
+        assert_eq!(value["line"], 3);
+        assert!(value.get("modulePath").is_none());
+    }
+
+    // The bridge is process-global, so install/filter/clear is exercised in a
+    // single test.
+    #[test]
+    fn installed_sink_receives_filtered_records_until_cleared() {
+        let sink = Arc::new(CollectingSink::default());
+        install_log_sink(sink.clone(), LogLevel::Info).expect("install bridge");
+
+        log::info!(target: "log_sink_test", "forwarded {}", 1);
+        log::debug!(target: "log_sink_test", "filtered");
+        set_log_sink_level(LogLevel::Debug);
+        log::debug!(target: "log_sink_test", "forwarded 2");
+        clear_log_sink();
+        log::error!(target: "log_sink_test", "after clear");
+
+        let records: Vec<LogRecord> = sink
+            .records
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|record| record.target == "log_sink_test")
+            .cloned()
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].level, LogLevel::Info);
+        assert_eq!(records[0].message, "forwarded 1");
+        assert_eq!(records[1].level, LogLevel::Debug);
+        assert!(sink
+            .records
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|record| record.message != "sink re-entry"));
+    }
+}
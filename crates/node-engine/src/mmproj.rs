@@ -0,0 +1,140 @@
+//! Multimodal projector (mmproj) pairing for GGUF vision models.
+//!
+//! llama.cpp vision models ship as a language model GGUF plus a separate
+//! projector file, conventionally named with `mmproj` and stored next to the
+//! model. [`find_mmproj`] locates that projector so workflows do not have to
+//! wire it up by hand.
+
+use std::path::{Path, PathBuf};
+
+/// Whether `path` names a multimodal projector rather than a model.
+pub fn is_mmproj_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let name = name.to_ascii_lowercase();
+    name.contains("mmproj") && (name.ends_with(".gguf") || name.ends_with(".mmproj"))
+}
+
+fn is_gguf(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gguf"))
+}
+
+/// Find the projector paired with a GGUF model.
+///
+/// `model_path` may be the model file or the directory holding it, as stored
+/// by pumas-library. Projectors are looked up in the same directory; when
+/// there are several, the one whose name shares the longest prefix with the
+/// model file wins, so `llava-7b-q4.gguf` pairs with `llava-7b-mmproj-f16.gguf`
+/// over `bakllava-mmproj-f16.gguf`. Returns `None` for non-GGUF models and
+/// models without a projector.
+pub fn find_mmproj(model_path: &Path) -> Option<PathBuf> {
+    let (dir, model_file) = if model_path.is_dir() {
+        (model_path, None)
+    } else {
+        (model_path.parent()?, Some(model_path))
+    };
+    if model_file.is_some_and(|file| !is_gguf(file) || is_mmproj_file(file)) {
+        return None;
+    }
+
+    let mut models = Vec::new();
+    let mut projectors = Vec::new();
+    for path in std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+    {
+        if is_mmproj_file(&path) {
+            projectors.push(path);
+        } else if is_gguf(&path) {
+            models.push(path);
+        }
+    }
+    models.sort();
+    let model = match model_file {
+        Some(file) => file.to_path_buf(),
+        None => models.into_iter().next()?,
+    };
+
+    let model_key = name_key(&model);
+    projectors.sort();
+    // `max_by_key` keeps the last maximum; reversing makes ties pick the
+    // first projector by name.
+    projectors
+        .into_iter()
+        .rev()
+        .max_by_key(|projector| common_prefix_len(&model_key, &name_key(projector)))
+}
+
+/// Lowercase alphanumeric file stem without the `mmproj` marker.
+fn name_key(path: &Path) -> String {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+        .replace("mmproj", "")
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect()
+}
+
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn touch(dir: &Path, names: &[&str]) {
+        for name in names {
+            std::fs::write(dir.join(name), b"gguf").unwrap();
+        }
+    }
+
+    #[test]
+    fn pairs_the_projector_with_the_closest_name() {
+        let dir = tempfile::tempdir().unwrap();
+        touch(
+            dir.path(),
+            &[
+                "llava-7b-q4_k_m.gguf",
+                "bakllava-mmproj-f16.gguf",
+                "llava-7b-mmproj-f16.gguf",
+            ],
+        );
+
+        let model = dir.path().join("llava-7b-q4_k_m.gguf");
+        assert_eq!(
+            find_mmproj(&model),
+            Some(dir.path().join("llava-7b-mmproj-f16.gguf"))
+        );
+        assert_eq!(
+            find_mmproj(dir.path()),
+            Some(dir.path().join("llava-7b-mmproj-f16.gguf"))
+        );
+    }
+
+    #[test]
+    fn a_lone_projector_pairs_regardless_of_name() {
+        let dir = tempfile::tempdir().unwrap();
+        touch(dir.path(), &["model-q4.gguf", "mmproj-model-f16.gguf"]);
+
+        assert_eq!(
+            find_mmproj(&dir.path().join("model-q4.gguf")),
+            Some(dir.path().join("mmproj-model-f16.gguf"))
+        );
+    }
+
+    #[test]
+    fn text_only_and_non_gguf_models_have_no_projector() {
+        let dir = tempfile::tempdir().unwrap();
+        touch(dir.path(), &["model-q4.gguf", "model.safetensors"]);
+
+        assert_eq!(find_mmproj(&dir.path().join("model-q4.gguf")), None);
+        assert_eq!(find_mmproj(&dir.path().join("model.safetensors")), None);
+    }
+}
@@ -0,0 +1,133 @@
+//! Model benchmark results and their library metadata layout.
+//!
+//! A `model-benchmark` node runs [`STANDARD_BENCHMARK_PROMPTS`] against one
+//! model on one backend and produces a [`ModelBenchmarkResult`]. Results are
+//! kept in the model's library metadata under [`BENCHMARKS_METADATA_KEY`],
+//! keyed by backend, so model selection can prefer faster models. Writing the
+//! metadata is left to the host through [`ModelBenchmarkStore`], which owns
+//! the model library.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Metadata key holding `{ "<backend>": ModelBenchmarkResult }`.
+pub const BENCHMARKS_METADATA_KEY: &str = "benchmarks";
+
+/// Prompt set used when a benchmark node has no `prompts` input. Mixes a
+/// short answer, a medium explanation and a long-form generation so the
+/// throughput average is not dominated by prompt processing.
+pub const STANDARD_BENCHMARK_PROMPTS: &[&str] = &[
+    "Name the capital of France in one word.",
+    "Explain in three sentences how a hash map handles collisions.",
+    "Write a short story of about two hundred words about a lighthouse keeper.",
+    "List ten common kitchen utensils, one per line.",
+];
+
+/// Measurements from one benchmark run of a model on a backend.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelBenchmarkResult {
+    pub backend: String,
+    pub model_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
+    pub prompt_count: usize,
+    /// Generated tokens per second, averaged over all prompts.
+    pub tokens_per_second: f64,
+    /// Mean time from sending a prompt to its first generated token.
+    pub time_to_first_token_ms: f64,
+    /// Growth in system RAM use across the model load, in MiB.
+    pub memory_mb: u64,
+    pub load_ms: u64,
+    pub measured_at_ms: i64,
+}
+
+impl ModelBenchmarkResult {
+    /// The [`BENCHMARKS_METADATA_KEY`] value of `metadata` with this result
+    /// replacing any earlier one for the same backend.
+    pub fn merged_benchmarks(&self, metadata: &serde_json::Value) -> serde_json::Value {
+        let mut benchmarks = metadata
+            .get(BENCHMARKS_METADATA_KEY)
+            .and_then(|value| value.as_object())
+            .cloned()
+            .unwrap_or_default();
+        benchmarks.insert(
+            self.backend.clone(),
+            serde_json::to_value(self).unwrap_or(serde_json::Value::Null),
+        );
+        serde_json::Value::Object(benchmarks)
+    }
+}
+
+/// Measured tokens per second for `backend` in library metadata, if a
+/// benchmark has been stored for it.
+pub fn benchmark_tokens_per_second(metadata: &serde_json::Value, backend: &str) -> Option<f64> {
+    metadata
+        .get(BENCHMARKS_METADATA_KEY)?
+        .get(backend)?
+        .get("tokensPerSecond")?
+        .as_f64()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+}
+
+/// Host-provided persistence for benchmark results.
+///
+/// Installed under [`crate::extension_keys::MODEL_BENCHMARK_STORE`]. Without
+/// one, benchmark nodes still report their measurements but store nothing.
+#[async_trait]
+pub trait ModelBenchmarkStore: Send + Sync {
+    async fn store_benchmark(
+        &self,
+        model_id: &str,
+        result: &ModelBenchmarkResult,
+    ) -> std::result::Result<(), String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(backend: &str, tokens_per_second: f64) -> ModelBenchmarkResult {
+        ModelBenchmarkResult {
+            backend: backend.to_string(),
+            model_path: "/models/tiny.gguf".to_string(),
+            model_id: Some("llm/tiny".to_string()),
+            prompt_count: 4,
+            tokens_per_second,
+            time_to_first_token_ms: 120.0,
+            memory_mb: 512,
+            load_ms: 900,
+            measured_at_ms: 1_700_000_000_000,
+        }
+    }
+
+    #[test]
+    fn merge_replaces_same_backend_and_keeps_others() {
+        let metadata = serde_json::json!({
+            "family": "llama",
+            "benchmarks": {
+                "llama_cpp": { "tokensPerSecond": 10.0 },
+                "ollama": { "tokensPerSecond": 8.0 }
+            }
+        });
+
+        let merged = result("llama_cpp", 32.5).merged_benchmarks(&metadata);
+        let metadata = serde_json::json!({ "benchmarks": merged });
+
+        assert_eq!(
+            benchmark_tokens_per_second(&metadata, "llama_cpp"),
+            Some(32.5)
+        );
+        assert_eq!(benchmark_tokens_per_second(&metadata, "ollama"), Some(8.0));
+        assert_eq!(benchmark_tokens_per_second(&metadata, "candle"), None);
+    }
+
+    #[test]
+    fn result_round_trips_camel_case() {
+        let value = serde_json::to_value(result("llama_cpp", 20.0)).unwrap();
+        assert_eq!(value["timeToFirstTokenMs"], serde_json::json!(120.0));
+        assert_eq!(value["modelId"], serde_json::json!("llm/tiny"));
+        let parsed: ModelBenchmarkResult = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed, result("llama_cpp", 20.0));
+    }
+}
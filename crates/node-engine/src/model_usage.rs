@@ -0,0 +1,340 @@
+//! Model usage tracking for library garbage collection.
+//!
+//! [`ModelUsageLog`] records when each library model was first seen and last
+//! used by an execution, plus which models are pinned. Combined with the model
+//! IDs referenced by stored graphs it decides which models have gone unused
+//! long enough to delete. Deleting is left to the host, which owns the model
+//! library.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Result, WorkflowGraph};
+
+/// Milliseconds in one day.
+pub const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Usage recorded for one model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelUsageEntry {
+    /// When the model was first recorded; new models are not collected
+    /// before they have had a chance to be used.
+    pub first_seen_ms: i64,
+    pub last_used_ms: Option<i64>,
+    pub use_count: u64,
+}
+
+impl ModelUsageEntry {
+    fn new(now_ms: i64) -> Self {
+        Self {
+            first_seen_ms: now_ms,
+            last_used_ms: None,
+            use_count: 0,
+        }
+    }
+
+    /// Start of the current idle period.
+    pub fn idle_since_ms(&self) -> i64 {
+        self.last_used_ms.unwrap_or(self.first_seen_ms)
+    }
+}
+
+/// A model eligible for garbage collection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedModel {
+    pub model_id: String,
+    pub last_used_ms: Option<i64>,
+    pub idle_since_ms: i64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ModelUsageState {
+    #[serde(default)]
+    models: BTreeMap<String, ModelUsageEntry>,
+    #[serde(default)]
+    pins: BTreeSet<String>,
+}
+
+/// Model usage and pins with optional JSON file persistence.
+///
+/// Executors record a use whenever a `puma-lib` node runs, when the log is
+/// installed under [`crate::extension_keys::MODEL_USAGE`].
+///
+/// # Example
+///
+/// ```ignore
+/// let usage = ModelUsageLog::with_persistence(library_root.join(ModelUsageLog::FILE_NAME));
+/// usage.load_from_disk()?;
+/// usage.record_use("llm/llama/llama-3-8b");
+/// let unused = usage.unused_models(&library_ids, &referenced, 30, now_ms)?;
+/// ```
+#[derive(Debug, Default)]
+pub struct ModelUsageLog {
+    state: Mutex<ModelUsageState>,
+    persist_path: Option<PathBuf>,
+}
+
+impl ModelUsageLog {
+    /// File name hosts use when keeping the log next to a model library.
+    pub const FILE_NAME: &'static str = "pantograph-model-usage.json";
+
+    /// Create an in-memory log without persistence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a log that persists to the given JSON file.
+    pub fn with_persistence(path: impl AsRef<Path>) -> Self {
+        Self {
+            state: Mutex::new(ModelUsageState::default()),
+            persist_path: Some(path.as_ref().to_path_buf()),
+        }
+    }
+
+    /// Load the persisted log, replacing in-memory state.
+    ///
+    /// Returns the number of models loaded.
+    pub fn load_from_disk(&self) -> Result<usize> {
+        let Some(ref path) = self.persist_path else {
+            return Ok(0);
+        };
+        if !path.exists() {
+            return Ok(0);
+        }
+        let content = std::fs::read_to_string(path)?;
+        let loaded: ModelUsageState = serde_json::from_str(&content)?;
+        let count = loaded.models.len();
+        *self.lock() = loaded;
+        log::info!("Loaded usage for {} models from {:?}", count, path);
+        Ok(count)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ModelUsageState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn save_to_disk(&self, state: &ModelUsageState) -> Result<()> {
+        let Some(ref path) = self.persist_path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(state)?)?;
+        Ok(())
+    }
+
+    /// Record that an execution used `model_id` now.
+    ///
+    /// Persistence failures are logged rather than failing the execution.
+    pub fn record_use(&self, model_id: &str) {
+        let now = now_ms();
+        let mut state = self.lock();
+        let entry = state
+            .models
+            .entry(model_id.to_string())
+            .or_insert_with(|| ModelUsageEntry::new(now));
+        entry.last_used_ms = Some(now);
+        entry.use_count += 1;
+        if let Err(e) = self.save_to_disk(&state) {
+            log::warn!("Failed to persist model usage: {}", e);
+        }
+    }
+
+    /// Usage recorded for `model_id`.
+    pub fn entry(&self, model_id: &str) -> Option<ModelUsageEntry> {
+        self.lock().models.get(model_id).cloned()
+    }
+
+    /// Pin `model_id` so it is never collected. Returns `false` if it was
+    /// already pinned.
+    pub fn pin(&self, model_id: &str) -> Result<bool> {
+        let mut state = self.lock();
+        let added = state.pins.insert(model_id.to_string());
+        self.save_to_disk(&state)?;
+        Ok(added)
+    }
+
+    /// Remove a pin. Returns `false` if the model was not pinned.
+    pub fn unpin(&self, model_id: &str) -> Result<bool> {
+        let mut state = self.lock();
+        let removed = state.pins.remove(model_id);
+        self.save_to_disk(&state)?;
+        Ok(removed)
+    }
+
+    /// Pinned model IDs in sorted order.
+    pub fn pins(&self) -> Vec<String> {
+        self.lock().pins.iter().cloned().collect()
+    }
+
+    /// Drop the usage of a deleted model. Pins are kept so a re-downloaded
+    /// model stays protected.
+    pub fn forget(&self, model_id: &str) -> Result<()> {
+        let mut state = self.lock();
+        if state.models.remove(model_id).is_some() {
+            self.save_to_disk(&state)?;
+        }
+        Ok(())
+    }
+
+    /// Library models that are neither pinned nor referenced by a stored
+    /// graph and have been idle for at least `unused_for_days`.
+    ///
+    /// Library models without usage are recorded as first seen at `now_ms`,
+    /// so they only become eligible after the full idle period.
+    pub fn unused_models(
+        &self,
+        library_model_ids: &[String],
+        referenced: &BTreeSet<String>,
+        unused_for_days: u32,
+        now_ms: i64,
+    ) -> Result<Vec<UnusedModel>> {
+        let cutoff = now_ms - i64::from(unused_for_days) * MILLIS_PER_DAY;
+        let mut state = self.lock();
+        let mut observed = false;
+        let mut unused = Vec::new();
+        for model_id in library_model_ids {
+            if !state.models.contains_key(model_id) {
+                state
+                    .models
+                    .insert(model_id.clone(), ModelUsageEntry::new(now_ms));
+                observed = true;
+            }
+            if state.pins.contains(model_id) || referenced.contains(model_id) {
+                continue;
+            }
+            let entry = &state.models[model_id];
+            if entry.idle_since_ms() <= cutoff {
+                unused.push(UnusedModel {
+                    model_id: model_id.clone(),
+                    last_used_ms: entry.last_used_ms,
+                    idle_since_ms: entry.idle_since_ms(),
+                });
+            }
+        }
+        if observed {
+            self.save_to_disk(&state)?;
+        }
+        Ok(unused)
+    }
+}
+
+/// Model IDs referenced by node data in `graphs`, such as `puma-lib` model
+/// selections.
+pub fn referenced_model_ids<'a>(
+    graphs: impl IntoIterator<Item = &'a WorkflowGraph>,
+) -> BTreeSet<String> {
+    graphs
+        .into_iter()
+        .flat_map(|graph| &graph.nodes)
+        .filter_map(|node| {
+            node.data
+                .get("model_id")
+                .or_else(|| node.data.get("modelId"))
+                .and_then(|value| value.as_str())
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+/// Current time in milliseconds since the Unix epoch.
+pub fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GraphNode;
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn new_models_wait_a_full_idle_period() {
+        let usage = ModelUsageLog::new();
+        let library = ids(&["a"]);
+        let now = 100 * MILLIS_PER_DAY;
+
+        assert!(usage
+            .unused_models(&library, &BTreeSet::new(), 30, now)
+            .unwrap()
+            .is_empty());
+        let later = usage
+            .unused_models(&library, &BTreeSet::new(), 30, now + 30 * MILLIS_PER_DAY)
+            .unwrap();
+        assert_eq!(later.len(), 1);
+        assert_eq!(later[0].idle_since_ms, now);
+    }
+
+    #[test]
+    fn pins_references_and_recent_use_protect_models() {
+        let usage = ModelUsageLog::new();
+        let library = ids(&["pinned", "referenced", "used", "idle"]);
+        usage
+            .unused_models(&library, &BTreeSet::new(), 0, 0)
+            .unwrap();
+        usage.pin("pinned").unwrap();
+        usage.record_use("used");
+        let referenced = BTreeSet::from(["referenced".to_string()]);
+
+        let unused = usage
+            .unused_models(&library, &referenced, 1, now_ms())
+            .unwrap();
+        let unused: Vec<_> = unused.into_iter().map(|model| model.model_id).collect();
+        assert_eq!(unused, ids(&["idle"]));
+    }
+
+    #[test]
+    fn usage_and_pins_survive_a_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(ModelUsageLog::FILE_NAME);
+        let usage = ModelUsageLog::with_persistence(&path);
+        usage.record_use("a");
+        usage.pin("b").unwrap();
+
+        let reloaded = ModelUsageLog::with_persistence(&path);
+        assert_eq!(reloaded.load_from_disk().unwrap(), 1);
+        assert_eq!(reloaded.entry("a").unwrap().use_count, 1);
+        assert_eq!(reloaded.pins(), ids(&["b"]));
+    }
+
+    #[test]
+    fn referenced_ids_come_from_node_data() {
+        let mut graph = WorkflowGraph::new("wf", "Workflow");
+        for (id, data) in [
+            ("lib", serde_json::json!({ "model_id": "llm/a" })),
+            ("alias", serde_json::json!({ "modelId": "llm/b" })),
+            ("blank", serde_json::json!({ "model_id": " " })),
+            ("text", serde_json::json!({ "text": "llm/c" })),
+        ] {
+            graph.nodes.push(GraphNode {
+                id: id.to_string(),
+                node_type: "puma-lib".to_string(),
+                data,
+                position: (0.0, 0.0),
+            });
+        }
+
+        assert_eq!(
+            referenced_model_ids([&graph]),
+            BTreeSet::from(["llm/a".to_string(), "llm/b".to_string()])
+        );
+    }
+}
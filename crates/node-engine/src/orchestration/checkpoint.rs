@@ -0,0 +1,499 @@
+//! Crash-safe checkpoints for in-flight orchestration executions.
+//!
+//! When an [`OrchestrationExecutor`](super::OrchestrationExecutor) is given a
+//! checkpoint store, it writes one JSON file per execution before each node
+//! runs. A checkpoint holds the next node to run, the orchestration context,
+//! and the event journal emitted so far, so a host that restarts
+//! mid-orchestration can list resumable executions and resume them (replaying
+//! the journal first) or discard them.
+//!
+//! The executor keeps the journal in a JSON-lines sidecar (`<id>.events.jsonl`)
+//! that only has new events appended, so checkpoint cost stays proportional to
+//! the events emitted since the previous node rather than the whole run.
+
+use super::nodes::OrchestrationContext;
+use crate::events::{EventError, EventSink, WorkflowEvent};
+use crate::{NodeEngineError, Result};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Current on-disk format version for orchestration checkpoints.
+pub const ORCHESTRATION_CHECKPOINT_FORMAT_VERSION: u32 = 1;
+
+/// Persisted state of an in-flight orchestration execution.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrchestrationCheckpoint {
+    pub format_version: u32,
+    pub execution_id: String,
+    pub orchestration_id: String,
+    /// Node that runs first when the execution is resumed.
+    pub next_node_id: String,
+    pub context: OrchestrationContext,
+    pub nodes_executed: u32,
+    /// Events emitted up to this checkpoint, replayed on resume.
+    #[serde(default)]
+    pub events: Vec<WorkflowEvent>,
+    /// Number of events held in the journal sidecar. When set, `load` fills
+    /// `events` from the first `journal_len` journal lines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub journal_len: Option<usize>,
+    pub updated_at_ms: u64,
+}
+
+/// Listing summary of a resumable execution.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumableExecution {
+    pub execution_id: String,
+    pub orchestration_id: String,
+    pub next_node_id: String,
+    pub nodes_executed: u32,
+    pub updated_at_ms: u64,
+}
+
+impl From<&OrchestrationCheckpoint> for ResumableExecution {
+    fn from(checkpoint: &OrchestrationCheckpoint) -> Self {
+        Self {
+            execution_id: checkpoint.execution_id.clone(),
+            orchestration_id: checkpoint.orchestration_id.clone(),
+            next_node_id: checkpoint.next_node_id.clone(),
+            nodes_executed: checkpoint.nodes_executed,
+            updated_at_ms: checkpoint.updated_at_ms,
+        }
+    }
+}
+
+/// Directory of orchestration checkpoint files, one per execution.
+///
+/// Writes go to a temporary file that is renamed over the checkpoint, so a
+/// crash mid-write leaves the previous checkpoint intact.
+#[derive(Debug, Clone)]
+pub struct OrchestrationCheckpointStore {
+    dir: PathBuf,
+}
+
+impl OrchestrationCheckpointStore {
+    /// Create a store rooted at `dir`. The directory is created on first save.
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Directory holding the checkpoint files.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Write (or replace) the checkpoint for its execution.
+    pub fn save(&self, checkpoint: &OrchestrationCheckpoint) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.checkpoint_path(&checkpoint.execution_id);
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec_pretty(checkpoint)?)?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+
+    /// Write `new_events` to the execution's journal sidecar, then the
+    /// checkpoint itself (which should carry `journal_len` and no inline
+    /// events). `rewrite` replaces the journal instead of appending to it.
+    ///
+    /// The journal is written first, so a crash between the two writes leaves
+    /// the previous checkpoint pointing at a valid journal prefix.
+    pub(super) fn save_journaled(
+        &self,
+        checkpoint: &OrchestrationCheckpoint,
+        new_events: &[WorkflowEvent],
+        rewrite: bool,
+    ) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let journal_path = self.journal_path(&checkpoint.execution_id);
+        let mut lines = Vec::new();
+        for event in new_events {
+            serde_json::to_writer(&mut lines, event)?;
+            lines.push(b'\n');
+        }
+        if rewrite {
+            let tmp_path = journal_path.with_extension("jsonl.tmp");
+            std::fs::write(&tmp_path, &lines)?;
+            std::fs::rename(&tmp_path, &journal_path)?;
+        } else if !lines.is_empty() {
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&journal_path)?
+                .write_all(&lines)?;
+        }
+        self.save(checkpoint)
+    }
+
+    /// Load the checkpoint for an execution, if one exists.
+    pub fn load(&self, execution_id: &str) -> Result<Option<OrchestrationCheckpoint>> {
+        let path = self.checkpoint_path(execution_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let mut checkpoint: OrchestrationCheckpoint =
+            serde_json::from_slice(&std::fs::read(&path)?)?;
+        if checkpoint.format_version != ORCHESTRATION_CHECKPOINT_FORMAT_VERSION {
+            return Err(NodeEngineError::failed(format!(
+                "Unsupported orchestration checkpoint format version {} (expected {})",
+                checkpoint.format_version, ORCHESTRATION_CHECKPOINT_FORMAT_VERSION
+            )));
+        }
+        if let Some(journal_len) = checkpoint.journal_len {
+            checkpoint.events = self.read_journal(execution_id, journal_len)?;
+        }
+        Ok(Some(checkpoint))
+    }
+
+    /// List all resumable executions, oldest update first.
+    ///
+    /// Unreadable or incompatible checkpoint files are skipped with a warning.
+    pub fn list(&self) -> Result<Vec<ResumableExecution>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut executions = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)? {
+            let file_path = entry?.path();
+            if file_path.extension().is_none_or(|e| e != "json") {
+                continue;
+            }
+            let parsed = std::fs::read(&file_path)
+                .map_err(NodeEngineError::from)
+                .and_then(|bytes| {
+                    serde_json::from_slice::<OrchestrationCheckpoint>(&bytes)
+                        .map_err(NodeEngineError::from)
+                });
+            match parsed {
+                Ok(checkpoint)
+                    if checkpoint.format_version == ORCHESTRATION_CHECKPOINT_FORMAT_VERSION =>
+                {
+                    executions.push(ResumableExecution::from(&checkpoint));
+                }
+                Ok(checkpoint) => log::warn!(
+                    "Skipping orchestration checkpoint {:?} with format version {}",
+                    file_path,
+                    checkpoint.format_version
+                ),
+                Err(e) => log::warn!(
+                    "Skipping unreadable orchestration checkpoint {:?}: {}",
+                    file_path,
+                    e
+                ),
+            }
+        }
+
+        executions.sort_by(|a, b| {
+            (a.updated_at_ms, &a.execution_id).cmp(&(b.updated_at_ms, &b.execution_id))
+        });
+        Ok(executions)
+    }
+
+    /// Delete the checkpoint for an execution. Returns whether one existed.
+    pub fn remove(&self, execution_id: &str) -> Result<bool> {
+        let path = self.checkpoint_path(execution_id);
+        if !path.exists() {
+            return Ok(false);
+        }
+        std::fs::remove_file(&path)?;
+        let journal_path = self.journal_path(execution_id);
+        if journal_path.exists() {
+            std::fs::remove_file(&journal_path)?;
+        }
+        Ok(true)
+    }
+
+    /// Read the first `len` events of an execution's journal. Lines past
+    /// `len` were appended after the checkpoint was written and are ignored.
+    fn read_journal(&self, execution_id: &str, len: usize) -> Result<Vec<WorkflowEvent>> {
+        let contents = std::fs::read_to_string(self.journal_path(execution_id))?;
+        let events = contents
+            .lines()
+            .take(len)
+            .map(serde_json::from_str)
+            .collect::<std::result::Result<Vec<WorkflowEvent>, _>>()?;
+        if events.len() != len {
+            return Err(NodeEngineError::failed(format!(
+                "Orchestration checkpoint journal for '{}' has {} events (expected {})",
+                execution_id,
+                events.len(),
+                len
+            )));
+        }
+        Ok(events)
+    }
+
+    fn journal_path(&self, execution_id: &str) -> PathBuf {
+        self.checkpoint_path(execution_id)
+            .with_extension("events.jsonl")
+    }
+
+    fn checkpoint_path(&self, execution_id: &str) -> PathBuf {
+        let file_stem: String = execution_id
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        self.dir.join(format!("{}.json", file_stem))
+    }
+}
+
+/// Event sink wrapper that records forwarded events for checkpoint journals.
+pub(super) struct JournalingEventSink<'a> {
+    inner: &'a dyn EventSink,
+    journal: Option<Mutex<Journal>>,
+}
+
+struct Journal {
+    events: Vec<WorkflowEvent>,
+    /// Events already written to the journal sidecar; `None` until the first
+    /// successful write of this run, which rewrites the sidecar in full.
+    persisted: Option<usize>,
+}
+
+/// Journal events not yet written to disk.
+pub(super) struct JournalDelta {
+    pub(super) events: Vec<WorkflowEvent>,
+    /// Journal length once `events` are written.
+    pub(super) len: usize,
+    /// Whether the sidecar must be rewritten rather than appended to.
+    pub(super) rewrite: bool,
+}
+
+impl<'a> JournalingEventSink<'a> {
+    /// Wrap `inner`; events are only recorded when `enabled`.
+    pub(super) fn new(
+        inner: &'a dyn EventSink,
+        enabled: bool,
+        replayed: Vec<WorkflowEvent>,
+    ) -> Self {
+        Self {
+            inner,
+            journal: enabled.then(|| {
+                Mutex::new(Journal {
+                    events: replayed,
+                    persisted: None,
+                })
+            }),
+        }
+    }
+
+    #[cfg(test)]
+    pub(super) fn events(&self) -> Vec<WorkflowEvent> {
+        self.journal
+            .as_ref()
+            .map(|journal| journal.lock().unwrap().events.clone())
+            .unwrap_or_default()
+    }
+
+    /// Events recorded since the last persisted journal write.
+    pub(super) fn delta(&self) -> JournalDelta {
+        let Some(journal) = &self.journal else {
+            return JournalDelta {
+                events: Vec::new(),
+                len: 0,
+                rewrite: false,
+            };
+        };
+        let journal = journal.lock().unwrap();
+        let from = journal.persisted.unwrap_or(0);
+        JournalDelta {
+            events: journal.events[from..].to_vec(),
+            len: journal.events.len(),
+            rewrite: journal.persisted.is_none(),
+        }
+    }
+
+    /// Record the outcome of a journal write: `Some(len)` after a successful
+    /// write, `None` to force the next write to rewrite the sidecar.
+    pub(super) fn mark_persisted(&self, len: Option<usize>) {
+        if let Some(journal) = &self.journal {
+            journal.lock().unwrap().persisted = len;
+        }
+    }
+}
+
+impl EventSink for JournalingEventSink<'_> {
+    fn send(&self, event: WorkflowEvent) -> std::result::Result<(), EventError> {
+        if let Some(journal) = &self.journal {
+            journal.lock().unwrap().events.push(event.clone());
+        }
+        self.inner.send(event)
+    }
+
+    fn flush(&self) -> std::result::Result<(), EventError> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn checkpoint(execution_id: &str, updated_at_ms: u64) -> OrchestrationCheckpoint {
+        let mut context = OrchestrationContext::with_data(HashMap::from([(
+            "prompt".to_string(),
+            serde_json::json!("hi"),
+        )]));
+        context.increment_loop_iteration("loop");
+        OrchestrationCheckpoint {
+            format_version: ORCHESTRATION_CHECKPOINT_FORMAT_VERSION,
+            execution_id: execution_id.to_string(),
+            orchestration_id: "orch-1".to_string(),
+            next_node_id: "generate".to_string(),
+            context,
+            nodes_executed: 2,
+            events: vec![WorkflowEvent::WorkflowStarted {
+                workflow_id: "orch-1".to_string(),
+                execution_id: execution_id.to_string(),
+                occurred_at_ms: Some(1),
+            }],
+            journal_len: None,
+            updated_at_ms,
+        }
+    }
+
+    fn started(execution_id: &str, occurred_at_ms: u64) -> WorkflowEvent {
+        WorkflowEvent::WorkflowStarted {
+            workflow_id: "orch-1".to_string(),
+            execution_id: execution_id.to_string(),
+            occurred_at_ms: Some(occurred_at_ms),
+        }
+    }
+
+    #[test]
+    fn save_load_list_and_remove_round_trip() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let store = OrchestrationCheckpointStore::new(dir.path().join("checkpoints"));
+
+        assert!(store.list().expect("list empty").is_empty());
+
+        let later = checkpoint("exec/b", 20);
+        let earlier = checkpoint("exec-a", 10);
+        store.save(&later).expect("save later");
+        store.save(&earlier).expect("save earlier");
+
+        assert_eq!(store.load("exec/b").expect("load"), Some(later.clone()));
+        assert_eq!(
+            store
+                .list()
+                .expect("list")
+                .into_iter()
+                .map(|execution| execution.execution_id)
+                .collect::<Vec<_>>(),
+            vec!["exec-a".to_string(), "exec/b".to_string()]
+        );
+
+        assert!(store.remove("exec-a").expect("remove"));
+        assert!(!store.remove("exec-a").expect("remove again"));
+        assert_eq!(store.load("exec-a").expect("load removed"), None);
+    }
+
+    #[test]
+    fn list_skips_unreadable_checkpoints() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let store = OrchestrationCheckpointStore::new(dir.path());
+        std::fs::write(dir.path().join("broken.json"), "{").expect("write broken");
+        store.save(&checkpoint("exec-a", 1)).expect("save");
+
+        assert_eq!(store.list().expect("list").len(), 1);
+    }
+
+    #[test]
+    fn journaling_sink_records_only_when_enabled() {
+        let inner = crate::events::VecEventSink::new();
+        let event = WorkflowEvent::WorkflowStarted {
+            workflow_id: "orch-1".to_string(),
+            execution_id: "exec-a".to_string(),
+            occurred_at_ms: None,
+        };
+
+        let disabled = JournalingEventSink::new(&inner, false, Vec::new());
+        disabled.send(event.clone()).expect("send");
+        assert!(disabled.events().is_empty());
+
+        let enabled = JournalingEventSink::new(&inner, true, vec![event.clone()]);
+        enabled.send(event.clone()).expect("send");
+        assert_eq!(enabled.events().len(), 2);
+        assert_eq!(inner.events().len(), 2);
+    }
+
+    #[test]
+    fn journaled_saves_append_and_load_only_checkpointed_events() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let store = OrchestrationCheckpointStore::new(dir.path());
+        let mut state = checkpoint("exec-a", 1);
+        state.events = Vec::new();
+
+        state.journal_len = Some(1);
+        store
+            .save_journaled(&state, &[started("exec-a", 1)], true)
+            .expect("first save");
+        state.journal_len = Some(2);
+        store
+            .save_journaled(&state, &[started("exec-a", 2)], false)
+            .expect("append save");
+        // Events journaled after the last checkpoint are not replayed.
+        store
+            .save_journaled(
+                &OrchestrationCheckpoint {
+                    journal_len: Some(2),
+                    ..state.clone()
+                },
+                &[started("exec-a", 3)],
+                false,
+            )
+            .expect("trailing append");
+
+        let loaded = store.load("exec-a").expect("load").expect("checkpoint");
+        assert_eq!(
+            loaded.events,
+            vec![started("exec-a", 1), started("exec-a", 2)]
+        );
+        let on_disk = std::fs::read_to_string(dir.path().join("exec-a.json")).expect("read");
+        assert!(!on_disk.contains("workflowStarted"));
+
+        // A rewrite drops the trailing lines before appending resumes.
+        state.journal_len = Some(1);
+        store
+            .save_journaled(&state, &[started("exec-a", 9)], true)
+            .expect("rewrite");
+        let loaded = store.load("exec-a").expect("load").expect("checkpoint");
+        assert_eq!(loaded.events, vec![started("exec-a", 9)]);
+
+        assert!(store.remove("exec-a").expect("remove"));
+        assert!(!dir.path().join("exec-a.events.jsonl").exists());
+    }
+
+    #[test]
+    fn journaling_sink_deltas_cover_only_unpersisted_events() {
+        let inner = crate::events::VecEventSink::new();
+        let sink = JournalingEventSink::new(&inner, true, vec![started("exec-a", 1)]);
+
+        let delta = sink.delta();
+        assert!(delta.rewrite);
+        assert_eq!((delta.events.len(), delta.len), (1, 1));
+        sink.mark_persisted(Some(delta.len));
+
+        sink.send(started("exec-a", 2)).expect("send");
+        let delta = sink.delta();
+        assert!(!delta.rewrite);
+        assert_eq!(delta.events, vec![started("exec-a", 2)]);
+        assert_eq!(delta.len, 2);
+
+        sink.mark_persisted(None);
+        assert_eq!(sink.delta().events.len(), 2);
+    }
+}
@@ -0,0 +1,455 @@
+//! Canonical data-graph execution.
+//!
+//! Every `DataGraphExecutor` runs its data graphs through [`DataGraphRun`] so
+//! hosts agree on how graph inputs reach nodes, which nodes are demanded, how
+//! outputs are keyed, and how failures surface. Hosts only decide how the
+//! `WorkflowExecutor` is built (event sink, extensions, execution ID).
+//!
+//! # Example
+//!
+//! ```ignore
+//! let run = DataGraphRun::prepare(&graph, &inputs)?;
+//! let executor = WorkflowExecutor::new(execution_id, run.graph().clone(), event_sink);
+//! let outputs = run.execute(&executor, &task_executor).await?;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::engine::{TaskExecutor, WorkflowExecutor};
+use crate::events::EventSink;
+use crate::types::{GraphNode, GraphPort, NodeId, WorkflowGraph};
+use crate::{NodeEngineError, Result};
+
+/// Node-data key holding the graph inputs bound to a node. The demand engine
+/// hands each entry to the task as a port input, next to `_data`.
+pub const GRAPH_INPUTS_DATA_KEY: &str = "_graph_inputs";
+
+/// Graph input name older graphs use for the `text` port of a `text-input`.
+const LEGACY_TEXT_INPUT: &str = "input";
+
+/// A data graph with its graph inputs bound, ready to be demanded.
+#[derive(Debug, Clone)]
+pub struct DataGraphRun {
+    graph: WorkflowGraph,
+    demand: Vec<NodeId>,
+    outputs: Option<Vec<GraphPort>>,
+}
+
+impl DataGraphRun {
+    /// Bind `inputs` to the graph's input ports and resolve what to demand.
+    ///
+    /// A bound graph input reaches its node both as a real port input and in
+    /// the node's data under the port name, so tasks see it whether they read
+    /// `inputs[port]` or `inputs["_data"][port]`. Declared input ports bind by
+    /// name. Without declarations an input binds to an entry node (one no
+    /// edge feeds): the entry nodes whose data already configures that port,
+    /// or the only entry node when there is just one. `input` also names the
+    /// `text` port of `text-input` nodes. An input matching several entry
+    /// nodes is an error; one matching none is ignored.
+    ///
+    /// Declared output ports select the nodes to demand. Without
+    /// declarations, every node without outgoing edges is demanded, and a
+    /// non-empty graph without such a node is an error.
+    pub fn prepare(graph: &WorkflowGraph, inputs: &HashMap<String, Value>) -> Result<Self> {
+        let mut bound = graph.clone();
+        if graph.ports.inputs.is_empty() {
+            let mut names: Vec<&String> = inputs.keys().collect();
+            names.sort();
+            for name in names {
+                let targets = implicit_input_targets(graph, name);
+                if targets.len() > 1 {
+                    return Err(NodeEngineError::failed(format!(
+                        "Graph input '{}' matches several entry nodes ({}) of data graph '{}'; \
+                         declare graph input ports to choose one",
+                        name,
+                        targets
+                            .iter()
+                            .map(|(node_id, port_id)| format!("{}.{}", node_id, port_id))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        graph.id
+                    )));
+                }
+                for (node_id, port_id) in targets {
+                    if let Some(node) = bound.find_node_mut(&node_id) {
+                        bind_input(node, &port_id, inputs[name].clone());
+                    }
+                }
+            }
+        } else {
+            for port in &graph.ports.inputs {
+                if is_edge_fed(graph, &port.node_id, &port.port_id) {
+                    return Err(NodeEngineError::failed(format!(
+                        "Graph input '{}' is bound to '{}.{}', which an edge already feeds",
+                        port.name, port.node_id, port.port_id
+                    )));
+                }
+                let node = bound
+                    .find_node_mut(&port.node_id)
+                    .ok_or_else(|| unknown_port_node("input", port))?;
+                if let Some(value) = inputs.get(&port.name) {
+                    bind_input(node, &port.port_id, value.clone());
+                }
+            }
+        }
+
+        let (demand, outputs) = if graph.ports.outputs.is_empty() {
+            let terminal: Vec<NodeId> = graph
+                .nodes
+                .iter()
+                .filter(|node| graph.outgoing_edges(&node.id).next().is_none())
+                .map(|node| node.id.clone())
+                .collect();
+            if terminal.is_empty() && !graph.nodes.is_empty() {
+                return Err(NodeEngineError::failed(format!(
+                    "Data graph '{}' has no terminal node to demand",
+                    graph.id
+                )));
+            }
+            (terminal, None)
+        } else {
+            let mut demand: Vec<NodeId> = Vec::new();
+            for port in &graph.ports.outputs {
+                if graph.find_node(&port.node_id).is_none() {
+                    return Err(unknown_port_node("output", port));
+                }
+                if !demand.contains(&port.node_id) {
+                    demand.push(port.node_id.clone());
+                }
+            }
+            (demand, Some(graph.ports.outputs.clone()))
+        };
+
+        Ok(Self {
+            graph: bound,
+            demand,
+            outputs,
+        })
+    }
+
+    /// The graph with inputs bound; the executor must be built from it.
+    pub fn graph(&self) -> &WorkflowGraph {
+        &self.graph
+    }
+
+    /// Nodes this run demands.
+    pub fn demanded_nodes(&self) -> &[NodeId] {
+        &self.demand
+    }
+
+    /// Demand the graph on `executor` and collect its outputs.
+    ///
+    /// With declared output ports, outputs are keyed by port name. Otherwise
+    /// every output of every terminal node is keyed `node.port`, and also by
+    /// the bare port name when only one terminal node produces that port;
+    /// `_graph_id` and `_terminal_nodes` describe the run. Any node failure
+    /// fails the run.
+    pub async fn execute(
+        &self,
+        executor: &WorkflowExecutor,
+        task_executor: &dyn TaskExecutor,
+    ) -> Result<HashMap<String, Value>> {
+        let results = if self.demand.is_empty() {
+            HashMap::new()
+        } else {
+            executor
+                .demand_multiple(&self.demand, task_executor)
+                .await?
+        };
+
+        let outputs = match &self.outputs {
+            Some(ports) => ports
+                .iter()
+                .filter_map(|port| {
+                    results
+                        .get(&port.node_id)
+                        .and_then(|node_outputs| node_outputs.get(&port.port_id))
+                        .map(|value| (port.name.clone(), value.clone()))
+                })
+                .collect(),
+            None => self.terminal_outputs(&results),
+        };
+        Ok(outputs)
+    }
+
+    fn terminal_outputs(
+        &self,
+        results: &HashMap<NodeId, HashMap<String, Value>>,
+    ) -> HashMap<String, Value> {
+        let mut outputs = HashMap::new();
+        let mut producers: HashMap<&str, usize> = HashMap::new();
+        for node_id in &self.demand {
+            for (port, value) in results.get(node_id).into_iter().flatten() {
+                outputs.insert(format!("{}.{}", node_id, port), value.clone());
+                *producers.entry(port.as_str()).or_default() += 1;
+            }
+        }
+        for node_id in &self.demand {
+            for (port, value) in results.get(node_id).into_iter().flatten() {
+                if producers[port.as_str()] == 1 {
+                    outputs.insert(port.clone(), value.clone());
+                }
+            }
+        }
+        outputs.insert(
+            "_graph_id".to_string(),
+            Value::String(self.graph.id.clone()),
+        );
+        outputs.insert(
+            "_terminal_nodes".to_string(),
+            Value::Array(self.demand.iter().cloned().map(Value::String).collect()),
+        );
+        outputs
+    }
+}
+
+/// Run a data graph on a fresh executor with execution ID `data-graph-<id>`.
+pub async fn execute_data_graph(
+    graph: &WorkflowGraph,
+    inputs: &HashMap<String, Value>,
+    event_sink: Arc<dyn EventSink>,
+    task_executor: &dyn TaskExecutor,
+) -> Result<HashMap<String, Value>> {
+    let run = DataGraphRun::prepare(graph, inputs)?;
+    let executor = WorkflowExecutor::new(
+        format!("data-graph-{}", graph.id),
+        run.graph().clone(),
+        event_sink,
+    );
+    run.execute(&executor, task_executor).await
+}
+
+fn is_edge_fed(graph: &WorkflowGraph, node_id: &str, port_id: &str) -> bool {
+    graph
+        .incoming_edges(node_id)
+        .any(|edge| edge.target_handle == port_id)
+}
+
+/// Entry-node ports an undeclared graph input named `name` binds to.
+fn implicit_input_targets(graph: &WorkflowGraph, name: &str) -> Vec<(NodeId, String)> {
+    let entry_nodes: Vec<&GraphNode> = graph
+        .nodes
+        .iter()
+        .filter(|node| graph.incoming_edges(&node.id).next().is_none())
+        .collect();
+    let port_for = |node: &GraphNode| -> String {
+        if node.node_type == "text-input" && name == LEGACY_TEXT_INPUT {
+            "text".to_string()
+        } else {
+            name.to_string()
+        }
+    };
+
+    let configured: Vec<(NodeId, String)> = entry_nodes
+        .iter()
+        .map(|node| (node, port_for(node)))
+        .filter(|(node, port)| node.data.get(port).is_some())
+        .map(|(node, port)| (node.id.clone(), port))
+        .collect();
+    match (configured.is_empty(), entry_nodes.as_slice()) {
+        (false, _) => configured,
+        (true, [only]) => vec![(only.id.clone(), port_for(only))],
+        (true, _) => Vec::new(),
+    }
+}
+
+/// Write a graph input into the node's data and its bound port inputs.
+fn bind_input(node: &mut GraphNode, port_id: &str, value: Value) {
+    if !node.data.is_object() {
+        node.data = Value::Object(serde_json::Map::new());
+    }
+    if let Some(data) = node.data.as_object_mut() {
+        data.insert(port_id.to_string(), value.clone());
+        let bound = data
+            .entry(GRAPH_INPUTS_DATA_KEY)
+            .or_insert_with(|| Value::Object(serde_json::Map::new()));
+        if let Some(bound) = bound.as_object_mut() {
+            bound.insert(port_id.to_string(), value);
+        }
+    }
+}
+
+fn unknown_port_node(direction: &str, port: &GraphPort) -> NodeEngineError {
+    NodeEngineError::failed(format!(
+        "Graph {} '{}' is bound to unknown node '{}'",
+        direction, port.name, port.node_id
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GraphEdge, GraphPorts};
+    use serde_json::json;
+
+    fn node(id: &str, data: Value) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            node_type: "mock".to_string(),
+            data,
+            position: (0.0, 0.0),
+        }
+    }
+
+    fn edge(source: &str, target: &str, port: &str) -> GraphEdge {
+        GraphEdge {
+            id: format!("{}-{}", source, target),
+            source: source.to_string(),
+            source_handle: port.to_string(),
+            target: target.to_string(),
+            target_handle: port.to_string(),
+        }
+    }
+
+    fn port(name: &str, node_id: &str, port_id: &str) -> GraphPort {
+        GraphPort {
+            name: name.to_string(),
+            node_id: node_id.to_string(),
+            port_id: port_id.to_string(),
+        }
+    }
+
+    fn chain() -> WorkflowGraph {
+        let mut graph = WorkflowGraph::new("chain", "Chain");
+        graph.nodes = vec![node("src", Value::Null), node("sink", json!({"k": 1}))];
+        graph.edges = vec![edge("src", "sink", "text")];
+        graph
+    }
+
+    #[test]
+    fn implicit_inputs_bind_to_the_only_entry_node() {
+        let inputs = HashMap::from([("text".to_string(), json!("hi"))]);
+        let run = DataGraphRun::prepare(&chain(), &inputs).unwrap();
+
+        assert_eq!(
+            run.graph().find_node("src").unwrap().data,
+            json!({"text": "hi", "_graph_inputs": {"text": "hi"}})
+        );
+        assert_eq!(run.graph().find_node("sink").unwrap().data, json!({"k": 1}));
+        assert_eq!(run.demanded_nodes(), ["sink".to_string()]);
+    }
+
+    #[test]
+    fn declared_ports_bind_by_name_and_select_demand() {
+        let mut graph = chain();
+        graph.ports = GraphPorts {
+            inputs: vec![port("prompt", "src", "text")],
+            outputs: vec![port("result", "src", "text")],
+        };
+        let inputs = HashMap::from([
+            ("prompt".to_string(), json!("hi")),
+            ("text".to_string(), json!("ignored")),
+        ]);
+        let run = DataGraphRun::prepare(&graph, &inputs).unwrap();
+
+        assert_eq!(
+            run.graph().find_node("src").unwrap().data,
+            json!({"text": "hi", "_graph_inputs": {"text": "hi"}})
+        );
+        assert_eq!(run.graph().find_node("sink").unwrap().data, json!({"k": 1}));
+        assert_eq!(run.demanded_nodes(), ["src".to_string()]);
+    }
+
+    fn prompts() -> WorkflowGraph {
+        let mut graph = WorkflowGraph::new("prompts", "Prompts");
+        let mut system = node("system", json!({"text": "Be terse."}));
+        system.node_type = "text-input".to_string();
+        let mut user = node("user", json!({"text": ""}));
+        user.node_type = "text-input".to_string();
+        graph.nodes = vec![system, user, node("llm", json!({"temperature": 0.2}))];
+        graph.edges = vec![edge("system", "llm", "text"), edge("user", "llm", "text")];
+        graph
+    }
+
+    #[test]
+    fn implicit_input_matching_several_entry_nodes_is_rejected() {
+        let inputs = HashMap::from([("input".to_string(), json!("hi"))]);
+
+        let error = DataGraphRun::prepare(&prompts(), &inputs).unwrap_err();
+        assert!(error.to_string().contains("system.text, user.text"));
+    }
+
+    #[test]
+    fn implicit_inputs_bind_to_entry_nodes_configuring_the_port() {
+        let mut graph = prompts();
+        graph.nodes[0].node_type = "mock".to_string();
+        graph.nodes[0].data = json!({"prompt": "Be terse."});
+        let inputs = HashMap::from([
+            ("input".to_string(), json!("hi")),
+            ("temperature".to_string(), json!(1.0)),
+        ]);
+        let run = DataGraphRun::prepare(&graph, &inputs).unwrap();
+
+        assert_eq!(
+            run.graph().find_node("user").unwrap().data,
+            json!({"text": "hi", "_graph_inputs": {"text": "hi"}})
+        );
+        assert_eq!(
+            run.graph().find_node("system").unwrap().data,
+            json!({"prompt": "Be terse."})
+        );
+        assert_eq!(
+            run.graph().find_node("llm").unwrap().data,
+            json!({"temperature": 0.2})
+        );
+    }
+
+    #[test]
+    fn terminal_outputs_keep_unambiguous_bare_port_aliases() {
+        let mut graph = prompts();
+        graph.nodes.push(node("log", Value::Null));
+        let run = DataGraphRun::prepare(&graph, &HashMap::new()).unwrap();
+        let results = HashMap::from([
+            (
+                "llm".to_string(),
+                HashMap::from([
+                    ("response".to_string(), json!("ok")),
+                    ("text".to_string(), json!("a")),
+                ]),
+            ),
+            (
+                "log".to_string(),
+                HashMap::from([("text".to_string(), json!("b"))]),
+            ),
+        ]);
+
+        let outputs = run.terminal_outputs(&results);
+
+        assert_eq!(outputs.get("llm.response"), Some(&json!("ok")));
+        assert_eq!(outputs.get("response"), Some(&json!("ok")));
+        assert_eq!(outputs.get("log.text"), Some(&json!("b")));
+        assert!(!outputs.contains_key("text"));
+        assert_eq!(outputs.get("_graph_id"), Some(&json!("prompts")));
+        assert_eq!(outputs.get("_terminal_nodes"), Some(&json!(["llm", "log"])));
+    }
+
+    #[test]
+    fn declared_input_on_edge_fed_port_is_rejected() {
+        let mut graph = chain();
+        graph.ports.inputs = vec![port("prompt", "sink", "text")];
+
+        let error = DataGraphRun::prepare(&graph, &HashMap::new()).unwrap_err();
+        assert!(error.to_string().contains("edge already feeds"));
+    }
+
+    #[test]
+    fn declared_port_on_unknown_node_is_rejected() {
+        let mut graph = chain();
+        graph.ports.outputs = vec![port("result", "missing", "text")];
+
+        let error = DataGraphRun::prepare(&graph, &HashMap::new()).unwrap_err();
+        assert!(error.to_string().contains("unknown node 'missing'"));
+    }
+
+    #[test]
+    fn graph_without_terminal_node_is_rejected() {
+        let mut graph = chain();
+        graph.edges.push(edge("sink", "src", "text"));
+
+        let error = DataGraphRun::prepare(&graph, &HashMap::new()).unwrap_err();
+        assert_eq!(error.kind(), "execution_failed");
+    }
+}
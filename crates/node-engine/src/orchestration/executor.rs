@@ -3,11 +3,16 @@
 //! This module provides the executor for running orchestration graphs,
 //! handling control flow between data graphs.
 
+use super::checkpoint::{
+    JournalingEventSink, OrchestrationCheckpoint, OrchestrationCheckpointStore,
+    ORCHESTRATION_CHECKPOINT_FORMAT_VERSION,
+};
 use super::nodes::{
     execute_node, prepare_data_graph_execution, NodeExecutionResult, OrchestrationContext,
 };
 use super::types::{OrchestrationGraph, OrchestrationNodeType, OrchestrationResult};
 use crate::events::{EventSink, WorkflowEvent};
+use crate::limits::GraphLimits;
 use crate::{NodeEngineError, Result, WorkflowGraph};
 use async_trait::async_trait;
 use serde_json::Value;
@@ -99,6 +104,10 @@ pub struct OrchestrationExecutor<E: DataGraphExecutor> {
     max_nodes: u32,
     /// Execution ID for this orchestration run.
     execution_id: String,
+    /// Optional checkpoint store for crash-safe resume.
+    checkpoints: Option<OrchestrationCheckpointStore>,
+    /// Size and loop bounds checked before a graph runs.
+    graph_limits: GraphLimits,
 }
 
 impl<E: DataGraphExecutor> OrchestrationExecutor<E> {
@@ -108,6 +117,8 @@ impl<E: DataGraphExecutor> OrchestrationExecutor<E> {
             data_executor,
             max_nodes: 1000, // Default limit
             execution_id: format!("orch-exec-{}", uuid::Uuid::new_v4()),
+            checkpoints: None,
+            graph_limits: GraphLimits::default(),
         }
     }
 
@@ -123,6 +134,27 @@ impl<E: DataGraphExecutor> OrchestrationExecutor<E> {
         self
     }
 
+    /// Checkpoint execution state before each node so the run can be resumed
+    /// with [`OrchestrationExecutor::resume`] after a host crash.
+    ///
+    /// The checkpoint is removed when the run reaches a terminal result; runs
+    /// paused waiting for input keep theirs.
+    pub fn with_checkpoints(mut self, checkpoints: OrchestrationCheckpointStore) -> Self {
+        self.checkpoints = Some(checkpoints);
+        self
+    }
+
+    /// Refuse to execute or resume graphs that exceed `limits`.
+    pub fn with_graph_limits(mut self, limits: GraphLimits) -> Self {
+        self.graph_limits = limits;
+        self
+    }
+
+    /// Get the execution ID.
+    pub fn execution_id(&self) -> &str {
+        &self.execution_id
+    }
+
     /// Execute an orchestration graph.
     pub async fn execute(
         &self,
@@ -130,19 +162,84 @@ impl<E: DataGraphExecutor> OrchestrationExecutor<E> {
         initial_data: HashMap<String, Value>,
         event_sink: &dyn EventSink,
     ) -> Result<OrchestrationResult> {
-        let start_time = Instant::now();
-        let mut nodes_executed: u32 = 0;
-        let mut context = OrchestrationContext::with_data(initial_data);
+        self.graph_limits.check_orchestration(graph)?;
+        let journal = JournalingEventSink::new(event_sink, self.checkpoints.is_some(), Vec::new());
+        self.emit_workflow_started(&journal, &graph.id);
+
+        let start_node_id = graph.find_start_node().map(|node| node.id.clone());
+        self.run_from(
+            graph,
+            start_node_id,
+            OrchestrationContext::with_data(initial_data),
+            0,
+            &journal,
+        )
+        .await
+    }
 
-        self.emit_workflow_started(event_sink, &graph.id);
+    /// Resume an execution from a checkpoint written by a previous run.
+    ///
+    /// Journaled events are replayed to `event_sink` before execution
+    /// continues at the checkpoint's next node. The executor must carry the
+    /// checkpoint's execution ID. `execution_time_ms` in the result covers
+    /// only the resumed segment.
+    pub async fn resume(
+        &self,
+        graph: &OrchestrationGraph,
+        checkpoint: OrchestrationCheckpoint,
+        event_sink: &dyn EventSink,
+    ) -> Result<OrchestrationResult> {
+        self.graph_limits.check_orchestration(graph)?;
+        if checkpoint.format_version != ORCHESTRATION_CHECKPOINT_FORMAT_VERSION {
+            return Err(NodeEngineError::failed(format!(
+                "Unsupported orchestration checkpoint format version {}",
+                checkpoint.format_version
+            )));
+        }
+        if checkpoint.execution_id != self.execution_id {
+            return Err(NodeEngineError::failed(format!(
+                "Checkpoint belongs to execution '{}', executor is '{}'",
+                checkpoint.execution_id, self.execution_id
+            )));
+        }
+        if checkpoint.orchestration_id != graph.id {
+            return Err(NodeEngineError::failed(format!(
+                "Checkpoint belongs to orchestration '{}', not '{}'",
+                checkpoint.orchestration_id, graph.id
+            )));
+        }
+
+        for event in &checkpoint.events {
+            let _ = event_sink.send(event.clone());
+        }
+        let journal =
+            JournalingEventSink::new(event_sink, self.checkpoints.is_some(), checkpoint.events);
+
+        self.run_from(
+            graph,
+            Some(checkpoint.next_node_id),
+            checkpoint.context,
+            checkpoint.nodes_executed,
+            &journal,
+        )
+        .await
+    }
+
+    async fn run_from(
+        &self,
+        graph: &OrchestrationGraph,
+        start_node_id: Option<String>,
+        mut context: OrchestrationContext,
+        mut nodes_executed: u32,
+        journal: &JournalingEventSink<'_>,
+    ) -> Result<OrchestrationResult> {
+        let start_time = Instant::now();
+        let event_sink: &dyn EventSink = journal;
 
         let execution = async {
-            let start_node = graph
-                .find_start_node()
+            let mut current_node_id = start_node_id
                 .ok_or_else(|| NodeEngineError::failed("Orchestration graph has no Start node"))?;
 
-            let mut current_node_id = start_node.id.clone();
-
             loop {
                 if nodes_executed >= self.max_nodes {
                     let elapsed = start_time.elapsed().as_millis() as u64;
@@ -158,6 +255,7 @@ impl<E: DataGraphExecutor> OrchestrationExecutor<E> {
                     ))
                 })?;
 
+                self.save_checkpoint(graph, &node.id, &context, nodes_executed, journal);
                 self.emit_task_started(event_sink, &node.id);
                 nodes_executed += 1;
 
@@ -169,6 +267,13 @@ impl<E: DataGraphExecutor> OrchestrationExecutor<E> {
                     _ => execute_node(node, &mut context)?,
                 };
 
+                if node.node_type == OrchestrationNodeType::Loop
+                    && result.next_handle == "iteration"
+                {
+                    self.graph_limits
+                        .check_loop_iteration(&node.id, context.get_loop_iteration(&node.id))?;
+                }
+
                 for (key, value) in result.context_updates {
                     context.set(key, value);
                 }
@@ -224,13 +329,71 @@ impl<E: DataGraphExecutor> OrchestrationExecutor<E> {
         }
         .await;
 
-        if let Err(error) = &execution {
-            self.emit_terminal_workflow_error(event_sink, &graph.id, error);
+        match &execution {
+            Err(error) => {
+                self.emit_terminal_workflow_error(event_sink, &graph.id, error);
+                if !matches!(error, NodeEngineError::WaitingForInput { .. }) {
+                    self.remove_checkpoint();
+                }
+            }
+            Ok(_) => self.remove_checkpoint(),
         }
 
         execution
     }
 
+    fn save_checkpoint(
+        &self,
+        graph: &OrchestrationGraph,
+        next_node_id: &str,
+        context: &OrchestrationContext,
+        nodes_executed: u32,
+        journal: &JournalingEventSink<'_>,
+    ) {
+        let Some(checkpoints) = &self.checkpoints else {
+            return;
+        };
+        let delta = journal.delta();
+        let checkpoint = OrchestrationCheckpoint {
+            format_version: ORCHESTRATION_CHECKPOINT_FORMAT_VERSION,
+            execution_id: self.execution_id.clone(),
+            orchestration_id: graph.id.clone(),
+            next_node_id: next_node_id.to_string(),
+            context: context.clone(),
+            nodes_executed,
+            events: Vec::new(),
+            journal_len: Some(delta.len),
+            updated_at_ms: crate::events::unix_timestamp_ms(),
+        };
+        // A failed write must not abort the run; the previous checkpoint
+        // stays valid because saves replace the file atomically and only
+        // replay the journal prefix it recorded.
+        match checkpoints.save_journaled(&checkpoint, &delta.events, delta.rewrite) {
+            Ok(()) => journal.mark_persisted(Some(delta.len)),
+            Err(e) => {
+                journal.mark_persisted(None);
+                log::warn!(
+                    "Failed to checkpoint orchestration execution '{}': {}",
+                    self.execution_id,
+                    e
+                );
+            }
+        }
+    }
+
+    fn remove_checkpoint(&self) {
+        let Some(checkpoints) = &self.checkpoints else {
+            return;
+        };
+        if let Err(e) = checkpoints.remove(&self.execution_id) {
+            log::warn!(
+                "Failed to remove orchestration checkpoint '{}': {}",
+                self.execution_id,
+                e
+            );
+        }
+    }
+
     /// Execute a DataGraph node by running the associated data graph.
     async fn execute_data_graph_node(
         &self,
@@ -244,6 +407,11 @@ impl<E: DataGraphExecutor> OrchestrationExecutor<E> {
             .get_data_graph_id(&node.id)
             .cloned()
             .unwrap_or(config.data_graph_id.clone());
+        if !self.graph_limits.is_unlimited() {
+            if let Some(data_graph) = self.data_executor.get_data_graph(&data_graph_id) {
+                self.graph_limits.check_graph(&data_graph)?;
+            }
+        }
 
         self.emit_task_progress(
             event_sink,
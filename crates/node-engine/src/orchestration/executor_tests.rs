@@ -209,6 +209,94 @@ async fn test_loop_execution() {
     assert_eq!(result.nodes_executed, 6);
 }
 
+#[tokio::test]
+async fn test_graph_limits_reject_loop_above_global_bound() {
+    let executor = OrchestrationExecutor::new(MockDataGraphExecutor::new()).with_graph_limits(
+        crate::limits::GraphLimits {
+            max_loop_iterations: Some(2),
+            ..Default::default()
+        },
+    );
+    let event_sink = VecEventSink::new();
+
+    let mut graph = OrchestrationGraph::new("test", "Test");
+    graph.nodes.push(OrchestrationNode::new(
+        "start",
+        OrchestrationNodeType::Start,
+        (0.0, 0.0),
+    ));
+    graph.nodes.push(OrchestrationNode::with_config(
+        "loop",
+        OrchestrationNodeType::Loop,
+        (100.0, 0.0),
+        serde_json::json!({"maxIterations": 3}),
+    ));
+
+    let error = executor
+        .execute(&graph, HashMap::new(), &event_sink)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), "graph_limit_exceeded");
+    assert!(event_sink.events().is_empty());
+}
+
+#[tokio::test]
+async fn test_graph_limits_stop_unbounded_loop_at_runtime() {
+    let executor = OrchestrationExecutor::new(MockDataGraphExecutor::new()).with_graph_limits(
+        crate::limits::GraphLimits {
+            max_loop_iterations: Some(2),
+            ..Default::default()
+        },
+    );
+    let event_sink = VecEventSink::new();
+
+    let mut graph = OrchestrationGraph::new("test", "Test");
+    graph.nodes.push(OrchestrationNode::new(
+        "start",
+        OrchestrationNodeType::Start,
+        (0.0, 0.0),
+    ));
+    graph.nodes.push(OrchestrationNode::with_config(
+        "loop",
+        OrchestrationNodeType::Loop,
+        (100.0, 0.0),
+        serde_json::json!({"maxIterations": 0}),
+    ));
+    graph.edges.push(OrchestrationEdge::new(
+        "e1", "start", "next", "loop", "input",
+    ));
+    graph.edges.push(OrchestrationEdge::new(
+        "e2",
+        "loop",
+        "iteration",
+        "loop",
+        "loop_back",
+    ));
+
+    let error = executor
+        .execute(&graph, HashMap::new(), &event_sink)
+        .await
+        .unwrap_err();
+    match error {
+        NodeEngineError::GraphLimitExceeded(
+            crate::limits::GraphLimitError::MaxLoopIterations {
+                node_id,
+                iterations,
+                limit,
+            },
+        ) => {
+            assert_eq!(node_id, "loop");
+            assert_eq!(iterations, 3);
+            assert_eq!(limit, 2);
+        }
+        other => panic!("expected a loop limit error, got {other:?}"),
+    }
+    assert!(event_sink
+        .events()
+        .iter()
+        .any(|event| matches!(event, WorkflowEvent::WorkflowFailed { .. })));
+}
+
 #[tokio::test]
 async fn test_data_graph_execution() {
     let mut outputs = HashMap::new();
@@ -445,3 +533,96 @@ fn test_emit_terminal_workflow_error_uses_cancelled_variant() {
             && error == "Workflow cancelled"
     ));
 }
+
+#[tokio::test]
+async fn test_checkpoint_removed_after_successful_execution() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let checkpoints = OrchestrationCheckpointStore::new(dir.path());
+    let executor = OrchestrationExecutor::new(MockDataGraphExecutor::new())
+        .with_execution_id("orch-exec-test")
+        .with_checkpoints(checkpoints.clone());
+
+    let result = executor
+        .execute(&create_simple_graph(), HashMap::new(), &NullEventSink)
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert!(checkpoints.list().expect("list").is_empty());
+}
+
+#[tokio::test]
+async fn test_resume_replays_journal_and_completes() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let checkpoints = OrchestrationCheckpointStore::new(dir.path());
+    let graph = create_simple_graph();
+    let started = WorkflowEvent::WorkflowStarted {
+        workflow_id: "test".to_string(),
+        execution_id: "orch-exec-test".to_string(),
+        occurred_at_ms: Some(1),
+    };
+    checkpoints
+        .save(&OrchestrationCheckpoint {
+            format_version: ORCHESTRATION_CHECKPOINT_FORMAT_VERSION,
+            execution_id: "orch-exec-test".to_string(),
+            orchestration_id: "test".to_string(),
+            next_node_id: "end".to_string(),
+            context: OrchestrationContext::with_data(HashMap::from([(
+                "answer".to_string(),
+                serde_json::json!(42),
+            )])),
+            nodes_executed: 1,
+            events: vec![started.clone()],
+            journal_len: None,
+            updated_at_ms: 1,
+        })
+        .expect("save checkpoint");
+
+    let checkpoint = checkpoints
+        .load("orch-exec-test")
+        .expect("load")
+        .expect("checkpoint exists");
+    let executor = OrchestrationExecutor::new(MockDataGraphExecutor::new())
+        .with_execution_id("orch-exec-test")
+        .with_checkpoints(checkpoints.clone());
+    let event_sink = VecEventSink::new();
+
+    let result = executor
+        .resume(&graph, checkpoint, &event_sink)
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert_eq!(result.nodes_executed, 2);
+    assert_eq!(result.outputs.get("answer"), Some(&serde_json::json!(42)));
+    let events = event_sink.events();
+    assert_eq!(events.first(), Some(&started));
+    assert!(matches!(
+        events.last(),
+        Some(WorkflowEvent::WorkflowCompleted { .. })
+    ));
+    assert!(checkpoints.list().expect("list").is_empty());
+}
+
+#[tokio::test]
+async fn test_resume_rejects_checkpoint_for_other_execution() {
+    let checkpoint = OrchestrationCheckpoint {
+        format_version: ORCHESTRATION_CHECKPOINT_FORMAT_VERSION,
+        execution_id: "other-exec".to_string(),
+        orchestration_id: "test".to_string(),
+        next_node_id: "end".to_string(),
+        context: OrchestrationContext::new(),
+        nodes_executed: 1,
+        events: Vec::new(),
+        journal_len: None,
+        updated_at_ms: 1,
+    };
+    let executor = OrchestrationExecutor::new(MockDataGraphExecutor::new())
+        .with_execution_id("orch-exec-test");
+
+    let result = executor
+        .resume(&create_simple_graph(), checkpoint, &NullEventSink)
+        .await;
+
+    assert!(matches!(result, Err(NodeEngineError::ExecutionFailed(_))));
+}
@@ -45,12 +45,19 @@
 //! let result = executor.execute(&graph, initial_data, &event_sink).await?;
 //! ```
 
+pub mod checkpoint;
+pub mod data_graph;
 pub mod executor;
 pub mod nodes;
 pub mod store;
 pub mod types;
 
 // Re-export commonly used types
+pub use checkpoint::{
+    OrchestrationCheckpoint, OrchestrationCheckpointStore, ResumableExecution,
+    ORCHESTRATION_CHECKPOINT_FORMAT_VERSION,
+};
+pub use data_graph::{execute_data_graph, DataGraphRun};
 pub use executor::{DataGraphExecutor, OrchestrationEvent, OrchestrationExecutor};
 pub use nodes::{NodeExecutionResult, OrchestrationContext};
 pub use store::{OrchestrationGraphMetadata, OrchestrationStore};
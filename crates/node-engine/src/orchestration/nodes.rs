@@ -10,11 +10,15 @@ use serde_json::Value;
 use std::collections::HashMap;
 
 /// Context for orchestration execution, holding data passed between nodes.
-#[derive(Debug, Clone, Default)]
+///
+/// Serializable so in-flight executions can be checkpointed and resumed.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct OrchestrationContext {
     /// Data values accessible by key.
     data: HashMap<String, Value>,
     /// Current loop iteration counts (keyed by loop node ID).
+    #[serde(default)]
     loop_iterations: HashMap<String, u32>,
 }
 
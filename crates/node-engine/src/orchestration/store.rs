@@ -3,6 +3,7 @@
 //! This module provides persistent storage for orchestration graphs,
 //! enabling the two-level workflow system to load orchestrations on startup.
 
+use super::checkpoint::OrchestrationCheckpointStore;
 use super::types::{OrchestrationGraph, OrchestrationGraphId};
 use crate::{Result, WorkflowGraph};
 use std::collections::HashMap;
@@ -99,6 +100,16 @@ impl OrchestrationStore {
         Ok(count)
     }
 
+    /// Checkpoint store for in-flight executions, kept in a `checkpoints`
+    /// subdirectory of the persistence directory.
+    ///
+    /// Returns `None` for in-memory stores, which cannot survive a restart.
+    pub fn checkpoint_store(&self) -> Option<OrchestrationCheckpointStore> {
+        self.persist_path
+            .as_ref()
+            .map(|path| OrchestrationCheckpointStore::new(path.join("checkpoints")))
+    }
+
     /// Save an orchestration to disk (if persistence is enabled).
     fn save_to_disk(&self, graph: &OrchestrationGraph) -> Result<()> {
         let Some(ref path) = self.persist_path else {
@@ -195,6 +206,11 @@ impl OrchestrationStore {
         self.data_graphs.insert(id, graph);
     }
 
+    /// All stored data graphs.
+    pub fn data_graphs(&self) -> impl Iterator<Item = &WorkflowGraph> {
+        self.data_graphs.values()
+    }
+
     /// Remove a data graph.
     pub fn remove_data_graph(&mut self, id: &str) -> Option<WorkflowGraph> {
         self.data_graphs.remove(id)
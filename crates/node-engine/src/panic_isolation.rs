@@ -0,0 +1,99 @@
+//! Panic containment for in-process task execution.
+//!
+//! Task code runs inside the host process (the BEAM, Python, Tauri). A panic
+//! that unwinds out of a task would skip executor bookkeeping and, across an
+//! FFI boundary, abort the host. Every in-process task call goes through
+//! [`execute_task_contained`], which turns a panic into a
+//! [`NodeFailureKind::Panic`](crate::error::NodeFailureKind::Panic) error.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::task::Poll;
+
+use crate::engine::TaskExecutor;
+use crate::error::{NodeEngineError, Result};
+use crate::extensions::ExecutorExtensions;
+
+/// Run `executor.execute_task`, converting a panic into a node failure.
+pub(crate) async fn execute_task_contained(
+    executor: &dyn TaskExecutor,
+    task_id: &str,
+    inputs: HashMap<String, serde_json::Value>,
+    context: &graph_flow::Context,
+    extensions: &ExecutorExtensions,
+) -> Result<HashMap<String, serde_json::Value>> {
+    catch_panic(executor.execute_task(task_id, inputs, context, extensions))
+        .await
+        .unwrap_or_else(|payload| {
+            let message = panic_message(payload.as_ref());
+            log::error!("Task '{}' panicked: {}", task_id, message);
+            Err(NodeEngineError::panicked(task_id, message))
+        })
+}
+
+/// Poll `future`, catching a panic raised while polling it.
+async fn catch_panic<F: Future>(future: F) -> std::thread::Result<F::Output> {
+    let mut future = std::pin::pin!(future);
+    std::future::poll_fn(move |cx| {
+        match std::panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    })
+    .await
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::NodeFailureKind;
+    use async_trait::async_trait;
+
+    struct PanickingExecutor;
+
+    #[async_trait]
+    impl TaskExecutor for PanickingExecutor {
+        async fn execute_task(
+            &self,
+            task_id: &str,
+            _inputs: HashMap<String, serde_json::Value>,
+            _context: &graph_flow::Context,
+            _extensions: &ExecutorExtensions,
+        ) -> Result<HashMap<String, serde_json::Value>> {
+            panic!("bad input for {}", task_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn panics_become_structured_node_failures() {
+        let error = execute_task_contained(
+            &PanickingExecutor,
+            "node-a",
+            HashMap::new(),
+            &graph_flow::Context::new(),
+            &ExecutorExtensions::new(),
+        )
+        .await
+        .expect_err("panic is contained");
+
+        match error {
+            NodeEngineError::NodeFailure(failure) => {
+                assert_eq!(failure.node_id, "node-a");
+                assert_eq!(failure.kind, NodeFailureKind::Panic);
+                assert_eq!(failure.message, "bad input for node-a");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+}
@@ -92,7 +92,8 @@ impl NodeRegistry {
     }
 
     /// Register a node type with metadata and an executor factory
-    pub fn register(&mut self, metadata: TaskMetadata, factory: Arc<dyn NodeExecutorFactory>) {
+    pub fn register(&mut self, mut metadata: TaskMetadata, factory: Arc<dyn NodeExecutorFactory>) {
+        metadata.resolve_ui_hints();
         self.entries.insert(
             metadata.node_type.clone(),
             RegistryEntry {
@@ -124,7 +125,8 @@ impl NodeRegistry {
     /// Register a node type with metadata only (no executor)
     ///
     /// Used for metadata-only registrations (e.g., UI palette listing).
-    pub fn register_metadata(&mut self, metadata: TaskMetadata) {
+    pub fn register_metadata(&mut self, mut metadata: TaskMetadata) {
+        metadata.resolve_ui_hints();
         self.entries.insert(
             metadata.node_type.clone(),
             RegistryEntry {
@@ -388,9 +390,10 @@ impl crate::engine::TaskExecutor for RegistryTaskExecutor {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::descriptor::{PortMetadata, TaskMetadata};
+    use crate::descriptor::{NodeDocs, PortMetadata, TaskMetadata};
     use crate::engine::TaskExecutor;
     use crate::types::{ExecutionMode, NodeCategory, PortDataType};
+    use crate::ui_hints::NodeUiHints;
 
     fn test_metadata(node_type: &str) -> TaskMetadata {
         TaskMetadata {
@@ -409,6 +412,8 @@ mod tests {
                 PortDataType::String,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::default(),
+            ui: NodeUiHints::default(),
         }
     }
 
@@ -424,6 +429,31 @@ mod tests {
         assert_eq!(meta.label, "Test test-node");
     }
 
+    #[test]
+    fn test_registration_resolves_ui_hints() {
+        let metadata: TaskMetadata = serde_json::from_value(serde_json::json!({
+            "nodeType": "host-node",
+            "category": "tool",
+            "label": "Host Node",
+            "description": "Registered from a host without hints",
+            "inputs": [{"id": "cache", "label": "Cache", "dataType": "kv_cache", "required": false, "multiple": false}],
+            "outputs": [],
+            "executionMode": "reactive",
+            "ui": {"icon": "plug"}
+        }))
+        .unwrap();
+        assert!(metadata.inputs[0].ui.is_unset());
+
+        let mut registry = NodeRegistry::new();
+        registry.register_metadata(metadata);
+
+        let meta = registry.get_metadata("host-node").unwrap();
+        assert_eq!(meta.ui.icon, "plug");
+        assert_eq!(meta.ui.color_token, "category-tool");
+        assert_eq!(meta.inputs[0].ui.color_token, "data-kv-cache");
+        assert_eq!(meta.inputs[0].ui.shape, crate::ui_hints::PortShape::Hexagon);
+    }
+
     #[test]
     fn test_all_metadata() {
         let mut registry = NodeRegistry::new();
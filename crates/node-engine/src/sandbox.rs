@@ -0,0 +1,465 @@
+//! Per-category node execution sandboxing.
+//!
+//! [`SandboxedTaskExecutor`] wraps another [`TaskExecutor`] and picks a
+//! [`SandboxProfile`] for every task from a [`SandboxPolicy`]:
+//!
+//! - `none` runs the task directly.
+//! - `restricted` runs the task in-process without host extensions (API
+//!   clients, gateways) and applies the policy timeout.
+//! - `isolated_process` runs the task in a short-lived worker process so a
+//!   crash or runaway node cannot take the host (e.g. the BEAM) down.
+//!
+//! Worker processes are started from [`SandboxWorkerCommand`] with a cleared
+//! environment and speak a one-shot JSON-lines protocol: the host writes one
+//! `{"taskId", "inputs"}` line to stdin, and the worker answers with one
+//! `{"status": "completed", "outputs"}` or `{"status": "failed", "error"}`
+//! line on stdout. Worker binaries implement it by calling
+//! [`run_sandbox_worker`] with the executor they want to expose. Workers get a
+//! fresh context and no extensions, so only nodes whose behavior depends
+//! solely on their inputs should be isolated.
+//!
+//! Process isolation is the only privilege reduction applied here: workers
+//! start with a cleared environment (plus `env_allowlist`), an optional
+//! working directory, and are killed when the policy timeout expires. They
+//! run as the host user with the host's filesystem, network, and resource
+//! limits; there is no seccomp filter, namespace, or user switch. Hosts that
+//! need those should point `program` at a wrapper that applies them (for
+//! example `bwrap`, `firejail`, or `systemd-run`) before exec'ing the worker.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+
+use crate::core_executor::resolve_node_type;
+use crate::engine::TaskExecutor;
+use crate::error::{NodeEngineError, Result};
+use crate::extensions::ExecutorExtensions;
+use crate::panic_isolation::execute_task_contained;
+use crate::registry::NodeRegistry;
+use crate::types::NodeCategory;
+
+/// How a node is isolated from the host process.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SandboxProfile {
+    /// Run directly on the host executor.
+    #[default]
+    None,
+    /// Run in-process without extensions and with the policy timeout.
+    Restricted,
+    /// Run in a separate worker process over stdin/stdout IPC.
+    IsolatedProcess,
+}
+
+/// Command used to start isolated-process workers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SandboxWorkerCommand {
+    pub program: PathBuf,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Host environment variables passed through; all others are cleared.
+    #[serde(default)]
+    pub env_allowlist: Vec<String>,
+    #[serde(default)]
+    pub working_dir: Option<PathBuf>,
+}
+
+/// Maps nodes to sandbox profiles.
+///
+/// A profile for the exact node type wins over one for its category, which
+/// wins over `default_profile`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SandboxPolicy {
+    #[serde(default)]
+    pub default_profile: SandboxProfile,
+    #[serde(default)]
+    pub categories: HashMap<NodeCategory, SandboxProfile>,
+    #[serde(default)]
+    pub node_types: HashMap<String, SandboxProfile>,
+    /// Time limit for restricted and isolated tasks.
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    /// Required when any rule selects `isolated_process`.
+    #[serde(default)]
+    pub worker: Option<SandboxWorkerCommand>,
+}
+
+impl SandboxPolicy {
+    /// Resolve the profile for a node type in an (optionally known) category.
+    pub fn profile_for(&self, node_type: &str, category: Option<NodeCategory>) -> SandboxProfile {
+        self.node_types
+            .get(node_type)
+            .or_else(|| category.and_then(|category| self.categories.get(&category)))
+            .copied()
+            .unwrap_or(self.default_profile)
+    }
+
+    /// Check that the policy can be enforced.
+    pub fn validate(&self) -> Result<()> {
+        let uses_workers = std::iter::once(&self.default_profile)
+            .chain(self.categories.values())
+            .chain(self.node_types.values())
+            .any(|profile| *profile == SandboxProfile::IsolatedProcess);
+        if uses_workers && self.worker.is_none() {
+            return Err(NodeEngineError::failed(
+                "Sandbox policy selects isolated_process but configures no worker command",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkerRequest {
+    task_id: String,
+    inputs: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+enum WorkerResponse {
+    Completed {
+        outputs: HashMap<String, serde_json::Value>,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+/// Task executor that applies a [`SandboxPolicy`] to an inner executor.
+pub struct SandboxedTaskExecutor {
+    inner: Arc<dyn TaskExecutor>,
+    policy: SandboxPolicy,
+    categories: HashMap<String, NodeCategory>,
+}
+
+impl SandboxedTaskExecutor {
+    /// Wrap `inner`. Category rules only match node types whose category was
+    /// registered with [`Self::with_registry_categories`] or
+    /// [`Self::with_node_category`].
+    pub fn new(inner: Arc<dyn TaskExecutor>, policy: SandboxPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            categories: HashMap::new(),
+        }
+    }
+
+    /// Learn node categories from registry metadata.
+    pub fn with_registry_categories(mut self, registry: &NodeRegistry) -> Self {
+        for metadata in registry.all_metadata() {
+            self.categories
+                .insert(metadata.node_type.clone(), metadata.category);
+        }
+        self
+    }
+
+    /// Set the category of a single node type.
+    pub fn with_node_category(
+        mut self,
+        node_type: impl Into<String>,
+        category: NodeCategory,
+    ) -> Self {
+        self.categories.insert(node_type.into(), category);
+        self
+    }
+
+    /// Profile that applies to a node type under this executor's policy.
+    pub fn profile_for(&self, node_type: &str) -> SandboxProfile {
+        self.policy
+            .profile_for(node_type, self.categories.get(node_type).copied())
+    }
+
+    async fn with_timeout<T>(
+        &self,
+        task_id: &str,
+        future: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        match self.policy.timeout_ms {
+            Some(timeout_ms) => tokio::time::timeout(Duration::from_millis(timeout_ms), future)
+                .await
+                .unwrap_or_else(|_| {
+                    Err(NodeEngineError::failed(format!(
+                        "Sandboxed task '{}' timed out after {}ms",
+                        task_id, timeout_ms
+                    )))
+                }),
+            None => future.await,
+        }
+    }
+
+    async fn execute_restricted(
+        &self,
+        task_id: &str,
+        inputs: HashMap<String, serde_json::Value>,
+        context: &graph_flow::Context,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        let no_extensions = ExecutorExtensions::new();
+        self.with_timeout(
+            task_id,
+            execute_task_contained(
+                self.inner.as_ref(),
+                task_id,
+                inputs,
+                context,
+                &no_extensions,
+            ),
+        )
+        .await
+    }
+
+    async fn execute_isolated(
+        &self,
+        task_id: &str,
+        inputs: HashMap<String, serde_json::Value>,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        let worker = self.policy.worker.as_ref().ok_or_else(|| {
+            NodeEngineError::failed(format!(
+                "Task '{}' requires an isolated worker but none is configured",
+                task_id
+            ))
+        })?;
+
+        let mut command = tokio::process::Command::new(&worker.program);
+        command
+            .args(&worker.args)
+            .env_clear()
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        for key in &worker.env_allowlist {
+            if let Some(value) = std::env::var_os(key) {
+                command.env(key, value);
+            }
+        }
+        if let Some(dir) = &worker.working_dir {
+            command.current_dir(dir);
+        }
+
+        let mut request = serde_json::to_vec(&WorkerRequest {
+            task_id: task_id.to_string(),
+            inputs,
+        })?;
+        request.push(b'\n');
+
+        self.with_timeout(task_id, async {
+            let mut child = command.spawn()?;
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin.write_all(&request).await?;
+            }
+            let output = child.wait_with_output().await?;
+            parse_worker_output(task_id, &output)
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl TaskExecutor for SandboxedTaskExecutor {
+    async fn execute_task(
+        &self,
+        task_id: &str,
+        inputs: HashMap<String, serde_json::Value>,
+        context: &graph_flow::Context,
+        extensions: &ExecutorExtensions,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        let node_type = resolve_node_type(task_id, &inputs);
+        match self.profile_for(&node_type) {
+            SandboxProfile::None => {
+                self.inner
+                    .execute_task(task_id, inputs, context, extensions)
+                    .await
+            }
+            SandboxProfile::Restricted => self.execute_restricted(task_id, inputs, context).await,
+            SandboxProfile::IsolatedProcess => self.execute_isolated(task_id, inputs).await,
+        }
+    }
+
+    async fn shutdown(&self) {
+        self.inner.shutdown().await;
+    }
+}
+
+fn parse_worker_output(
+    task_id: &str,
+    output: &std::process::Output,
+) -> Result<HashMap<String, serde_json::Value>> {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let response = stdout
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .and_then(|line| serde_json::from_str::<WorkerResponse>(line).ok());
+
+    match response {
+        Some(WorkerResponse::Completed { outputs }) => Ok(outputs),
+        Some(WorkerResponse::Failed { error }) => Err(NodeEngineError::failed(error)),
+        None => Err(NodeEngineError::failed(format!(
+            "Sandbox worker for task '{}' exited with {} without a response: {}",
+            task_id,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))),
+    }
+}
+
+/// Serve one isolated-process request on stdin/stdout with `executor`.
+///
+/// Worker binaries call this from `main`. Task errors are reported to the
+/// host as a `failed` response; the returned error covers only protocol I/O.
+pub async fn run_sandbox_worker(executor: &dyn TaskExecutor) -> Result<()> {
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+    let request: WorkerRequest = serde_json::from_str(&line)?;
+
+    let context = graph_flow::Context::new();
+    let extensions = ExecutorExtensions::new();
+    let response = match executor
+        .execute_task(&request.task_id, request.inputs, &context, &extensions)
+        .await
+    {
+        Ok(outputs) => WorkerResponse::Completed { outputs },
+        Err(e) => WorkerResponse::Failed {
+            error: e.to_string(),
+        },
+    };
+
+    let mut stdout = std::io::stdout().lock();
+    serde_json::to_writer(&mut stdout, &response)?;
+    stdout.write_all(b"\n")?;
+    stdout.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct PanickyExecutor;
+
+    #[async_trait]
+    impl TaskExecutor for PanickyExecutor {
+        async fn execute_task(
+            &self,
+            task_id: &str,
+            _inputs: HashMap<String, serde_json::Value>,
+            _context: &graph_flow::Context,
+            extensions: &ExecutorExtensions,
+        ) -> Result<HashMap<String, serde_json::Value>> {
+            if task_id.starts_with("explode") {
+                panic!("node blew up");
+            }
+            Ok(HashMap::from([(
+                "has_extensions".to_string(),
+                serde_json::json!(extensions.get::<u32>("marker").is_some()),
+            )]))
+        }
+    }
+
+    fn policy() -> SandboxPolicy {
+        SandboxPolicy {
+            categories: HashMap::from([(NodeCategory::Tool, SandboxProfile::Restricted)]),
+            node_types: HashMap::from([("shell".to_string(), SandboxProfile::IsolatedProcess)]),
+            ..SandboxPolicy::default()
+        }
+    }
+
+    #[test]
+    fn node_type_rules_override_category_rules() {
+        let policy = policy();
+
+        assert_eq!(
+            policy.profile_for("shell", Some(NodeCategory::Tool)),
+            SandboxProfile::IsolatedProcess
+        );
+        assert_eq!(
+            policy.profile_for("http", Some(NodeCategory::Tool)),
+            SandboxProfile::Restricted
+        );
+        assert_eq!(policy.profile_for("http", None), SandboxProfile::None);
+    }
+
+    #[test]
+    fn validate_requires_worker_for_isolated_profiles() {
+        assert!(policy().validate().is_err());
+        assert!(SandboxPolicy::default().validate().is_ok());
+    }
+
+    #[test]
+    fn policy_deserializes_snake_case_profiles() {
+        let policy: SandboxPolicy = serde_json::from_value(serde_json::json!({
+            "categories": {"tool": "isolated_process"},
+            "timeoutMs": 500,
+            "worker": {"program": "/usr/bin/pantograph-worker"}
+        }))
+        .expect("parse policy");
+
+        assert_eq!(
+            policy.categories.get(&NodeCategory::Tool),
+            Some(&SandboxProfile::IsolatedProcess)
+        );
+        assert!(policy.validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn restricted_profile_contains_panics_and_withholds_extensions() {
+        let executor = SandboxedTaskExecutor::new(Arc::new(PanickyExecutor), policy())
+            .with_node_category("explode", NodeCategory::Tool)
+            .with_node_category("calm", NodeCategory::Tool);
+        let context = graph_flow::Context::new();
+        let mut extensions = ExecutorExtensions::new();
+        extensions.set("marker", 1_u32);
+
+        let error = executor
+            .execute_task("explode-1", HashMap::new(), &context, &extensions)
+            .await
+            .expect_err("panic becomes an error");
+        assert!(error.to_string().contains("node blew up"));
+
+        let outputs = executor
+            .execute_task("calm-1", HashMap::new(), &context, &extensions)
+            .await
+            .expect("restricted run");
+        assert_eq!(outputs["has_extensions"], false);
+    }
+
+    #[tokio::test]
+    async fn isolated_profile_reports_worker_failures() {
+        let executor = SandboxedTaskExecutor::new(
+            Arc::new(PanickyExecutor),
+            SandboxPolicy {
+                default_profile: SandboxProfile::IsolatedProcess,
+                worker: Some(SandboxWorkerCommand {
+                    program: PathBuf::from("/nonexistent/pantograph-worker"),
+                    args: Vec::new(),
+                    env_allowlist: Vec::new(),
+                    working_dir: None,
+                }),
+                ..SandboxPolicy::default()
+            },
+        );
+
+        let result = executor
+            .execute_task(
+                "calm-1",
+                HashMap::new(),
+                &graph_flow::Context::new(),
+                &ExecutorExtensions::new(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(NodeEngineError::Io(_))));
+    }
+}
@@ -0,0 +1,328 @@
+//! Workflow simulation with synthetic latencies.
+//!
+//! [`SimulatedTaskExecutor`] stands in for the real executor and never runs
+//! node logic: every task waits for its configured synthetic latency and then
+//! returns placeholder values for the output ports its node type declares.
+//! This exercises control flow, loops, and merges of large orchestrations
+//! without model, network, or host access.
+//!
+//! Placeholders only have the right shape, so [`SimulationConfig`] can pin
+//! outputs per node ID or node type to steer conditions and loop exits. Node
+//! types without known metadata (e.g. host-defined callback nodes) echo their
+//! connected inputs back as outputs.
+//!
+//! Latencies are slept on the clock installed in the executor extensions, so
+//! a [`SimulatedClock`](crate::clock::SimulatedClock) replays hours of
+//! synthetic latency instantly while still reporting it as elapsed time.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::clock;
+use crate::core_executor::resolve_node_type;
+use crate::descriptor::{DescriptorFn, PortMetadata};
+use crate::engine::TaskExecutor;
+use crate::error::Result;
+use crate::extensions::ExecutorExtensions;
+use crate::registry::NodeRegistry;
+use crate::types::PortDataType;
+
+/// Latencies and output overrides for a simulated run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationConfig {
+    /// Latency applied to every node without a node-type latency.
+    #[serde(default)]
+    pub latency_ms: u64,
+    #[serde(default)]
+    pub node_type_latency_ms: HashMap<String, u64>,
+    /// Outputs merged over the placeholders of every node of a type.
+    #[serde(default)]
+    pub node_type_outputs: HashMap<String, HashMap<String, serde_json::Value>>,
+    /// Outputs merged over the placeholders of a single node; these win over
+    /// `node_type_outputs`.
+    #[serde(default)]
+    pub node_outputs: HashMap<String, HashMap<String, serde_json::Value>>,
+}
+
+impl SimulationConfig {
+    /// Synthetic latency for a node type.
+    pub fn latency_for(&self, node_type: &str) -> Duration {
+        Duration::from_millis(
+            self.node_type_latency_ms
+                .get(node_type)
+                .copied()
+                .unwrap_or(self.latency_ms),
+        )
+    }
+}
+
+/// Task executor that returns mocked outputs instead of running nodes.
+pub struct SimulatedTaskExecutor {
+    config: SimulationConfig,
+    outputs: HashMap<String, Vec<PortMetadata>>,
+}
+
+impl SimulatedTaskExecutor {
+    /// Create a simulator that knows the output ports of every node type
+    /// described in the linked descriptor inventory.
+    pub fn new(config: SimulationConfig) -> Self {
+        let outputs = inventory::iter::<DescriptorFn>
+            .into_iter()
+            .map(|descriptor| {
+                let metadata = (descriptor.0)();
+                (metadata.node_type, metadata.outputs)
+            })
+            .collect();
+        Self { config, outputs }
+    }
+
+    /// Learn output ports from registry metadata, e.g. host-registered nodes.
+    pub fn with_registry(mut self, registry: &NodeRegistry) -> Self {
+        for metadata in registry.all_metadata() {
+            self.outputs
+                .insert(metadata.node_type.clone(), metadata.outputs.clone());
+        }
+        self
+    }
+
+    /// Set the output ports of a single node type.
+    pub fn with_node_outputs(
+        mut self,
+        node_type: impl Into<String>,
+        outputs: Vec<PortMetadata>,
+    ) -> Self {
+        self.outputs.insert(node_type.into(), outputs);
+        self
+    }
+
+    /// Simulation settings used by this executor.
+    pub fn config(&self) -> &SimulationConfig {
+        &self.config
+    }
+
+    fn mock_outputs(
+        &self,
+        task_id: &str,
+        node_type: &str,
+        inputs: HashMap<String, serde_json::Value>,
+    ) -> HashMap<String, serde_json::Value> {
+        let mut outputs: HashMap<String, serde_json::Value> = match self.outputs.get(node_type) {
+            Some(ports) => ports
+                .iter()
+                .map(|port| {
+                    (
+                        port.id.clone(),
+                        placeholder(node_type, &port.id, &port.data_type),
+                    )
+                })
+                .collect(),
+            None => inputs
+                .into_iter()
+                .filter(|(port, _)| !port.starts_with('_'))
+                .collect(),
+        };
+        for overrides in [
+            self.config.node_type_outputs.get(node_type),
+            self.config.node_outputs.get(task_id),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            outputs.extend(overrides.clone());
+        }
+        outputs
+    }
+}
+
+/// Placeholder value with the shape of a port type.
+fn placeholder(node_type: &str, port_id: &str, data_type: &PortDataType) -> serde_json::Value {
+    match data_type {
+        PortDataType::String | PortDataType::Prompt | PortDataType::Document => {
+            serde_json::json!(format!("[simulated {}.{}]", node_type, port_id))
+        }
+        PortDataType::Number => serde_json::json!(0),
+        PortDataType::Boolean => serde_json::json!(false),
+        PortDataType::Tools
+        | PortDataType::Embedding
+        | PortDataType::Vector
+        | PortDataType::AudioSamples => serde_json::json!([]),
+        PortDataType::Image | PortDataType::Audio => serde_json::json!(""),
+        PortDataType::Stream | PortDataType::AudioStream => serde_json::Value::Null,
+        PortDataType::Any
+        | PortDataType::Json
+        | PortDataType::Component
+        | PortDataType::KvCache
+        | PortDataType::VectorDb
+        | PortDataType::ModelHandle
+        | PortDataType::EmbeddingHandle
+        | PortDataType::DatabaseHandle
+        | PortDataType::Tensor => serde_json::json!({ "simulated": true }),
+    }
+}
+
+#[async_trait]
+impl TaskExecutor for SimulatedTaskExecutor {
+    async fn execute_task(
+        &self,
+        task_id: &str,
+        inputs: HashMap<String, serde_json::Value>,
+        _context: &graph_flow::Context,
+        extensions: &ExecutorExtensions,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        let node_type = resolve_node_type(task_id, &inputs);
+        let latency = self.config.latency_for(&node_type);
+        if !latency.is_zero() {
+            clock::clock(extensions).sleep(latency).await;
+        }
+        Ok(self.mock_outputs(task_id, &node_type, inputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn executor(config: SimulationConfig) -> SimulatedTaskExecutor {
+        SimulatedTaskExecutor::new(config).with_node_outputs(
+            "classifier",
+            vec![
+                PortMetadata::required("label", "Label", PortDataType::String),
+                PortMetadata::optional("score", "Score", PortDataType::Number),
+                PortMetadata::optional("matched", "Matched", PortDataType::Boolean),
+            ],
+        )
+    }
+
+    async fn run(
+        executor: &SimulatedTaskExecutor,
+        task_id: &str,
+        inputs: HashMap<String, serde_json::Value>,
+    ) -> HashMap<String, serde_json::Value> {
+        executor
+            .execute_task(
+                task_id,
+                inputs,
+                &graph_flow::Context::new(),
+                &ExecutorExtensions::new(),
+            )
+            .await
+            .expect("simulated run")
+    }
+
+    #[tokio::test]
+    async fn declared_outputs_get_typed_placeholders() {
+        let outputs = run(
+            &executor(SimulationConfig::default()),
+            "classifier-1",
+            HashMap::new(),
+        )
+        .await;
+
+        assert_eq!(outputs["label"], "[simulated classifier.label]");
+        assert_eq!(outputs["score"], 0);
+        assert_eq!(outputs["matched"], false);
+    }
+
+    #[tokio::test]
+    async fn node_overrides_win_over_node_type_overrides() {
+        let executor = executor(SimulationConfig {
+            node_type_outputs: HashMap::from([(
+                "classifier".to_string(),
+                HashMap::from([("matched".to_string(), serde_json::json!(true))]),
+            )]),
+            node_outputs: HashMap::from([(
+                "classifier-2".to_string(),
+                HashMap::from([("matched".to_string(), serde_json::json!(false))]),
+            )]),
+            ..SimulationConfig::default()
+        });
+
+        assert_eq!(
+            run(&executor, "classifier-1", HashMap::new()).await["matched"],
+            true
+        );
+        assert_eq!(
+            run(&executor, "classifier-2", HashMap::new()).await["matched"],
+            false
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_node_types_echo_inputs() {
+        let outputs = run(
+            &executor(SimulationConfig::default()),
+            "custom-1",
+            HashMap::from([
+                ("text".to_string(), serde_json::json!("hello")),
+                (
+                    "_data".to_string(),
+                    serde_json::json!({ "node_type": "custom" }),
+                ),
+            ]),
+        )
+        .await;
+
+        assert_eq!(
+            outputs,
+            HashMap::from([("text".to_string(), serde_json::json!("hello"))])
+        );
+    }
+
+    #[tokio::test]
+    async fn node_type_latency_overrides_default() {
+        let executor = executor(SimulationConfig {
+            latency_ms: 0,
+            node_type_latency_ms: HashMap::from([("classifier".to_string(), 20)]),
+            ..SimulationConfig::default()
+        });
+
+        assert_eq!(executor.config().latency_for("custom"), Duration::ZERO);
+        let started = std::time::Instant::now();
+        run(&executor, "classifier-1", HashMap::new()).await;
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn latency_advances_installed_clock() {
+        let executor = executor(SimulationConfig {
+            latency_ms: 60_000,
+            ..SimulationConfig::default()
+        });
+        let simulated = std::sync::Arc::new(crate::clock::SimulatedClock::new());
+        let mut extensions = ExecutorExtensions::new();
+        extensions.set(
+            crate::extension_keys::CLOCK,
+            simulated.clone() as std::sync::Arc<dyn crate::clock::Clock>,
+        );
+
+        let started = std::time::Instant::now();
+        executor
+            .execute_task(
+                "classifier-1",
+                HashMap::new(),
+                &graph_flow::Context::new(),
+                &extensions,
+            )
+            .await
+            .expect("simulated run");
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(simulated.elapsed(), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn config_deserializes_camel_case() {
+        let config: SimulationConfig = serde_json::from_value(serde_json::json!({
+            "latencyMs": 10,
+            "nodeOutputs": {"check-1": {"result": true}}
+        }))
+        .expect("parse config");
+
+        assert_eq!(config.latency_for("anything"), Duration::from_millis(10));
+        assert_eq!(config.node_outputs["check-1"]["result"], true);
+    }
+}
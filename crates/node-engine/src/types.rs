@@ -249,6 +249,43 @@ pub struct GraphNode {
     pub position: (f64, f64),
 }
 
+/// A graph-level port: a named input or output of a whole data graph, bound
+/// to one port of one of its nodes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphPort {
+    /// Name callers use for the value (graph input key or output key)
+    pub name: String,
+    /// Node the port is bound to
+    pub node_id: NodeId,
+    /// Port on that node
+    pub port_id: PortId,
+}
+
+/// Graph-level input and output ports of a data graph
+///
+/// When a list is empty, data-graph execution infers it: graph inputs bind to
+/// entry nodes that configure a same-named port (or to the only entry node),
+/// and outputs are every port of every terminal node, keyed `node.port` and by
+/// the bare port name when unambiguous.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphPorts {
+    /// Graph inputs and the node ports they feed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<GraphPort>,
+    /// Graph outputs and the node ports they read
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outputs: Vec<GraphPort>,
+}
+
+impl GraphPorts {
+    /// Whether neither inputs nor outputs are declared
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty() && self.outputs.is_empty()
+    }
+}
+
 /// A complete workflow graph
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -264,6 +301,9 @@ pub struct WorkflowGraph {
     /// Node groups (collapsed node collections)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub groups: Vec<NodeGroup>,
+    /// Graph-level ports used when the graph runs as a data graph
+    #[serde(default, skip_serializing_if = "GraphPorts::is_empty")]
+    pub ports: GraphPorts,
 }
 
 impl WorkflowGraph {
@@ -275,6 +315,7 @@ impl WorkflowGraph {
             nodes: Vec::new(),
             edges: Vec::new(),
             groups: Vec::new(),
+            ports: GraphPorts::default(),
         }
     }
 
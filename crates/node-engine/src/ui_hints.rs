@@ -0,0 +1,186 @@
+//! Standard editor hints for node categories and port data types.
+//!
+//! Every editor (Svelte, LiveView, Python) renders ports and nodes from these
+//! hints instead of keeping its own palette. Colors are carried twice: as a
+//! semantic token (`data-string`, `category-control`) that a theme may remap,
+//! and as the default hex value for hosts without a theme. Port shapes keep
+//! types distinguishable without relying on color alone.
+//!
+//! Descriptors normally leave hints empty; [`crate::NodeRegistry`] fills them
+//! from the category and port data types on registration, so a descriptor
+//! only sets the fields it wants to override (usually the node icon).
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{NodeCategory, PortDataType};
+
+/// Handle shape for a port, grouped by the kind of value it carries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PortShape {
+    /// Scalar values: text, numbers, booleans, and `any`
+    #[default]
+    Circle,
+    /// Structured payloads: JSON, documents, media, vectors
+    Square,
+    /// Streams delivered in chunks
+    Diamond,
+    /// Handles to runtime resources: models, caches, databases
+    Hexagon,
+}
+
+/// Rendering hints for a port.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortUiHints {
+    /// Semantic color token, e.g. `data-kv-cache`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub color_token: String,
+    /// Default hex color for the token
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub color: String,
+    #[serde(default)]
+    pub shape: PortShape,
+}
+
+impl PortUiHints {
+    /// Standard hints for `data_type`.
+    pub fn for_data_type(data_type: PortDataType) -> Self {
+        let (token, color, shape) = match data_type {
+            PortDataType::Any => ("any", "#6b7280", PortShape::Circle),
+            PortDataType::String => ("string", "#22c55e", PortShape::Circle),
+            PortDataType::Prompt => ("prompt", "#3b82f6", PortShape::Circle),
+            PortDataType::Number => ("number", "#f59e0b", PortShape::Circle),
+            PortDataType::Boolean => ("boolean", "#ef4444", PortShape::Circle),
+            PortDataType::Image => ("image", "#8b5cf6", PortShape::Square),
+            PortDataType::Audio => ("audio", "#f472b6", PortShape::Square),
+            PortDataType::AudioSamples => ("audio-samples", "#db2777", PortShape::Square),
+            PortDataType::Component => ("component", "#ec4899", PortShape::Square),
+            PortDataType::Tools => ("tools", "#d97706", PortShape::Square),
+            PortDataType::Embedding => ("embedding", "#6366f1", PortShape::Square),
+            PortDataType::Vector => ("vector", "#818cf8", PortShape::Square),
+            PortDataType::Tensor => ("tensor", "#64748b", PortShape::Square),
+            PortDataType::Document => ("document", "#14b8a6", PortShape::Square),
+            PortDataType::Json => ("json", "#f97316", PortShape::Square),
+            PortDataType::Stream => ("stream", "#06b6d4", PortShape::Diamond),
+            PortDataType::AudioStream => ("audio-stream", "#0ea5e9", PortShape::Diamond),
+            PortDataType::KvCache => ("kv-cache", "#84cc16", PortShape::Hexagon),
+            PortDataType::VectorDb => ("vector-db", "#a855f7", PortShape::Hexagon),
+            PortDataType::ModelHandle => ("model-handle", "#e11d48", PortShape::Hexagon),
+            PortDataType::EmbeddingHandle => ("embedding-handle", "#4f46e5", PortShape::Hexagon),
+            PortDataType::DatabaseHandle => ("database-handle", "#7c3aed", PortShape::Hexagon),
+        };
+        Self {
+            color_token: format!("data-{}", token),
+            color: color.to_string(),
+            shape,
+        }
+    }
+
+    /// Whether no color has been assigned yet.
+    pub fn is_unset(&self) -> bool {
+        self.color_token.is_empty() && self.color.is_empty()
+    }
+}
+
+/// Rendering hints for a node.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeUiHints {
+    /// Lucide icon name, e.g. `timer`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub icon: String,
+    /// Semantic color token, e.g. `category-processing`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub color_token: String,
+    /// Default hex color for the token
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub color: String,
+}
+
+impl NodeUiHints {
+    /// Hints that override only the icon; color comes from the category.
+    pub fn icon(icon: impl Into<String>) -> Self {
+        Self {
+            icon: icon.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Standard hints for `category`.
+    pub fn for_category(category: NodeCategory) -> Self {
+        let (token, color, icon) = match category {
+            NodeCategory::Input => ("input", "#2563eb", "log-in"),
+            NodeCategory::Output => ("output", "#0891b2", "log-out"),
+            NodeCategory::Processing => ("processing", "#16a34a", "cpu"),
+            NodeCategory::Control => ("control", "#9333ea", "git-branch"),
+            NodeCategory::Tool => ("tool", "#d97706", "wrench"),
+        };
+        Self {
+            icon: icon.to_string(),
+            color_token: format!("category-{}", token),
+            color: color.to_string(),
+        }
+    }
+
+    /// Fill empty fields from the standard hints for `category`.
+    pub fn resolve(&mut self, category: NodeCategory) {
+        let standard = Self::for_category(category);
+        if self.icon.is_empty() {
+            self.icon = standard.icon;
+        }
+        if self.color_token.is_empty() && self.color.is_empty() {
+            self.color_token = standard.color_token;
+            self.color = standard.color;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_type_tokens_follow_serde_names() {
+        for data_type in [
+            PortDataType::KvCache,
+            PortDataType::AudioStream,
+            PortDataType::ModelHandle,
+            PortDataType::String,
+        ] {
+            let wire = serde_json::to_value(data_type).unwrap();
+            let expected = format!("data-{}", wire.as_str().unwrap().replace('_', "-"));
+            assert_eq!(PortUiHints::for_data_type(data_type).color_token, expected);
+        }
+    }
+
+    #[test]
+    fn shapes_group_scalars_streams_and_handles() {
+        assert_eq!(
+            PortUiHints::for_data_type(PortDataType::Number).shape,
+            PortShape::Circle
+        );
+        assert_eq!(
+            PortUiHints::for_data_type(PortDataType::Json).shape,
+            PortShape::Square
+        );
+        assert_eq!(
+            PortUiHints::for_data_type(PortDataType::Stream).shape,
+            PortShape::Diamond
+        );
+        assert_eq!(
+            PortUiHints::for_data_type(PortDataType::VectorDb).shape,
+            PortShape::Hexagon
+        );
+    }
+
+    #[test]
+    fn resolve_keeps_icon_override_and_fills_category_color() {
+        let mut hints = NodeUiHints::icon("timer");
+        hints.resolve(NodeCategory::Control);
+
+        assert_eq!(hints.icon, "timer");
+        assert_eq!(hints.color_token, "category-control");
+        assert_eq!(hints.color, "#9333ea");
+    }
+}
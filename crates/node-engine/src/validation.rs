@@ -346,9 +346,10 @@ fn detect_orchestration_cycles(graph: &OrchestrationGraph, errors: &mut Vec<Vali
 mod tests {
     use super::*;
     use crate::builder::{OrchestrationBuilder, WorkflowBuilder};
-    use crate::descriptor::{PortMetadata, TaskMetadata};
+    use crate::descriptor::{NodeDocs, PortMetadata, TaskMetadata};
     use crate::registry::NodeRegistry;
     use crate::types::{ExecutionMode, NodeCategory, PortDataType};
+    use crate::ui_hints::NodeUiHints;
 
     fn make_test_registry() -> NodeRegistry {
         let mut registry = NodeRegistry::new();
@@ -360,6 +361,8 @@ mod tests {
             inputs: vec![],
             outputs: vec![PortMetadata::optional("text", "Text", PortDataType::String)],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::default(),
+            ui: NodeUiHints::default(),
         });
         registry.register_metadata(TaskMetadata {
             node_type: "text-output".to_string(),
@@ -369,6 +372,8 @@ mod tests {
             inputs: vec![PortMetadata::required("text", "Text", PortDataType::String)],
             outputs: vec![],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::default(),
+            ui: NodeUiHints::default(),
         });
         registry.register_metadata(TaskMetadata {
             node_type: "kv-source".to_string(),
@@ -382,6 +387,8 @@ mod tests {
                 PortDataType::KvCache,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::default(),
+            ui: NodeUiHints::default(),
         });
         registry.register_metadata(TaskMetadata {
             node_type: "kv-target".to_string(),
@@ -395,6 +402,8 @@ mod tests {
             )],
             outputs: vec![],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::default(),
+            ui: NodeUiHints::default(),
         });
         registry.register_metadata(TaskMetadata {
             node_type: "json-target".to_string(),
@@ -404,6 +413,8 @@ mod tests {
             inputs: vec![PortMetadata::required("json", "Json", PortDataType::Json)],
             outputs: vec![],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::default(),
+            ui: NodeUiHints::default(),
         });
         registry
     }
@@ -0,0 +1,209 @@
+//! Round trips through a real isolated-process worker.
+//!
+//! This test target has no libtest harness: run with `--sandbox-test-worker`
+//! the binary serves one sandbox request as the worker, and otherwise it runs
+//! the tests below against itself. The task ID prefix selects the worker
+//! behavior: `echo-*` returns its inputs, `env-*` reports the environment
+//! variables it can see, `fail-*` returns a task error, and `hang-*` writes
+//! its PID to `inputs.pidFile` and then sleeps far past any test timeout.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use node_engine::{
+    run_sandbox_worker, ExecutorExtensions, NodeEngineError, SandboxPolicy, SandboxProfile,
+    SandboxWorkerCommand, SandboxedTaskExecutor, TaskExecutor,
+};
+
+const WORKER_ARG: &str = "--sandbox-test-worker";
+
+struct TestWorkerExecutor;
+
+#[async_trait::async_trait]
+impl TaskExecutor for TestWorkerExecutor {
+    async fn execute_task(
+        &self,
+        task_id: &str,
+        inputs: HashMap<String, serde_json::Value>,
+        _context: &graph_flow::Context,
+        _extensions: &ExecutorExtensions,
+    ) -> node_engine::Result<HashMap<String, serde_json::Value>> {
+        if task_id.starts_with("echo") {
+            return Ok(inputs);
+        }
+        if task_id.starts_with("env") {
+            let names: Vec<String> = std::env::vars_os()
+                .map(|(name, _)| name.to_string_lossy().into_owned())
+                .collect();
+            return Ok(HashMap::from([(
+                "env".to_string(),
+                serde_json::json!(names),
+            )]));
+        }
+        if task_id.starts_with("hang") {
+            if let Some(pid_file) = inputs.get("pidFile").and_then(|value| value.as_str()) {
+                std::fs::write(pid_file, std::process::id().to_string())?;
+            }
+            tokio::time::sleep(Duration::from_secs(600)).await;
+        }
+        Err(NodeEngineError::failed(format!(
+            "test worker refused task '{}'",
+            task_id
+        )))
+    }
+}
+
+struct UnreachableExecutor;
+
+#[async_trait::async_trait]
+impl TaskExecutor for UnreachableExecutor {
+    async fn execute_task(
+        &self,
+        task_id: &str,
+        _inputs: HashMap<String, serde_json::Value>,
+        _context: &graph_flow::Context,
+        _extensions: &ExecutorExtensions,
+    ) -> node_engine::Result<HashMap<String, serde_json::Value>> {
+        panic!("task '{}' ran on the host executor", task_id);
+    }
+}
+
+fn isolated_executor(timeout_ms: Option<u64>, env_allowlist: Vec<String>) -> SandboxedTaskExecutor {
+    SandboxedTaskExecutor::new(
+        Arc::new(UnreachableExecutor),
+        SandboxPolicy {
+            default_profile: SandboxProfile::IsolatedProcess,
+            timeout_ms,
+            worker: Some(SandboxWorkerCommand {
+                program: std::env::current_exe().expect("test binary path"),
+                args: vec![WORKER_ARG.to_string()],
+                env_allowlist,
+                working_dir: None,
+            }),
+            ..SandboxPolicy::default()
+        },
+    )
+}
+
+async fn run(
+    executor: &SandboxedTaskExecutor,
+    task_id: &str,
+    inputs: HashMap<String, serde_json::Value>,
+) -> node_engine::Result<HashMap<String, serde_json::Value>> {
+    executor
+        .execute_task(
+            task_id,
+            inputs,
+            &graph_flow::Context::new(),
+            &ExecutorExtensions::new(),
+        )
+        .await
+}
+
+async fn worker_round_trips_request_and_response() {
+    let executor = isolated_executor(Some(10_000), Vec::new());
+    let inputs = HashMap::from([
+        ("prompt".to_string(), serde_json::json!("hello")),
+        ("nested".to_string(), serde_json::json!({"n": [1, 2, 3]})),
+    ]);
+
+    let outputs = run(&executor, "echo-1", inputs.clone())
+        .await
+        .expect("worker response");
+
+    assert_eq!(outputs, inputs);
+}
+
+async fn worker_task_errors_surface_as_failures() {
+    let executor = isolated_executor(Some(10_000), Vec::new());
+
+    let error = run(&executor, "fail-1", HashMap::new())
+        .await
+        .expect_err("worker reports failure");
+
+    assert!(error
+        .to_string()
+        .contains("test worker refused task 'fail-1'"));
+}
+
+async fn worker_sees_only_allowlisted_environment() {
+    std::env::set_var("PANTOGRAPH_SANDBOX_TEST_ALLOWED", "1");
+    std::env::set_var("PANTOGRAPH_SANDBOX_TEST_SECRET", "1");
+    let executor = isolated_executor(
+        Some(10_000),
+        vec!["PANTOGRAPH_SANDBOX_TEST_ALLOWED".to_string()],
+    );
+
+    let outputs = run(&executor, "env-1", HashMap::new())
+        .await
+        .expect("worker response");
+
+    assert_eq!(
+        outputs["env"],
+        serde_json::json!(["PANTOGRAPH_SANDBOX_TEST_ALLOWED"])
+    );
+}
+
+#[cfg(target_os = "linux")]
+async fn timeout_kills_the_worker_process() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let pid_file = dir.path().join("worker.pid");
+    let executor = isolated_executor(Some(2_000), Vec::new());
+
+    let error = run(
+        &executor,
+        "hang-1",
+        HashMap::from([(
+            "pidFile".to_string(),
+            serde_json::json!(pid_file.to_string_lossy()),
+        )]),
+    )
+    .await
+    .expect_err("worker times out");
+    assert!(matches!(error, NodeEngineError::ExecutionFailed(_)));
+    assert!(error.to_string().contains("timed out"));
+
+    let pid = std::fs::read_to_string(&pid_file).expect("worker wrote its pid");
+    let status_path = std::path::PathBuf::from(format!("/proc/{}/status", pid.trim()));
+    let mut killed = false;
+    for _ in 0..50 {
+        // A killed worker is gone, or a zombie until tokio reaps it.
+        match std::fs::read_to_string(&status_path) {
+            Err(_) => killed = true,
+            Ok(status) => {
+                killed = status.lines().any(|line| {
+                    line.starts_with("State:") && (line.contains('Z') || line.contains('X'))
+                })
+            }
+        }
+        if killed {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(killed, "worker {} still running after timeout", pid.trim());
+}
+
+fn main() {
+    let runtime = tokio::runtime::Runtime::new().expect("tokio runtime");
+    if std::env::args().any(|arg| arg == WORKER_ARG) {
+        if let Err(error) = runtime.block_on(run_sandbox_worker(&TestWorkerExecutor)) {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    println!("worker_round_trips_request_and_response");
+    runtime.block_on(worker_round_trips_request_and_response());
+    println!("worker_task_errors_surface_as_failures");
+    runtime.block_on(worker_task_errors_surface_as_failures());
+    println!("worker_sees_only_allowlisted_environment");
+    runtime.block_on(worker_sees_only_allowlisted_environment());
+    #[cfg(target_os = "linux")]
+    {
+        println!("timeout_kills_the_worker_process");
+        runtime.block_on(timeout_kills_the_worker_process());
+    }
+}
@@ -0,0 +1,78 @@
+//! Graphviz DOT export.
+
+use std::fmt::Write;
+
+use node_engine::WorkflowGraph;
+
+/// Escape `value` for use inside a DOT string literal.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", escape(value))
+}
+
+/// Render `graph` as a left-to-right DOT digraph.
+///
+/// Nodes are labelled with their ID and type; edges with their port handles.
+pub fn to_dot(graph: &WorkflowGraph) -> String {
+    let mut dot = String::new();
+    let _ = writeln!(dot, "digraph {} {{", quote(&graph.id));
+    let _ = writeln!(dot, "  rankdir=LR;");
+    let _ = writeln!(dot, "  node [shape=box];");
+    for node in &graph.nodes {
+        let _ = writeln!(
+            dot,
+            "  {} [label=\"{}\\n{}\"];",
+            quote(&node.id),
+            escape(&node.id),
+            escape(&node.node_type)
+        );
+    }
+    for edge in &graph.edges {
+        let label = format!("{} -> {}", edge.source_handle, edge.target_handle);
+        let _ = writeln!(
+            dot,
+            "  {} -> {} [label={}];",
+            quote(&edge.source),
+            quote(&edge.target),
+            quote(&label)
+        );
+    }
+    dot.push('}');
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use node_engine::{GraphEdge, GraphNode};
+
+    use super::*;
+
+    #[test]
+    fn dot_lists_nodes_and_labelled_edges() {
+        let mut graph = WorkflowGraph::new("wf", "Workflow");
+        for id in ["in", "say \"hi\""] {
+            graph.nodes.push(GraphNode {
+                id: id.to_string(),
+                node_type: "text-input".to_string(),
+                data: serde_json::json!({}),
+                position: (0.0, 0.0),
+            });
+        }
+        graph.edges.push(GraphEdge {
+            id: "e1".to_string(),
+            source: "in".to_string(),
+            source_handle: "text".to_string(),
+            target: "say \"hi\"".to_string(),
+            target_handle: "prompt".to_string(),
+        });
+
+        let dot = to_dot(&graph);
+        assert!(dot.starts_with("digraph \"wf\" {"));
+        assert!(dot.contains(r#""in" [label="in\ntext-input"];"#));
+        assert!(dot.contains(r#""in" -> "say \"hi\"" [label="text -> prompt"];"#));
+        assert!(dot.ends_with('}'));
+    }
+}
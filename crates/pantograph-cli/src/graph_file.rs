@@ -0,0 +1,263 @@
+//! Loading, saving, and binding inputs into workflow graph files.
+//!
+//! Two file shapes are accepted: a bare `node_engine::WorkflowGraph` (what
+//! the bindings and server exchange) and a saved `WorkflowFile` from the
+//! desktop app. Files are written back in the shape they were read in.
+
+use std::path::Path;
+
+use node_engine::{NodeRegistry, WorkflowGraph};
+use pantograph_workflow_service::{convert_graph_to_node_engine, WorkflowFile};
+
+/// A workflow graph together with the file shape it came from.
+pub enum GraphDocument {
+    Engine(WorkflowGraph),
+    Saved(Box<WorkflowFile>),
+}
+
+impl GraphDocument {
+    pub fn parse(json: &str) -> Result<Self, String> {
+        if let Ok(graph) = serde_json::from_str::<WorkflowGraph>(json) {
+            return Ok(Self::Engine(graph));
+        }
+        serde_json::from_str::<WorkflowFile>(json)
+            .map(|file| Self::Saved(Box::new(file)))
+            .map_err(|e| format!("Not a workflow graph or saved workflow file: {}", e))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read '{}': {}", path.display(), e))?;
+        Self::parse(&json).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// The graph in engine form; saved files take their ID from metadata.
+    pub fn graph(&self) -> WorkflowGraph {
+        match self {
+            Self::Engine(graph) => graph.clone(),
+            Self::Saved(file) => {
+                let mut graph = convert_graph_to_node_engine(&file.graph);
+                graph.id = file
+                    .metadata
+                    .id
+                    .clone()
+                    .unwrap_or_else(|| file.metadata.name.clone());
+                graph.name = file.metadata.name.clone();
+                graph
+            }
+        }
+    }
+
+    /// Copy node positions from `graph`, leaving everything else untouched.
+    pub fn set_positions(&mut self, graph: &WorkflowGraph) {
+        match self {
+            Self::Engine(current) => {
+                for node in &mut current.nodes {
+                    if let Some(laid_out) = graph.find_node(&node.id) {
+                        node.position = laid_out.position;
+                    }
+                }
+            }
+            Self::Saved(file) => {
+                for node in &mut file.graph.nodes {
+                    if let Some(laid_out) = graph.find_node(&node.id) {
+                        node.position.x = laid_out.position.0;
+                        node.position.y = laid_out.position.1;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        match self {
+            Self::Engine(graph) => serde_json::to_string_pretty(graph),
+            Self::Saved(file) => serde_json::to_string_pretty(file),
+        }
+        .map_err(|e| format!("Serialization error: {}", e))
+    }
+}
+
+/// Write `contents` to `path`, or to stdout when no path is given.
+pub fn write_output(path: Option<&Path>, contents: &str) -> Result<(), String> {
+    match path {
+        Some(path) => std::fs::write(path, format!("{}\n", contents))
+            .map_err(|e| format!("Cannot write '{}': {}", path.display(), e)),
+        None => {
+            println!("{}", contents);
+            Ok(())
+        }
+    }
+}
+
+/// A `--input` binding: `NODE.PORT=VALUE` or `NODE=VALUE`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputBinding {
+    pub node_id: String,
+    /// `None` binds the node's only output port.
+    pub port_id: Option<String>,
+    pub value: serde_json::Value,
+}
+
+impl std::str::FromStr for InputBinding {
+    type Err = String;
+
+    /// Values that parse as JSON keep their type; anything else is a string.
+    fn from_str(arg: &str) -> Result<Self, Self::Err> {
+        let (target, raw) = arg
+            .split_once('=')
+            .ok_or_else(|| format!("Expected NODE=VALUE or NODE.PORT=VALUE, got '{}'", arg))?;
+        let (node_id, port_id) = match target.split_once('.') {
+            Some((node_id, port_id)) => (node_id, Some(port_id.to_string())),
+            None => (target, None),
+        };
+        if node_id.is_empty() {
+            return Err(format!("Missing node ID in '{}'", arg));
+        }
+        let value = serde_json::from_str(raw)
+            .unwrap_or_else(|_| serde_json::Value::String(raw.to_string()));
+        Ok(Self {
+            node_id: node_id.to_string(),
+            port_id,
+            value,
+        })
+    }
+}
+
+/// Write each binding into its node's data, like host input bindings do.
+///
+/// A binding without a port targets the node type's single output port, so
+/// `--input question=...` fills a `text-input` node's `text` field.
+pub fn apply_inputs(
+    graph: &mut WorkflowGraph,
+    inputs: &[InputBinding],
+    registry: &NodeRegistry,
+) -> Result<(), String> {
+    for input in inputs {
+        let node = graph
+            .find_node_mut(&input.node_id)
+            .ok_or_else(|| format!("Input references unknown node '{}'", input.node_id))?;
+        let port_id = match &input.port_id {
+            Some(port_id) => port_id.clone(),
+            None => match registry
+                .get_metadata(&node.node_type)
+                .map(|metadata| metadata.outputs.as_slice())
+            {
+                Some([port]) => port.id.clone(),
+                _ => {
+                    return Err(format!(
+                        "Node '{}' ({}) has no single output port; use {}.PORT=VALUE",
+                        node.id, node.node_type, node.id
+                    ))
+                }
+            },
+        };
+
+        if node.data.is_null() {
+            node.data = serde_json::json!({});
+        }
+        let data = node
+            .data
+            .as_object_mut()
+            .ok_or_else(|| format!("Node '{}' has non-object data", node.id))?;
+        data.insert(port_id, input.value.clone());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use node_engine::{
+        GraphNode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskMetadata,
+    };
+
+    use super::*;
+
+    fn registry() -> NodeRegistry {
+        let mut registry = NodeRegistry::new();
+        registry.register_metadata(TaskMetadata {
+            node_type: "text-input".to_string(),
+            category: NodeCategory::Input,
+            label: "Text Input".to_string(),
+            description: String::new(),
+            inputs: vec![],
+            outputs: vec![PortMetadata::optional("text", "Text", PortDataType::String)],
+            execution_mode: node_engine::ExecutionMode::Batch,
+            docs: NodeDocs::default(),
+            ui: NodeUiHints::default(),
+        });
+        registry
+    }
+
+    fn graph() -> WorkflowGraph {
+        let mut graph = WorkflowGraph::new("wf", "Workflow");
+        graph.nodes.push(GraphNode {
+            id: "question".to_string(),
+            node_type: "text-input".to_string(),
+            data: serde_json::Value::Null,
+            position: (0.0, 0.0),
+        });
+        graph
+    }
+
+    #[test]
+    fn input_values_keep_json_types() {
+        let input: InputBinding = "n.count=3".parse().unwrap();
+        assert_eq!(input.port_id.as_deref(), Some("count"));
+        assert_eq!(input.value, serde_json::json!(3));
+
+        let input: InputBinding = "question=What is 2+2?".parse().unwrap();
+        assert_eq!(input.port_id, None);
+        assert_eq!(input.value, serde_json::json!("What is 2+2?"));
+
+        assert!("no-equals".parse::<InputBinding>().is_err());
+    }
+
+    #[test]
+    fn portless_inputs_bind_the_single_output_port() {
+        let mut graph = graph();
+        let input: InputBinding = "question=hello".parse().unwrap();
+        apply_inputs(&mut graph, &[input], &registry()).unwrap();
+        assert_eq!(graph.nodes[0].data["text"], "hello");
+
+        let unknown: InputBinding = "missing=hello".parse().unwrap();
+        assert!(apply_inputs(&mut graph, &[unknown], &registry()).is_err());
+    }
+
+    #[test]
+    fn saved_workflow_files_round_trip_their_shape() {
+        let json = serde_json::json!({
+            "version": "1.0",
+            "metadata": {
+                "name": "Saved",
+                "created": "2026-01-01T00:00:00Z",
+                "modified": "2026-01-01T00:00:00Z"
+            },
+            "graph": {
+                "nodes": [{
+                    "id": "a",
+                    "node_type": "text-input",
+                    "position": { "x": 1.0, "y": 2.0 },
+                    "data": {}
+                }],
+                "edges": []
+            }
+        })
+        .to_string();
+
+        let mut document = GraphDocument::parse(&json).unwrap();
+        let mut graph = document.graph();
+        assert_eq!(graph.id, "Saved");
+        assert_eq!(graph.nodes[0].position, (1.0, 2.0));
+
+        graph.nodes[0].position = (5.0, 6.0);
+        document.set_positions(&graph);
+        let written: serde_json::Value =
+            serde_json::from_str(&document.to_json().unwrap()).unwrap();
+        assert_eq!(written["metadata"]["name"], "Saved");
+        assert_eq!(written["graph"]["nodes"][0]["position"]["x"], 5.0);
+        assert!(written["graph"]["nodes"][0]["data"]
+            .get("node_type")
+            .is_none());
+    }
+}
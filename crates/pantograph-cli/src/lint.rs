@@ -0,0 +1,260 @@
+//! Graph lints beyond `node_engine::validate_workflow`.
+//!
+//! Validation errors make a graph unrunnable; lints flag graphs that run but
+//! probably do not do what the author meant.
+
+use std::collections::HashSet;
+
+use node_engine::{NodeCategory, NodeRegistry, WorkflowGraph};
+use serde::Serialize;
+
+/// Severity of a finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// A validation error; the graph cannot run.
+    Error,
+    Warning,
+}
+
+/// One lint or validation finding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Finding {
+    pub severity: Severity,
+    /// Stable rule name, e.g. `unknown-port`.
+    pub rule: &'static str,
+    pub message: String,
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}[{}]: {}", severity, self.rule, self.message)
+    }
+}
+
+fn warning(rule: &'static str, message: String) -> Finding {
+    Finding {
+        severity: Severity::Warning,
+        rule,
+        message,
+    }
+}
+
+/// Validation errors followed by lint warnings.
+pub fn lint(graph: &WorkflowGraph, registry: &NodeRegistry) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = node_engine::validate_workflow(graph, Some(registry))
+        .into_iter()
+        .map(|error| Finding {
+            severity: Severity::Error,
+            rule: "validation",
+            message: error.to_string(),
+        })
+        .collect();
+
+    unknown_ports(graph, registry, &mut findings);
+    duplicate_edges(graph, &mut findings);
+    disconnected_nodes(graph, &mut findings);
+    unused_outputs(graph, registry, &mut findings);
+    overlapping_nodes(graph, &mut findings);
+    findings
+}
+
+/// Edges whose handles are not declared by the node type; validation skips
+/// these, and the value silently never arrives.
+fn unknown_ports(graph: &WorkflowGraph, registry: &NodeRegistry, findings: &mut Vec<Finding>) {
+    for edge in &graph.edges {
+        let ends = [
+            (&edge.source, &edge.source_handle, true),
+            (&edge.target, &edge.target_handle, false),
+        ];
+        for (node_id, handle, is_output) in ends {
+            let Some(node) = graph.find_node(node_id) else {
+                continue;
+            };
+            let Some(metadata) = registry.get_metadata(&node.node_type) else {
+                continue;
+            };
+            let ports = if is_output {
+                &metadata.outputs
+            } else {
+                &metadata.inputs
+            };
+            if !ports.iter().any(|port| &port.id == handle) {
+                findings.push(warning(
+                    "unknown-port",
+                    format!(
+                        "Edge '{}' uses {} port '{}' not declared by '{}' ({})",
+                        edge.id,
+                        if is_output { "output" } else { "input" },
+                        handle,
+                        node.id,
+                        node.node_type
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+fn duplicate_edges(graph: &WorkflowGraph, findings: &mut Vec<Finding>) {
+    let mut seen = HashSet::new();
+    for edge in &graph.edges {
+        let key = (
+            &edge.source,
+            &edge.source_handle,
+            &edge.target,
+            &edge.target_handle,
+        );
+        if !seen.insert(key) {
+            findings.push(warning(
+                "duplicate-edge",
+                format!(
+                    "Edge '{}' repeats {}.{} -> {}.{}",
+                    edge.id, edge.source, edge.source_handle, edge.target, edge.target_handle
+                ),
+            ));
+        }
+    }
+}
+
+fn disconnected_nodes(graph: &WorkflowGraph, findings: &mut Vec<Finding>) {
+    if graph.nodes.len() < 2 {
+        return;
+    }
+    for node in &graph.nodes {
+        let connected = graph
+            .edges
+            .iter()
+            .any(|edge| edge.source == node.id || edge.target == node.id);
+        if !connected {
+            findings.push(warning(
+                "disconnected-node",
+                format!("Node '{}' has no edges", node.id),
+            ));
+        }
+    }
+}
+
+/// Non-output nodes with inputs but no consumers compute results nobody reads.
+fn unused_outputs(graph: &WorkflowGraph, registry: &NodeRegistry, findings: &mut Vec<Finding>) {
+    for node in &graph.nodes {
+        let Some(metadata) = registry.get_metadata(&node.node_type) else {
+            continue;
+        };
+        if metadata.category == NodeCategory::Output || metadata.outputs.is_empty() {
+            continue;
+        }
+        let has_inputs = graph.edges.iter().any(|edge| edge.target == node.id);
+        let has_consumers = graph.edges.iter().any(|edge| edge.source == node.id);
+        if has_inputs && !has_consumers {
+            findings.push(warning(
+                "unused-output",
+                format!(
+                    "Outputs of '{}' ({}) are not connected to anything",
+                    node.id, node.node_type
+                ),
+            ));
+        }
+    }
+}
+
+fn overlapping_nodes(graph: &WorkflowGraph, findings: &mut Vec<Finding>) {
+    for (index, node) in graph.nodes.iter().enumerate() {
+        if let Some(other) = graph.nodes[..index]
+            .iter()
+            .find(|other| other.position == node.position)
+        {
+            findings.push(warning(
+                "overlapping-nodes",
+                format!(
+                    "Nodes '{}' and '{}' share a position; run `pantograph layout`",
+                    other.id, node.id
+                ),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use node_engine::{
+        GraphEdge, GraphNode, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskMetadata,
+    };
+
+    use super::*;
+
+    fn registry() -> NodeRegistry {
+        let mut registry = NodeRegistry::new();
+        registry.register_metadata(TaskMetadata {
+            node_type: "upper".to_string(),
+            category: NodeCategory::Processing,
+            label: "Upper".to_string(),
+            description: String::new(),
+            inputs: vec![PortMetadata::optional("text", "Text", PortDataType::String)],
+            outputs: vec![PortMetadata::optional("text", "Text", PortDataType::String)],
+            execution_mode: node_engine::ExecutionMode::Batch,
+            docs: NodeDocs::default(),
+            ui: NodeUiHints::default(),
+        });
+        registry
+    }
+
+    fn node(id: &str, x: f64) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            node_type: "upper".to_string(),
+            data: serde_json::json!({}),
+            position: (x, 0.0),
+        }
+    }
+
+    fn edge(id: &str, source: &str, source_handle: &str, target: &str) -> GraphEdge {
+        GraphEdge {
+            id: id.to_string(),
+            source: source.to_string(),
+            source_handle: source_handle.to_string(),
+            target: target.to_string(),
+            target_handle: "text".to_string(),
+        }
+    }
+
+    fn rules(findings: &[Finding]) -> Vec<&'static str> {
+        findings.iter().map(|finding| finding.rule).collect()
+    }
+
+    #[test]
+    fn clean_chain_has_only_the_dangling_tail_warning() {
+        let mut graph = WorkflowGraph::new("wf", "Workflow");
+        graph.nodes.extend([node("a", 0.0), node("b", 100.0)]);
+        graph.edges.push(edge("e1", "a", "text", "b"));
+
+        assert_eq!(rules(&lint(&graph, &registry())), vec!["unused-output"]);
+    }
+
+    #[test]
+    fn lints_flag_suspicious_graphs() {
+        let mut graph = WorkflowGraph::new("wf", "Workflow");
+        graph
+            .nodes
+            .extend([node("a", 0.0), node("b", 100.0), node("c", 100.0)]);
+        graph.edges.push(edge("e1", "a", "text", "b"));
+        graph.edges.push(edge("e2", "a", "text", "b"));
+        graph.edges.push(edge("e3", "b", "missing", "a"));
+
+        let rules = rules(&lint(&graph, &registry()));
+        for rule in [
+            "validation",
+            "unknown-port",
+            "duplicate-edge",
+            "disconnected-node",
+            "overlapping-nodes",
+        ] {
+            assert!(rules.contains(&rule), "missing {rule} in {rules:?}");
+        }
+    }
+}
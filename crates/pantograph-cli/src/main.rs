@@ -0,0 +1,283 @@
+//! `pantograph` command-line interface.
+//!
+//! Runs and inspects workflow graphs without a host app, for scripts and CI:
+//!
+//! ```text
+//! pantograph run graph.json --input question="..." --out results.json
+//! pantograph validate graph.json
+//! pantograph lint graph.json
+//! pantograph plan graph.json --input question="..."
+//! pantograph layout graph.json --out graph.json
+//! pantograph export-dot graph.json | dot -Tsvg > graph.svg
+//! pantograph serve --config server.toml
+//! pantograph sandbox-worker   # started by SandboxedTaskExecutor, not by hand
+//! ```
+//!
+//! Commands exit with `0` on success, `1` when the graph is invalid or the
+//! run fails, and `2` on usage errors. `serve` runs until Ctrl-C or SIGTERM
+//! and exits with `0` after a graceful shutdown.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use node_engine::NodeRegistry;
+
+mod dot;
+mod graph_file;
+mod lint;
+mod plan;
+mod run;
+mod sandbox_worker;
+mod serve;
+
+use graph_file::{apply_inputs, write_output, GraphDocument, InputBinding};
+
+#[derive(Parser)]
+#[command(
+    name = "pantograph",
+    version,
+    about = "Run and inspect Pantograph workflows"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a workflow and write the outputs of its final nodes as JSON.
+    Run {
+        graph: PathBuf,
+        /// Input binding, `NODE=VALUE` or `NODE.PORT=VALUE`; VALUE is parsed
+        /// as JSON when possible.
+        #[arg(short, long = "input", value_name = "BINDING")]
+        inputs: Vec<InputBinding>,
+        /// Node to demand; repeatable. Defaults to nodes without outgoing edges.
+        #[arg(short, long = "target", value_name = "NODE")]
+        targets: Vec<String>,
+        /// Output file; stdout when omitted.
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+        /// Directory containing the `llama-server-wrapper` binary. Defaults
+        /// to the directory of this executable.
+        #[arg(long, env = "PANTOGRAPH_BINARIES_DIR")]
+        binaries_dir: Option<PathBuf>,
+        /// Directory for inference runtime data.
+        #[arg(long, env = "PANTOGRAPH_DATA_DIR")]
+        data_dir: Option<PathBuf>,
+        /// Print workflow events to stderr as JSON lines.
+        #[arg(long)]
+        events: bool,
+        /// TOML or JSON extensions config (secrets, filesystem policy,
+        /// backend endpoints, cache, Pumas library path).
+        #[arg(long, env = "PANTOGRAPH_EXTENSIONS_CONFIG")]
+        extensions_config: Option<PathBuf>,
+    },
+    /// Check a workflow for errors that prevent it from running.
+    Validate {
+        graph: PathBuf,
+        #[arg(long)]
+        json: bool,
+    },
+    /// Report validation errors and suspicious graph patterns.
+    Lint {
+        graph: PathBuf,
+        #[arg(long)]
+        json: bool,
+        /// Exit with `1` on warnings as well as errors.
+        #[arg(long)]
+        deny_warnings: bool,
+    },
+    /// Show parallel execution levels and estimated cost.
+    Plan {
+        graph: PathBuf,
+        #[arg(short, long = "input", value_name = "BINDING")]
+        inputs: Vec<InputBinding>,
+        #[arg(long)]
+        json: bool,
+    },
+    /// Arrange nodes left to right by dependency level.
+    Layout {
+        graph: PathBuf,
+        /// Output file (may be the input file); stdout when omitted.
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+    /// Export a workflow as a Graphviz DOT digraph.
+    ExportDot {
+        graph: PathBuf,
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+    /// Serve the REST and SSE API until Ctrl-C or SIGTERM.
+    Serve {
+        /// TOML server config; defaults apply when omitted.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Listen address, overriding the config file.
+        #[arg(long)]
+        listen: Option<std::net::SocketAddr>,
+        /// Directory containing the `llama-server-wrapper` binary, overriding
+        /// `inference.binaries_dir`.
+        #[arg(long, env = "PANTOGRAPH_BINARIES_DIR")]
+        binaries_dir: Option<PathBuf>,
+        /// Inference runtime data directory, overriding `inference.data_dir`.
+        #[arg(long, env = "PANTOGRAPH_DATA_DIR")]
+        data_dir: Option<PathBuf>,
+    },
+    /// Serve one isolated-process sandbox request on stdin/stdout.
+    ///
+    /// Point a `SandboxPolicy` worker `program` at this binary with
+    /// `args = ["sandbox-worker"]`.
+    SandboxWorker,
+}
+
+fn to_json(value: &impl serde::Serialize) -> Result<String, String> {
+    serde_json::to_string_pretty(value).map_err(|e| format!("Serialization error: {}", e))
+}
+
+async fn execute(command: Command) -> Result<ExitCode, String> {
+    let registry = NodeRegistry::with_builtins();
+    match command {
+        Command::Run {
+            graph,
+            inputs,
+            targets,
+            out,
+            binaries_dir,
+            data_dir,
+            events,
+            extensions_config,
+        } => {
+            let mut graph = GraphDocument::load(&graph)?.graph();
+            apply_inputs(&mut graph, &inputs, &registry)?;
+            let data_dir = data_dir.unwrap_or_else(run::default_data_dir);
+            std::fs::create_dir_all(&data_dir)
+                .map_err(|e| format!("Cannot create '{}': {}", data_dir.display(), e))?;
+
+            let outputs = run::run(
+                graph,
+                run::RunOptions {
+                    targets,
+                    binaries_dir: binaries_dir.unwrap_or_else(run::default_binaries_dir),
+                    data_dir,
+                    print_events: events,
+                    extensions: extensions_config
+                        .map(node_engine::config::ExtensionsConfig::load)
+                        .transpose()
+                        .map_err(|e| e.to_string())?,
+                },
+            )
+            .await?;
+            write_output(out.as_deref(), &to_json(&outputs)?)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Validate { graph, json } => {
+            let graph = GraphDocument::load(&graph)?.graph();
+            let errors: Vec<String> = node_engine::validate_workflow(&graph, Some(&registry))
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            if json {
+                println!("{}", to_json(&errors)?);
+            } else if errors.is_empty() {
+                println!("{} is valid", graph.id);
+            } else {
+                errors.iter().for_each(|error| println!("error: {}", error));
+            }
+            Ok(if errors.is_empty() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Command::Lint {
+            graph,
+            json,
+            deny_warnings,
+        } => {
+            let graph = GraphDocument::load(&graph)?.graph();
+            let findings = lint::lint(&graph, &registry);
+            if json {
+                println!("{}", to_json(&findings)?);
+            } else {
+                findings.iter().for_each(|finding| println!("{}", finding));
+            }
+            let failed = findings
+                .iter()
+                .any(|finding| deny_warnings || finding.severity == lint::Severity::Error);
+            Ok(if failed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            })
+        }
+        Command::Plan {
+            graph,
+            inputs,
+            json,
+        } => {
+            let mut graph = GraphDocument::load(&graph)?.graph();
+            apply_inputs(&mut graph, &inputs, &registry)?;
+            let plan = plan::plan(&graph, &Default::default())?;
+            if json {
+                println!("{}", to_json(&plan)?);
+            } else {
+                println!("{}", plan);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Layout { graph, out } => {
+            let mut document = GraphDocument::load(&graph)?;
+            let mut laid_out = document.graph();
+            plan::layout(&mut laid_out)?;
+            document.set_positions(&laid_out);
+            write_output(out.as_deref(), &document.to_json()?)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::ExportDot { graph, out } => {
+            let graph = GraphDocument::load(&graph)?.graph();
+            write_output(out.as_deref(), &dot::to_dot(&graph))?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Serve {
+            config,
+            listen,
+            binaries_dir,
+            data_dir,
+        } => {
+            let mut config = match config {
+                Some(path) => serve::ServeConfig::load(&path)?,
+                None => serve::ServeConfig::default(),
+            };
+            if let Some(listen) = listen {
+                config.listen = listen;
+            }
+            let binaries_dir = binaries_dir
+                .or(config.inference.binaries_dir.take())
+                .unwrap_or_else(run::default_binaries_dir);
+            let data_dir = data_dir
+                .or(config.inference.data_dir.take())
+                .unwrap_or_else(run::default_data_dir);
+            serve::serve(config, binaries_dir, data_dir).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::SandboxWorker => {
+            sandbox_worker::serve_one().await?;
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match execute(cli.command).await {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Execution planning and automatic layout.
+//!
+//! Both group nodes into dependency levels: every node in a level depends
+//! only on nodes in earlier levels, so a level's nodes can run in parallel
+//! and are drawn in one column.
+
+use std::collections::HashMap;
+
+use node_engine::{ExecutionEstimate, NodeId, WorkflowGraph};
+use serde::Serialize;
+
+/// Horizontal distance between layout columns.
+pub const COLUMN_SPACING: f64 = 320.0;
+/// Vertical distance between nodes in a column.
+pub const ROW_SPACING: f64 = 160.0;
+
+/// Nodes grouped by dependency depth, in graph order within each level.
+pub fn levels(graph: &WorkflowGraph) -> Result<Vec<Vec<NodeId>>, String> {
+    let mut in_degree: HashMap<&str, usize> = graph
+        .nodes
+        .iter()
+        .map(|node| (node.id.as_str(), 0))
+        .collect();
+    for edge in &graph.edges {
+        if !in_degree.contains_key(edge.source.as_str()) {
+            continue;
+        }
+        if let Some(degree) = in_degree.get_mut(edge.target.as_str()) {
+            *degree += 1;
+        }
+    }
+
+    let order: HashMap<&str, usize> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.id.as_str(), index))
+        .collect();
+    let mut current: Vec<&str> = graph
+        .nodes
+        .iter()
+        .map(|node| node.id.as_str())
+        .filter(|id| in_degree[id] == 0)
+        .collect();
+    let mut levels = Vec::new();
+    let mut placed = 0;
+    while !current.is_empty() {
+        placed += current.len();
+        let mut next = Vec::new();
+        for &node_id in &current {
+            for edge in graph.outgoing_edges(node_id) {
+                if let Some(degree) = in_degree.get_mut(edge.target.as_str()) {
+                    *degree -= 1;
+                    if *degree == 0 {
+                        next.push(edge.target.as_str());
+                    }
+                }
+            }
+        }
+        next.sort_by_key(|id| order[id]);
+        levels.push(current.iter().map(|id| id.to_string()).collect());
+        current = next;
+    }
+
+    if placed != graph.nodes.len() {
+        return Err(format!("Workflow '{}' contains a cycle", graph.id));
+    }
+    Ok(levels)
+}
+
+/// Execution plan printed by `pantograph plan`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Plan {
+    /// Parallel execution waves in dependency order.
+    pub levels: Vec<Vec<NodeId>>,
+    pub estimate: ExecutionEstimate,
+}
+
+impl std::fmt::Display for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let by_id: HashMap<&str, &node_engine::NodeEstimate> = self
+            .estimate
+            .nodes
+            .iter()
+            .map(|node| (node.node_id.as_str(), node))
+            .collect();
+        for (index, level) in self.levels.iter().enumerate() {
+            writeln!(f, "level {}:", index)?;
+            for node_id in level {
+                match by_id.get(node_id.as_str()) {
+                    Some(node) => writeln!(
+                        f,
+                        "  {} ({}) tokens in/out {}/{}, {}-{} ms",
+                        node_id,
+                        node.node_type,
+                        node.input_tokens,
+                        node.output_tokens,
+                        node.wall_time_ms.min,
+                        node.wall_time_ms.max
+                    )?,
+                    None => writeln!(f, "  {}", node_id)?,
+                }
+            }
+        }
+        write!(
+            f,
+            "total: {} input tokens, {} output tokens, {} API calls, {}-{} ms",
+            self.estimate.total_input_tokens,
+            self.estimate.total_output_tokens,
+            self.estimate.total_api_calls,
+            self.estimate.wall_time_ms.min,
+            self.estimate.wall_time_ms.max
+        )
+    }
+}
+
+/// Plan `graph` with run inputs keyed `"{node_id}.{port}"`.
+pub fn plan(
+    graph: &WorkflowGraph,
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Result<Plan, String> {
+    Ok(Plan {
+        levels: levels(graph)?,
+        estimate: node_engine::estimate(graph, inputs).map_err(|e| e.to_string())?,
+    })
+}
+
+/// Place each level in its own column, left to right.
+pub fn layout(graph: &mut WorkflowGraph) -> Result<(), String> {
+    let levels = levels(graph)?;
+    let mut positions = HashMap::new();
+    for (column, level) in levels.iter().enumerate() {
+        for (row, node_id) in level.iter().enumerate() {
+            positions.insert(
+                node_id.clone(),
+                (column as f64 * COLUMN_SPACING, row as f64 * ROW_SPACING),
+            );
+        }
+    }
+    for node in &mut graph.nodes {
+        if let Some(position) = positions.get(&node.id) {
+            node.position = *position;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use node_engine::{GraphEdge, GraphNode};
+
+    use super::*;
+
+    fn graph(edges: &[(&str, &str)]) -> WorkflowGraph {
+        let mut graph = WorkflowGraph::new("wf", "Workflow");
+        for id in ["a", "b", "c", "d"] {
+            graph.nodes.push(GraphNode {
+                id: id.to_string(),
+                node_type: "custom".to_string(),
+                data: serde_json::json!({}),
+                position: (0.0, 0.0),
+            });
+        }
+        for (source, target) in edges {
+            graph.edges.push(GraphEdge {
+                id: format!("{}-{}", source, target),
+                source: source.to_string(),
+                source_handle: "out".to_string(),
+                target: target.to_string(),
+                target_handle: "in".to_string(),
+            });
+        }
+        graph
+    }
+
+    #[test]
+    fn levels_group_independent_nodes() {
+        let graph = graph(&[("a", "c"), ("b", "c"), ("c", "d")]);
+        assert_eq!(
+            levels(&graph).unwrap(),
+            vec![vec!["a", "b"], vec!["c"], vec!["d"]]
+        );
+    }
+
+    #[test]
+    fn cycles_are_rejected() {
+        let graph = graph(&[("a", "b"), ("b", "a")]);
+        assert!(levels(&graph).is_err());
+    }
+
+    #[test]
+    fn layout_places_levels_in_columns() {
+        let mut graph = graph(&[("a", "c"), ("b", "c"), ("c", "d")]);
+        layout(&mut graph).unwrap();
+        let position = |id: &str| graph.find_node(id).unwrap().position;
+        assert_eq!(position("a"), (0.0, 0.0));
+        assert_eq!(position("b"), (0.0, ROW_SPACING));
+        assert_eq!(position("c"), (COLUMN_SPACING, 0.0));
+        assert_eq!(position("d"), (2.0 * COLUMN_SPACING, 0.0));
+    }
+}
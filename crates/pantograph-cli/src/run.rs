@@ -0,0 +1,102 @@
+//! Headless workflow execution.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use node_engine::config::ExtensionsConfig;
+use node_engine::{
+    CallbackEventSink, CoreTaskExecutor, EventSink, NodeId, NullEventSink, WorkflowExecutor,
+    WorkflowGraph,
+};
+
+/// Outputs of every demanded node, keyed by node ID and then port.
+pub type RunOutputs = HashMap<NodeId, HashMap<String, serde_json::Value>>;
+
+pub struct RunOptions {
+    /// Nodes to demand; empty demands every node without outgoing edges.
+    pub targets: Vec<NodeId>,
+    /// Directory containing the `llama-server-wrapper` binary.
+    pub binaries_dir: PathBuf,
+    /// Directory for inference server PID files and runtime data.
+    pub data_dir: PathBuf,
+    /// Print workflow events to stderr as JSON lines.
+    pub print_events: bool,
+    /// Extensions config; PumasApi discovery only when `None`.
+    pub extensions: Option<ExtensionsConfig>,
+}
+
+/// Directory of this executable, where release bundles place
+/// `llama-server-wrapper`.
+pub fn default_binaries_dir() -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+pub fn default_data_dir() -> PathBuf {
+    std::env::temp_dir().join("pantograph")
+}
+
+/// Nodes without outgoing edges: the results a run exists to produce.
+pub fn terminal_nodes(graph: &WorkflowGraph) -> Vec<NodeId> {
+    graph
+        .nodes
+        .iter()
+        .filter(|node| !graph.edges.iter().any(|edge| edge.source == node.id))
+        .map(|node| node.id.clone())
+        .collect()
+}
+
+/// Run `graph` to completion with `CoreTaskExecutor` and an inference gateway.
+///
+/// The gateway only starts an inference server when an inference node runs,
+/// and is stopped before returning.
+pub async fn run(graph: WorkflowGraph, options: RunOptions) -> Result<RunOutputs, String> {
+    let event_sink: Arc<dyn EventSink> = if options.print_events {
+        Arc::new(CallbackEventSink::new(|event| {
+            if let Ok(line) = serde_json::to_string(&event) {
+                eprintln!("{}", line);
+            }
+        }))
+    } else {
+        Arc::new(NullEventSink)
+    };
+
+    let gateway = Arc::new(inference::InferenceGateway::new());
+    gateway
+        .set_spawner(Arc::new(inference::StdProcessSpawner::new(
+            options.binaries_dir,
+            options.data_dir,
+        )))
+        .await;
+
+    let execution_id = format!("cli-{}", graph.id);
+    let mut core = CoreTaskExecutor::new()
+        .with_gateway(gateway.clone())
+        .with_event_sink(event_sink.clone())
+        .with_execution_id(execution_id.clone());
+    if let Ok(cwd) = std::env::current_dir() {
+        core = core.with_project_root(cwd);
+    }
+
+    let targets = if options.targets.is_empty() {
+        terminal_nodes(&graph)
+    } else {
+        options.targets
+    };
+    let mut executor = WorkflowExecutor::new(execution_id, graph, event_sink);
+    match &options.extensions {
+        Some(config) => {
+            workflow_nodes::setup_extensions_from_config(executor.extensions_mut(), config)
+                .await
+                .map_err(|e| e.to_string())?
+        }
+        None => workflow_nodes::setup_extensions(executor.extensions_mut()).await,
+    }
+
+    let result = executor.demand_multiple(&targets, &core).await;
+    gateway.stop().await;
+    result.map_err(|e| e.to_string())
+}
@@ -0,0 +1,21 @@
+//! Isolated-process sandbox worker.
+//!
+//! `SandboxPolicy` worker commands point at `pantograph sandbox-worker`,
+//! which serves one request from the host on stdin/stdout and exits.
+
+use node_engine::{run_sandbox_worker, CoreTaskExecutor};
+
+/// Serve one sandbox request with `CoreTaskExecutor`.
+///
+/// Workers get no gateway, extensions, or host callbacks, so only nodes
+/// `CoreTaskExecutor` runs from their inputs alone succeed here. File nodes
+/// resolve paths against the worker's working directory.
+pub async fn serve_one() -> Result<(), String> {
+    let mut executor = CoreTaskExecutor::new();
+    if let Ok(cwd) = std::env::current_dir() {
+        executor = executor.with_project_root(cwd);
+    }
+    run_sandbox_worker(&executor)
+        .await
+        .map_err(|e| format!("Sandbox worker error: {}", e))
+}
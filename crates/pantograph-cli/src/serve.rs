@@ -0,0 +1,512 @@
+//! `pantograph serve`: the HTTP server as a long-running daemon.
+//!
+//! A TOML file configures the listen address, persistent stores, executor
+//! extensions, inference binaries, auth, and rate limits:
+//!
+//! ```toml
+//! listen = "0.0.0.0:8080"
+//!
+//! [stores]
+//! orchestrations = "data/orchestrations"
+//!
+//! [extensions]
+//! pumas_library_path = "/opt/pumas"
+//! backends.ollama = "http://gpu-box:11434"
+//!
+//! [inference]
+//! binaries_dir = "bin"
+//!
+//! [[auth.api_keys]]
+//! key = "change-me"
+//! subject = "ci"
+//! scopes = ["read", "execute"]
+//!
+//! [drain]
+//! timeout_secs = 30
+//! checkpoint_dir = "data/checkpoints"
+//!
+//! [rate_limits]
+//! # Share counters across instances; needs the `redis` feature.
+//! redis_url = "redis://cache:6379/0"
+//!
+//! [rate_limits.default]
+//! requests_per_minute = 120
+//! ```
+//!
+//! Relative paths resolve against the directory containing the config file.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use node_engine::config::ExtensionsConfig;
+use node_engine::{
+    CoreTaskExecutor, EventRingBuffer, EventSink, ExecutorExtensions, OrchestrationStore,
+};
+use pantograph_server::auth::{AuthConfig, JwtValidator, Scope, StaticApiKeys};
+use pantograph_server::rate_limit::{RateLimitConfig, RateLimiter, RateLimits};
+use pantograph_server::ServerState;
+use serde::Deserialize;
+use tokio::sync::RwLock;
+
+/// Default listen address; loopback so an unconfigured daemon is not exposed.
+pub const DEFAULT_LISTEN: &str = "127.0.0.1:8080";
+/// Default number of events retained for SSE resume.
+pub const DEFAULT_EVENT_CAPACITY: usize = 4096;
+/// Default time running work gets to finish after a shutdown signal.
+pub const DEFAULT_DRAIN_TIMEOUT_SECS: u64 = 30;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServeConfig {
+    pub listen: SocketAddr,
+    /// Events retained for clients resuming with `Last-Event-ID`.
+    pub event_capacity: usize,
+    pub stores: StoresConfig,
+    /// Executor extensions, in `node_engine::config` format.
+    pub extensions: ExtensionsConfig,
+    pub inference: InferenceConfig,
+    /// `None` serves every endpoint unauthenticated.
+    pub auth: Option<AuthSection>,
+    /// `None` serves every request without limits.
+    pub rate_limits: Option<RateLimitSection>,
+    pub drain: DrainConfig,
+}
+
+impl Default for ServeConfig {
+    fn default() -> Self {
+        Self {
+            listen: DEFAULT_LISTEN.parse().expect("valid default address"),
+            event_capacity: DEFAULT_EVENT_CAPACITY,
+            stores: StoresConfig::default(),
+            extensions: ExtensionsConfig::default(),
+            inference: InferenceConfig::default(),
+            auth: None,
+            rate_limits: None,
+            drain: DrainConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DrainConfig {
+    /// Time running demands and orchestration runs get after a shutdown
+    /// signal before they are cancelled or abandoned.
+    pub timeout_secs: u64,
+    /// Directory receiving each workflow's demand cache; none written when unset.
+    pub checkpoint_dir: Option<PathBuf>,
+}
+
+impl Default for DrainConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: DEFAULT_DRAIN_TIMEOUT_SECS,
+            checkpoint_dir: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StoresConfig {
+    /// Directory persisting orchestration graphs; in-memory when unset.
+    pub orchestrations: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct InferenceConfig {
+    /// Directory containing the `llama-server-wrapper` binary.
+    pub binaries_dir: Option<PathBuf>,
+    /// Directory for inference server PID files and runtime data.
+    pub data_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthSection {
+    pub api_keys: Vec<ApiKeyConfig>,
+    pub jwt: Option<JwtConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyConfig {
+    pub key: String,
+    pub subject: String,
+    pub scopes: Vec<Scope>,
+}
+
+/// Exactly one of `hs256_secret` and `rs256_public_key` must be set.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JwtConfig {
+    pub issuer: String,
+    pub audience: Option<String>,
+    pub hs256_secret: Option<String>,
+    /// Path to a PEM-encoded RSA public key.
+    pub rs256_public_key: Option<PathBuf>,
+}
+
+/// Snake-case mirror of [`RateLimitConfig`] for TOML files.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitSection {
+    pub default: LimitsConfig,
+    pub per_subject: HashMap<String, LimitsConfig>,
+    /// Redis holding counters shared by every instance; in-memory when unset.
+    pub redis_url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsConfig {
+    pub requests_per_minute: Option<u32>,
+    pub max_concurrent_executions: Option<u32>,
+    pub daily_quota: Option<u64>,
+}
+
+impl From<LimitsConfig> for RateLimits {
+    fn from(limits: LimitsConfig) -> Self {
+        Self {
+            requests_per_minute: limits.requests_per_minute,
+            max_concurrent_executions: limits.max_concurrent_executions,
+            daily_quota: limits.daily_quota,
+        }
+    }
+}
+
+impl From<RateLimitSection> for RateLimitConfig {
+    fn from(section: RateLimitSection) -> Self {
+        Self {
+            default: section.default.into(),
+            per_subject: section
+                .per_subject
+                .into_iter()
+                .map(|(subject, limits)| (subject, limits.into()))
+                .collect(),
+        }
+    }
+}
+
+impl RateLimitSection {
+    /// Limiter backed by Redis when `redis_url` is set, in memory otherwise.
+    pub async fn into_limiter(mut self) -> Result<RateLimiter, String> {
+        let redis_url = self.redis_url.take();
+        let config = RateLimitConfig::from(self);
+        match redis_url {
+            None => Ok(RateLimiter::in_memory(config)),
+            #[cfg(feature = "redis")]
+            Some(url) => {
+                let store = pantograph_server::rate_limit::RedisRateLimitStore::connect(&url)
+                    .await
+                    .map_err(|e| format!("Cannot connect to rate limit store '{}': {}", url, e))?;
+                Ok(RateLimiter::with_store(config, Arc::new(store)))
+            }
+            #[cfg(not(feature = "redis"))]
+            Some(_) => Err(
+                "rate_limits.redis_url requires pantograph built with the `redis` feature"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+impl ServeConfig {
+    /// Parse a config file, resolving relative paths against its directory.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read '{}': {}", path.display(), e))?;
+        let mut config = Self::parse(&content)
+            .map_err(|e| format!("Invalid config '{}': {}", path.display(), e))?;
+        config.resolve_paths(path.parent().unwrap_or(Path::new(".")));
+        Ok(config)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+
+    fn resolve_paths(&mut self, base: &Path) {
+        self.extensions.resolve_paths(base);
+        let paths = [
+            &mut self.stores.orchestrations,
+            &mut self.inference.binaries_dir,
+            &mut self.inference.data_dir,
+            &mut self.drain.checkpoint_dir,
+        ];
+        let jwt_key = self
+            .auth
+            .as_mut()
+            .and_then(|auth| auth.jwt.as_mut())
+            .map(|jwt| &mut jwt.rs256_public_key);
+        for path in paths.into_iter().chain(jwt_key).flatten() {
+            if path.is_relative() {
+                *path = base.join(&*path);
+            }
+        }
+    }
+
+    /// Auth config, or `None` when no credentials are configured.
+    pub fn auth_config(&self) -> Result<Option<AuthConfig>, String> {
+        let Some(section) = &self.auth else {
+            return Ok(None);
+        };
+        let mut auth = AuthConfig::new();
+        if !section.api_keys.is_empty() {
+            let keys = section
+                .api_keys
+                .iter()
+                .fold(StaticApiKeys::new(), |keys, entry| {
+                    keys.with_key(&entry.key, &entry.subject, entry.scopes.iter().copied())
+                });
+            auth = auth.with_authenticator(keys);
+        }
+        if let Some(jwt) = &section.jwt {
+            let validator = match (&jwt.hs256_secret, &jwt.rs256_public_key) {
+                (Some(secret), None) => JwtValidator::hs256(secret.as_bytes(), &jwt.issuer),
+                (None, Some(key_path)) => {
+                    let pem = std::fs::read(key_path)
+                        .map_err(|e| format!("Cannot read '{}': {}", key_path.display(), e))?;
+                    JwtValidator::rs256_pem(&pem, &jwt.issuer)?
+                }
+                _ => {
+                    return Err(
+                        "auth.jwt needs exactly one of hs256_secret and rs256_public_key"
+                            .to_string(),
+                    )
+                }
+            };
+            auth = auth.with_authenticator(match &jwt.audience {
+                Some(audience) => validator.with_audience(audience),
+                None => validator,
+            });
+        }
+        Ok(Some(auth))
+    }
+}
+
+/// Resolve when the process receives Ctrl-C, or SIGTERM on Unix.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+/// Serve until Ctrl-C or SIGTERM, then drain running work, checkpoint demand
+/// caches, and stop inference.
+pub async fn serve(
+    config: ServeConfig,
+    binaries_dir: PathBuf,
+    data_dir: PathBuf,
+) -> Result<(), String> {
+    let auth = config.auth_config()?;
+
+    let mut store = match &config.stores.orchestrations {
+        Some(path) => OrchestrationStore::with_persistence(path),
+        None => OrchestrationStore::new(),
+    };
+    let loaded = store.load_from_disk().map_err(|e| e.to_string())?;
+    if loaded > 0 {
+        eprintln!("loaded {} orchestrations", loaded);
+    }
+
+    let mut extensions = ExecutorExtensions::new();
+    workflow_nodes::setup_extensions_from_config(&mut extensions, &config.extensions)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    std::fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Cannot create '{}': {}", data_dir.display(), e))?;
+    if let Some(dir) = &config.drain.checkpoint_dir {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Cannot create '{}': {}", dir.display(), e))?;
+    }
+    let gateway = Arc::new(inference::InferenceGateway::new());
+    gateway
+        .set_spawner(Arc::new(inference::StdProcessSpawner::new(
+            binaries_dir,
+            data_dir,
+        )))
+        .await;
+
+    let events = Arc::new(EventRingBuffer::new(config.event_capacity));
+    let event_sink: Arc<dyn EventSink> = events.clone();
+    let task_executor = CoreTaskExecutor::new()
+        .with_gateway(gateway.clone())
+        .with_event_sink(event_sink);
+
+    let mut state = ServerState::new(events)
+        .with_task_executor(Arc::new(task_executor))
+        .with_orchestration_store(Arc::new(RwLock::new(store)))
+        .with_extensions(extensions);
+    if let Some(auth) = auth {
+        state = state.with_auth(auth);
+    }
+    if let Some(limits) = config.rate_limits {
+        state = state.with_rate_limits(limits.into_limiter().await?);
+    }
+
+    let listener = tokio::net::TcpListener::bind(config.listen)
+        .await
+        .map_err(|e| format!("Cannot listen on {}: {}", config.listen, e))?;
+    eprintln!("serving on http://{}", config.listen);
+
+    let drain_state = state.clone();
+    let drain_config = config.drain;
+    let shutdown = async move {
+        shutdown_signal().await;
+        eprintln!("draining");
+        let summary = pantograph_server::drain::drain(
+            &drain_state,
+            std::time::Duration::from_secs(drain_config.timeout_secs),
+            drain_config.checkpoint_dir.as_deref(),
+        )
+        .await;
+        eprintln!(
+            "drained: {} demands finished, {} cancelled, {} orchestration runs unfinished",
+            summary.finished, summary.cancelled, summary.runs_unfinished
+        );
+    };
+
+    let result = pantograph_server::serve_with_shutdown(listener, state, shutdown).await;
+    eprintln!("shutting down");
+    gateway.stop().await;
+    result.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_config_uses_defaults() {
+        let config = ServeConfig::parse("").unwrap();
+        assert_eq!(config.listen, DEFAULT_LISTEN.parse().unwrap());
+        assert_eq!(config.event_capacity, DEFAULT_EVENT_CAPACITY);
+        assert!(config.auth_config().unwrap().is_none());
+        assert!(config.rate_limits.is_none());
+        assert_eq!(config.drain.timeout_secs, DEFAULT_DRAIN_TIMEOUT_SECS);
+        assert!(config.drain.checkpoint_dir.is_none());
+    }
+
+    #[test]
+    fn config_resolves_paths_and_builds_auth_and_limits() {
+        let mut config = ServeConfig::parse(
+            r#"
+            listen = "0.0.0.0:9000"
+
+            [stores]
+            orchestrations = "data/orchestrations"
+
+            [extensions]
+            pumas_library_path = "/opt/pumas"
+
+            [extensions.cache]
+            kv_cache_dir = "cache/kv"
+
+            [[auth.api_keys]]
+            key = "secret"
+            subject = "ci"
+            scopes = ["read", "execute"]
+
+            [auth.jwt]
+            issuer = "https://issuer.example"
+            hs256_secret = "shared"
+
+            [drain]
+            timeout_secs = 5
+            checkpoint_dir = "checkpoints"
+
+            [rate_limits.default]
+            requests_per_minute = 60
+
+            [rate_limits.per_subject.ci]
+            daily_quota = 10
+            "#,
+        )
+        .unwrap();
+        config.resolve_paths(Path::new("/etc/pantograph"));
+
+        assert_eq!(
+            config.stores.orchestrations.as_deref(),
+            Some(Path::new("/etc/pantograph/data/orchestrations"))
+        );
+        assert_eq!(config.drain.timeout_secs, 5);
+        assert_eq!(
+            config.drain.checkpoint_dir.as_deref(),
+            Some(Path::new("/etc/pantograph/checkpoints"))
+        );
+        assert_eq!(
+            config.extensions.pumas_library_path.as_deref(),
+            Some(Path::new("/opt/pumas"))
+        );
+        assert_eq!(
+            config.extensions.cache.kv_cache_dir.as_deref(),
+            Some(Path::new("/etc/pantograph/cache/kv"))
+        );
+
+        let auth = config.auth_config().unwrap().unwrap();
+        let principal = auth.authenticate("secret").unwrap();
+        assert_eq!(principal.subject, "ci");
+        assert!(principal.allows(Scope::Execute));
+        assert!(!principal.allows(Scope::Admin));
+
+        let limits: RateLimitConfig = config.rate_limits.take().unwrap().into();
+        assert_eq!(limits.default.requests_per_minute, Some(60));
+        assert_eq!(limits.limits_for("ci").daily_quota, Some(10));
+    }
+
+    #[cfg(not(feature = "redis"))]
+    #[tokio::test]
+    async fn redis_rate_limits_require_the_redis_feature() {
+        let config = ServeConfig::parse(
+            r#"
+            [rate_limits]
+            redis_url = "redis://127.0.0.1:6379"
+            "#,
+        )
+        .unwrap();
+
+        let error = config
+            .rate_limits
+            .unwrap()
+            .into_limiter()
+            .await
+            .err()
+            .unwrap();
+        assert!(error.contains("`redis` feature"));
+    }
+
+    #[test]
+    fn config_rejects_unknown_keys_and_ambiguous_jwt_keys() {
+        assert!(ServeConfig::parse("listn = \"0.0.0.0:1\"").is_err());
+
+        let config = ServeConfig::parse(
+            r#"
+            [auth.jwt]
+            issuer = "https://issuer.example"
+            "#,
+        )
+        .unwrap();
+        assert!(config.auth_config().is_err());
+    }
+}
@@ -0,0 +1,81 @@
+//! Sandboxed tasks round trip through `pantograph sandbox-worker`.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use node_engine::{
+    Context, ExecutorExtensions, SandboxPolicy, SandboxProfile, SandboxWorkerCommand,
+    SandboxedTaskExecutor, TaskExecutor,
+};
+
+struct UnreachableExecutor;
+
+#[async_trait::async_trait]
+impl TaskExecutor for UnreachableExecutor {
+    async fn execute_task(
+        &self,
+        task_id: &str,
+        _inputs: HashMap<String, serde_json::Value>,
+        _context: &Context,
+        _extensions: &ExecutorExtensions,
+    ) -> node_engine::Result<HashMap<String, serde_json::Value>> {
+        panic!("task '{}' ran on the host executor", task_id);
+    }
+}
+
+fn isolated_executor() -> SandboxedTaskExecutor {
+    SandboxedTaskExecutor::new(
+        Arc::new(UnreachableExecutor),
+        SandboxPolicy {
+            default_profile: SandboxProfile::IsolatedProcess,
+            timeout_ms: Some(10_000),
+            worker: Some(SandboxWorkerCommand {
+                program: PathBuf::from(env!("CARGO_BIN_EXE_pantograph")),
+                args: vec!["sandbox-worker".to_string()],
+                env_allowlist: Vec::new(),
+                working_dir: None,
+            }),
+            ..SandboxPolicy::default()
+        },
+    )
+}
+
+async fn run(
+    task_id: &str,
+    inputs: HashMap<String, serde_json::Value>,
+) -> node_engine::Result<HashMap<String, serde_json::Value>> {
+    isolated_executor()
+        .execute_task(task_id, inputs, &Context::new(), &ExecutorExtensions::new())
+        .await
+}
+
+#[tokio::test]
+async fn worker_runs_core_nodes() {
+    let outputs = run(
+        "text-input-1",
+        HashMap::from([(
+            "_data".to_string(),
+            serde_json::json!({"node_type": "text-input", "text": "hello"}),
+        )]),
+    )
+    .await
+    .expect("worker response");
+
+    assert_eq!(outputs["text"], "hello");
+}
+
+#[tokio::test]
+async fn worker_reports_nodes_it_cannot_run() {
+    let error = run(
+        "host-only-1",
+        HashMap::from([(
+            "_data".to_string(),
+            serde_json::json!({"node_type": "host-only"}),
+        )]),
+    )
+    .await
+    .expect_err("worker reports failure");
+
+    assert!(!error.to_string().contains("without a response"));
+}
@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use node_engine::{CoreTaskExecutor, EventSink, WorkflowExecutor, WorkflowGraph};
+use node_engine::{
+    CoreTaskExecutor, DataGraphRun, EventSink, TaskExecutor, WorkflowExecutor, WorkflowGraph,
+};
 use uuid::Uuid;
 
 use crate::{
-    apply_runtime_extensions_for_execution, task_executor, EmbeddedRuntime, EmbeddedWorkflowHost,
+    apply_runtime_extensions_for_execution, task_executor, EmbeddedRuntime,
     RuntimeExtensionsSnapshot,
 };
 
@@ -37,65 +39,45 @@ impl EmbeddedRuntime {
         );
         let python_runtime_execution_recorder =
             Arc::new(task_executor::PythonRuntimeExecutionRecorder::default());
-        let mut graph = graph.clone();
-        let terminal_nodes = EmbeddedWorkflowHost::terminal_data_graph_node_ids(&graph);
-        EmbeddedWorkflowHost::apply_data_graph_inputs(&mut graph, inputs);
-
-        let mut executor = WorkflowExecutor::new(execution_id.clone(), graph, event_sink);
-        apply_runtime_extensions_for_execution(
-            &mut executor,
-            &runtime_ext,
-            Some(workflow_event_sink),
-            Some(execution_id),
-            Some(python_runtime_execution_recorder.clone()),
-        );
-
-        let mut node_outputs = HashMap::new();
-        let mut terminal_errors = HashMap::new();
-        let mut terminal_outcome_error = None;
-        for terminal_id in &terminal_nodes {
-            match executor.demand(terminal_id, &task_executor).await {
-                Ok(outputs) => {
-                    node_outputs.insert(terminal_id.clone(), outputs);
-                }
-                Err(error) => {
-                    if matches!(
-                        error,
-                        node_engine::NodeEngineError::WaitingForInput { .. }
-                            | node_engine::NodeEngineError::Cancelled
-                    ) {
-                        terminal_outcome_error = Some(error);
-                        break;
-                    }
-                    log::error!(
-                        "Error executing terminal node '{}' in data graph '{}': {}",
-                        terminal_id,
-                        graph_id,
-                        error
-                    );
-                    terminal_errors.insert(
-                        format!("{}.error", terminal_id),
-                        serde_json::Value::String(error.to_string()),
-                    );
-                }
-            }
-        }
+        let result = run_data_graph(
+            graph,
+            inputs,
+            execution_id.clone(),
+            event_sink,
+            &task_executor,
+            |executor| {
+                apply_runtime_extensions_for_execution(
+                    executor,
+                    &runtime_ext,
+                    Some(workflow_event_sink),
+                    Some(execution_id.clone()),
+                    Some(python_runtime_execution_recorder.clone()),
+                )
+            },
+        )
+        .await;
 
         let python_runtime_execution_metadata = python_runtime_execution_recorder.snapshots();
         self.host()
             .observe_python_runtime_execution_metadata(&python_runtime_execution_metadata)
             .map_err(|error| node_engine::NodeEngineError::failed(error.to_string()))?;
 
-        if let Some(error) = terminal_outcome_error {
-            return Err(error);
-        }
-
-        let mut outputs = EmbeddedWorkflowHost::collect_data_graph_outputs(
-            graph_id,
-            &terminal_nodes,
-            &node_outputs,
-        );
-        outputs.extend(terminal_errors);
-        Ok(outputs)
+        result
     }
 }
+
+/// Run a data graph through `node_engine::DataGraphRun` on an executor that
+/// `configure` prepares with the runtime's extensions.
+pub(crate) async fn run_data_graph(
+    graph: &WorkflowGraph,
+    inputs: &HashMap<String, serde_json::Value>,
+    execution_id: String,
+    event_sink: Arc<dyn EventSink>,
+    task_executor: &dyn TaskExecutor,
+    configure: impl FnOnce(&mut WorkflowExecutor),
+) -> node_engine::Result<HashMap<String, serde_json::Value>> {
+    let run = DataGraphRun::prepare(graph, inputs)?;
+    let mut executor = WorkflowExecutor::new(execution_id, run.graph().clone(), event_sink);
+    configure(&mut executor);
+    run.execute(&executor, task_executor).await
+}
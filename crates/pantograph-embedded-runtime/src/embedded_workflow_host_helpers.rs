@@ -392,75 +392,6 @@ impl EmbeddedWorkflowHost {
         Ok(outputs)
     }
 
-    pub(crate) fn apply_data_graph_inputs(
-        graph: &mut WorkflowGraph,
-        inputs: &HashMap<String, serde_json::Value>,
-    ) {
-        for (port_name, value) in inputs {
-            for node in &mut graph.nodes {
-                if node.node_type == "text-input" && (port_name == "text" || port_name == "input") {
-                    if let Some(obj) = node.data.as_object_mut() {
-                        obj.insert("text".to_string(), value.clone());
-                    } else {
-                        node.data = serde_json::json!({ "text": value });
-                    }
-                }
-
-                if let Some(obj) = node.data.as_object_mut() {
-                    obj.insert(format!("_input_{}", port_name), value.clone());
-                }
-            }
-        }
-    }
-
-    pub(crate) fn terminal_data_graph_node_ids(graph: &WorkflowGraph) -> Vec<String> {
-        graph
-            .nodes
-            .iter()
-            .filter(|node| !graph.edges.iter().any(|edge| edge.source == node.id))
-            .map(|node| node.id.clone())
-            .collect()
-    }
-
-    pub(crate) fn collect_data_graph_outputs(
-        graph_id: &str,
-        terminal_nodes: &[String],
-        node_outputs: &HashMap<String, HashMap<String, serde_json::Value>>,
-    ) -> HashMap<String, serde_json::Value> {
-        let mut outputs = HashMap::new();
-
-        for terminal_id in terminal_nodes {
-            let Some(terminal_outputs) = node_outputs.get(terminal_id) else {
-                continue;
-            };
-
-            for (output_port, output_value) in terminal_outputs {
-                outputs.insert(
-                    format!("{}.{}", terminal_id, output_port),
-                    output_value.clone(),
-                );
-                outputs.insert(output_port.clone(), output_value.clone());
-            }
-        }
-
-        outputs.insert(
-            "_graph_id".to_string(),
-            serde_json::Value::String(graph_id.to_string()),
-        );
-        outputs.insert(
-            "_terminal_nodes".to_string(),
-            serde_json::Value::Array(
-                terminal_nodes
-                    .iter()
-                    .cloned()
-                    .map(serde_json::Value::String)
-                    .collect(),
-            ),
-        );
-
-        outputs
-    }
-
     pub(crate) fn fallback_runtime_unload_candidate(
         target: &WorkflowExecutionSessionRuntimeSelectionTarget,
         candidates: &[WorkflowExecutionSessionRuntimeUnloadCandidate],
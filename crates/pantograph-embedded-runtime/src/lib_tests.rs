@@ -29,6 +29,8 @@ use std::time::Duration;
 use tempfile::TempDir;
 use tokio::sync::{mpsc, RwLock};
 
+#[path = "lib_tests/data_graph_conformance_tests.rs"]
+mod data_graph_conformance_tests;
 #[path = "lib_tests/data_graph_execution_tests.rs"]
 mod data_graph_execution_tests;
 #[path = "lib_tests/edit_session_execution_tests.rs"]
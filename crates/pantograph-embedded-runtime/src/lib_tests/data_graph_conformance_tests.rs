@@ -0,0 +1,55 @@
+//! Runs the node-engine conformance vectors through the data-graph path the
+//! Tauri app uses, with the runtime's task executor replaced by the vector
+//! mocks.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use node_engine::conformance::{check_suite, ConformanceHarness, ConformanceObservation};
+use node_engine::{TaskExecutor, VecEventSink, WorkflowGraph};
+
+use crate::embedded_data_graph_execution::run_data_graph;
+
+/// Vectors the Tauri data-graph path does not satisfy yet.
+const KNOWN_DIVERGENCES: &[&str] = &[];
+
+struct TauriHarness;
+
+#[async_trait::async_trait]
+impl ConformanceHarness for TauriHarness {
+    fn host(&self) -> &str {
+        "tauri"
+    }
+
+    async fn run(
+        &self,
+        graph: &WorkflowGraph,
+        inputs: &HashMap<String, serde_json::Value>,
+        task_executor: Arc<dyn TaskExecutor>,
+    ) -> ConformanceObservation {
+        let sink = Arc::new(VecEventSink::new());
+        let result = run_data_graph(
+            graph,
+            inputs,
+            format!("conformance-{}", graph.id),
+            sink.clone(),
+            task_executor.as_ref(),
+            |_| {},
+        )
+        .await
+        .map_err(|error| error.kind().to_string());
+        let events = sink
+            .events()
+            .iter()
+            .map(|event| serde_json::to_value(event).expect("serialize workflow event"))
+            .collect();
+        ConformanceObservation { result, events }
+    }
+}
+
+#[tokio::test]
+async fn tauri_data_graph_path_matches_conformance_vectors() {
+    check_suite(&TauriHarness, KNOWN_DIVERGENCES)
+        .await
+        .unwrap_or_else(|report| panic!("{}", report));
+}
@@ -149,6 +149,7 @@ async fn execute_data_graph_propagates_waiting_for_input_without_synthetic_error
         }],
         edges: Vec::new(),
         groups: Vec::new(),
+        ports: node_engine::GraphPorts::default(),
     };
 
     let result = runtime
@@ -46,6 +46,7 @@ pub(super) fn runtime_diffusion_data_graph() -> node_engine::WorkflowGraph {
             },
         ],
         groups: Vec::new(),
+        ports: node_engine::GraphPorts::default(),
     }
 }
 
@@ -112,6 +113,7 @@ pub(super) fn multi_python_runtime_data_graph() -> node_engine::WorkflowGraph {
             },
         ],
         groups: Vec::new(),
+        ports: node_engine::GraphPorts::default(),
     }
 }
 
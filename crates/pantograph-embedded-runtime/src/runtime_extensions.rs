@@ -10,6 +10,8 @@ pub struct RuntimeExtensionsSnapshot {
     pub pumas_api: Option<Arc<pumas_library::PumasApi>>,
     pub kv_cache_store: Option<Arc<inference::kv_cache::KvCacheStore>>,
     pub dependency_resolver: Option<Arc<dyn node_engine::ModelDependencyResolver>>,
+    pub model_usage: Option<Arc<node_engine::ModelUsageLog>>,
+    pub benchmark_store: Option<Arc<dyn node_engine::ModelBenchmarkStore>>,
 }
 
 impl RuntimeExtensionsSnapshot {
@@ -33,6 +35,14 @@ impl RuntimeExtensionsSnapshot {
                     node_engine::extension_keys::MODEL_DEPENDENCY_RESOLVER,
                 )
                 .cloned(),
+            model_usage: shared
+                .get::<Arc<node_engine::ModelUsageLog>>(node_engine::extension_keys::MODEL_USAGE)
+                .cloned(),
+            benchmark_store: shared
+                .get::<Arc<dyn node_engine::ModelBenchmarkStore>>(
+                    node_engine::extension_keys::MODEL_BENCHMARK_STORE,
+                )
+                .cloned(),
         }
     }
 }
@@ -69,6 +79,17 @@ pub fn apply_runtime_extensions_for_execution(
             resolver.clone(),
         );
     }
+    if let Some(usage) = &snapshot.model_usage {
+        executor
+            .extensions_mut()
+            .set(node_engine::extension_keys::MODEL_USAGE, usage.clone());
+    }
+    if let Some(store) = &snapshot.benchmark_store {
+        executor.extensions_mut().set(
+            node_engine::extension_keys::MODEL_BENCHMARK_STORE,
+            store.clone(),
+        );
+    }
     if let Some(event_sink) = event_sink {
         executor.extensions_mut().set(
             crate::task_executor::runtime_extension_keys::EVENT_SINK,
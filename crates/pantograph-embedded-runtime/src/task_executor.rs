@@ -33,6 +33,7 @@ use crate::runtime_health::failed_runtime_health_assessment;
 ///
 /// Currently handles:
 /// - `rag-search`: requires an injected `RagBackend`
+/// - `model-convert`: llama.cpp quantization/conversion tools and library import
 /// - `pytorch-inference`: python sidecar execution
 /// - `diffusion-inference`: python sidecar execution
 /// - `audio-generation`: python sidecar execution
@@ -60,6 +61,7 @@ pub mod runtime_extension_keys {
 }
 
 mod dependency_environment;
+mod model_convert;
 mod puma_lib;
 mod python_execution;
 mod rag_search;
@@ -103,6 +105,10 @@ impl TaskExecutor for TauriTaskExecutor {
         match node_type.as_str() {
             "rag-search" => self.execute_rag_search(&inputs).await,
             "puma-lib" => self.execute_puma_lib(&inputs, extensions).await,
+            "model-convert" => {
+                self.execute_model_convert(task_id, &inputs, extensions)
+                    .await
+            }
             "dependency-environment" => {
                 self.execute_dependency_environment(&inputs, extensions)
                     .await
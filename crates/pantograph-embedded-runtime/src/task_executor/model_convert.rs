@@ -0,0 +1,572 @@
+use super::*;
+
+use std::collections::VecDeque;
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::Stdio;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+
+const ENV_LLAMA_QUANTIZE: &str = "PANTOGRAPH_LLAMA_QUANTIZE";
+const ENV_LLAMA_CONVERT_SCRIPT: &str = "PANTOGRAPH_LLAMA_CONVERT_SCRIPT";
+const DEFAULT_QUANTIZATION: &str = "Q4_K_M";
+/// Output types `convert_hf_to_gguf.py` writes directly. Other requested
+/// types are converted to `f16` first and then quantized.
+const CONVERT_OUTTYPES: &[&str] = &["f32", "f16", "bf16", "q8_0", "auto"];
+/// Types `llama-quantize` accepts. The requested type ends up in the output
+/// file name, so anything else is rejected before a path is built.
+const QUANTIZATION_TYPES: &[&str] = &[
+    "F32", "F16", "BF16", "Q8_0", "Q6_K", "Q5_K_M", "Q5_K_S", "Q5_1", "Q5_0", "Q4_K_M", "Q4_K_S",
+    "Q4_1", "Q4_0", "Q3_K_L", "Q3_K_M", "Q3_K_S", "Q2_K", "Q2_K_S", "IQ4_NL", "IQ4_XS", "IQ3_M",
+    "IQ3_S", "IQ3_XS", "IQ3_XXS", "IQ2_M", "IQ2_S", "IQ2_XS", "IQ2_XXS", "IQ1_M", "IQ1_S", "TQ1_0",
+    "TQ2_0",
+];
+const OUTPUT_TAIL_LINES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ModelConvertMode {
+    /// Requantize an existing GGUF with `llama-quantize`.
+    Quantize,
+    /// Convert a safetensors checkpoint with `convert_hf_to_gguf.py`.
+    Convert,
+}
+
+struct ConvertStage {
+    label: &'static str,
+    program: PathBuf,
+    args: Vec<OsString>,
+}
+
+/// A file a job writes before it is complete. It is removed when dropped, so
+/// failures and cancelled workflows (which drop the job future mid-stage)
+/// leave no partial output behind.
+struct ScratchFile(PathBuf);
+
+impl Drop for ScratchFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+impl TauriTaskExecutor {
+    /// Pick the conversion mode from the `mode` input, or from the source
+    /// when it is `auto`: GGUF files are quantized, anything else converted.
+    pub(super) fn resolve_model_convert_mode(
+        mode: Option<&str>,
+        source: &Path,
+    ) -> Result<ModelConvertMode> {
+        match mode
+            .map(|value| value.trim().to_ascii_lowercase())
+            .as_deref()
+        {
+            None | Some("") | Some("auto") => Ok(
+                if source.is_file()
+                    && source
+                        .extension()
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case("gguf"))
+                {
+                    ModelConvertMode::Quantize
+                } else {
+                    ModelConvertMode::Convert
+                },
+            ),
+            Some("quantize") => Ok(ModelConvertMode::Quantize),
+            Some("convert") => Ok(ModelConvertMode::Convert),
+            Some(other) => Err(NodeEngineError::ExecutionFailed(format!(
+                "Unknown model-convert mode '{}' (expected auto, quantize, or convert)",
+                other
+            ))),
+        }
+    }
+
+    /// Canonical upper-case quantization type for the `quantization` input.
+    ///
+    /// `AUTO` lets `convert_hf_to_gguf.py` pick, so it is only accepted when
+    /// converting.
+    pub(super) fn resolve_model_convert_quantization(
+        quantization: Option<&str>,
+        mode: ModelConvertMode,
+    ) -> Result<String> {
+        let quantization = quantization
+            .map(|value| value.trim().to_ascii_uppercase())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| DEFAULT_QUANTIZATION.to_string());
+        if QUANTIZATION_TYPES.contains(&quantization.as_str())
+            || (mode == ModelConvertMode::Convert && quantization == "AUTO")
+        {
+            return Ok(quantization);
+        }
+        Err(NodeEngineError::ExecutionFailed(format!(
+            "Unsupported model-convert quantization '{}' (expected one of {})",
+            quantization,
+            QUANTIZATION_TYPES.join(", ")
+        )))
+    }
+
+    /// Fraction complete reported by a llama.cpp conversion tool output line.
+    ///
+    /// `llama-quantize` prints one `[  12/ 291] tensor ...` line per tensor and
+    /// `convert_hf_to_gguf.py` prints tqdm bars such as `Writing:  45%|...`.
+    pub(super) fn parse_model_convert_progress(line: &str) -> Option<f32> {
+        let line = line.trim();
+        if let Some((counts, _)) = line.strip_prefix('[').and_then(|rest| rest.split_once(']')) {
+            if let Some((done, total)) = counts.split_once('/') {
+                if let (Ok(done), Ok(total)) =
+                    (done.trim().parse::<f32>(), total.trim().parse::<f32>())
+                {
+                    if total > 0.0 {
+                        return Some((done / total).clamp(0.0, 1.0));
+                    }
+                }
+            }
+        }
+
+        let (before, _) = line.split_once('%')?;
+        let start = before
+            .char_indices()
+            .rev()
+            .take_while(|(_, ch)| ch.is_ascii_digit() || *ch == '.')
+            .last()
+            .map_or(before.len(), |(index, _)| index);
+        let percent = before[start..].parse::<f32>().ok()?;
+        Some((percent / 100.0).clamp(0.0, 1.0))
+    }
+
+    /// Library models are often stored as directories; quantize the GGUF
+    /// inside one when there is one.
+    pub(super) fn resolve_model_convert_source(source: PathBuf) -> PathBuf {
+        if !source.is_dir() {
+            return source;
+        }
+        let mut ggufs: Vec<PathBuf> = std::fs::read_dir(&source)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .extension()
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case("gguf"))
+                    && !node_engine::mmproj::is_mmproj_file(path)
+            })
+            .collect();
+        ggufs.sort();
+        ggufs.into_iter().next().unwrap_or(source)
+    }
+
+    fn resolve_model_convert_tool(
+        explicit: Option<&str>,
+        env_key: &str,
+        command: &str,
+    ) -> Result<PathBuf> {
+        let configured = explicit
+            .map(str::to_string)
+            .or_else(|| std::env::var(env_key).ok())
+            .filter(|value| !value.trim().is_empty());
+        if let Some(configured) = configured {
+            let path = PathBuf::from(configured.trim());
+            if path.exists() {
+                return Ok(path);
+            }
+            return which::which(configured.trim()).map_err(|_| {
+                NodeEngineError::ExecutionFailed(format!(
+                    "Configured {} was not found: {}",
+                    command,
+                    path.display()
+                ))
+            });
+        }
+
+        which::which(command).map_err(|_| {
+            NodeEngineError::ExecutionFailed(format!(
+                "{} was not found. Set {} or add it to PATH",
+                command, env_key
+            ))
+        })
+    }
+
+    fn quantize_stage(
+        inputs: &HashMap<String, serde_json::Value>,
+        source: &Path,
+        output: &Path,
+        quantization: &str,
+    ) -> Result<ConvertStage> {
+        let program = Self::resolve_model_convert_tool(
+            Self::read_optional_input_string_aliases(
+                inputs,
+                &["quantize_tool_path", "quantizeToolPath"],
+            )
+            .as_deref(),
+            ENV_LLAMA_QUANTIZE,
+            "llama-quantize",
+        )?;
+        Ok(ConvertStage {
+            label: "Quantizing",
+            program,
+            args: vec![source.into(), output.into(), quantization.into()],
+        })
+    }
+
+    fn convert_stage(
+        inputs: &HashMap<String, serde_json::Value>,
+        source_dir: &Path,
+        output: &Path,
+        outtype: &str,
+    ) -> Result<ConvertStage> {
+        let script = Self::resolve_model_convert_tool(
+            Self::read_optional_input_string_aliases(
+                inputs,
+                &["convert_script_path", "convertScriptPath"],
+            )
+            .as_deref(),
+            ENV_LLAMA_CONVERT_SCRIPT,
+            "convert_hf_to_gguf.py",
+        )?;
+        let python = crate::python_runtime::resolve_python_executable_for_env_ids(&[])
+            .map_err(NodeEngineError::ExecutionFailed)?;
+        Ok(ConvertStage {
+            label: "Converting to GGUF",
+            program: python,
+            args: vec![
+                script.into(),
+                source_dir.into(),
+                "--outfile".into(),
+                output.into(),
+                "--outtype".into(),
+                outtype.into(),
+            ],
+        })
+    }
+
+    /// Run one tool invocation, forwarding its progress as task progress
+    /// scaled into the stage's share of the whole job.
+    ///
+    /// The child is killed if the workflow drops this future.
+    async fn run_model_convert_stage(
+        task_id: &str,
+        stage: &ConvertStage,
+        stage_index: usize,
+        stage_count: usize,
+        extensions: &ExecutorExtensions,
+    ) -> Result<()> {
+        let mut child = Command::new(&stage.program)
+            .args(&stage.args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| {
+                NodeEngineError::ExecutionFailed(format!(
+                    "Failed to start {}: {}",
+                    stage.program.display(),
+                    e
+                ))
+            })?;
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        if let Some(stdout) = child.stdout.take() {
+            tokio::spawn(forward_output_lines(stdout, tx.clone()));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            tokio::spawn(forward_output_lines(stderr, tx));
+        }
+
+        let stream_target = Self::resolve_stream_target(extensions);
+        let mut tail = VecDeque::with_capacity(OUTPUT_TAIL_LINES);
+        let mut last_reported = -1.0_f32;
+        while let Some(line) = rx.recv().await {
+            if let Some(fraction) = Self::parse_model_convert_progress(&line) {
+                let progress = (stage_index as f32 + fraction) / stage_count as f32;
+                if progress - last_reported >= 0.01 {
+                    last_reported = progress;
+                    if let Some((sink, execution_id)) = &stream_target {
+                        let _ = sink.send(WorkflowEvent::task_progress(
+                            task_id,
+                            execution_id,
+                            progress,
+                            Some(stage.label.to_string()),
+                        ));
+                    }
+                }
+            }
+            if tail.len() == OUTPUT_TAIL_LINES {
+                tail.pop_front();
+            }
+            tail.push_back(line);
+        }
+
+        let status = child.wait().await.map_err(|e| {
+            NodeEngineError::ExecutionFailed(format!(
+                "Failed to wait for {}: {}",
+                stage.program.display(),
+                e
+            ))
+        })?;
+        if !status.success() {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "{} failed ({}): {}",
+                stage.program.display(),
+                status,
+                Vec::from(tail).join("\n")
+            )));
+        }
+        Ok(())
+    }
+
+    async fn import_converted_model(
+        api: &pumas_library::PumasApi,
+        output: &Path,
+        official_name: &str,
+        family: &str,
+    ) -> Result<(Option<String>, Option<String>)> {
+        let spec: pumas_library::model_library::ModelImportSpec =
+            serde_json::from_value(serde_json::json!({
+                "path": output.display().to_string(),
+                "family": family,
+                "official_name": official_name,
+            }))
+            .map_err(|e| {
+                NodeEngineError::ExecutionFailed(format!("Invalid model import spec: {}", e))
+            })?;
+        let result = api.import_model(&spec).await.map_err(|e| {
+            NodeEngineError::ExecutionFailed(format!(
+                "Failed to import converted model '{}': {}",
+                output.display(),
+                e
+            ))
+        })?;
+
+        let result = serde_json::to_value(&result).unwrap_or_default();
+        if result.get("success").and_then(|v| v.as_bool()) == Some(false) {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Failed to import converted model '{}': {}",
+                output.display(),
+                result
+                    .get("error")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("import was not successful")
+            )));
+        }
+        let field = |keys: &[&str]| {
+            keys.iter().find_map(|key| {
+                result
+                    .get(*key)
+                    .and_then(|v| v.as_str())
+                    .filter(|v| !v.trim().is_empty())
+                    .map(str::to_string)
+            })
+        };
+        Ok((
+            field(&["model_id", "modelId", "id"]),
+            field(&["model_path", "modelPath", "path"]),
+        ))
+    }
+
+    pub(super) async fn execute_model_convert(
+        &self,
+        task_id: &str,
+        inputs: &HashMap<String, serde_json::Value>,
+        extensions: &ExecutorExtensions,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        let source = Self::read_optional_input_string_aliases(inputs, &["model_path", "modelPath"])
+            .filter(|value| !value.trim().is_empty())
+            .map(PathBuf::from)
+            .ok_or_else(|| {
+                NodeEngineError::ExecutionFailed(
+                    "model-convert requires a model_path input".to_string(),
+                )
+            })?;
+        if !source.exists() {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Model path does not exist: {}",
+                source.display()
+            )));
+        }
+        let source = Self::resolve_model_convert_source(source);
+        let mode = Self::resolve_model_convert_mode(
+            Self::read_optional_input_string(inputs, "mode").as_deref(),
+            &source,
+        )?;
+        let quantization = Self::resolve_model_convert_quantization(
+            Self::read_optional_input_string(inputs, "quantization").as_deref(),
+            mode,
+        )?;
+
+        // convert_hf_to_gguf.py takes the checkpoint directory.
+        let source_dir = if mode == ModelConvertMode::Convert && source.is_file() {
+            source.parent().unwrap_or(Path::new(".")).to_path_buf()
+        } else {
+            source.clone()
+        };
+        let base_name = match mode {
+            ModelConvertMode::Quantize => source.file_stem(),
+            ModelConvertMode::Convert => source_dir.file_name(),
+        }
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "model".to_string());
+        let output_dir =
+            Self::read_optional_input_string_aliases(inputs, &["output_dir", "outputDir"])
+                .filter(|value| !value.trim().is_empty())
+                .map(PathBuf::from)
+                .unwrap_or_else(|| {
+                    source_dir
+                        .parent()
+                        .filter(|_| mode == ModelConvertMode::Convert)
+                        .or_else(|| source.parent())
+                        .unwrap_or(Path::new("."))
+                        .to_path_buf()
+                });
+        std::fs::create_dir_all(&output_dir).map_err(|e| {
+            NodeEngineError::ExecutionFailed(format!(
+                "Failed to create output directory {}: {}",
+                output_dir.display(),
+                e
+            ))
+        })?;
+        let output = output_dir.join(format!("{}-{}.gguf", base_name, quantization));
+
+        // Tools write to job-unique scratch files, and only a finished
+        // result replaces `output`, so a failed or concurrent re-run never
+        // touches an earlier good conversion.
+        let job_id = uuid::Uuid::new_v4().simple().to_string();
+        let scratch = |label: &str| {
+            ScratchFile(output_dir.join(format!(
+                "{}-{}.{}.convert-tmp.gguf",
+                base_name, label, job_id
+            )))
+        };
+        let staged = scratch(quantization.as_str());
+        let mut intermediate = None;
+        let stages = match mode {
+            ModelConvertMode::Quantize => {
+                vec![Self::quantize_stage(
+                    inputs,
+                    &source,
+                    &staged.0,
+                    &quantization,
+                )?]
+            }
+            ModelConvertMode::Convert
+                if CONVERT_OUTTYPES.contains(&quantization.to_ascii_lowercase().as_str()) =>
+            {
+                vec![Self::convert_stage(
+                    inputs,
+                    &source_dir,
+                    &staged.0,
+                    &quantization.to_ascii_lowercase(),
+                )?]
+            }
+            ModelConvertMode::Convert => {
+                let f16 = intermediate.insert(scratch("F16"));
+                vec![
+                    Self::convert_stage(inputs, &source_dir, &f16.0, "f16")?,
+                    Self::quantize_stage(inputs, &f16.0, &staged.0, &quantization)?,
+                ]
+            }
+        };
+
+        for (index, stage) in stages.iter().enumerate() {
+            Self::run_model_convert_stage(task_id, stage, index, stages.len(), extensions).await?;
+        }
+        drop(intermediate);
+        if !staged.0.is_file() {
+            return Err(NodeEngineError::ExecutionFailed(format!(
+                "Conversion finished without writing {}",
+                output.display()
+            )));
+        }
+        std::fs::rename(&staged.0, &output).map_err(|e| {
+            NodeEngineError::ExecutionFailed(format!(
+                "Failed to move converted model to {}: {}",
+                output.display(),
+                e
+            ))
+        })?;
+
+        let mut model_path = output.display().to_string();
+        let mut model_id = None;
+        let import = Self::read_optional_input_value_aliases(
+            inputs,
+            &["import_to_library", "importToLibrary"],
+        )
+        .and_then(|value| value.as_bool())
+        .unwrap_or(true);
+        if let (true, Some(api)) = (
+            import,
+            extensions.get::<Arc<pumas_library::PumasApi>>(extension_keys::PUMAS_API),
+        ) {
+            let source_model =
+                match Self::read_optional_input_string_aliases(inputs, &["model_id", "modelId"]) {
+                    Some(id) => api.get_model(&id).await.ok().flatten(),
+                    None => None,
+                };
+            let official_name =
+                Self::read_optional_input_string_aliases(inputs, &["model_name", "modelName"])
+                    .filter(|value| !value.trim().is_empty())
+                    .or_else(|| {
+                        source_model
+                            .as_ref()
+                            .map(|model| format!("{} {}", model.official_name, quantization))
+                    })
+                    .unwrap_or_else(|| format!("{}-{}", base_name, quantization));
+            let family = Self::read_optional_input_string(inputs, "family")
+                .filter(|value| !value.trim().is_empty())
+                .or_else(|| {
+                    source_model
+                        .as_ref()
+                        .and_then(|model| model.metadata.as_object())
+                        .and_then(|metadata| Self::puma_lib_metadata_string(metadata, &["family"]))
+                })
+                .unwrap_or_else(|| "converted".to_string());
+
+            let (imported_id, imported_path) =
+                Self::import_converted_model(api, &output, &official_name, &family).await?;
+            model_id = imported_id;
+            if let Some(path) = imported_path {
+                model_path = path;
+            }
+        }
+
+        let mut outputs = HashMap::new();
+        outputs.insert("model_path".to_string(), serde_json::json!(model_path));
+        outputs.insert(
+            "output_path".to_string(),
+            serde_json::json!(output.display().to_string()),
+        );
+        Self::insert_puma_lib_output_string(&mut outputs, "model_id", model_id);
+        Ok(outputs)
+    }
+}
+
+/// Send each line a tool writes, splitting on `\r` as well so tqdm progress
+/// redraws arrive as separate lines.
+async fn forward_output_lines<R>(mut reader: R, tx: mpsc::UnboundedSender<String>)
+where
+    R: AsyncRead + Unpin,
+{
+    let mut pending = Vec::new();
+    let mut buf = [0_u8; 4096];
+    loop {
+        let read = match reader.read(&mut buf).await {
+            Ok(0) | Err(_) => break,
+            Ok(read) => read,
+        };
+        for byte in &buf[..read] {
+            if *byte == b'\n' || *byte == b'\r' {
+                if !pending.is_empty() {
+                    let _ = tx.send(String::from_utf8_lossy(&pending).into_owned());
+                    pending.clear();
+                }
+            } else {
+                pending.push(*byte);
+            }
+        }
+    }
+    if !pending.is_empty() {
+        let _ = tx.send(String::from_utf8_lossy(&pending).into_owned());
+    }
+}
@@ -116,6 +116,9 @@ impl TauriTaskExecutor {
             inputs,
             &["recommended_backend", "recommendedBackend"],
         );
+        let mut mmproj_path =
+            Self::read_optional_input_string_aliases(inputs, &["mmproj_path", "mmprojPath"])
+                .filter(|value| !value.trim().is_empty());
 
         if let Some(api) = extensions.get::<Arc<pumas_library::PumasApi>>(extension_keys::PUMAS_API)
         {
@@ -171,6 +174,13 @@ impl TauriTaskExecutor {
                                 &["recommended_backend", "recommendedBackend"],
                             );
                         }
+
+                        if mmproj_path.is_none() {
+                            mmproj_path = Self::puma_lib_metadata_string(
+                                metadata,
+                                &["mmproj_path", "mmprojPath"],
+                            );
+                        }
                     }
 
                     match api.resolve_model_execution_descriptor(&model.id).await {
@@ -217,6 +227,15 @@ impl TauriTaskExecutor {
             }
         }
 
+        // Vision models need their projector; fall back to the naming
+        // convention next to the resolved model file.
+        let mmproj_path = mmproj_path.or_else(|| {
+            Some(model_path.as_str())
+                .filter(|path| !path.trim().is_empty())
+                .and_then(|path| node_engine::find_mmproj(std::path::Path::new(path)))
+                .map(|path| path.to_string_lossy().into_owned())
+        });
+
         let inference_settings = Self::read_optional_input_value_aliases(
             inputs,
             &["inference_settings", "inferenceSettings"],
@@ -236,6 +255,7 @@ impl TauriTaskExecutor {
             "recommended_backend",
             recommended_backend,
         );
+        Self::insert_puma_lib_output_string(&mut outputs, "mmproj_path", mmproj_path);
 
         if let Some(selected_binding_ids) = Self::read_optional_input_value_aliases(
             inputs,
@@ -281,6 +301,13 @@ impl TauriTaskExecutor {
             ),
         );
 
+        if let (Some(usage), Some(model_id)) = (
+            extensions.get::<Arc<node_engine::ModelUsageLog>>(extension_keys::MODEL_USAGE),
+            outputs.get("model_id").and_then(|value| value.as_str()),
+        ) {
+            usage.record_use(model_id);
+        }
+
         log::debug!("PumaLib: providing model path '{}'", model_path);
         Ok(outputs)
     }
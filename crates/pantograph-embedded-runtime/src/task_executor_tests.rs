@@ -210,6 +210,8 @@ mod dependency_fallback;
 mod dependency_preflight;
 #[path = "task_executor_tests/input_helpers.rs"]
 mod input_helpers;
+#[path = "task_executor_tests/model_convert.rs"]
+mod model_convert;
 #[path = "task_executor_tests/puma_lib.rs"]
 mod puma_lib;
 #[path = "task_executor_tests/recorder_stream.rs"]
@@ -0,0 +1,208 @@
+use super::*;
+use crate::task_executor::model_convert::ModelConvertMode;
+
+#[test]
+fn model_convert_progress_parses_quantize_and_tqdm_output() {
+    assert_eq!(
+        TauriTaskExecutor::parse_model_convert_progress(
+            "[  97/ 388]   blk.10.attn_k.weight - [ 4096,  1024], type =   bf16, converting to q4_K"
+        ),
+        Some(0.25)
+    );
+    assert_eq!(
+        TauriTaskExecutor::parse_model_convert_progress(
+            "Writing:  45%|████▌     | 3.20G/7.11G [00:12<00:15, 250Mbyte/s]"
+        ),
+        Some(0.45)
+    );
+    assert_eq!(
+        TauriTaskExecutor::parse_model_convert_progress("INFO:hf-to-gguf:Loading model"),
+        None
+    );
+}
+
+#[test]
+fn model_convert_mode_follows_the_source_format() {
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let gguf = temp_dir.path().join("model-F16.gguf");
+    std::fs::write(&gguf, b"gguf").expect("write gguf");
+
+    assert_eq!(
+        TauriTaskExecutor::resolve_model_convert_mode(None, &gguf).unwrap(),
+        ModelConvertMode::Quantize
+    );
+    assert_eq!(
+        TauriTaskExecutor::resolve_model_convert_mode(Some("auto"), temp_dir.path()).unwrap(),
+        ModelConvertMode::Convert
+    );
+    assert_eq!(
+        TauriTaskExecutor::resolve_model_convert_source(temp_dir.path().to_path_buf()),
+        gguf
+    );
+    assert!(TauriTaskExecutor::resolve_model_convert_mode(Some("merge"), &gguf).is_err());
+}
+
+#[test]
+fn model_convert_quantization_is_checked_against_llama_quantize_types() {
+    assert_eq!(
+        TauriTaskExecutor::resolve_model_convert_quantization(None, ModelConvertMode::Quantize)
+            .unwrap(),
+        "Q4_K_M"
+    );
+    assert_eq!(
+        TauriTaskExecutor::resolve_model_convert_quantization(
+            Some(" q5_k_s "),
+            ModelConvertMode::Quantize
+        )
+        .unwrap(),
+        "Q5_K_S"
+    );
+    assert_eq!(
+        TauriTaskExecutor::resolve_model_convert_quantization(
+            Some("auto"),
+            ModelConvertMode::Convert
+        )
+        .unwrap(),
+        "AUTO"
+    );
+    for rejected in ["auto", "../Q4_K_M", "Q4_K_M/../../x", "q9"] {
+        assert!(
+            TauriTaskExecutor::resolve_model_convert_quantization(
+                Some(rejected),
+                ModelConvertMode::Quantize
+            )
+            .is_err(),
+            "{rejected:?} should be rejected"
+        );
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn model_convert_failure_keeps_an_earlier_output() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let adapter: Arc<dyn PythonRuntimeAdapter> = Arc::new(RecordingPythonAdapter {
+        requests: Arc::new(Mutex::new(Vec::new())),
+        response: HashMap::new(),
+    });
+    let resolver: Arc<dyn ModelDependencyResolver> = Arc::new(StubDependencyResolver {
+        requirements: make_requirements(DependencyValidationState::Resolved),
+        status: make_status(DependencyState::Ready, None),
+        model_ref: None,
+    });
+    let (executor, extensions) = test_executor(adapter, resolver);
+
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let source = temp_dir.path().join("tiny-F16.gguf");
+    std::fs::write(&source, b"gguf").expect("write source");
+    let existing = temp_dir.path().join("tiny-F16-Q4_K_M.gguf");
+    std::fs::write(&existing, b"earlier conversion").expect("write existing output");
+    let tool = temp_dir.path().join("llama-quantize");
+    std::fs::write(
+        &tool,
+        "#!/bin/sh
+echo partial > \"$2\"
+exit 1
+",
+    )
+    .expect("write tool");
+    std::fs::set_permissions(&tool, std::fs::Permissions::from_mode(0o755)).expect("chmod tool");
+
+    let mut inputs = HashMap::new();
+    inputs.insert(
+        "model_path".to_string(),
+        serde_json::json!(source.display().to_string()),
+    );
+    inputs.insert(
+        "_data".to_string(),
+        serde_json::json!({ "quantize_tool_path": tool.display().to_string() }),
+    );
+
+    let result = executor
+        .execute_task("model-convert-1", inputs, &Context::new(), &extensions)
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        std::fs::read(&existing).expect("existing output"),
+        b"earlier conversion"
+    );
+    let scratch: Vec<_> = std::fs::read_dir(temp_dir.path())
+        .expect("read dir")
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .contains(".convert-tmp.")
+        })
+        .collect();
+    assert!(scratch.is_empty(), "scratch files left: {scratch:?}");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn model_convert_runs_quantize_tool_with_progress_events() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let adapter: Arc<dyn PythonRuntimeAdapter> = Arc::new(RecordingPythonAdapter {
+        requests: Arc::new(Mutex::new(Vec::new())),
+        response: HashMap::new(),
+    });
+    let resolver: Arc<dyn ModelDependencyResolver> = Arc::new(StubDependencyResolver {
+        requirements: make_requirements(DependencyValidationState::Resolved),
+        status: make_status(DependencyState::Ready, None),
+        model_ref: None,
+    });
+    let (executor, mut extensions) = test_executor(adapter, resolver);
+    let sink = Arc::new(VecEventSink::new());
+    extensions.set(
+        runtime_extension_keys::EVENT_SINK,
+        sink.clone() as Arc<dyn node_engine::EventSink>,
+    );
+    extensions.set(runtime_extension_keys::EXECUTION_ID, "exec-1".to_string());
+
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let source = temp_dir.path().join("tiny-F16.gguf");
+    std::fs::write(&source, b"gguf").expect("write source");
+    let tool = temp_dir.path().join("llama-quantize");
+    std::fs::write(
+        &tool,
+        "#!/bin/sh\necho '[   1/   2] a'\necho '[   2/   2] b'\ncp \"$1\" \"$2\"\n",
+    )
+    .expect("write tool");
+    std::fs::set_permissions(&tool, std::fs::Permissions::from_mode(0o755)).expect("chmod tool");
+
+    let mut inputs = HashMap::new();
+    inputs.insert(
+        "model_path".to_string(),
+        serde_json::json!(source.display().to_string()),
+    );
+    inputs.insert("quantization".to_string(), serde_json::json!("q4_k_m"));
+    inputs.insert(
+        "_data".to_string(),
+        serde_json::json!({ "quantize_tool_path": tool.display().to_string() }),
+    );
+
+    let outputs = executor
+        .execute_task("model-convert-1", inputs, &Context::new(), &extensions)
+        .await
+        .expect("model-convert should execute");
+
+    let expected = temp_dir.path().join("tiny-F16-Q4_K_M.gguf");
+    assert!(expected.is_file());
+    assert_eq!(
+        outputs.get("model_path"),
+        Some(&serde_json::json!(expected.display().to_string()))
+    );
+    let progress: Vec<f32> = sink
+        .events()
+        .into_iter()
+        .filter_map(|event| match event {
+            WorkflowEvent::TaskProgress { progress, .. } => Some(progress),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(progress, vec![0.5, 1.0]);
+}
@@ -120,3 +120,71 @@ async fn puma_lib_execution_resolves_saved_model_name_without_path_or_id() {
         Some(&serde_json::json!("text-to-image"))
     );
 }
+
+#[tokio::test]
+async fn puma_lib_execution_pairs_mmproj_beside_gguf_model() {
+    let adapter: Arc<dyn PythonRuntimeAdapter> = Arc::new(RecordingPythonAdapter {
+        requests: Arc::new(Mutex::new(Vec::new())),
+        response: HashMap::new(),
+    });
+    let resolver: Arc<dyn ModelDependencyResolver> = Arc::new(StubDependencyResolver {
+        requirements: make_requirements(DependencyValidationState::Resolved),
+        status: make_status(DependencyState::Ready, None),
+        model_ref: None,
+    });
+    let (executor, extensions) = test_executor(adapter, resolver);
+
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let model = temp_dir.path().join("qwen2-vl-7b-q4_k_m.gguf");
+    let mmproj = temp_dir.path().join("mmproj-qwen2-vl-7b-f16.gguf");
+    std::fs::write(&model, b"gguf").expect("write model");
+    std::fs::write(&mmproj, b"gguf").expect("write mmproj");
+
+    let mut inputs = HashMap::new();
+    inputs.insert(
+        "_data".to_string(),
+        serde_json::json!({ "modelPath": model.display().to_string() }),
+    );
+
+    let outputs = executor
+        .execute_task("puma-lib-1", inputs, &Context::new(), &extensions)
+        .await
+        .expect("puma-lib should execute");
+
+    assert_eq!(
+        outputs.get("mmproj_path"),
+        Some(&serde_json::json!(mmproj.display().to_string()))
+    );
+}
+
+#[tokio::test]
+async fn puma_lib_execution_records_model_usage() {
+    let adapter: Arc<dyn PythonRuntimeAdapter> = Arc::new(RecordingPythonAdapter {
+        requests: Arc::new(Mutex::new(Vec::new())),
+        response: HashMap::new(),
+    });
+    let resolver: Arc<dyn ModelDependencyResolver> = Arc::new(StubDependencyResolver {
+        requirements: make_requirements(DependencyValidationState::Resolved),
+        status: make_status(DependencyState::Ready, None),
+        model_ref: None,
+    });
+    let (executor, mut extensions) = test_executor(adapter, resolver);
+    let usage = Arc::new(node_engine::ModelUsageLog::new());
+    extensions.set(extension_keys::MODEL_USAGE, usage.clone());
+
+    let mut inputs = HashMap::new();
+    inputs.insert(
+        "_data".to_string(),
+        serde_json::json!({
+            "modelPath": "/models/llm/a.gguf",
+            "model_id": "llm/a",
+        }),
+    );
+
+    executor
+        .execute_task("puma-lib-1", inputs, &Context::new(), &extensions)
+        .await
+        .expect("puma-lib should execute");
+
+    assert_eq!(usage.entry("llm/a").map(|entry| entry.use_count), Some(1));
+}
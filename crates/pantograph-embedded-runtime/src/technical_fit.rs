@@ -98,6 +98,7 @@ fn runtime_capability_candidates(
             residency_state: Some(runtime_capability_residency_state(capability)),
             warmup_state: runtime_capability_warmup_state(capability),
             supports_runtime_requirements: runtime_capability_is_ready(capability),
+            benchmark_tokens_per_second: None,
         })
         .collect()
 }
@@ -185,6 +186,9 @@ fn project_reason_code(
         RuntimeTechnicalFitReasonCode::QueuePressure => {
             WorkflowTechnicalFitReasonCode::QueuePressure
         }
+        RuntimeTechnicalFitReasonCode::MeasuredThroughput => {
+            WorkflowTechnicalFitReasonCode::MeasuredThroughput
+        }
         RuntimeTechnicalFitReasonCode::MissingCandidateData => {
             WorkflowTechnicalFitReasonCode::MissingCandidateData
         }
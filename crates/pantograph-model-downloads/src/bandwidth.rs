@@ -0,0 +1,116 @@
+//! Process-wide bandwidth cap shared by every active download
+//!
+//! A token bucket refilled at the configured rate and holding at most one
+//! second of bytes. Transfers call [`BandwidthLimiter::acquire`] for each chunk
+//! they receive; a chunk that overdraws the bucket sleeps off the deficit
+//! while holding the bucket, so concurrent transfers queue behind it instead
+//! of each spending the same refill. The limiter runs on wall time because it
+//! paces real sockets.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Token-bucket limiter for downloaded bytes.
+#[derive(Debug)]
+pub struct BandwidthLimiter {
+    /// Bytes per second; zero means unlimited.
+    bytes_per_sec: AtomicU64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    available: f64,
+    refilled_at: Instant,
+}
+
+impl Default for BandwidthLimiter {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl BandwidthLimiter {
+    /// Create a limiter capped at `bytes_per_sec`, or unlimited for `None`.
+    pub fn new(bytes_per_sec: Option<u64>) -> Self {
+        let rate = bytes_per_sec.unwrap_or(0);
+        Self {
+            bytes_per_sec: AtomicU64::new(rate),
+            bucket: Mutex::new(Bucket {
+                available: rate as f64,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Current cap in bytes per second, `None` when unlimited.
+    pub fn limit(&self) -> Option<u64> {
+        match self.bytes_per_sec.load(Ordering::Relaxed) {
+            0 => None,
+            rate => Some(rate),
+        }
+    }
+
+    /// Change the cap; takes effect from the next acquired chunk.
+    pub fn set_limit(&self, bytes_per_sec: Option<u64>) {
+        self.bytes_per_sec
+            .store(bytes_per_sec.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    /// Account for `bytes` just received, waiting if they exceed the cap.
+    pub async fn acquire(&self, bytes: u64) {
+        let Some(rate) = self.limit() else {
+            return;
+        };
+        let rate = rate as f64;
+        let mut bucket = self.bucket.lock().await;
+        let now = Instant::now();
+        let refill = now.duration_since(bucket.refilled_at).as_secs_f64() * rate;
+        bucket.available = (bucket.available + refill).min(rate);
+        bucket.refilled_at = now;
+        bucket.available -= bytes as f64;
+        if bucket.available < 0.0 {
+            tokio::time::sleep(Duration::from_secs_f64(-bucket.available / rate)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn unlimited_limiter_never_waits() {
+        let limiter = BandwidthLimiter::default();
+        let started = Instant::now();
+        limiter.acquire(u64::MAX / 2).await;
+        assert_eq!(started.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn limiter_allows_one_second_burst_then_paces() {
+        let limiter = BandwidthLimiter::new(Some(1_000));
+        let started = Instant::now();
+        limiter.acquire(1_000).await;
+        assert_eq!(started.elapsed(), Duration::ZERO);
+
+        limiter.acquire(2_000).await;
+        assert_eq!(started.elapsed(), Duration::from_secs(2));
+
+        limiter.acquire(500).await;
+        assert_eq!(started.elapsed(), Duration::from_millis(2_500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn lifting_the_limit_stops_pacing() {
+        let limiter = BandwidthLimiter::new(Some(10));
+        limiter.set_limit(None);
+        let started = Instant::now();
+        limiter.acquire(1_000).await;
+        assert_eq!(started.elapsed(), Duration::ZERO);
+        assert_eq!(limiter.limit(), None);
+    }
+}
@@ -0,0 +1,137 @@
+//! Civitai source: resolves a model version into file transfers
+//!
+//! Version metadata comes from the public REST API, which lists each file's
+//! download URL and SHA256. Without `filename`/`filenames` the version's
+//! primary file is downloaded.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::{DownloadError, Result};
+use crate::request::ModelDownloadRequest;
+use crate::source::{relative_file_path, Resolved};
+use crate::transfer::FileTransfer;
+
+/// Public Civitai endpoint.
+pub const DEFAULT_CIVITAI_ENDPOINT: &str = "https://civitai.com";
+
+#[derive(Debug, Deserialize)]
+struct ModelVersion {
+    model: VersionModel,
+    #[serde(default)]
+    files: Vec<VersionFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VersionModel {
+    name: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct VersionFile {
+    name: String,
+    #[serde(rename = "downloadUrl")]
+    download_url: String,
+    #[serde(default)]
+    primary: bool,
+    #[serde(default)]
+    hashes: HashMap<String, String>,
+}
+
+fn version_id(request: &ModelDownloadRequest) -> Result<u64> {
+    request.civitai_model_version_id.ok_or_else(|| {
+        DownloadError::InvalidRequest(
+            "Civitai downloads need 'civitai_model_version_id'".to_string(),
+        )
+    })
+}
+
+/// Directory under `staging_root` that holds files for this model version.
+pub(crate) fn staging_dir(staging_root: &Path, request: &ModelDownloadRequest) -> Result<PathBuf> {
+    let id = version_id(request)?;
+    for name in request.requested_files() {
+        relative_file_path(&name)?;
+    }
+    Ok(staging_root.join("civitai").join(id.to_string()))
+}
+
+/// List the requested files of a model version as transfers into `dest_dir`.
+pub(crate) async fn resolve(
+    client: &reqwest::Client,
+    endpoint: &str,
+    token: Option<&str>,
+    request: &ModelDownloadRequest,
+    dest_dir: &Path,
+) -> Result<Resolved> {
+    let id = version_id(request)?;
+    let mut call = client.get(format!(
+        "{}/api/v1/model-versions/{}",
+        endpoint.trim_end_matches('/'),
+        id
+    ));
+    if let Some(token) = token {
+        call = call.bearer_auth(token);
+    }
+    let response = call.send().await?;
+    if !response.status().is_success() {
+        return Err(DownloadError::Http(format!(
+            "Civitai model version {} returned {}",
+            id,
+            response.status()
+        )));
+    }
+    let version: ModelVersion = response.json().await?;
+
+    let wanted = request.requested_files();
+    if let Some(missing) = wanted
+        .iter()
+        .find(|name| !version.files.iter().any(|file| &file.name == *name))
+    {
+        return Err(DownloadError::InvalidRequest(format!(
+            "Civitai model version {} has no file '{}'",
+            id, missing
+        )));
+    }
+    let selected: Vec<&VersionFile> = if wanted.is_empty() {
+        version
+            .files
+            .iter()
+            .find(|file| file.primary)
+            .or_else(|| version.files.first())
+            .into_iter()
+            .collect()
+    } else {
+        version
+            .files
+            .iter()
+            .filter(|file| wanted.contains(&file.name))
+            .collect()
+    };
+
+    let transfers = selected
+        .into_iter()
+        .map(|file| {
+            Ok(FileTransfer {
+                name: file.name.clone(),
+                url: file.download_url.clone(),
+                dest: dest_dir.join(relative_file_path(&file.name)?),
+                expected_sha256: file
+                    .hashes
+                    .iter()
+                    .find(|(algorithm, _)| algorithm.eq_ignore_ascii_case("sha256"))
+                    .map(|(_, digest)| digest.to_ascii_lowercase()),
+                expected_size: None,
+                bearer_token: token.map(str::to_string),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Resolved {
+        transfers,
+        family: version.model.kind.to_ascii_lowercase(),
+        official_name: version.model.name,
+    })
+}
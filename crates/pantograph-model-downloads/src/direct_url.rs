@@ -0,0 +1,225 @@
+//! Direct-URL source: one file from a plain HTTP(S) link
+//!
+//! A URL carries no model metadata, so these requests must name the family
+//! and official name to import under. [`inspect`] lets hosts prefill that
+//! prompt from the response headers before starting the download.
+
+use std::path::{Path, PathBuf};
+
+use reqwest::header::{CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::{DownloadError, Result};
+use crate::request::ModelDownloadRequest;
+use crate::source::{relative_file_path, Resolved};
+use crate::transfer::{hex, FileTransfer};
+
+/// Request fields a URL download must set because the URL cannot supply them.
+pub const URL_REQUIRED_FIELDS: [&str; 2] = ["family", "official_name"];
+
+/// What a URL serves, for prefilling a URL download request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UrlInspection {
+    pub url: String,
+    /// Name the file will be saved under unless the request sets `filename`.
+    pub filename: Option<String>,
+    pub size: Option<u64>,
+    pub content_type: Option<String>,
+    /// File name without its extension, as a starting `official_name`.
+    pub suggested_official_name: Option<String>,
+    /// Request fields the caller still has to fill in.
+    pub required_fields: Vec<String>,
+}
+
+fn parse_url(url: &str) -> Result<Url> {
+    let parsed = Url::parse(url)
+        .map_err(|e| DownloadError::InvalidRequest(format!("'{}' is not a URL: {}", url, e)))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(DownloadError::InvalidRequest(format!(
+            "'{}' is not an http(s) URL",
+            url
+        )));
+    }
+    Ok(parsed)
+}
+
+fn url_file_name(url: &Url) -> Option<String> {
+    url.path_segments()?
+        .next_back()
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_string)
+}
+
+fn disposition_file_name(value: &str) -> Option<String> {
+    value.split(';').find_map(|part| {
+        let name = part.trim().strip_prefix("filename=")?.trim_matches('"');
+        Path::new(name)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+    })
+}
+
+fn is_sha256(value: &str) -> bool {
+    value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Validated URL and saved file name of a URL request.
+fn validate(request: &ModelDownloadRequest) -> Result<(Url, String)> {
+    let url = request.download_url.as_deref().ok_or_else(|| {
+        DownloadError::InvalidRequest("URL downloads need 'download_url'".to_string())
+    })?;
+    let url = parse_url(url)?;
+    if request.family.trim().is_empty() || request.official_name.trim().is_empty() {
+        return Err(DownloadError::InvalidRequest(format!(
+            "URL downloads need {} to import the model",
+            URL_REQUIRED_FIELDS
+                .iter()
+                .map(|field| format!("'{}'", field))
+                .collect::<Vec<_>>()
+                .join(" and ")
+        )));
+    }
+    if let Some(sha256) = &request.sha256 {
+        if !is_sha256(sha256) {
+            return Err(DownloadError::InvalidRequest(format!(
+                "'{}' is not a hex sha256 digest",
+                sha256
+            )));
+        }
+    }
+    let filename = match request.requested_files().as_slice() {
+        [] => url_file_name(&url).ok_or_else(|| {
+            DownloadError::InvalidRequest(format!("'{}' names no file; pass 'filename'", url))
+        })?,
+        [single] => single.clone(),
+        _ => {
+            return Err(DownloadError::InvalidRequest(
+                "URL downloads save exactly one file".to_string(),
+            ))
+        }
+    };
+    if relative_file_path(&filename)?.components().count() != 1 {
+        return Err(DownloadError::InvalidRequest(format!(
+            "'{}' must be a plain file name",
+            filename
+        )));
+    }
+    Ok((url, filename))
+}
+
+/// Directory under `staging_root` for this URL, stable across restarts.
+pub(crate) fn staging_dir(staging_root: &Path, request: &ModelDownloadRequest) -> Result<PathBuf> {
+    let (url, _) = validate(request)?;
+    let digest = hex(&Sha256::digest(url.as_str().as_bytes()));
+    Ok(staging_root.join("url").join(&digest[..16]))
+}
+
+pub(crate) fn resolve(request: &ModelDownloadRequest, dest_dir: &Path) -> Result<Resolved> {
+    let (url, filename) = validate(request)?;
+    Ok(Resolved {
+        transfers: vec![FileTransfer {
+            dest: dest_dir.join(&filename),
+            name: filename,
+            url: url.to_string(),
+            expected_sha256: request.sha256.as_ref().map(|s| s.to_ascii_lowercase()),
+            expected_size: None,
+            bearer_token: None,
+        }],
+        family: request.family.clone(),
+        official_name: request.official_name.clone(),
+    })
+}
+
+/// Ask the server what `url` serves without downloading it.
+pub(crate) async fn inspect(client: &reqwest::Client, url: &str) -> Result<UrlInspection> {
+    let parsed = parse_url(url)?;
+    let response = client.head(parsed.clone()).send().await?;
+    if !response.status().is_success() {
+        return Err(DownloadError::Http(format!(
+            "HEAD '{}' returned {}",
+            url,
+            response.status()
+        )));
+    }
+    let header = |name| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let filename = header(CONTENT_DISPOSITION)
+        .as_deref()
+        .and_then(disposition_file_name)
+        .or_else(|| url_file_name(response.url()))
+        .or_else(|| url_file_name(&parsed));
+    let suggested_official_name = filename.as_deref().map(|name| {
+        Path::new(name)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| name.to_string())
+    });
+    Ok(UrlInspection {
+        url: parsed.to_string(),
+        size: header(CONTENT_LENGTH).and_then(|len| len.parse().ok()),
+        content_type: header(CONTENT_TYPE),
+        filename,
+        suggested_official_name,
+        required_fields: URL_REQUIRED_FIELDS.iter().map(|f| f.to_string()).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(url: &str) -> ModelDownloadRequest {
+        ModelDownloadRequest {
+            download_url: Some(url.to_string()),
+            family: "llama".to_string(),
+            official_name: "Tiny".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn saved_name_comes_from_the_url_or_the_request() {
+        let (_, name) = validate(&request("https://example.com/models/tiny.gguf?dl=1")).unwrap();
+        assert_eq!(name, "tiny.gguf");
+
+        let mut named = request("https://example.com/download/123");
+        named.filename = Some("tiny.gguf".to_string());
+        assert_eq!(validate(&named).unwrap().1, "tiny.gguf");
+
+        named.filename = Some("../tiny.gguf".to_string());
+        assert!(validate(&named).is_err());
+        named.filename = Some("nested/tiny.gguf".to_string());
+        assert!(validate(&named).is_err());
+    }
+
+    #[test]
+    fn url_downloads_need_import_metadata_and_http() {
+        let mut missing = request("https://example.com/tiny.gguf");
+        missing.family.clear();
+        let error = validate(&missing).unwrap_err();
+        assert_eq!(error.kind(), "invalid_request");
+        assert!(error.to_string().contains("'family'"));
+
+        assert!(validate(&request("file:///etc/passwd")).is_err());
+
+        let mut bad_digest = request("https://example.com/tiny.gguf");
+        bad_digest.sha256 = Some("abc".to_string());
+        assert!(validate(&bad_digest).is_err());
+    }
+
+    #[test]
+    fn content_disposition_names_are_reduced_to_a_file_name() {
+        assert_eq!(
+            disposition_file_name("attachment; filename=\"../../tiny.gguf\""),
+            Some("tiny.gguf".to_string())
+        );
+        assert_eq!(disposition_file_name("inline"), None);
+    }
+}
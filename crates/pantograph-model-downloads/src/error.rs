@@ -0,0 +1,58 @@
+//! Error types for model downloads
+
+use thiserror::Error;
+
+/// Result type alias using DownloadError
+pub type Result<T> = std::result::Result<T, DownloadError>;
+
+/// Errors that can occur while resolving, downloading, or importing a model
+#[derive(Debug, Error)]
+pub enum DownloadError {
+    /// The request is malformed or names files the source does not have
+    #[error("Invalid download request: {0}")]
+    InvalidRequest(String),
+
+    /// The remote source answered with an error or the connection failed
+    #[error("HTTP error: {0}")]
+    Http(String),
+
+    /// Local file I/O failed
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A finished file does not hash to the sha256 the source published
+    #[error("Checksum mismatch for '{file}': expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        file: String,
+        expected: String,
+        actual: String,
+    },
+
+    /// The download was cancelled; partial files are kept for resume
+    #[error("Download cancelled")]
+    Cancelled,
+
+    /// The files downloaded but could not be imported into the model library
+    #[error("Import failed: {0}")]
+    Import(String),
+}
+
+impl DownloadError {
+    /// Snake-case label of the error variant, independent of its message.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidRequest(_) => "invalid_request",
+            Self::Http(_) => "http",
+            Self::Io(_) => "io",
+            Self::ChecksumMismatch { .. } => "checksum_mismatch",
+            Self::Cancelled => "cancelled",
+            Self::Import(_) => "import",
+        }
+    }
+}
+
+impl From<reqwest::Error> for DownloadError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Http(error.to_string())
+    }
+}
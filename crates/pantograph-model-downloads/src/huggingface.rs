@@ -0,0 +1,206 @@
+//! HuggingFace source: resolves a repo revision into file transfers
+//!
+//! File listings come from the Hub tree API, which reports the LFS sha256 of
+//! every large file. Small files stored directly in git carry no sha256 and
+//! download as unverified.
+
+use std::path::{Path, PathBuf};
+
+use reqwest::header::LINK;
+use serde::Deserialize;
+
+use crate::error::{DownloadError, Result};
+use crate::request::ModelDownloadRequest;
+use crate::source::{relative_file_path, Resolved};
+use crate::transfer::FileTransfer;
+
+/// Public HuggingFace Hub endpoint.
+pub const DEFAULT_HF_ENDPOINT: &str = "https://huggingface.co";
+
+#[derive(Debug, Deserialize)]
+struct TreeEntry {
+    #[serde(rename = "type")]
+    kind: String,
+    path: String,
+    size: Option<u64>,
+    lfs: Option<LfsPointer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LfsPointer {
+    oid: String,
+    size: u64,
+}
+
+fn is_safe_name(value: &str) -> bool {
+    !value.is_empty()
+        && value != "."
+        && value != ".."
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Check the repo id and revision before they are used in URLs and paths.
+pub(crate) fn validate(request: &ModelDownloadRequest) -> Result<()> {
+    let valid_repo = request
+        .repo_id
+        .split_once('/')
+        .is_some_and(|(owner, name)| is_safe_name(owner) && is_safe_name(name));
+    if !valid_repo {
+        return Err(DownloadError::InvalidRequest(format!(
+            "repo_id '{}' is not of the form 'owner/name'",
+            request.repo_id
+        )));
+    }
+    if !request.revision().split('/').all(is_safe_name) {
+        return Err(DownloadError::InvalidRequest(format!(
+            "revision '{}' is not a valid branch, tag, or commit",
+            request.revision()
+        )));
+    }
+    for name in request.requested_files() {
+        relative_file_path(&name)?;
+    }
+    Ok(())
+}
+
+/// Directory under `staging_root` that holds files for this repo revision.
+pub(crate) fn staging_dir(staging_root: &Path, request: &ModelDownloadRequest) -> PathBuf {
+    staging_root
+        .join("huggingface")
+        .join(request.repo_id.replace('/', "--"))
+        .join(request.revision().replace('/', "--"))
+}
+
+/// `rel="next"` target of a `Link` header, as used by Hub pagination.
+fn next_page(response: &reqwest::Response) -> Option<String> {
+    let link = response.headers().get(LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (target, params) = part.split_once(';')?;
+        params.contains("rel=\"next\"").then(|| {
+            target
+                .trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string()
+        })
+    })
+}
+
+/// List the requested files of a repo revision as transfers into `dest_dir`.
+pub(crate) async fn resolve(
+    client: &reqwest::Client,
+    endpoint: &str,
+    token: Option<&str>,
+    request: &ModelDownloadRequest,
+    dest_dir: &Path,
+) -> Result<Resolved> {
+    let endpoint = endpoint.trim_end_matches('/');
+    let revision = request.revision();
+    let mut next = Some(format!(
+        "{}/api/models/{}/tree/{}?recursive=true",
+        endpoint, request.repo_id, revision
+    ));
+    let mut entries = Vec::new();
+    while let Some(url) = next.take() {
+        let mut call = client.get(&url);
+        if let Some(token) = token {
+            call = call.bearer_auth(token);
+        }
+        let response = call.send().await?;
+        if !response.status().is_success() {
+            return Err(DownloadError::Http(format!(
+                "Listing '{}' at '{}' returned {}",
+                request.repo_id,
+                revision,
+                response.status()
+            )));
+        }
+        next = next_page(&response);
+        entries.extend(response.json::<Vec<TreeEntry>>().await?);
+    }
+
+    let wanted = request.requested_files();
+    if let Some(missing) = wanted
+        .iter()
+        .find(|name| !entries.iter().any(|entry| &entry.path == *name))
+    {
+        return Err(DownloadError::InvalidRequest(format!(
+            "'{}' has no file '{}' at '{}'",
+            request.repo_id, missing, revision
+        )));
+    }
+
+    let transfers = entries
+        .into_iter()
+        .filter(|entry| entry.kind == "file")
+        .filter(|entry| wanted.is_empty() || wanted.contains(&entry.path))
+        .map(|entry| {
+            let dest = dest_dir.join(relative_file_path(&entry.path)?);
+            let (expected_sha256, expected_size) = match entry.lfs {
+                Some(lfs) => (Some(lfs.oid.to_ascii_lowercase()), Some(lfs.size)),
+                None => (None, entry.size),
+            };
+            Ok(FileTransfer {
+                url: format!(
+                    "{}/{}/resolve/{}/{}",
+                    endpoint, request.repo_id, revision, entry.path
+                ),
+                name: entry.path,
+                dest,
+                expected_sha256,
+                expected_size,
+                bearer_token: token.map(str::to_string),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (owner, name) = request
+        .repo_id
+        .split_once('/')
+        .unwrap_or((&request.repo_id, &request.repo_id));
+    Ok(Resolved {
+        transfers,
+        family: owner.to_string(),
+        official_name: name.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(
+        repo_id: &str,
+        revision: Option<&str>,
+        filename: Option<&str>,
+    ) -> ModelDownloadRequest {
+        ModelDownloadRequest {
+            repo_id: repo_id.to_string(),
+            revision: revision.map(str::to_string),
+            filename: filename.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn validate_rejects_paths_that_escape_staging() {
+        assert!(validate(&request("acme/tiny", None, Some("weights/model.gguf"))).is_ok());
+        assert!(validate(&request("acme/tiny", Some("refs/pr/1"), None)).is_ok());
+        assert!(validate(&request("acme", None, None)).is_err());
+        assert!(validate(&request("acme/../etc", None, None)).is_err());
+        assert!(validate(&request("acme/tiny", Some(".."), None)).is_err());
+        assert!(validate(&request("acme/tiny", None, Some("../model.gguf"))).is_err());
+        assert!(validate(&request("acme/tiny", None, Some("/etc/passwd"))).is_err());
+    }
+
+    #[test]
+    fn staging_dir_is_stable_per_repo_revision() {
+        let root = Path::new("/staging");
+        assert_eq!(
+            staging_dir(root, &request("acme/tiny", Some("refs/pr/1"), None)),
+            Path::new("/staging/huggingface/acme--tiny/refs--pr--1")
+        );
+    }
+}
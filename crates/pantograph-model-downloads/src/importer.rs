@@ -0,0 +1,97 @@
+//! Registration of verified downloads in a model library
+
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use crate::error::Result;
+
+/// A verified download ready to be registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelImport {
+    /// The single downloaded file, or the staging directory for multi-file
+    /// downloads.
+    pub path: PathBuf,
+    pub family: String,
+    pub official_name: String,
+}
+
+/// Where an imported model ended up.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportedModel {
+    pub model_id: Option<String>,
+    pub model_path: Option<String>,
+}
+
+/// Registers finished downloads with a model library.
+#[async_trait]
+pub trait ModelImporter: Send + Sync {
+    async fn import(&self, model: &ModelImport) -> Result<ImportedModel>;
+}
+
+#[cfg(feature = "pumas")]
+pub use pumas::PumasImporter;
+
+#[cfg(feature = "pumas")]
+mod pumas {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use super::{ImportedModel, ModelImport, ModelImporter};
+    use crate::error::{DownloadError, Result};
+
+    /// Imports downloads into a Pumas library through `PumasApi::import_model`.
+    #[derive(Clone)]
+    pub struct PumasImporter {
+        api: Arc<pumas_library::PumasApi>,
+    }
+
+    impl PumasImporter {
+        pub fn new(api: Arc<pumas_library::PumasApi>) -> Self {
+            Self { api }
+        }
+    }
+
+    #[async_trait]
+    impl ModelImporter for PumasImporter {
+        async fn import(&self, model: &ModelImport) -> Result<ImportedModel> {
+            let spec: pumas_library::model_library::ModelImportSpec =
+                serde_json::from_value(serde_json::json!({
+                    "path": model.path.display().to_string(),
+                    "family": model.family,
+                    "official_name": model.official_name,
+                }))
+                .map_err(|e| DownloadError::Import(format!("Invalid model import spec: {}", e)))?;
+            let result = self
+                .api
+                .import_model(&spec)
+                .await
+                .map_err(|e| DownloadError::Import(e.to_string()))?;
+
+            let result = serde_json::to_value(&result).unwrap_or_default();
+            if result.get("success").and_then(|v| v.as_bool()) == Some(false) {
+                return Err(DownloadError::Import(
+                    result
+                        .get("error")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("import was not successful")
+                        .to_string(),
+                ));
+            }
+            let field = |keys: &[&str]| {
+                keys.iter().find_map(|key| {
+                    result
+                        .get(*key)
+                        .and_then(|v| v.as_str())
+                        .filter(|v| !v.trim().is_empty())
+                        .map(str::to_string)
+                })
+            };
+            Ok(ImportedModel {
+                model_id: field(&["model_id", "modelId", "id"]),
+                model_path: field(&["model_path", "modelPath", "path"]),
+            })
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Checksum-verified, resumable, bandwidth-capped model downloads.
+//!
+//! [`ModelDownloader`] resolves a HuggingFace repo revision, a Civitai model
+//! version, or a direct URL, downloads the files concurrently under one
+//! shared bandwidth cap, verifies each against the sha256 its source
+//! publishes, and hands the result to a [`ModelImporter`] (Pumas by default).
+//! Interrupted files resume from their `.part` on the next start of the same
+//! request.
+
+mod bandwidth;
+mod civitai;
+mod direct_url;
+mod error;
+mod huggingface;
+mod importer;
+mod manager;
+mod progress;
+mod request;
+mod source;
+mod transfer;
+
+pub use bandwidth::BandwidthLimiter;
+pub use civitai::DEFAULT_CIVITAI_ENDPOINT;
+pub use direct_url::{UrlInspection, URL_REQUIRED_FIELDS};
+pub use error::{DownloadError, Result};
+pub use huggingface::DEFAULT_HF_ENDPOINT;
+#[cfg(feature = "pumas")]
+pub use importer::PumasImporter;
+pub use importer::{ImportedModel, ModelImport, ModelImporter};
+pub use manager::{ModelDownloader, STAGING_DIR_NAME};
+pub use progress::{DownloadProgress, DownloadStatus, FileProgress, FileState};
+pub use request::{DownloadSource, ModelDownloadRequest};
@@ -0,0 +1,297 @@
+//! Download jobs: resolve, transfer concurrently, verify, and import
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use futures_util::{stream, StreamExt, TryStreamExt};
+use tokio::task::AbortHandle;
+
+use crate::bandwidth::BandwidthLimiter;
+use crate::direct_url::{self, UrlInspection};
+use crate::error::{DownloadError, Result};
+use crate::importer::{ImportedModel, ModelImport, ModelImporter};
+use crate::progress::{DownloadProgress, DownloadStatus, FileState};
+use crate::request::ModelDownloadRequest;
+use crate::source::{self, SourceConfig};
+use crate::transfer::{self, FileTransfer, TransferContext};
+
+/// Name of the staging directory hosts create next to their model library.
+pub const STAGING_DIR_NAME: &str = "pantograph-downloads";
+
+struct Job {
+    progress: Arc<Mutex<DownloadProgress>>,
+    staging: PathBuf,
+    task: Option<AbortHandle>,
+}
+
+/// Runs model downloads in the background and reports their progress.
+///
+/// Files are staged under a directory that is stable per HuggingFace repo
+/// revision, Civitai model version, or URL, so starting the same request
+/// again after a cancel, failure, or restart resumes its partial files. Every
+/// job shares one [`BandwidthLimiter`].
+pub struct ModelDownloader {
+    client: reqwest::Client,
+    staging_dir: PathBuf,
+    sources: SourceConfig,
+    limiter: Arc<BandwidthLimiter>,
+    importer: Option<Arc<dyn ModelImporter>>,
+    jobs: Arc<Mutex<HashMap<String, Job>>>,
+}
+
+impl ModelDownloader {
+    /// Create a downloader that stages files under `staging_dir`.
+    pub fn new(staging_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            staging_dir: staging_dir.into(),
+            sources: SourceConfig::default(),
+            limiter: Arc::new(BandwidthLimiter::default()),
+            importer: None,
+            jobs: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Import verified downloads through `importer`; without one, completed
+    /// jobs report the staged path.
+    pub fn with_importer(mut self, importer: Arc<dyn ModelImporter>) -> Self {
+        self.importer = Some(importer);
+        self
+    }
+
+    /// Use another HuggingFace endpoint, such as a mirror.
+    pub fn with_hf_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.sources.hf_endpoint = endpoint.into();
+        self
+    }
+
+    /// Send `token` to HuggingFace for gated or private repos.
+    pub fn with_hf_token(mut self, token: impl Into<String>) -> Self {
+        self.sources.hf_token = Some(token.into());
+        self
+    }
+
+    /// Use another Civitai endpoint.
+    pub fn with_civitai_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.sources.civitai_endpoint = endpoint.into();
+        self
+    }
+
+    /// Send `token` to Civitai for downloads that require an API key.
+    pub fn with_civitai_token(mut self, token: impl Into<String>) -> Self {
+        self.sources.civitai_token = Some(token.into());
+        self
+    }
+
+    /// Use a preconfigured HTTP client (proxy, TLS, timeouts).
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Directory downloads are staged under.
+    pub fn staging_dir(&self) -> &Path {
+        &self.staging_dir
+    }
+
+    /// Cap the combined rate of every download, or lift the cap with `None`.
+    pub fn set_bandwidth_limit(&self, bytes_per_sec: Option<u64>) {
+        self.limiter.set_limit(bytes_per_sec);
+    }
+
+    /// Current cap in bytes per second.
+    pub fn bandwidth_limit(&self) -> Option<u64> {
+        self.limiter.limit()
+    }
+
+    /// Describe what `url` serves so a host can prompt for the metadata a
+    /// URL download needs before starting it.
+    pub async fn inspect_url(&self, url: &str) -> Result<UrlInspection> {
+        direct_url::inspect(&self.client, url).await
+    }
+
+    /// Start downloading `request` in the background, returning its id.
+    ///
+    /// Fails up front for malformed requests and for requests whose files
+    /// are already downloading.
+    pub async fn start(&self, request: ModelDownloadRequest) -> Result<String> {
+        let staging = source::staging_dir(&self.staging_dir, &request)?;
+        let download_id = uuid::Uuid::new_v4().to_string();
+        let progress = Arc::new(Mutex::new(DownloadProgress::new(
+            download_id.clone(),
+            &request,
+        )));
+
+        let mut jobs = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        let busy = jobs.values().any(|job| {
+            job.staging == staging
+                && !job
+                    .progress
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .status
+                    .is_terminal()
+        });
+        if busy {
+            return Err(DownloadError::InvalidRequest(format!(
+                "'{}' is already downloading",
+                staging.display()
+            )));
+        }
+
+        let run = JobRun {
+            client: self.client.clone(),
+            sources: self.sources.clone(),
+            limiter: self.limiter.clone(),
+            importer: self.importer.clone(),
+            progress: progress.clone(),
+            staging: staging.clone(),
+            request,
+        };
+        let task = tokio::spawn(run.execute());
+        jobs.insert(
+            download_id.clone(),
+            Job {
+                progress,
+                staging,
+                task: Some(task.abort_handle()),
+            },
+        );
+        Ok(download_id)
+    }
+
+    /// Latest progress of a job started by this downloader.
+    pub fn progress(&self, download_id: &str) -> Option<DownloadProgress> {
+        let jobs = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        jobs.get(download_id).map(|job| {
+            job.progress
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone()
+        })
+    }
+
+    /// Stop a running job, keeping its partial files for a later resume.
+    ///
+    /// Returns `false` when the job is unknown or already finished.
+    pub fn cancel(&self, download_id: &str) -> bool {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(job) = jobs.get_mut(download_id) else {
+            return false;
+        };
+        let mut progress = job.progress.lock().unwrap_or_else(|e| e.into_inner());
+        if progress.status.is_terminal() {
+            return false;
+        }
+        if let Some(task) = job.task.take() {
+            task.abort();
+        }
+        progress.status = DownloadStatus::Cancelled;
+        progress.error = Some(DownloadError::Cancelled.to_string());
+        progress.error_kind = Some(DownloadError::Cancelled.kind().to_string());
+        true
+    }
+}
+
+/// Everything a background job needs, detached from the downloader.
+struct JobRun {
+    client: reqwest::Client,
+    sources: SourceConfig,
+    limiter: Arc<BandwidthLimiter>,
+    importer: Option<Arc<dyn ModelImporter>>,
+    progress: Arc<Mutex<DownloadProgress>>,
+    staging: PathBuf,
+    request: ModelDownloadRequest,
+}
+
+impl JobRun {
+    fn update(&self, apply: impl FnOnce(&mut DownloadProgress)) {
+        let mut progress = self.progress.lock().unwrap_or_else(|e| e.into_inner());
+        // A cancelled job keeps its final state even if the task is still
+        // unwinding.
+        if progress.status != DownloadStatus::Cancelled {
+            apply(&mut progress);
+        }
+    }
+
+    async fn execute(self) {
+        let result = self.download_and_import().await;
+        self.update(|progress| match result {
+            Ok(imported) => {
+                progress.status = DownloadStatus::Completed;
+                progress.model_id = imported.model_id;
+                progress.model_path = imported.model_path;
+            }
+            Err(error) => {
+                log::warn!("Download {} failed: {}", progress.download_id, error);
+                for file in &mut progress.files {
+                    if !matches!(file.state, FileState::Verified | FileState::Unverified) {
+                        file.state = FileState::Failed;
+                    }
+                }
+                progress.status = DownloadStatus::Failed;
+                progress.error_kind = Some(error.kind().to_string());
+                progress.error = Some(error.to_string());
+            }
+        });
+    }
+
+    async fn download_and_import(&self) -> Result<ImportedModel> {
+        self.update(|progress| progress.status = DownloadStatus::Resolving);
+        let resolved =
+            source::resolve(&self.client, &self.sources, &self.request, &self.staging).await?;
+        let transfers = resolved.transfers;
+        if transfers.is_empty() {
+            return Err(DownloadError::InvalidRequest(
+                "the source lists no files to download".to_string(),
+            ));
+        }
+
+        self.update(|progress| {
+            progress.files = transfers.iter().map(FileTransfer::progress).collect();
+            progress.recount();
+            progress.status = DownloadStatus::Downloading;
+        });
+        let concurrency = self.request.max_concurrent_files.unwrap_or(1).max(1);
+        stream::iter(transfers.iter().cloned().enumerate())
+            .map(|(index, file)| {
+                let ctx = TransferContext {
+                    client: self.client.clone(),
+                    limiter: self.limiter.clone(),
+                    progress: self.progress.clone(),
+                    index,
+                };
+                async move { transfer::run(&file, &ctx).await }
+            })
+            .buffer_unordered(concurrency)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let path = match transfers.as_slice() {
+            [single] => single.dest.clone(),
+            _ => self.staging.clone(),
+        };
+        let Some(importer) = &self.importer else {
+            return Ok(ImportedModel {
+                model_id: None,
+                model_path: Some(path.display().to_string()),
+            });
+        };
+        self.update(|progress| progress.status = DownloadStatus::Importing);
+        let or_resolved = |given: &str, resolved: String| {
+            if given.trim().is_empty() {
+                resolved
+            } else {
+                given.to_string()
+            }
+        };
+        importer
+            .import(&ModelImport {
+                path,
+                family: or_resolved(&self.request.family, resolved.family),
+                official_name: or_resolved(&self.request.official_name, resolved.official_name),
+            })
+            .await
+    }
+}
@@ -0,0 +1,106 @@
+//! Progress snapshots reported for each download job
+
+use serde::{Deserialize, Serialize};
+
+use crate::request::{DownloadSource, ModelDownloadRequest};
+
+/// Lifecycle of a download job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadStatus {
+    Queued,
+    Resolving,
+    Downloading,
+    Importing,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl DownloadStatus {
+    /// Whether the job has stopped and will not change again.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// Verification state of one file in a job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileState {
+    Queued,
+    Downloading,
+    Verifying,
+    /// The file matched the sha256 the source published.
+    Verified,
+    /// The source published no sha256, so the file was kept unchecked.
+    Unverified,
+    Failed,
+}
+
+/// Progress of one file in a job.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileProgress {
+    /// Path of the file inside the repo.
+    pub name: String,
+    pub bytes_done: u64,
+    pub bytes_total: Option<u64>,
+    /// Bytes reused from an earlier partial download of this file.
+    pub resumed_from_bytes: u64,
+    pub state: FileState,
+    pub expected_sha256: Option<String>,
+}
+
+/// Snapshot of a download job, serialized snake_case like Pumas
+/// `ModelDownloadProgress`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadProgress {
+    pub download_id: String,
+    pub source: DownloadSource,
+    /// HuggingFace repo id; empty for other sources.
+    pub repo_id: String,
+    pub status: DownloadStatus,
+    pub bytes_done: u64,
+    /// Sum of known file sizes; `None` until every file size is known.
+    pub bytes_total: Option<u64>,
+    pub resumed_from_bytes: u64,
+    pub files: Vec<FileProgress>,
+    pub error: Option<String>,
+    /// [`crate::DownloadError::kind`] of the failure, e.g. `checksum_mismatch`.
+    pub error_kind: Option<String>,
+    /// Library id of the imported model.
+    pub model_id: Option<String>,
+    /// Where the model lives once completed: the imported path, or the
+    /// staged download when no importer is configured.
+    pub model_path: Option<String>,
+}
+
+impl DownloadProgress {
+    pub(crate) fn new(download_id: String, request: &ModelDownloadRequest) -> Self {
+        Self {
+            download_id,
+            source: request.source(),
+            repo_id: request.repo_id.clone(),
+            status: DownloadStatus::Queued,
+            bytes_done: 0,
+            bytes_total: None,
+            resumed_from_bytes: 0,
+            files: Vec::new(),
+            error: None,
+            error_kind: None,
+            model_id: None,
+            model_path: None,
+        }
+    }
+
+    /// Recompute the job totals from its files.
+    pub(crate) fn recount(&mut self) {
+        self.bytes_done = self.files.iter().map(|file| file.bytes_done).sum();
+        self.resumed_from_bytes = self.files.iter().map(|file| file.resumed_from_bytes).sum();
+        self.bytes_total = self
+            .files
+            .iter()
+            .map(|file| file.bytes_total)
+            .sum::<Option<u64>>();
+    }
+}
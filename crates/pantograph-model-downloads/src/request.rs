@@ -0,0 +1,138 @@
+//! Download request accepted by [`crate::ModelDownloader`]
+
+use serde::{Deserialize, Serialize};
+
+/// Where a download comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadSource {
+    /// A HuggingFace repo revision, named by `repo_id`.
+    #[serde(rename = "huggingface")]
+    HuggingFace,
+    /// A single file at `download_url`.
+    Url,
+    /// A Civitai model version, named by `civitai_model_version_id`.
+    Civitai,
+}
+
+/// What to download and how to register it once verified.
+///
+/// The JSON shape follows Pumas `DownloadRequest` (snake_case, unknown fields
+/// ignored), so hosts can pass the same payload to either download path.
+/// Every source shares this request and [`crate::DownloadProgress`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModelDownloadRequest {
+    /// Source to download from; inferred from the other fields when absent.
+    pub source: Option<DownloadSource>,
+    /// HuggingFace repository, e.g. `TheBloke/Llama-2-7B-GGUF`.
+    pub repo_id: String,
+    /// Model family recorded on import. HuggingFace defaults to the repo
+    /// owner and Civitai to the model type; URL downloads must set it.
+    pub family: String,
+    /// Display name recorded on import. HuggingFace defaults to the repo name
+    /// and Civitai to the model name; URL downloads must set it.
+    pub official_name: String,
+    /// Branch, tag, or commit to download; defaults to `main`.
+    pub revision: Option<String>,
+    /// Single file to download: a repo path, a Civitai file name, or the
+    /// saved name of a URL download.
+    pub filename: Option<String>,
+    /// Several files to download; combined with `filename`.
+    pub filenames: Option<Vec<String>>,
+    /// Files fetched in parallel; defaults to one at a time.
+    pub max_concurrent_files: Option<usize>,
+    /// Direct file URL for `url` downloads.
+    pub download_url: Option<String>,
+    /// Expected sha256 of a `url` download; without it the file is kept
+    /// unverified.
+    pub sha256: Option<String>,
+    /// Civitai model version to download.
+    pub civitai_model_version_id: Option<u64>,
+}
+
+impl ModelDownloadRequest {
+    /// Source of this request: `source` when given, otherwise Civitai for a
+    /// version id, URL for a `download_url` without a `repo_id`, and
+    /// HuggingFace for everything else.
+    pub fn source(&self) -> DownloadSource {
+        if let Some(source) = self.source {
+            return source;
+        }
+        if self.civitai_model_version_id.is_some() {
+            DownloadSource::Civitai
+        } else if self.repo_id.trim().is_empty() && self.download_url.is_some() {
+            DownloadSource::Url
+        } else {
+            DownloadSource::HuggingFace
+        }
+    }
+
+    /// Revision to resolve, defaulting to `main`.
+    pub fn revision(&self) -> &str {
+        self.revision
+            .as_deref()
+            .filter(|revision| !revision.trim().is_empty())
+            .unwrap_or("main")
+    }
+
+    /// Explicitly requested files, or empty for the source's default set.
+    pub fn requested_files(&self) -> Vec<String> {
+        let mut files: Vec<String> = Vec::new();
+        for name in self.filename.iter().chain(self.filenames.iter().flatten()) {
+            if !name.trim().is_empty() && !files.contains(name) {
+                files.push(name.clone());
+            }
+        }
+        files
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_pumas_download_request_json() {
+        let request: ModelDownloadRequest = serde_json::from_value(serde_json::json!({
+            "repo_id": "acme/tiny-gguf",
+            "family": "llama",
+            "official_name": "Tiny",
+            "model_type": "llm",
+            "filename": "tiny-Q4_K_M.gguf",
+            "filenames": ["tiny-Q4_K_M.gguf", "README.md"],
+            "download_url": "https://huggingface.co/acme/tiny-gguf",
+        }))
+        .unwrap();
+
+        assert_eq!(request.source(), DownloadSource::HuggingFace);
+        assert_eq!(request.revision(), "main");
+        assert_eq!(
+            request.requested_files(),
+            vec!["tiny-Q4_K_M.gguf".to_string(), "README.md".to_string()]
+        );
+    }
+
+    #[test]
+    fn source_is_inferred_from_the_request() {
+        let url: ModelDownloadRequest = serde_json::from_value(serde_json::json!({
+            "download_url": "https://example.com/tiny.gguf",
+        }))
+        .unwrap();
+        assert_eq!(url.source(), DownloadSource::Url);
+
+        let civitai: ModelDownloadRequest = serde_json::from_value(serde_json::json!({
+            "civitai_model_version_id": 42,
+        }))
+        .unwrap();
+        assert_eq!(civitai.source(), DownloadSource::Civitai);
+
+        let explicit: ModelDownloadRequest = serde_json::from_value(serde_json::json!({
+            "source": "url",
+            "repo_id": "acme/tiny",
+            "download_url": "https://example.com/tiny.gguf",
+        }))
+        .unwrap();
+        assert_eq!(explicit.source(), DownloadSource::Url);
+    }
+}
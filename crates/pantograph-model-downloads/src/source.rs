@@ -0,0 +1,102 @@
+//! Per-source validation, staging layout, and resolution
+//!
+//! Sources only turn a request into [`FileTransfer`]s and suggested import
+//! names; the transfer protocol, progress, and import are shared.
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::civitai::{self, DEFAULT_CIVITAI_ENDPOINT};
+use crate::direct_url;
+use crate::error::{DownloadError, Result};
+use crate::huggingface::{self, DEFAULT_HF_ENDPOINT};
+use crate::request::{DownloadSource, ModelDownloadRequest};
+use crate::transfer::FileTransfer;
+
+/// Endpoints and credentials for every source.
+#[derive(Debug, Clone)]
+pub(crate) struct SourceConfig {
+    pub hf_endpoint: String,
+    pub hf_token: Option<String>,
+    pub civitai_endpoint: String,
+    pub civitai_token: Option<String>,
+}
+
+impl Default for SourceConfig {
+    fn default() -> Self {
+        Self {
+            hf_endpoint: DEFAULT_HF_ENDPOINT.to_string(),
+            hf_token: None,
+            civitai_endpoint: DEFAULT_CIVITAI_ENDPOINT.to_string(),
+            civitai_token: None,
+        }
+    }
+}
+
+/// Files a source resolved, plus the import names it suggests when the
+/// request leaves them empty.
+#[derive(Debug)]
+pub(crate) struct Resolved {
+    pub transfers: Vec<FileTransfer>,
+    pub family: String,
+    pub official_name: String,
+}
+
+/// Validate `request` and return the directory its files are staged in.
+pub(crate) fn staging_dir(staging_root: &Path, request: &ModelDownloadRequest) -> Result<PathBuf> {
+    match request.source() {
+        DownloadSource::HuggingFace => {
+            huggingface::validate(request)?;
+            Ok(huggingface::staging_dir(staging_root, request))
+        }
+        DownloadSource::Url => direct_url::staging_dir(staging_root, request),
+        DownloadSource::Civitai => civitai::staging_dir(staging_root, request),
+    }
+}
+
+/// Resolve `request` into transfers into `dest_dir`.
+pub(crate) async fn resolve(
+    client: &reqwest::Client,
+    config: &SourceConfig,
+    request: &ModelDownloadRequest,
+    dest_dir: &Path,
+) -> Result<Resolved> {
+    match request.source() {
+        DownloadSource::HuggingFace => {
+            huggingface::resolve(
+                client,
+                &config.hf_endpoint,
+                config.hf_token.as_deref(),
+                request,
+                dest_dir,
+            )
+            .await
+        }
+        DownloadSource::Url => direct_url::resolve(request, dest_dir),
+        DownloadSource::Civitai => {
+            civitai::resolve(
+                client,
+                &config.civitai_endpoint,
+                config.civitai_token.as_deref(),
+                request,
+                dest_dir,
+            )
+            .await
+        }
+    }
+}
+
+/// Reject file paths that would escape the staging directory.
+pub(crate) fn relative_file_path(path: &str) -> Result<PathBuf> {
+    let relative = Path::new(path);
+    if path.is_empty()
+        || !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(DownloadError::InvalidRequest(format!(
+            "'{}' is not a relative file path",
+            path
+        )));
+    }
+    Ok(relative.to_path_buf())
+}
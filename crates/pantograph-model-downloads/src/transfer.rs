@@ -0,0 +1,296 @@
+//! Single-file transfer with resume and sha256 verification
+//!
+//! Bytes land in `<dest>.part` next to a `<dest>.part.json` marker recording
+//! the URL and expected sha256 they came from. A later transfer of the same
+//! file rehashes the part, asks the server for the rest with a `Range`
+//! request, and only renames the part to `dest` once the whole file hashes to
+//! the expected digest.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use futures_util::StreamExt;
+use reqwest::header::{CONTENT_RANGE, RANGE};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use crate::bandwidth::BandwidthLimiter;
+use crate::error::{DownloadError, Result};
+use crate::progress::{DownloadProgress, FileProgress, FileState};
+
+/// One remote file resolved from a download source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FileTransfer {
+    /// Path of the file inside the repo, used in progress and errors.
+    pub name: String,
+    pub url: String,
+    pub dest: PathBuf,
+    /// Lowercase hex sha256 the source published, if any.
+    pub expected_sha256: Option<String>,
+    pub expected_size: Option<u64>,
+    /// Bearer token sent with the request.
+    pub bearer_token: Option<String>,
+}
+
+impl FileTransfer {
+    pub(crate) fn progress(&self) -> FileProgress {
+        FileProgress {
+            name: self.name.clone(),
+            bytes_done: 0,
+            bytes_total: self.expected_size,
+            resumed_from_bytes: 0,
+            state: FileState::Queued,
+            expected_sha256: self.expected_sha256.clone(),
+        }
+    }
+}
+
+/// Identifies which remote file a `.part` belongs to.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct PartMarker {
+    url: String,
+    sha256: Option<String>,
+}
+
+/// Shared handles a transfer reports through.
+pub(crate) struct TransferContext {
+    pub client: reqwest::Client,
+    pub limiter: Arc<BandwidthLimiter>,
+    pub progress: Arc<Mutex<DownloadProgress>>,
+    /// Index of this transfer in `progress.files`.
+    pub index: usize,
+}
+
+impl TransferContext {
+    fn update(&self, apply: impl FnOnce(&mut FileProgress)) {
+        let mut progress = self.progress.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(file) = progress.files.get_mut(self.index) {
+            apply(file);
+        }
+        progress.recount();
+    }
+}
+
+pub(crate) fn part_path(dest: &Path) -> PathBuf {
+    with_suffix(dest, ".part")
+}
+
+fn marker_path(dest: &Path) -> PathBuf {
+    with_suffix(dest, ".part.json")
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+pub(crate) fn hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Feed the contents of `path` into `hasher`, returning the bytes read.
+async fn hash_into(path: &Path, hasher: &mut Sha256) -> Result<u64> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut total = 0u64;
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            return Ok(total);
+        }
+        hasher.update(&buffer[..read]);
+        total += read as u64;
+    }
+}
+
+async fn read_marker(path: &Path) -> Option<PartMarker> {
+    let bytes = tokio::fs::read(path).await.ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+async fn remove_if_exists(path: &Path) -> Result<()> {
+    match tokio::fs::remove_file(path).await {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Download `transfer` into its destination, resuming and verifying it.
+pub(crate) async fn run(transfer: &FileTransfer, ctx: &TransferContext) -> Result<FileState> {
+    if let Some(state) = reuse_existing(transfer, ctx).await? {
+        return Ok(state);
+    }
+
+    let part = part_path(&transfer.dest);
+    let marker_file = marker_path(&transfer.dest);
+    let marker = PartMarker {
+        url: transfer.url.clone(),
+        sha256: transfer.expected_sha256.clone(),
+    };
+
+    let mut hasher = Sha256::new();
+    let mut offset = 0u64;
+    if tokio::fs::try_exists(&part).await? {
+        if read_marker(&marker_file).await.as_ref() == Some(&marker) {
+            ctx.update(|file| file.state = FileState::Verifying);
+            offset = hash_into(&part, &mut hasher).await?;
+        } else {
+            remove_if_exists(&part).await?;
+        }
+    }
+    if let Some(parent) = part.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(
+        &marker_file,
+        serde_json::to_vec(&marker).map_err(|e| DownloadError::Io(e.into()))?,
+    )
+    .await?;
+
+    let mut request = ctx.client.get(&transfer.url);
+    if let Some(token) = &transfer.bearer_token {
+        request = request.bearer_auth(token);
+    }
+    if offset > 0 {
+        request = request.header(RANGE, format!("bytes={}-", offset));
+    }
+    let response = request.send().await?;
+    let status = response.status();
+
+    let append = if offset > 0 && status == StatusCode::RANGE_NOT_SATISFIABLE {
+        // The part already holds the whole file.
+        None
+    } else if offset > 0 && status == StatusCode::PARTIAL_CONTENT {
+        let range = response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        if !range.starts_with(&format!("bytes {}-", offset)) {
+            return Err(DownloadError::Http(format!(
+                "'{}' answered range {} with '{}'",
+                transfer.name, offset, range
+            )));
+        }
+        Some((response, true))
+    } else if status.is_success() {
+        // The server ignored the range; start over.
+        offset = 0;
+        hasher = Sha256::new();
+        Some((response, false))
+    } else {
+        return Err(DownloadError::Http(format!(
+            "GET '{}' returned {}",
+            transfer.name, status
+        )));
+    };
+
+    let resumed = offset;
+    let mut written = offset;
+    if let Some((response, append)) = append {
+        let total = transfer
+            .expected_size
+            .or_else(|| response.content_length().map(|len| len + offset));
+        ctx.update(|file| {
+            file.state = FileState::Downloading;
+            file.resumed_from_bytes = resumed;
+            file.bytes_done = resumed;
+            file.bytes_total = total;
+        });
+
+        let mut out = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(&part)
+            .await?;
+        let mut body = response.bytes_stream();
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk?;
+            ctx.limiter.acquire(chunk.len() as u64).await;
+            hasher.update(&chunk);
+            out.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+            ctx.update(|file| file.bytes_done = written);
+        }
+        out.flush().await?;
+
+        if let Some(expected) = total.filter(|expected| written < *expected) {
+            // Keep the part so the next attempt resumes from here.
+            return Err(DownloadError::Http(format!(
+                "'{}' ended after {} of {} bytes",
+                transfer.name, written, expected
+            )));
+        }
+    }
+
+    ctx.update(|file| {
+        file.state = FileState::Verifying;
+        file.resumed_from_bytes = resumed;
+        file.bytes_done = written;
+    });
+    let actual = hex(&hasher.finalize());
+    if let Some(expected) = &transfer.expected_sha256 {
+        if !actual.eq_ignore_ascii_case(expected) {
+            remove_if_exists(&part).await?;
+            remove_if_exists(&marker_file).await?;
+            return Err(DownloadError::ChecksumMismatch {
+                file: transfer.name.clone(),
+                expected: expected.clone(),
+                actual,
+            });
+        }
+    }
+
+    tokio::fs::rename(&part, &transfer.dest).await?;
+    remove_if_exists(&marker_file).await?;
+    let state = if transfer.expected_sha256.is_some() {
+        FileState::Verified
+    } else {
+        FileState::Unverified
+    };
+    ctx.update(|file| file.state = state);
+    Ok(state)
+}
+
+/// Keep a destination file left by an earlier run when it still verifies.
+async fn reuse_existing(
+    transfer: &FileTransfer,
+    ctx: &TransferContext,
+) -> Result<Option<FileState>> {
+    if !tokio::fs::try_exists(&transfer.dest).await? {
+        return Ok(None);
+    }
+    ctx.update(|file| file.state = FileState::Verifying);
+    let mut hasher = Sha256::new();
+    let len = hash_into(&transfer.dest, &mut hasher).await?;
+    let digest = hex(&hasher.finalize());
+    let still_valid = match &transfer.expected_sha256 {
+        Some(expected) => digest.eq_ignore_ascii_case(expected),
+        None => transfer.expected_size.is_none_or(|size| size == len),
+    };
+    if !still_valid {
+        log::warn!(
+            "Discarding '{}': it no longer matches the published file",
+            transfer.dest.display()
+        );
+        tokio::fs::remove_file(&transfer.dest).await?;
+        return Ok(None);
+    }
+    let state = if transfer.expected_sha256.is_some() {
+        FileState::Verified
+    } else {
+        FileState::Unverified
+    };
+    ctx.update(|file| {
+        file.state = state;
+        file.bytes_done = len;
+        file.bytes_total = Some(len);
+    });
+    Ok(Some(state))
+}
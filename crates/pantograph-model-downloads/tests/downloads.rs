@@ -0,0 +1,615 @@
+//! End-to-end download jobs against an in-process stand-in for the
+//! HuggingFace Hub, Civitai, and plain file hosts.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use pantograph_model_downloads::{
+    DownloadProgress, DownloadSource, DownloadStatus, FileState, ImportedModel,
+    ModelDownloadRequest, ModelDownloader, ModelImport, ModelImporter,
+};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+struct Route {
+    body: Vec<u8>,
+    /// Drop the connection after this many body bytes, once.
+    cut_once_at: Option<usize>,
+    cut_done: AtomicBool,
+}
+
+impl Route {
+    fn new(body: Vec<u8>) -> Self {
+        Self {
+            body,
+            cut_once_at: None,
+            cut_done: AtomicBool::new(false),
+        }
+    }
+}
+
+#[derive(Default)]
+struct HubState {
+    routes: Mutex<HashMap<String, Arc<Route>>>,
+    /// `(path, Range header)` of every request received.
+    requests: Mutex<Vec<(String, Option<String>)>>,
+}
+
+struct TestHub {
+    base_url: String,
+    state: Arc<HubState>,
+}
+
+impl TestHub {
+    /// Serve `body` at `path` in addition to the repo routes.
+    fn route(&self, path: &str, body: Vec<u8>) {
+        self.state
+            .routes
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), Arc::new(Route::new(body)));
+    }
+
+    fn ranges_for(&self, path: &str) -> Vec<Option<String>> {
+        self.state
+            .requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(requested, _)| requested == path)
+            .map(|(_, range)| range.clone())
+            .collect()
+    }
+}
+
+/// A repo file served by the hub; `published_sha256` of `None` serves it as
+/// a plain git file without LFS metadata.
+struct RepoFile {
+    path: &'static str,
+    body: Vec<u8>,
+    published_sha256: Option<String>,
+    cut_once_at: Option<usize>,
+}
+
+impl RepoFile {
+    fn lfs(path: &'static str, body: Vec<u8>) -> Self {
+        Self {
+            path,
+            published_sha256: Some(sha256(&body)),
+            body,
+            cut_once_at: None,
+        }
+    }
+
+    fn plain(path: &'static str, body: Vec<u8>) -> Self {
+        Self {
+            path,
+            body,
+            published_sha256: None,
+            cut_once_at: None,
+        }
+    }
+}
+
+fn sha256(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn weights(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+async fn start_hub(repo_id: &str, files: Vec<RepoFile>) -> TestHub {
+    let tree: Vec<serde_json::Value> = files
+        .iter()
+        .map(|file| match &file.published_sha256 {
+            Some(oid) => serde_json::json!({
+                "type": "file",
+                "path": file.path,
+                "size": file.body.len(),
+                "lfs": { "oid": oid, "size": file.body.len() },
+            }),
+            None => serde_json::json!({
+                "type": "file",
+                "path": file.path,
+                "size": file.body.len(),
+            }),
+        })
+        .collect();
+
+    let mut routes = HashMap::new();
+    routes.insert(
+        format!("/api/models/{}/tree/main?recursive=true", repo_id),
+        Arc::new(Route::new(serde_json::to_vec(&tree).unwrap())),
+    );
+    for file in files {
+        let mut route = Route::new(file.body);
+        route.cut_once_at = file.cut_once_at;
+        routes.insert(
+            format!("/{}/resolve/main/{}", repo_id, file.path),
+            Arc::new(route),
+        );
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let state = Arc::new(HubState {
+        routes: Mutex::new(routes),
+        ..Default::default()
+    });
+    let served = state.clone();
+    tokio::spawn(async move {
+        loop {
+            let Ok((socket, _)) = listener.accept().await else {
+                return;
+            };
+            tokio::spawn(serve(socket, served.clone()));
+        }
+    });
+    TestHub { base_url, state }
+}
+
+async fn serve(mut socket: TcpStream, state: Arc<HubState>) {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        match socket.read(&mut buffer).await {
+            Ok(0) | Err(_) => return,
+            Ok(read) => head.extend_from_slice(&buffer[..read]),
+        }
+    }
+    let head = String::from_utf8_lossy(&head).to_string();
+    let mut request_line = head.split_whitespace();
+    let is_head = request_line.next() == Some("HEAD");
+    let path = request_line.next().unwrap_or_default().to_string();
+    let range = head.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.eq_ignore_ascii_case("range")
+            .then(|| value.trim().to_string())
+    });
+    state
+        .requests
+        .lock()
+        .unwrap()
+        .push((path.clone(), range.clone()));
+
+    let route = state.routes.lock().unwrap().get(&path).cloned();
+    let Some(route) = route else {
+        let _ = socket
+            .write_all(b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+            .await;
+        return;
+    };
+    let len = route.body.len();
+    let start = range
+        .as_deref()
+        .and_then(|range| range.strip_prefix("bytes="))
+        .and_then(|range| range.strip_suffix('-'))
+        .and_then(|start| start.parse::<usize>().ok());
+    let (status, extra, body) = match start {
+        Some(start) if start >= len => (
+            "416 Range Not Satisfiable",
+            format!("content-range: bytes */{}\r\n", len),
+            &route.body[..0],
+        ),
+        Some(start) => (
+            "206 Partial Content",
+            format!("content-range: bytes {}-{}/{}\r\n", start, len - 1, len),
+            &route.body[start..],
+        ),
+        None => ("200 OK", String::new(), &route.body[..]),
+    };
+    let header = format!(
+        "HTTP/1.1 {}\r\n{}content-length: {}\r\nconnection: close\r\n\r\n",
+        status,
+        extra,
+        body.len()
+    );
+    if socket.write_all(header.as_bytes()).await.is_err() || is_head {
+        let _ = socket.shutdown().await;
+        return;
+    }
+    match route.cut_once_at {
+        Some(cut) if !route.cut_done.swap(true, Ordering::SeqCst) => {
+            let _ = socket.write_all(&body[..cut.min(body.len())]).await;
+            let _ = socket.flush().await;
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        _ => {
+            let _ = socket.write_all(body).await;
+        }
+    }
+    let _ = socket.shutdown().await;
+}
+
+#[derive(Default)]
+struct RecordingImporter {
+    imports: Mutex<Vec<ModelImport>>,
+}
+
+#[async_trait]
+impl ModelImporter for RecordingImporter {
+    async fn import(
+        &self,
+        model: &ModelImport,
+    ) -> pantograph_model_downloads::Result<ImportedModel> {
+        self.imports.lock().unwrap().push(model.clone());
+        Ok(ImportedModel {
+            model_id: Some(format!("llm/{}", model.official_name)),
+            model_path: Some(model.path.display().to_string()),
+        })
+    }
+}
+
+fn downloader(hub: &TestHub, staging: &Path) -> ModelDownloader {
+    ModelDownloader::new(staging)
+        .with_client(reqwest::Client::builder().no_proxy().build().unwrap())
+        .with_hf_endpoint(hub.base_url.clone())
+}
+
+fn request(filename: Option<&str>) -> ModelDownloadRequest {
+    ModelDownloadRequest {
+        repo_id: "acme/tiny".to_string(),
+        family: "llama".to_string(),
+        official_name: "Tiny".to_string(),
+        filename: filename.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+async fn finished(downloader: &ModelDownloader, download_id: &str) -> DownloadProgress {
+    for _ in 0..500 {
+        let progress = downloader.progress(download_id).unwrap();
+        if progress.status.is_terminal() {
+            return progress;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("download {} did not finish", download_id);
+}
+
+#[tokio::test]
+async fn verified_download_is_imported() {
+    let body = weights(4096);
+    let hub = start_hub(
+        "acme/tiny",
+        vec![
+            RepoFile::lfs("tiny-Q4_K_M.gguf", body.clone()),
+            RepoFile::plain("README.md", b"# tiny".to_vec()),
+        ],
+    )
+    .await;
+    let staging = tempfile::tempdir().unwrap();
+    let importer = Arc::new(RecordingImporter::default());
+    let downloader = downloader(&hub, staging.path()).with_importer(importer.clone());
+
+    let id = downloader
+        .start(request(Some("tiny-Q4_K_M.gguf")))
+        .await
+        .unwrap();
+    let progress = finished(&downloader, &id).await;
+
+    assert_eq!(
+        progress.status,
+        DownloadStatus::Completed,
+        "{:?}",
+        progress.error
+    );
+    assert_eq!(progress.files.len(), 1);
+    assert_eq!(progress.files[0].state, FileState::Verified);
+    assert_eq!(progress.bytes_done, 4096);
+    assert_eq!(progress.bytes_total, Some(4096));
+    assert_eq!(progress.model_id.as_deref(), Some("llm/Tiny"));
+
+    let imports = importer.imports.lock().unwrap();
+    assert_eq!(imports.len(), 1);
+    assert_eq!(imports[0].family, "llama");
+    assert_eq!(std::fs::read(&imports[0].path).unwrap(), body);
+}
+
+#[tokio::test]
+async fn checksum_mismatch_fails_without_importing() {
+    let mut file = RepoFile::lfs("tiny.gguf", weights(2048));
+    file.published_sha256 = Some(sha256(b"something else"));
+    let hub = start_hub("acme/tiny", vec![file]).await;
+    let staging = tempfile::tempdir().unwrap();
+    let importer = Arc::new(RecordingImporter::default());
+    let downloader = downloader(&hub, staging.path()).with_importer(importer.clone());
+
+    let id = downloader.start(request(None)).await.unwrap();
+    let progress = finished(&downloader, &id).await;
+
+    assert_eq!(progress.status, DownloadStatus::Failed);
+    assert_eq!(progress.error_kind.as_deref(), Some("checksum_mismatch"));
+    assert_eq!(progress.files[0].state, FileState::Failed);
+    assert!(importer.imports.lock().unwrap().is_empty());
+    let repo_dir = staging.path().join("huggingface/acme--tiny/main");
+    assert!(!repo_dir.join("tiny.gguf").exists());
+    assert!(!repo_dir.join("tiny.gguf.part").exists());
+}
+
+#[tokio::test]
+async fn interrupted_download_resumes_from_the_part() {
+    let body = weights(8192);
+    let mut file = RepoFile::lfs("tiny.gguf", body.clone());
+    file.cut_once_at = Some(3000);
+    let hub = start_hub("acme/tiny", vec![file]).await;
+    let staging = tempfile::tempdir().unwrap();
+    let downloader = downloader(&hub, staging.path());
+    let part = staging
+        .path()
+        .join("huggingface/acme--tiny/main/tiny.gguf.part");
+
+    let first = downloader.start(request(None)).await.unwrap();
+    let progress = finished(&downloader, &first).await;
+    assert_eq!(progress.status, DownloadStatus::Failed);
+    assert_eq!(progress.error_kind.as_deref(), Some("http"));
+    assert_eq!(std::fs::metadata(&part).unwrap().len(), 3000);
+
+    let second = downloader.start(request(None)).await.unwrap();
+    let progress = finished(&downloader, &second).await;
+    assert_eq!(
+        progress.status,
+        DownloadStatus::Completed,
+        "{:?}",
+        progress.error
+    );
+    assert_eq!(progress.resumed_from_bytes, 3000);
+    assert_eq!(progress.files[0].state, FileState::Verified);
+    assert!(!part.exists());
+    assert_eq!(
+        std::fs::read(staging.path().join("huggingface/acme--tiny/main/tiny.gguf")).unwrap(),
+        body
+    );
+    assert_eq!(
+        hub.ranges_for("/acme/tiny/resolve/main/tiny.gguf"),
+        vec![None, Some("bytes=3000-".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn files_without_a_published_sha256_download_unverified() {
+    let hub = start_hub(
+        "acme/tiny",
+        vec![
+            RepoFile::lfs("model.safetensors", weights(4096)),
+            RepoFile::plain("config.json", b"{}".to_vec()),
+        ],
+    )
+    .await;
+    let staging = tempfile::tempdir().unwrap();
+    let downloader = downloader(&hub, staging.path());
+
+    let mut request = request(None);
+    request.max_concurrent_files = Some(2);
+    let id = downloader.start(request).await.unwrap();
+    let progress = finished(&downloader, &id).await;
+
+    assert_eq!(
+        progress.status,
+        DownloadStatus::Completed,
+        "{:?}",
+        progress.error
+    );
+    let state = |name: &str| {
+        progress
+            .files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.state)
+    };
+    assert_eq!(state("model.safetensors"), Some(FileState::Verified));
+    assert_eq!(state("config.json"), Some(FileState::Unverified));
+    assert_eq!(
+        progress.model_path,
+        Some(
+            staging
+                .path()
+                .join("huggingface/acme--tiny/main")
+                .display()
+                .to_string()
+        )
+    );
+}
+
+#[tokio::test]
+async fn unknown_files_fail_the_request() {
+    let hub = start_hub("acme/tiny", vec![RepoFile::lfs("tiny.gguf", weights(16))]).await;
+    let staging = tempfile::tempdir().unwrap();
+    let downloader = downloader(&hub, staging.path());
+
+    let id = downloader
+        .start(request(Some("missing.gguf")))
+        .await
+        .unwrap();
+    let progress = finished(&downloader, &id).await;
+
+    assert_eq!(progress.status, DownloadStatus::Failed);
+    assert_eq!(progress.error_kind.as_deref(), Some("invalid_request"));
+}
+
+#[tokio::test]
+async fn a_repo_revision_downloads_once_at_a_time_and_cancels() {
+    let hub = start_hub(
+        "acme/tiny",
+        vec![RepoFile::lfs("tiny.gguf", weights(65536))],
+    )
+    .await;
+    let staging = tempfile::tempdir().unwrap();
+    let downloader = downloader(&hub, staging.path());
+    downloader.set_bandwidth_limit(Some(1));
+
+    let id = downloader.start(request(None)).await.unwrap();
+    let duplicate = downloader.start(request(None)).await.unwrap_err();
+    assert_eq!(duplicate.kind(), "invalid_request");
+
+    assert!(downloader.cancel(&id));
+    assert!(!downloader.cancel(&id));
+    let progress = downloader.progress(&id).unwrap();
+    assert_eq!(progress.status, DownloadStatus::Cancelled);
+    assert_eq!(progress.error_kind.as_deref(), Some("cancelled"));
+
+    downloader.set_bandwidth_limit(None);
+    let resumed = downloader.start(request(None)).await.unwrap();
+    let progress = finished(&downloader, &resumed).await;
+    assert_eq!(
+        progress.status,
+        DownloadStatus::Completed,
+        "{:?}",
+        progress.error
+    );
+}
+
+#[tokio::test]
+async fn direct_url_downloads_verify_the_given_sha256() {
+    let body = weights(4096);
+    let hub = start_hub("acme/tiny", Vec::new()).await;
+    hub.route("/files/tiny-q4.gguf", body.clone());
+    let staging = tempfile::tempdir().unwrap();
+    let importer = Arc::new(RecordingImporter::default());
+    let downloader = downloader(&hub, staging.path()).with_importer(importer.clone());
+
+    let url = format!("{}/files/tiny-q4.gguf", hub.base_url);
+    let mut missing_metadata = ModelDownloadRequest {
+        download_url: Some(url.clone()),
+        ..Default::default()
+    };
+    let error = downloader
+        .start(missing_metadata.clone())
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), "invalid_request");
+
+    missing_metadata.family = "llama".to_string();
+    missing_metadata.official_name = "Tiny URL".to_string();
+    missing_metadata.sha256 = Some(sha256(&body).to_uppercase());
+    let id = downloader.start(missing_metadata).await.unwrap();
+    let progress = finished(&downloader, &id).await;
+
+    assert_eq!(
+        progress.status,
+        DownloadStatus::Completed,
+        "{:?}",
+        progress.error
+    );
+    assert_eq!(progress.source, DownloadSource::Url);
+    assert_eq!(progress.files[0].name, "tiny-q4.gguf");
+    assert_eq!(progress.files[0].state, FileState::Verified);
+    let imports = importer.imports.lock().unwrap();
+    assert_eq!(imports[0].family, "llama");
+    assert_eq!(imports[0].official_name, "Tiny URL");
+    assert_eq!(std::fs::read(&imports[0].path).unwrap(), body);
+}
+
+#[tokio::test]
+async fn direct_url_checksum_mismatch_fails() {
+    let hub = start_hub("acme/tiny", Vec::new()).await;
+    hub.route("/files/tiny.gguf", weights(512));
+    let staging = tempfile::tempdir().unwrap();
+    let downloader = downloader(&hub, staging.path());
+
+    let id = downloader
+        .start(ModelDownloadRequest {
+            download_url: Some(format!("{}/files/tiny.gguf", hub.base_url)),
+            family: "llama".to_string(),
+            official_name: "Tiny".to_string(),
+            sha256: Some(sha256(b"something else")),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let progress = finished(&downloader, &id).await;
+
+    assert_eq!(progress.status, DownloadStatus::Failed);
+    assert_eq!(progress.error_kind.as_deref(), Some("checksum_mismatch"));
+}
+
+#[tokio::test]
+async fn inspecting_a_url_suggests_the_request_metadata() {
+    let hub = start_hub("acme/tiny", Vec::new()).await;
+    hub.route("/files/tiny-q4.gguf", weights(1234));
+    let staging = tempfile::tempdir().unwrap();
+    let downloader = downloader(&hub, staging.path());
+
+    let inspection = downloader
+        .inspect_url(&format!("{}/files/tiny-q4.gguf", hub.base_url))
+        .await
+        .unwrap();
+
+    assert_eq!(inspection.filename.as_deref(), Some("tiny-q4.gguf"));
+    assert_eq!(inspection.size, Some(1234));
+    assert_eq!(
+        inspection.suggested_official_name.as_deref(),
+        Some("tiny-q4")
+    );
+    assert_eq!(inspection.required_fields, vec!["family", "official_name"]);
+    assert!(downloader.inspect_url("ftp://example.com/x").await.is_err());
+}
+
+#[tokio::test]
+async fn civitai_versions_download_the_primary_file() {
+    let body = weights(2048);
+    let hub = start_hub("acme/tiny", Vec::new()).await;
+    hub.route("/api/download/models/42", body.clone());
+    hub.route("/api/download/models/42-vae", weights(64));
+    hub.route(
+        "/api/v1/model-versions/42",
+        serde_json::to_vec(&serde_json::json!({
+            "model": { "name": "Tiny Diffusion", "type": "Checkpoint" },
+            "files": [
+                {
+                    "name": "tiny-vae.safetensors",
+                    "downloadUrl": format!("{}/api/download/models/42-vae", hub.base_url),
+                    "hashes": {},
+                },
+                {
+                    "name": "tiny.safetensors",
+                    "downloadUrl": format!("{}/api/download/models/42", hub.base_url),
+                    "primary": true,
+                    "hashes": { "SHA256": sha256(&body).to_uppercase() },
+                },
+            ],
+        }))
+        .unwrap(),
+    );
+    let staging = tempfile::tempdir().unwrap();
+    let importer = Arc::new(RecordingImporter::default());
+    let downloader = downloader(&hub, staging.path())
+        .with_civitai_endpoint(hub.base_url.clone())
+        .with_importer(importer.clone());
+
+    let id = downloader
+        .start(ModelDownloadRequest {
+            civitai_model_version_id: Some(42),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let progress = finished(&downloader, &id).await;
+
+    assert_eq!(
+        progress.status,
+        DownloadStatus::Completed,
+        "{:?}",
+        progress.error
+    );
+    assert_eq!(progress.source, DownloadSource::Civitai);
+    assert_eq!(progress.files.len(), 1);
+    assert_eq!(progress.files[0].name, "tiny.safetensors");
+    assert_eq!(progress.files[0].state, FileState::Verified);
+    let imports = importer.imports.lock().unwrap();
+    assert_eq!(imports[0].family, "checkpoint");
+    assert_eq!(imports[0].official_name, "Tiny Diffusion");
+    assert_eq!(
+        imports[0].path,
+        staging.path().join("civitai/42/tiny.safetensors")
+    );
+}
@@ -4,6 +4,7 @@
 //! workflow-service graph authoring, GUI, Tauri, UniFFI, Rustler, or other
 //! host-language surfaces.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::str::FromStr;
 
@@ -355,8 +356,46 @@ pub struct NodeAuthoringMetadata {
     pub icon: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<String>,
+    /// Semantic color token that themes may remap; `color` is its default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color_token: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub documentation_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<NodeDocumentation>,
+}
+
+/// Long-form node help for editor panels and example-based autofill.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct NodeDocumentation {
+    /// Markdown body.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<NodeDocumentationExample>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<NodeDocumentationLink>,
+}
+
+/// Example node data and port inputs, with the outputs they produce.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct NodeDocumentationExample {
+    pub title: String,
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub data: serde_json::Value,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub inputs: BTreeMap<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub outputs: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct NodeDocumentationLink {
+    pub label: String,
+    pub url: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -373,6 +412,31 @@ pub struct PortContract {
     pub constraints: Vec<PortConstraint>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub editor_hints: Vec<EditorHint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui: Option<PortUiHints>,
+}
+
+/// Handle shape for a port, grouped by the kind of value it carries.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PortShape {
+    #[default]
+    Circle,
+    Square,
+    Diamond,
+    Hexagon,
+}
+
+/// Standard port rendering hints, shared by every editor.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct PortUiHints {
+    /// Semantic color token that themes may remap, e.g. `data-kv-cache`.
+    pub color_token: String,
+    /// Default hex color for `color_token`.
+    pub color: String,
+    #[serde(default)]
+    pub shape: PortShape,
 }
 
 impl PortContract {
@@ -392,6 +456,7 @@ impl PortContract {
             visibility: PortVisibility::Public,
             constraints: Vec::new(),
             editor_hints: Vec::new(),
+            ui: None,
         }
     }
 
@@ -406,6 +471,7 @@ impl PortContract {
             visibility: PortVisibility::Public,
             constraints: Vec::new(),
             editor_hints: Vec::new(),
+            ui: None,
         }
     }
 
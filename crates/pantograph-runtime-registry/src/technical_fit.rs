@@ -16,16 +16,18 @@ pub enum RuntimeTechnicalFitFactor {
     WarmupCost,
     BudgetPressure,
     QueuePressure,
+    MeasuredThroughput,
 }
 
 impl RuntimeTechnicalFitFactor {
-    pub const ALL: [Self; 6] = [
+    pub const ALL: [Self; 7] = [
         Self::RequiredContextLength,
         Self::RuntimeRequirements,
         Self::ResidencyReuse,
         Self::WarmupCost,
         Self::BudgetPressure,
         Self::QueuePressure,
+        Self::MeasuredThroughput,
     ];
 
     pub fn all() -> &'static [Self] {
@@ -119,6 +121,10 @@ pub struct RuntimeTechnicalFitCandidate {
     pub warmup_state: Option<RuntimeTechnicalFitWarmupState>,
     #[serde(default)]
     pub supports_runtime_requirements: bool,
+    /// Generation throughput measured by a `model-benchmark` run for this
+    /// model and backend, used to prefer faster candidates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmark_tokens_per_second: Option<u64>,
 }
 
 impl RuntimeTechnicalFitCandidate {
@@ -145,6 +151,7 @@ impl RuntimeTechnicalFitCandidate {
             residency_state: self.residency_state,
             warmup_state: self.warmup_state,
             supports_runtime_requirements: self.supports_runtime_requirements,
+            benchmark_tokens_per_second: self.benchmark_tokens_per_second,
         }
     }
 }
@@ -225,6 +232,7 @@ pub enum RuntimeTechnicalFitReasonCode {
     WarmupCost,
     BudgetPressure,
     QueuePressure,
+    MeasuredThroughput,
     MissingCandidateData,
     MissingRuntimeState,
     DeterministicTieBreak,
@@ -372,6 +380,18 @@ pub fn select_runtime_technical_fit(
             ));
         }
 
+        if uses_factor(&normalized, RuntimeTechnicalFitFactor::MeasuredThroughput)
+            && eligible_candidates.iter().skip(1).any(|candidate| {
+                candidate_throughput_rank(selected_candidate, &normalized)
+                    > candidate_throughput_rank(candidate, &normalized)
+            })
+        {
+            reasons.push(RuntimeTechnicalFitReason::new(
+                RuntimeTechnicalFitReasonCode::MeasuredThroughput,
+                Some(selected_candidate.candidate_id.as_str()),
+            ));
+        }
+
         if eligible_candidates.iter().skip(1).any(|candidate| {
             compare_candidate_priority(selected_candidate, candidate, &normalized).is_eq()
         }) {
@@ -535,6 +555,7 @@ fn override_fallback_candidate(
         residency_state: None,
         warmup_state: None,
         supports_runtime_requirements: true,
+        benchmark_tokens_per_second: None,
     }
     .normalized()
 }
@@ -598,6 +619,11 @@ fn compare_candidate_priority(
                 .cmp(&candidate_budget_pressure_rank(right, request))
                 .reverse()
         })
+        .then_with(|| {
+            candidate_throughput_rank(left, request)
+                .cmp(&candidate_throughput_rank(right, request))
+                .reverse()
+        })
 }
 
 fn compare_candidate_ids(
@@ -763,6 +789,17 @@ fn candidate_budget_pressure_rank(
     runtime_headroom_rank(candidate, request)
 }
 
+fn candidate_throughput_rank(
+    candidate: &RuntimeTechnicalFitCandidate,
+    request: &RuntimeTechnicalFitRequest,
+) -> u64 {
+    if !uses_factor(request, RuntimeTechnicalFitFactor::MeasuredThroughput) {
+        return 0;
+    }
+
+    candidate.benchmark_tokens_per_second.unwrap_or(0)
+}
+
 fn runtime_headroom_rank(
     candidate: &RuntimeTechnicalFitCandidate,
     request: &RuntimeTechnicalFitRequest,
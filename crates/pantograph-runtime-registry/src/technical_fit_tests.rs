@@ -53,6 +53,7 @@ fn technical_fit_request_normalizes_inputs_and_defaults_legal_factors() {
             residency_state: Some(RuntimeTechnicalFitResidencyState::Loaded),
             warmup_state: Some(RuntimeTechnicalFitWarmupState::Warm),
             supports_runtime_requirements: true,
+            benchmark_tokens_per_second: None,
         }],
         resource_pressure: Some(RuntimeTechnicalFitResourcePressure {
             queued_run_count: Some(2),
@@ -176,6 +177,7 @@ fn selector_prefers_explicit_override_over_hotter_candidate() {
                 residency_state: Some(RuntimeTechnicalFitResidencyState::Active),
                 warmup_state: Some(RuntimeTechnicalFitWarmupState::Ready),
                 supports_runtime_requirements: true,
+                benchmark_tokens_per_second: None,
             },
             RuntimeTechnicalFitCandidate {
                 candidate_id: "runtime-b".to_string(),
@@ -187,6 +189,7 @@ fn selector_prefers_explicit_override_over_hotter_candidate() {
                 residency_state: Some(RuntimeTechnicalFitResidencyState::Loaded),
                 warmup_state: Some(RuntimeTechnicalFitWarmupState::Warm),
                 supports_runtime_requirements: true,
+                benchmark_tokens_per_second: None,
             },
         ],
         resource_pressure: None,
@@ -247,6 +250,7 @@ fn selector_uses_snapshot_residency_and_deterministic_tie_break() {
                 residency_state: None,
                 warmup_state: None,
                 supports_runtime_requirements: true,
+                benchmark_tokens_per_second: None,
             },
             RuntimeTechnicalFitCandidate {
                 candidate_id: "runtime-a".to_string(),
@@ -258,6 +262,7 @@ fn selector_uses_snapshot_residency_and_deterministic_tie_break() {
                 residency_state: None,
                 warmup_state: None,
                 supports_runtime_requirements: true,
+                benchmark_tokens_per_second: None,
             },
         ],
         resource_pressure: None,
@@ -296,6 +301,7 @@ fn selector_falls_back_conservatively_when_required_context_is_missing() {
             residency_state: None,
             warmup_state: None,
             supports_runtime_requirements: true,
+            benchmark_tokens_per_second: None,
         }],
         resource_pressure: None,
     });
@@ -337,6 +343,7 @@ fn selector_conservative_fallback_stays_with_required_backend_candidate() {
                 residency_state: Some(RuntimeTechnicalFitResidencyState::Active),
                 warmup_state: Some(RuntimeTechnicalFitWarmupState::Ready),
                 supports_runtime_requirements: true,
+                benchmark_tokens_per_second: None,
             },
             RuntimeTechnicalFitCandidate {
                 candidate_id: "llama_cpp".to_string(),
@@ -348,6 +355,7 @@ fn selector_conservative_fallback_stays_with_required_backend_candidate() {
                 residency_state: Some(RuntimeTechnicalFitResidencyState::Unloaded),
                 warmup_state: None,
                 supports_runtime_requirements: false,
+                benchmark_tokens_per_second: None,
             },
         ],
         resource_pressure: None,
@@ -405,6 +413,7 @@ fn selector_prefers_more_headroom_under_queue_pressure() {
                 residency_state: None,
                 warmup_state: None,
                 supports_runtime_requirements: true,
+                benchmark_tokens_per_second: None,
             },
             RuntimeTechnicalFitCandidate {
                 candidate_id: "runtime-cool".to_string(),
@@ -416,6 +425,7 @@ fn selector_prefers_more_headroom_under_queue_pressure() {
                 residency_state: None,
                 warmup_state: None,
                 supports_runtime_requirements: true,
+                benchmark_tokens_per_second: None,
             },
         ],
         resource_pressure: Some(RuntimeTechnicalFitResourcePressure {
@@ -476,6 +486,7 @@ fn selector_prefers_more_headroom_under_budget_pressure() {
                 residency_state: None,
                 warmup_state: None,
                 supports_runtime_requirements: true,
+                benchmark_tokens_per_second: None,
             },
             RuntimeTechnicalFitCandidate {
                 candidate_id: "runtime-roomy".to_string(),
@@ -487,6 +498,7 @@ fn selector_prefers_more_headroom_under_budget_pressure() {
                 residency_state: None,
                 warmup_state: None,
                 supports_runtime_requirements: true,
+                benchmark_tokens_per_second: None,
             },
         ],
         resource_pressure: Some(RuntimeTechnicalFitResourcePressure {
@@ -507,3 +519,45 @@ fn selector_prefers_more_headroom_under_budget_pressure() {
             && reason.candidate_id.as_deref() == Some("runtime-roomy")
     }));
 }
+
+#[test]
+fn selector_prefers_faster_benchmarked_model() {
+    let candidate = |model_id: &str, tokens_per_second: Option<u64>| RuntimeTechnicalFitCandidate {
+        candidate_id: model_id.to_string(),
+        runtime_id: None,
+        backend_key: Some("llama_cpp".to_string()),
+        model_id: Some(model_id.to_string()),
+        source_kind: RuntimeTechnicalFitCandidateSourceKind::PumasFeasible,
+        context_window_tokens: Some(8192),
+        residency_state: None,
+        warmup_state: None,
+        supports_runtime_requirements: true,
+        benchmark_tokens_per_second: tokens_per_second,
+    };
+    let decision = select_runtime_technical_fit(&RuntimeTechnicalFitRequest {
+        runtime_snapshot: empty_snapshot(),
+        workflow_id: Some("workflow-a".to_string()),
+        required_model_ids: Vec::new(),
+        required_backend_keys: vec!["llama_cpp".to_string()],
+        required_extensions: Vec::new(),
+        required_context_window_tokens: None,
+        override_selection: None,
+        legal_factors: RuntimeTechnicalFitFactor::all().to_vec(),
+        candidates: vec![
+            candidate("model-a", Some(18)),
+            candidate("model-b", Some(42)),
+            candidate("model-c", None),
+        ],
+        resource_pressure: None,
+    });
+
+    assert_eq!(decision.selected_model_id.as_deref(), Some("model-b"));
+    assert!(decision.reasons.iter().any(|reason| {
+        reason.code == RuntimeTechnicalFitReasonCode::MeasuredThroughput
+            && reason.candidate_id.as_deref() == Some("model-b")
+    }));
+    assert!(!decision
+        .reasons
+        .iter()
+        .any(|reason| reason.code == RuntimeTechnicalFitReasonCode::DeterministicTieBreak));
+}
@@ -67,6 +67,8 @@ pub struct ElixirCacheStats {
     pub cached_nodes: u32,
     pub total_versions: u32,
     pub global_version: u64,
+    /// Nodes whose last execution panicked
+    pub poisoned_nodes: Vec<String>,
 }
 
 /// Orchestration graph metadata for Elixir.
@@ -159,6 +159,10 @@ impl TaskExecutor for CoreFirstExecutor {
             other => other,
         }
     }
+
+    async fn shutdown(&self) {
+        self.core.shutdown().await;
+    }
 }
 
 /// EventSink that sends events to an Elixir PID.
@@ -174,15 +178,12 @@ impl BeamEventSink {
             owned_env: Arc::new(Mutex::new(OwnedEnv::new())),
         }
     }
-}
 
-impl EventSink for BeamEventSink {
-    fn send(
+    /// Send an already-serialized event on the `:workflow_event` channel.
+    pub(crate) fn send_json(
         &self,
-        event: node_engine::WorkflowEvent,
+        json: String,
     ) -> std::result::Result<(), node_engine::EventError> {
-        let json = serialize_workflow_event_json(&event)?;
-
         let pid = self.pid;
         let owned_env = self.owned_env.clone();
         std::thread::spawn(move || {
@@ -200,6 +201,16 @@ impl EventSink for BeamEventSink {
     }
 }
 
+impl EventSink for BeamEventSink {
+    fn send(
+        &self,
+        event: node_engine::WorkflowEvent,
+    ) -> std::result::Result<(), node_engine::EventError> {
+        let json = serialize_workflow_event_json(&event)?;
+        self.send_json(json)
+    }
+}
+
 pub(crate) fn callback_respond(callback_id: String, outputs_json: String) -> NifResult<Atom> {
     complete_callback(callback_id, Ok(outputs_json))
 }
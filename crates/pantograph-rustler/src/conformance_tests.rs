@@ -0,0 +1,62 @@
+//! Runs the node-engine conformance vectors through the routine
+//! `ElixirDataGraphExecutor` delegates to, with events serialized exactly as
+//! BEAM receives them.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use node_engine::conformance::{check_suite, ConformanceHarness, ConformanceObservation};
+use node_engine::{EventError, EventSink, TaskExecutor, WorkflowEvent, WorkflowGraph};
+
+use crate::workflow_event_contract::serialize_workflow_event_json;
+
+/// Vectors the NIF data-graph path does not satisfy yet.
+const KNOWN_DIVERGENCES: &[&str] = &[];
+
+/// Captures events exactly as they would be sent on `:workflow_event`.
+#[derive(Default)]
+struct BeamJsonEventSink {
+    events: Mutex<Vec<serde_json::Value>>,
+}
+
+impl EventSink for BeamJsonEventSink {
+    fn send(&self, event: WorkflowEvent) -> Result<(), EventError> {
+        let json = serialize_workflow_event_json(&event)?;
+        let value = serde_json::from_str(&json).map_err(|e| EventError {
+            message: e.to_string(),
+        })?;
+        self.events.lock().unwrap().push(value);
+        Ok(())
+    }
+}
+
+struct NifHarness;
+
+#[async_trait::async_trait]
+impl ConformanceHarness for NifHarness {
+    fn host(&self) -> &str {
+        "nif"
+    }
+
+    async fn run(
+        &self,
+        graph: &WorkflowGraph,
+        inputs: &HashMap<String, serde_json::Value>,
+        task_executor: Arc<dyn TaskExecutor>,
+    ) -> ConformanceObservation {
+        let sink = Arc::new(BeamJsonEventSink::default());
+        let result =
+            node_engine::execute_data_graph(graph, inputs, sink.clone(), task_executor.as_ref())
+                .await
+                .map_err(|error| error.kind().to_string());
+        let events = sink.events.lock().unwrap().clone();
+        ConformanceObservation { result, events }
+    }
+}
+
+#[tokio::test]
+async fn nif_data_graph_path_matches_conformance_vectors() {
+    check_suite(&NifHarness, KNOWN_DIVERGENCES)
+        .await
+        .unwrap_or_else(|report| panic!("{}", report));
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use node_engine::{EventSink, OrchestrationStore, TaskExecutor, WorkflowExecutor, WorkflowGraph};
+use node_engine::{EventSink, OrchestrationStore, TaskExecutor, WorkflowGraph};
 
 use crate::callback_bridge::BeamEventSink;
 
@@ -45,41 +45,8 @@ impl node_engine::DataGraphExecutor for ElixirDataGraphExecutor {
         };
 
         let event_sink: Arc<dyn EventSink> = Arc::new(BeamEventSink::new(self.event_sink_pid));
-        let exec_id = format!("data-graph-{}", graph_id);
-        let executor = WorkflowExecutor::new(&exec_id, graph.clone(), event_sink);
-
-        for (port, value) in &inputs {
-            for node in &graph.nodes {
-                let key = node_engine::ContextKeys::input(&node.id, port);
-                executor.set_context_value(&key, value.clone()).await;
-            }
-        }
-
-        let terminal_nodes: Vec<String> = graph
-            .nodes
-            .iter()
-            .filter(|node| !graph.edges.iter().any(|edge| edge.source == node.id))
-            .map(|node| node.id.clone())
-            .collect();
-
-        let demand_nodes = if terminal_nodes.is_empty() {
-            graph.nodes.iter().map(|node| node.id.clone()).collect()
-        } else {
-            terminal_nodes
-        };
-
-        let results = executor
-            .demand_multiple(&demand_nodes, self.task_executor.as_ref())
-            .await?;
-
-        let mut outputs = HashMap::new();
-        for (node_id, node_outputs) in results {
-            for (port, value) in node_outputs {
-                outputs.insert(format!("{}.{}", node_id, port), value);
-            }
-        }
-
-        Ok(outputs)
+        node_engine::execute_data_graph(&graph, &inputs, event_sink, self.task_executor.as_ref())
+            .await
     }
 
     fn get_data_graph(&self, graph_id: &str) -> Option<WorkflowGraph> {
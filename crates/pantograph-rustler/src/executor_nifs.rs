@@ -1,19 +1,26 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use node_engine::{EventSink, TaskExecutor, WorkflowExecutor, WorkflowGraph};
+use node_engine::{
+    EventSink, GraphLimits, SandboxPolicy, SandboxedTaskExecutor, SimulatedTaskExecutor,
+    SimulationConfig, TaskExecutor, WorkflowExecutor, WorkflowGraph,
+};
 use rustler::{Atom, Encoder, NifResult, OwnedEnv, ResourceArc};
 
 use crate::atoms;
 use crate::binding_types::ElixirCacheStats;
 use crate::callback_bridge::{BeamEventSink, CoreFirstExecutor, ElixirCallbackTaskExecutor};
 use crate::resources::{InferenceGatewayResource, WorkflowExecutorResource};
+use crate::workflow_graph_contract::estimate_graph_json;
+use crate::workflow_graph_patch::{
+    diff_workflow_graphs, graph_changed_event_json, revisioned_snapshot_json,
+};
 
 pub(crate) fn new_executor(
     graph_json: String,
     caller_pid: rustler::LocalPid,
 ) -> NifResult<ResourceArc<WorkflowExecutorResource>> {
-    create_executor_resource(graph_json, caller_pid, None, None)
+    create_executor_resource(graph_json, caller_pid, ExecutorResourceOptions::default())
 }
 
 pub(crate) fn new_executor_with_timeout(
@@ -21,7 +28,14 @@ pub(crate) fn new_executor_with_timeout(
     caller_pid: rustler::LocalPid,
     timeout_secs: u64,
 ) -> NifResult<ResourceArc<WorkflowExecutorResource>> {
-    create_executor_resource(graph_json, caller_pid, Some(timeout_secs), None)
+    create_executor_resource(
+        graph_json,
+        caller_pid,
+        ExecutorResourceOptions {
+            timeout_secs: Some(timeout_secs),
+            ..ExecutorResourceOptions::default()
+        },
+    )
 }
 
 pub(crate) fn new_inference_gateway(
@@ -49,7 +63,14 @@ pub(crate) fn new_executor_with_inference(
     caller_pid: rustler::LocalPid,
     gateway_resource: ResourceArc<InferenceGatewayResource>,
 ) -> NifResult<ResourceArc<WorkflowExecutorResource>> {
-    create_executor_resource(graph_json, caller_pid, None, Some(gateway_resource))
+    create_executor_resource(
+        graph_json,
+        caller_pid,
+        ExecutorResourceOptions {
+            gateway_resource: Some(gateway_resource),
+            ..ExecutorResourceOptions::default()
+        },
+    )
 }
 
 pub(crate) fn new_executor_with_inference_timeout(
@@ -61,45 +82,143 @@ pub(crate) fn new_executor_with_inference_timeout(
     create_executor_resource(
         graph_json,
         caller_pid,
-        Some(timeout_secs),
-        Some(gateway_resource),
+        ExecutorResourceOptions {
+            timeout_secs: Some(timeout_secs),
+            gateway_resource: Some(gateway_resource),
+            ..ExecutorResourceOptions::default()
+        },
     )
 }
 
-fn create_executor_resource(
+pub(crate) fn new_executor_sandboxed(
+    graph_json: String,
+    caller_pid: rustler::LocalPid,
+    policy_json: String,
+) -> NifResult<ResourceArc<WorkflowExecutorResource>> {
+    let policy: SandboxPolicy = serde_json::from_str(&policy_json)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Parse error: {}", e))))?;
+    policy
+        .validate()
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Sandbox policy error: {}", e))))?;
+    create_executor_resource(
+        graph_json,
+        caller_pid,
+        ExecutorResourceOptions {
+            sandbox_policy: Some(policy),
+            ..ExecutorResourceOptions::default()
+        },
+    )
+}
+
+pub(crate) fn new_executor_simulated(
+    graph_json: String,
+    caller_pid: rustler::LocalPid,
+    config_json: String,
+) -> NifResult<ResourceArc<WorkflowExecutorResource>> {
+    let config: SimulationConfig = serde_json::from_str(&config_json)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Parse error: {}", e))))?;
+    create_executor_resource(
+        graph_json,
+        caller_pid,
+        ExecutorResourceOptions {
+            simulation: Some(config),
+            ..ExecutorResourceOptions::default()
+        },
+    )
+}
+
+pub(crate) fn new_executor_with_limits(
     graph_json: String,
     caller_pid: rustler::LocalPid,
+    limits_json: String,
+) -> NifResult<ResourceArc<WorkflowExecutorResource>> {
+    let limits: GraphLimits = serde_json::from_str(&limits_json)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Parse error: {}", e))))?;
+    create_executor_resource(
+        graph_json,
+        caller_pid,
+        ExecutorResourceOptions {
+            graph_limits: Some(limits),
+            ..ExecutorResourceOptions::default()
+        },
+    )
+}
+
+/// Optional settings for an executor created by one of the `executor_new*`
+/// NIFs; each NIF fills in the fields it takes.
+#[derive(Default)]
+struct ExecutorResourceOptions {
+    /// Timeout for callbacks into Elixir.
     timeout_secs: Option<u64>,
     gateway_resource: Option<ResourceArc<InferenceGatewayResource>>,
+    sandbox_policy: Option<SandboxPolicy>,
+    /// Replaces the core and Elixir executors with simulated outputs.
+    simulation: Option<SimulationConfig>,
+    graph_limits: Option<GraphLimits>,
+}
+
+fn create_executor_resource(
+    graph_json: String,
+    caller_pid: rustler::LocalPid,
+    options: ExecutorResourceOptions,
 ) -> NifResult<ResourceArc<WorkflowExecutorResource>> {
+    let ExecutorResourceOptions {
+        timeout_secs,
+        gateway_resource,
+        sandbox_policy,
+        simulation,
+        graph_limits,
+    } = options;
     let graph: WorkflowGraph = serde_json::from_str(&graph_json)
         .map_err(|e| rustler::Error::Term(Box::new(format!("Parse error: {}", e))))?;
 
     let runtime = tokio::runtime::Runtime::new()
         .map_err(|e| rustler::Error::Term(Box::new(format!("Runtime error: {}", e))))?;
 
-    let event_sink: Arc<dyn EventSink> = Arc::new(BeamEventSink::new(caller_pid));
-    let core = match gateway_resource {
-        Some(gateway_resource) => node_engine::CoreTaskExecutor::new()
-            .with_gateway(gateway_resource.gateway.clone())
-            .with_event_sink(event_sink.clone())
-            .with_execution_id("nif-execution".to_string()),
-        None => node_engine::CoreTaskExecutor::new(),
-    };
-    let elixir = match timeout_secs {
-        Some(timeout_secs) => {
-            ElixirCallbackTaskExecutor::new(caller_pid).with_timeout(timeout_secs)
+    let graph_events = Arc::new(BeamEventSink::new(caller_pid));
+    let event_sink: Arc<dyn EventSink> = graph_events.clone();
+    let task_executor: Arc<dyn TaskExecutor> = match simulation {
+        Some(config) => Arc::new(SimulatedTaskExecutor::new(config)),
+        None => {
+            let core = match gateway_resource {
+                Some(gateway_resource) => node_engine::CoreTaskExecutor::new()
+                    .with_gateway(gateway_resource.gateway.clone())
+                    .with_event_sink(event_sink.clone())
+                    .with_execution_id("nif-execution".to_string()),
+                None => node_engine::CoreTaskExecutor::new(),
+            };
+            let elixir = match timeout_secs {
+                Some(timeout_secs) => {
+                    ElixirCallbackTaskExecutor::new(caller_pid).with_timeout(timeout_secs)
+                }
+                None => ElixirCallbackTaskExecutor::new(caller_pid),
+            };
+            let mut task_executor: Arc<dyn TaskExecutor> =
+                Arc::new(CoreFirstExecutor::new(core, elixir));
+            if let Some(policy) = sandbox_policy {
+                let registry = node_engine::NodeRegistry::with_builtins();
+                task_executor = Arc::new(
+                    SandboxedTaskExecutor::new(task_executor, policy)
+                        .with_registry_categories(&registry),
+                );
+            }
+            task_executor
         }
-        None => ElixirCallbackTaskExecutor::new(caller_pid),
     };
-    let task_executor: Arc<dyn TaskExecutor> = Arc::new(CoreFirstExecutor::new(core, elixir));
 
-    let executor = WorkflowExecutor::new("nif-execution", graph, event_sink);
+    let mut executor = WorkflowExecutor::new("nif-execution", graph, event_sink);
+    if let Some(limits) = graph_limits {
+        runtime
+            .block_on(executor.set_graph_limits(limits))
+            .map_err(|e| rustler::Error::Term(Box::new(format!("Graph limit error: {}", e))))?;
+    }
 
     Ok(ResourceArc::new(WorkflowExecutorResource {
         executor: Arc::new(tokio::sync::RwLock::new(executor)),
         task_executor,
         runtime: Arc::new(runtime),
+        graph_events,
+        graph_revision: Arc::new(tokio::sync::Mutex::new(0)),
     }))
 }
 
@@ -176,14 +295,63 @@ pub(crate) fn update_node_data(
     let data: serde_json::Value = serde_json::from_str(&data_json).unwrap_or_default();
 
     rt.block_on(async {
+        let mut revision = resource.graph_revision.lock().await;
         let exec = executor.read().await;
+        let before = exec.get_graph_snapshot().await;
         exec.update_node_data(&node_id, data)
             .await
             .map_err(|e| rustler::Error::Term(Box::new(format!("Update error: {}", e))))?;
+        let after = exec.get_graph_snapshot().await;
+        publish_graph_patch(&resource, &mut revision, &before, &after);
         Ok(atoms::ok())
     })
 }
 
+pub(crate) fn replace_graph(
+    resource: ResourceArc<WorkflowExecutorResource>,
+    graph_json: String,
+) -> NifResult<Atom> {
+    let rt = &resource.runtime;
+    let executor = &resource.executor;
+
+    let graph: WorkflowGraph = serde_json::from_str(&graph_json)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Parse error: {}", e))))?;
+
+    rt.block_on(async {
+        let mut revision = resource.graph_revision.lock().await;
+        let exec = executor.read().await;
+        let before = exec.get_graph_snapshot().await;
+        exec.replace_graph(graph.clone())
+            .await
+            .map_err(|e| rustler::Error::Term(Box::new(format!("Replace error: {}", e))))?;
+        publish_graph_patch(&resource, &mut revision, &before, &graph);
+        Ok(atoms::ok())
+    })
+}
+
+/// Emit a `graphChanged` patch and bump the revision when the graph changed.
+///
+/// Delivery failures are logged rather than surfaced: the mutation already
+/// happened, and hosts recover through the revision gap on their next event.
+fn publish_graph_patch(
+    resource: &WorkflowExecutorResource,
+    revision: &mut u64,
+    before: &WorkflowGraph,
+    after: &WorkflowGraph,
+) {
+    let patch = diff_workflow_graphs(before, after);
+    if patch.is_empty() {
+        return;
+    }
+
+    *revision += 1;
+    let sent = graph_changed_event_json(&after.id, *revision, &patch)
+        .and_then(|json| resource.graph_events.send_json(json));
+    if let Err(e) = sent {
+        log::warn!("Failed to send graphChanged event: {}", e.message);
+    }
+}
+
 pub(crate) fn mark_modified(
     resource: ResourceArc<WorkflowExecutorResource>,
     node_id: String,
@@ -211,10 +379,61 @@ pub(crate) fn cache_stats(
             cached_nodes: stats.cached_nodes as u32,
             total_versions: stats.total_versions as u32,
             global_version: stats.global_version,
+            poisoned_nodes: stats.poisoned_nodes,
         })
     })
 }
 
+pub(crate) fn set_deterministic_mode(
+    resource: ResourceArc<WorkflowExecutorResource>,
+    seed: Option<i64>,
+) -> Atom {
+    resource.runtime.block_on(async {
+        let exec = resource.executor.read().await;
+        exec.set_deterministic_mode(seed.map(|seed| node_engine::DeterministicMode { seed }))
+            .await;
+    });
+    atoms::ok()
+}
+
+pub(crate) fn shutdown(
+    resource: ResourceArc<WorkflowExecutorResource>,
+    checkpoint_path: Option<String>,
+) -> NifResult<String> {
+    let checkpoint_path = checkpoint_path.map(PathBuf::from);
+    resource.runtime.block_on(async {
+        let exec = resource.executor.read().await;
+        let report = exec
+            .shutdown(
+                Some(resource.task_executor.as_ref()),
+                checkpoint_path.as_deref(),
+            )
+            .await;
+        serde_json::to_string(&report)
+            .map_err(|e| rustler::Error::Term(Box::new(format!("Serialization error: {}", e))))
+    })
+}
+
+pub(crate) fn drain(
+    resource: ResourceArc<WorkflowExecutorResource>,
+    timeout_ms: u64,
+    checkpoint_path: Option<String>,
+) -> NifResult<String> {
+    let checkpoint_path = checkpoint_path.map(PathBuf::from);
+    resource.runtime.block_on(async {
+        let exec = resource.executor.read().await;
+        let report = exec
+            .drain(
+                std::time::Duration::from_millis(timeout_ms),
+                Some(resource.task_executor.as_ref()),
+                checkpoint_path.as_deref(),
+            )
+            .await;
+        serde_json::to_string(&report)
+            .map_err(|e| rustler::Error::Term(Box::new(format!("Serialization error: {}", e))))
+    })
+}
+
 pub(crate) fn get_graph_snapshot(
     resource: ResourceArc<WorkflowExecutorResource>,
 ) -> NifResult<String> {
@@ -229,6 +448,35 @@ pub(crate) fn get_graph_snapshot(
     })
 }
 
+pub(crate) fn estimate(
+    resource: ResourceArc<WorkflowExecutorResource>,
+    inputs_json: String,
+) -> NifResult<String> {
+    let rt = &resource.runtime;
+    let executor = &resource.executor;
+
+    rt.block_on(async {
+        let exec = executor.read().await;
+        let graph = exec.get_graph_snapshot().await;
+        estimate_graph_json(&graph, &inputs_json)
+    })
+}
+
+pub(crate) fn get_graph_snapshot_with_revision(
+    resource: ResourceArc<WorkflowExecutorResource>,
+) -> NifResult<String> {
+    let rt = &resource.runtime;
+    let executor = &resource.executor;
+
+    rt.block_on(async {
+        let revision = resource.graph_revision.lock().await;
+        let exec = executor.read().await;
+        let graph = exec.get_graph_snapshot().await;
+        revisioned_snapshot_json(&graph, *revision)
+            .map_err(|e| rustler::Error::Term(Box::new(format!("Serialization error: {}", e))))
+    })
+}
+
 pub(crate) fn set_input(
     resource: ResourceArc<WorkflowExecutorResource>,
     node_id: String,
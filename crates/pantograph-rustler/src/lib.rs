@@ -43,6 +43,7 @@ mod elixir_data_graph_executor;
 mod executor_nifs;
 #[cfg(feature = "frontend-http")]
 mod frontend_http_nifs;
+mod log_bridge_nifs;
 mod orchestration_execution_nifs;
 mod orchestration_store_nifs;
 mod pumas_nifs;
@@ -52,6 +53,7 @@ mod resources;
 mod type_parsing_contract;
 mod workflow_event_contract;
 mod workflow_graph_contract;
+mod workflow_graph_patch;
 #[cfg(feature = "frontend-http")]
 mod workflow_host_contract;
 
@@ -68,9 +70,9 @@ use type_parsing_contract::{
     parse_execution_mode_string, parse_node_category_string, parse_port_data_type_string,
 };
 use workflow_graph_contract::{
-    workflow_add_edge_json, workflow_add_node_json, workflow_from_json_string, workflow_new_json,
-    workflow_remove_edge_json, workflow_remove_node_json, workflow_update_node_data_json,
-    workflow_validate_json,
+    workflow_add_edge_json, workflow_add_node_json, workflow_estimate_json,
+    workflow_from_json_string, workflow_new_json, workflow_remove_edge_json,
+    workflow_remove_node_json, workflow_update_node_data_json, workflow_validate_json,
 };
 
 // ============================================================================
@@ -87,6 +89,7 @@ mod atoms {
         demand_error,
         node_stream,
         node_stream_done,
+        pantograph_log,
     }
 }
 
@@ -245,6 +248,15 @@ fn workflow_validate(graph_json: String) -> NifResult<Vec<String>> {
     workflow_validate_json(graph_json)
 }
 
+/// Estimate token usage, API calls, and wall time of running a workflow.
+///
+/// `inputs_json` is an object of run inputs keyed `"{node_id}.{port}"`.
+/// Returns JSON `ExecutionEstimate` with per-node and total predictions.
+#[rustler::nif]
+fn workflow_estimate(graph_json: String, inputs_json: String) -> NifResult<String> {
+    workflow_estimate_json(graph_json, inputs_json)
+}
+
 // ============================================================================
 // NIF Functions - Executor (dirty CPU scheduler)
 // ============================================================================
@@ -278,6 +290,57 @@ fn executor_new_with_timeout(
     executor_nifs::new_executor_with_timeout(graph_json, caller_pid, timeout_secs)
 }
 
+/// Create a new WorkflowExecutor that applies a node sandbox policy.
+///
+/// `policy_json` is a `SandboxPolicy`, e.g.
+/// `{"categories": {"tool": "isolated_process"}, "worker": {"program": "..."}}`.
+/// Isolated nodes run in a worker process so a crashing node cannot take the
+/// BEAM down; they cannot use Elixir callbacks or host extensions.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn executor_new_sandboxed(
+    env: Env,
+    graph_json: String,
+    caller_pid: rustler::LocalPid,
+    policy_json: String,
+) -> NifResult<ResourceArc<WorkflowExecutorResource>> {
+    let _ = env;
+    executor_nifs::new_executor_sandboxed(graph_json, caller_pid, policy_json)
+}
+
+/// Create a new WorkflowExecutor that simulates every node.
+///
+/// `config_json` is a `SimulationConfig`, e.g.
+/// `{"latencyMs": 50, "nodeOutputs": {"check-1": {"result": true}}}`.
+/// Nodes return typed placeholder outputs after their synthetic latency;
+/// no model, network, or Elixir callback is ever invoked.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn executor_new_simulated(
+    env: Env,
+    graph_json: String,
+    caller_pid: rustler::LocalPid,
+    config_json: String,
+) -> NifResult<ResourceArc<WorkflowExecutorResource>> {
+    let _ = env;
+    executor_nifs::new_executor_simulated(graph_json, caller_pid, config_json)
+}
+
+/// Create a new WorkflowExecutor that enforces graph size limits.
+///
+/// `limits_json` is a `GraphLimits`, e.g.
+/// `{"maxNodes": 500, "maxEdges": 2000, "maxNestingDepth": 8}`.
+/// Creation fails when the initial graph already exceeds them, and
+/// `executor_replace_graph` rejects replacements that would.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn executor_new_with_limits(
+    env: Env,
+    graph_json: String,
+    caller_pid: rustler::LocalPid,
+    limits_json: String,
+) -> NifResult<ResourceArc<WorkflowExecutorResource>> {
+    let _ = env;
+    executor_nifs::new_executor_with_limits(graph_json, caller_pid, limits_json)
+}
+
 // ============================================================================
 // NIF Functions - Inference Gateway
 // ============================================================================
@@ -398,6 +461,45 @@ fn executor_cache_stats(
     executor_nifs::cache_stats(resource)
 }
 
+/// Enable deterministic execution with `seed`, or disable it with `nil`.
+///
+/// Deterministic mode pins seeds, forces temperature 0, demands nodes in a
+/// stable order, and rejects nondeterministic nodes. Clears the cache.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn executor_set_deterministic_mode(
+    resource: ResourceArc<WorkflowExecutorResource>,
+    seed: Option<i64>,
+) -> Atom {
+    executor_nifs::set_deterministic_mode(resource, seed)
+}
+
+/// Shut the executor down and return the shutdown report as JSON.
+///
+/// Cancels running demands, flushes events, writes the demand cache to
+/// `checkpoint_path` when given, and stops the executor's inference backend.
+/// Later demands on this executor fail.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn executor_shutdown(
+    resource: ResourceArc<WorkflowExecutorResource>,
+    checkpoint_path: Option<String>,
+) -> NifResult<String> {
+    executor_nifs::shutdown(resource, checkpoint_path)
+}
+
+/// Drain the executor and return the drain report as JSON.
+///
+/// New demands fail at once; running demands get `timeout_ms` to finish
+/// before the rest are cancelled and the executor is shut down as by
+/// `executor_shutdown`.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn executor_drain(
+    resource: ResourceArc<WorkflowExecutorResource>,
+    timeout_ms: u64,
+    checkpoint_path: Option<String>,
+) -> NifResult<String> {
+    executor_nifs::drain(resource, timeout_ms, checkpoint_path)
+}
+
 /// Get a snapshot of the current graph as JSON.
 #[rustler::nif(schedule = "DirtyCpu")]
 fn executor_get_graph_snapshot(
@@ -406,6 +508,40 @@ fn executor_get_graph_snapshot(
     executor_nifs::get_graph_snapshot(resource)
 }
 
+/// Estimate the cost of running the executor's current graph.
+///
+/// Same result format as `workflow_estimate`.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn executor_estimate(
+    resource: ResourceArc<WorkflowExecutorResource>,
+    inputs_json: String,
+) -> NifResult<String> {
+    executor_nifs::estimate(resource, inputs_json)
+}
+
+/// Get the current graph as JSON together with its `snapshotRevision`.
+///
+/// Hosts tracking `graphChanged` patches call this to resync after a gap in
+/// revisions.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn executor_get_graph_snapshot_with_revision(
+    resource: ResourceArc<WorkflowExecutorResource>,
+) -> NifResult<String> {
+    executor_nifs::get_graph_snapshot_with_revision(resource)
+}
+
+/// Replace the executor graph and emit a `graphChanged` patch describing the
+/// structural difference (clears the demand cache).
+///
+/// Fails without changing the graph when it exceeds the executor's limits.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn executor_replace_graph(
+    resource: ResourceArc<WorkflowExecutorResource>,
+    graph_json: String,
+) -> NifResult<Atom> {
+    executor_nifs::replace_graph(resource, graph_json)
+}
+
 // ============================================================================
 // NIF Functions - Executor I/O
 // ============================================================================
@@ -436,6 +572,32 @@ fn executor_get_output(
     executor_nifs::get_output(resource, node_id, port)
 }
 
+// ============================================================================
+// NIF Functions - Host Logging
+// ============================================================================
+
+/// Forward engine `log` records at or above `level` to `caller_pid` as
+/// `{:pantograph_log, record_json}` messages.
+///
+/// Replaces any previously installed forwarder. `level` is one of `error`,
+/// `warn`, `info`, `debug`, or `trace`.
+#[rustler::nif]
+fn log_sink_install(caller_pid: rustler::LocalPid, level: String) -> NifResult<Atom> {
+    log_bridge_nifs::install(caller_pid, level)
+}
+
+/// Change the forwarding level of the installed log forwarder.
+#[rustler::nif]
+fn log_sink_set_level(level: String) -> NifResult<Atom> {
+    log_bridge_nifs::set_level(level)
+}
+
+/// Stop forwarding engine log records.
+#[rustler::nif]
+fn log_sink_clear() -> Atom {
+    log_bridge_nifs::clear()
+}
+
 // ============================================================================
 // NIF Functions - Callback Bridge
 // ============================================================================
@@ -592,6 +754,20 @@ fn extensions_setup(
     registry_nifs::extensions_setup(resource, library_path)
 }
 
+/// Initialize extensions from a TOML or JSON extensions config file.
+///
+/// Wraps `workflow_nodes::setup_extensions_from_file()`: installs the
+/// configured secrets provider, filesystem policy, backend endpoints, and KV
+/// cache store, then PumasApi from `pumas_library_path` with the same
+/// discovery chain as `extensions_setup`.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn extensions_setup_from_file(
+    resource: ResourceArc<ExtensionsResource>,
+    config_path: String,
+) -> NifResult<Atom> {
+    registry_nifs::extensions_setup_from_file(resource, config_path)
+}
+
 /// Query available options for a node's port.
 ///
 /// Dispatches to the registered `PortOptionsProvider` for the given node type
@@ -663,6 +839,81 @@ fn execute_orchestration_with_inference(
     )
 }
 
+/// Execute an orchestration graph with every data-graph node simulated.
+///
+/// `config_json` is a `SimulationConfig`. Control flow, loops, and merges run
+/// for real; data-graph nodes return mocked outputs after their synthetic
+/// latency. Simulated runs never checkpoint.
+///
+/// Returns JSON string of OrchestrationResult.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn execute_orchestration_simulated(
+    env: Env,
+    store_resource: ResourceArc<OrchestrationStoreResource>,
+    graph_id: String,
+    initial_data_json: String,
+    callback_pid: rustler::LocalPid,
+    config_json: String,
+) -> NifResult<String> {
+    let _ = env;
+    orchestration_execution_nifs::execute_simulated(
+        store_resource,
+        graph_id,
+        initial_data_json,
+        callback_pid,
+        config_json,
+    )
+}
+
+/// List orchestration executions that can be resumed after a host restart.
+///
+/// Returns JSON array of ResumableExecution; empty for in-memory stores.
+/// Call after loading the persistent store, then resume or discard each.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn orchestration_recover_executions(
+    store_resource: ResourceArc<OrchestrationStoreResource>,
+) -> NifResult<String> {
+    orchestration_store_nifs::recover_executions(store_resource)
+}
+
+/// Resume a recovered orchestration execution from its last checkpoint.
+///
+/// Journaled events are replayed to callback_pid before execution continues.
+/// Returns JSON string of OrchestrationResult.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn orchestration_resume_execution(
+    store_resource: ResourceArc<OrchestrationStoreResource>,
+    execution_id: String,
+    callback_pid: rustler::LocalPid,
+) -> NifResult<String> {
+    orchestration_execution_nifs::resume(store_resource, execution_id, callback_pid)
+}
+
+/// Resume a recovered orchestration execution with inference gateway support.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn orchestration_resume_execution_with_inference(
+    store_resource: ResourceArc<OrchestrationStoreResource>,
+    execution_id: String,
+    callback_pid: rustler::LocalPid,
+    gateway_resource: ResourceArc<InferenceGatewayResource>,
+) -> NifResult<String> {
+    orchestration_execution_nifs::resume_with_inference(
+        store_resource,
+        execution_id,
+        callback_pid,
+        gateway_resource,
+    )
+}
+
+/// Discard the checkpoint of a recovered orchestration execution.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn orchestration_discard_execution(
+    store_resource: ResourceArc<OrchestrationStoreResource>,
+    execution_id: String,
+) -> NifResult<bool> {
+    orchestration_store_nifs::discard_execution(store_resource, execution_id)
+}
+
 /// Insert a data graph (workflow) into the orchestration store.
 ///
 /// Data graphs are the low-level workflow graphs that orchestration
@@ -698,6 +949,16 @@ fn pumas_api_new(launcher_root_path: String) -> NifResult<ResourceArc<PumasApiRe
     pumas_nifs::api_new(launcher_root_path)
 }
 
+/// Create a PumasApi view merging several library roots.
+///
+/// `roots_json` is a JSON array of `{"path", "read_only", "priority"}`.
+/// Listing, search, and lookup span every root, preferring higher priority on
+/// duplicate model IDs; other calls use the highest-priority writable root.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn pumas_api_new_with_roots(roots_json: String) -> NifResult<ResourceArc<PumasApiResource>> {
+    pumas_nifs::api_new_with_roots(roots_json)
+}
+
 /// Inject a PumasApi into a WorkflowExecutor's extensions.
 #[rustler::nif(schedule = "DirtyCpu")]
 fn executor_set_pumas_api(
@@ -750,6 +1011,47 @@ fn pumas_rebuild_index(resource: ResourceArc<PumasApiResource>) -> NifResult<usi
     pumas_nifs::rebuild_index(resource)
 }
 
+// --- Garbage collection NIFs ---
+
+/// List models unused for `unused_for_days`. Returns JSON array of UnusedModel.
+///
+/// Pinned models and models referenced by the data graphs in `store` are
+/// never listed. Uses are recorded by executors given this resource through
+/// `executor_set_pumas_api`.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn pumas_unused_models(
+    resource: ResourceArc<PumasApiResource>,
+    store: Option<ResourceArc<OrchestrationStoreResource>>,
+    unused_for_days: u32,
+) -> NifResult<String> {
+    pumas_nifs::unused_models(resource, store, unused_for_days)
+}
+
+/// Delete models unused for `unused_for_days`, or only report them when
+/// `dry_run` is true. Returns JSON ModelGcReport.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn pumas_gc(
+    resource: ResourceArc<PumasApiResource>,
+    store: Option<ResourceArc<OrchestrationStoreResource>>,
+    unused_for_days: u32,
+    dry_run: bool,
+) -> NifResult<String> {
+    pumas_nifs::gc(resource, store, unused_for_days, dry_run)
+}
+
+/// Pin a model so garbage collection never deletes it. Returns false if it
+/// was already pinned.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn pumas_pin_model(resource: ResourceArc<PumasApiResource>, model_id: String) -> NifResult<bool> {
+    pumas_nifs::pin_model(resource, model_id)
+}
+
+/// Remove a model pin. Returns false if the model was not pinned.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn pumas_unpin_model(resource: ResourceArc<PumasApiResource>, model_id: String) -> NifResult<bool> {
+    pumas_nifs::unpin_model(resource, model_id)
+}
+
 // --- HuggingFace NIFs ---
 
 /// Search HuggingFace for models. Returns JSON array of HuggingFaceModel.
@@ -806,6 +1108,64 @@ fn pumas_cancel_download(
     pumas_nifs::cancel_download(resource, download_id)
 }
 
+/// Start a checksum-verified, resumable download. Returns the download ID.
+///
+/// Takes the same JSON as `pumas_start_download`, plus optional `revision`
+/// and `max_concurrent_files`. Set `download_url` (with `family`,
+/// `official_name`, and optionally `sha256`) to import a plain URL, or
+/// `civitai_model_version_id` to download a Civitai model version. Files are
+/// staged next to the library, checked against their sha256, then imported.
+/// Starting the same request again after a cancel or failure resumes its
+/// partial files.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn pumas_start_verified_download(
+    resource: ResourceArc<PumasApiResource>,
+    request_json: String,
+) -> NifResult<String> {
+    pumas_nifs::start_verified_download(resource, request_json)
+}
+
+/// Get verified download progress. Returns JSON DownloadProgress (with
+/// per-file `state` and `error_kind`) or nil.
+#[rustler::nif]
+fn pumas_get_verified_download_progress(
+    resource: ResourceArc<PumasApiResource>,
+    download_id: String,
+) -> NifResult<Option<String>> {
+    pumas_nifs::get_verified_download_progress(resource, download_id)
+}
+
+/// Cancel a verified download, keeping its partial files. Returns true if
+/// it was running.
+#[rustler::nif]
+fn pumas_cancel_verified_download(
+    resource: ResourceArc<PumasApiResource>,
+    download_id: String,
+) -> NifResult<bool> {
+    pumas_nifs::cancel_verified_download(resource, download_id)
+}
+
+/// Describe what a URL serves before downloading it. Returns JSON
+/// UrlInspection with the file name, size, and the request fields still to
+/// prompt for.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn pumas_inspect_download_url(
+    resource: ResourceArc<PumasApiResource>,
+    url: String,
+) -> NifResult<String> {
+    pumas_nifs::inspect_download_url(resource, url)
+}
+
+/// Cap the combined rate of all verified downloads in bytes per second, or
+/// lift the cap with nil.
+#[rustler::nif]
+fn pumas_set_download_bandwidth_limit(
+    resource: ResourceArc<PumasApiResource>,
+    bytes_per_sec: Option<u64>,
+) -> NifResult<Atom> {
+    pumas_nifs::set_download_bandwidth_limit(resource, bytes_per_sec)
+}
+
 // --- Import NIFs ---
 
 /// Import a model into the library. Returns JSON ModelImportResult.
@@ -864,4 +1224,6 @@ rustler::init!("Elixir.Pantograph.Native", load = load);
 // Integration testing of NIF functions requires an Elixir/Erlang runtime.
 // The crate-local tests verify the underlying non-NIF logic.
 #[cfg(test)]
+mod conformance_tests;
+#[cfg(test)]
 mod lib_tests;
@@ -81,6 +81,8 @@ fn test_node_registry_metadata() {
         inputs: vec![],
         outputs: vec![],
         execution_mode: node_engine::ExecutionMode::Reactive,
+        docs: node_engine::NodeDocs::default(),
+        ui: node_engine::NodeUiHints::default(),
     };
 
     registry.register_metadata(metadata);
@@ -92,6 +94,42 @@ fn test_node_registry_metadata() {
     assert!(json.contains("test-node"));
 }
 
+#[test]
+fn test_builtin_registry_json_includes_node_docs() {
+    let mut registry = node_engine::NodeRegistry::new();
+    registry.register_builtins();
+
+    let json = serde_json::to_value(registry.all_metadata()).unwrap();
+    let sort = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|metadata| metadata["nodeType"] == "sort")
+        .expect("sort metadata");
+    assert!(sort["docs"]["body"]
+        .as_str()
+        .is_some_and(|body| !body.is_empty()));
+    assert_eq!(sort["docs"]["examples"][0]["data"]["order"], "desc");
+}
+
+#[test]
+fn test_builtin_registry_json_includes_ui_hints() {
+    let mut registry = node_engine::NodeRegistry::new();
+    registry.register_builtins();
+
+    let json = serde_json::to_value(registry.all_metadata()).unwrap();
+    let delay = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|metadata| metadata["nodeType"] == "delay")
+        .expect("delay metadata");
+    assert_eq!(delay["ui"]["icon"], "timer");
+    assert_eq!(delay["ui"]["colorToken"], "category-control");
+    assert_eq!(delay["inputs"][0]["ui"]["colorToken"], "data-any");
+    assert_eq!(delay["inputs"][1]["ui"]["shape"], "circle");
+}
+
 #[test]
 fn test_task_metadata_json_roundtrip() {
     let json = r#"{
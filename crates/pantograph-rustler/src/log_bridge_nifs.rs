@@ -0,0 +1,71 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+
+use node_engine::{LogLevel, LogRecord, LogSink};
+use rustler::{Atom, Encoder, NifResult, OwnedEnv};
+
+use crate::atoms;
+
+/// LogSink that forwards records to an Elixir PID as
+/// `{:pantograph_log, record_json}` messages.
+///
+/// `OwnedEnv` sends must not happen on BEAM-managed threads, and records are
+/// logged from arbitrary threads, so a dedicated forwarder thread owns the
+/// env and drains a channel. The thread exits when the sink is dropped.
+struct BeamLogSink {
+    sender: Mutex<mpsc::Sender<String>>,
+}
+
+impl BeamLogSink {
+    fn spawn(pid: rustler::LocalPid) -> Self {
+        let (sender, receiver) = mpsc::channel::<String>();
+        std::thread::spawn(move || {
+            let mut env = OwnedEnv::new();
+            while let Ok(json) = receiver.recv() {
+                let _ = env.send_and_clear(&pid, |env| {
+                    (atoms::pantograph_log().encode(env), json.encode(env)).encode(env)
+                });
+            }
+        });
+        Self {
+            sender: Mutex::new(sender),
+        }
+    }
+}
+
+impl LogSink for BeamLogSink {
+    fn log(&self, record: LogRecord) {
+        let Ok(json) = serde_json::to_string(&record) else {
+            return;
+        };
+        if let Ok(sender) = self.sender.lock() {
+            let _ = sender.send(json);
+        }
+    }
+}
+
+fn parse_level(level: &str) -> NifResult<LogLevel> {
+    LogLevel::parse(level).ok_or_else(|| {
+        rustler::Error::Term(Box::new(format!(
+            "Invalid log level '{}': expected error, warn, info, debug, or trace",
+            level
+        )))
+    })
+}
+
+pub(crate) fn install(caller_pid: rustler::LocalPid, level: String) -> NifResult<Atom> {
+    let level = parse_level(&level)?;
+    node_engine::install_log_sink(Arc::new(BeamLogSink::spawn(caller_pid)), level)
+        .map_err(|e| rustler::Error::Term(Box::new(e.to_string())))?;
+    Ok(atoms::ok())
+}
+
+pub(crate) fn set_level(level: String) -> NifResult<Atom> {
+    node_engine::set_log_sink_level(parse_level(&level)?);
+    Ok(atoms::ok())
+}
+
+pub(crate) fn clear() -> Atom {
+    node_engine::clear_log_sink();
+    atoms::ok()
+}
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use node_engine::{EventSink, TaskExecutor, WorkflowGraph};
+use node_engine::{
+    EventSink, SimulatedTaskExecutor, SimulationConfig, TaskExecutor, WorkflowGraph,
+};
 use rustler::{Atom, NifResult, ResourceArc};
 
 use crate::atoms;
@@ -19,16 +21,12 @@ pub(crate) fn execute(
     let graph = get_orchestration_graph(&store_resource, &graph_id)?;
     let runtime = create_runtime()?;
 
-    let core = node_engine::CoreTaskExecutor::new();
-    let elixir = ElixirCallbackTaskExecutor::new(callback_pid);
-    let task_executor: Arc<dyn TaskExecutor> = Arc::new(CoreFirstExecutor::new(core, elixir));
+    let task_executor = default_task_executor(callback_pid);
     let event_sink = BeamEventSink::new(callback_pid);
 
-    let data_executor =
-        ElixirDataGraphExecutor::new(store_resource.store.clone(), task_executor, callback_pid);
-
-    let orch_executor = node_engine::OrchestrationExecutor::new(data_executor)
-        .with_execution_id(format!("nif-orch-{}", graph_id));
+    let execution_id = execution_id_for(&store_resource, &graph_id);
+    let orch_executor =
+        build_orchestration_executor(&store_resource, task_executor, callback_pid, execution_id);
 
     let result = runtime.block_on(async {
         orch_executor
@@ -50,23 +48,110 @@ pub(crate) fn execute_with_inference(
     let graph = get_orchestration_graph(&store_resource, &graph_id)?;
     let runtime = create_runtime()?;
 
+    let execution_id = execution_id_for(&store_resource, &graph_id);
     let event_sink: Arc<dyn EventSink> = Arc::new(BeamEventSink::new(callback_pid));
     let core = node_engine::CoreTaskExecutor::new()
         .with_gateway(gateway_resource.gateway.clone())
         .with_event_sink(event_sink.clone())
-        .with_execution_id(format!("nif-orch-{}", graph_id));
+        .with_execution_id(execution_id.clone());
     let elixir = ElixirCallbackTaskExecutor::new(callback_pid);
     let task_executor: Arc<dyn TaskExecutor> = Arc::new(CoreFirstExecutor::new(core, elixir));
 
+    let orch_executor =
+        build_orchestration_executor(&store_resource, task_executor, callback_pid, execution_id);
+
+    let result = runtime.block_on(async {
+        orch_executor
+            .execute(&graph, initial_data, event_sink.as_ref())
+            .await
+    });
+
+    serialize_orchestration_result(result)
+}
+
+/// Resume an execution from the checkpoint a previous host process left in
+/// the store's persistence directory.
+///
+/// Events journaled before the checkpoint are replayed to `callback_pid`
+/// before execution continues. Returns JSON string of OrchestrationResult.
+/// Run an orchestration against a `SimulatedTaskExecutor`.
+///
+/// Simulated runs never checkpoint, so they cannot leave recoverable
+/// executions behind in a persistent store.
+pub(crate) fn execute_simulated(
+    store_resource: ResourceArc<OrchestrationStoreResource>,
+    graph_id: String,
+    initial_data_json: String,
+    callback_pid: rustler::LocalPid,
+    config_json: String,
+) -> NifResult<String> {
+    let initial_data = parse_initial_data(initial_data_json)?;
+    let config: SimulationConfig = serde_json::from_str(&config_json)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Parse error: {}", e))))?;
+    let graph = get_orchestration_graph(&store_resource, &graph_id)?;
+    let runtime = create_runtime()?;
+
+    let task_executor: Arc<dyn TaskExecutor> = Arc::new(SimulatedTaskExecutor::new(config));
+    let event_sink = BeamEventSink::new(callback_pid);
     let data_executor =
         ElixirDataGraphExecutor::new(store_resource.store.clone(), task_executor, callback_pid);
-
     let orch_executor = node_engine::OrchestrationExecutor::new(data_executor)
-        .with_execution_id(format!("nif-orch-{}", graph_id));
+        .with_execution_id(format!("nif-sim-{}", graph_id));
 
     let result = runtime.block_on(async {
         orch_executor
-            .execute(&graph, initial_data, event_sink.as_ref())
+            .execute(&graph, initial_data, &event_sink)
+            .await
+    });
+
+    serialize_orchestration_result(result)
+}
+
+pub(crate) fn resume(
+    store_resource: ResourceArc<OrchestrationStoreResource>,
+    execution_id: String,
+    callback_pid: rustler::LocalPid,
+) -> NifResult<String> {
+    let (checkpoint, graph) = load_checkpoint(&store_resource, &execution_id)?;
+    let runtime = create_runtime()?;
+
+    let task_executor = default_task_executor(callback_pid);
+    let event_sink = BeamEventSink::new(callback_pid);
+
+    let orch_executor =
+        build_orchestration_executor(&store_resource, task_executor, callback_pid, execution_id);
+
+    let result =
+        runtime.block_on(async { orch_executor.resume(&graph, checkpoint, &event_sink).await });
+
+    serialize_orchestration_result(result)
+}
+
+/// Same as `resume` but with inference gateway support, for executions that
+/// were started with `execute_with_inference`.
+pub(crate) fn resume_with_inference(
+    store_resource: ResourceArc<OrchestrationStoreResource>,
+    execution_id: String,
+    callback_pid: rustler::LocalPid,
+    gateway_resource: ResourceArc<InferenceGatewayResource>,
+) -> NifResult<String> {
+    let (checkpoint, graph) = load_checkpoint(&store_resource, &execution_id)?;
+    let runtime = create_runtime()?;
+
+    let event_sink: Arc<dyn EventSink> = Arc::new(BeamEventSink::new(callback_pid));
+    let core = node_engine::CoreTaskExecutor::new()
+        .with_gateway(gateway_resource.gateway.clone())
+        .with_event_sink(event_sink.clone())
+        .with_execution_id(execution_id.clone());
+    let elixir = ElixirCallbackTaskExecutor::new(callback_pid);
+    let task_executor: Arc<dyn TaskExecutor> = Arc::new(CoreFirstExecutor::new(core, elixir));
+
+    let orch_executor =
+        build_orchestration_executor(&store_resource, task_executor, callback_pid, execution_id);
+
+    let result = runtime.block_on(async {
+        orch_executor
+            .resume(&graph, checkpoint, event_sink.as_ref())
             .await
     });
 
@@ -105,6 +190,84 @@ fn get_orchestration_graph(
     })
 }
 
+fn default_task_executor(callback_pid: rustler::LocalPid) -> Arc<dyn TaskExecutor> {
+    let core = node_engine::CoreTaskExecutor::new();
+    let elixir = ElixirCallbackTaskExecutor::new(callback_pid);
+    Arc::new(CoreFirstExecutor::new(core, elixir))
+}
+
+/// Execution ID for a fresh NIF orchestration run.
+///
+/// In-memory stores use the stable `nif-orch-{graph_id}` ID. Persistent
+/// stores checkpoint every run, so the ID gets a UUID suffix to keep
+/// concurrent runs of one graph from overwriting each other's checkpoints.
+/// Resumed runs keep their checkpointed ID instead.
+fn execution_id_for(
+    store_resource: &ResourceArc<OrchestrationStoreResource>,
+    graph_id: &str,
+) -> String {
+    if store_resource
+        .store
+        .blocking_read()
+        .checkpoint_store()
+        .is_some()
+    {
+        format!("nif-orch-{}-{}", graph_id, uuid::Uuid::new_v4())
+    } else {
+        format!("nif-orch-{}", graph_id)
+    }
+}
+
+/// Build the orchestration executor for a NIF run under `execution_id`,
+/// which task executors built for the run must share so task and
+/// orchestration events carry the same ID.
+fn build_orchestration_executor(
+    store_resource: &ResourceArc<OrchestrationStoreResource>,
+    task_executor: Arc<dyn TaskExecutor>,
+    callback_pid: rustler::LocalPid,
+    execution_id: String,
+) -> node_engine::OrchestrationExecutor<ElixirDataGraphExecutor> {
+    let checkpoints = store_resource.store.blocking_read().checkpoint_store();
+    let data_executor =
+        ElixirDataGraphExecutor::new(store_resource.store.clone(), task_executor, callback_pid);
+
+    let mut orch_executor =
+        node_engine::OrchestrationExecutor::new(data_executor).with_execution_id(execution_id);
+    if let Some(checkpoints) = checkpoints {
+        orch_executor = orch_executor.with_checkpoints(checkpoints);
+    }
+    orch_executor
+}
+
+fn load_checkpoint(
+    store_resource: &ResourceArc<OrchestrationStoreResource>,
+    execution_id: &str,
+) -> NifResult<(
+    node_engine::OrchestrationCheckpoint,
+    node_engine::OrchestrationGraph,
+)> {
+    let checkpoints = store_resource
+        .store
+        .blocking_read()
+        .checkpoint_store()
+        .ok_or_else(|| {
+            rustler::Error::Term(Box::new(
+                "Orchestration store has no persistence path".to_string(),
+            ))
+        })?;
+    let checkpoint = checkpoints
+        .load(execution_id)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Checkpoint error: {}", e))))?
+        .ok_or_else(|| {
+            rustler::Error::Term(Box::new(format!(
+                "No checkpoint for orchestration execution '{}'",
+                execution_id
+            )))
+        })?;
+    let graph = get_orchestration_graph(store_resource, &checkpoint.orchestration_id)?;
+    Ok((checkpoint, graph))
+}
+
 fn create_runtime() -> NifResult<tokio::runtime::Runtime> {
     tokio::runtime::Runtime::new()
         .map_err(|e| rustler::Error::Term(Box::new(format!("Runtime error: {}", e))))
@@ -78,3 +78,34 @@ pub(crate) fn remove(
         .map_err(|e| rustler::Error::Term(Box::new(format!("Remove error: {}", e))))?;
     Ok(true)
 }
+
+/// List executions with a checkpoint left behind by a previous host process.
+///
+/// Returns a JSON array of `ResumableExecution`; stores without persistence
+/// have nothing to recover.
+pub(crate) fn recover_executions(
+    resource: ResourceArc<OrchestrationStoreResource>,
+) -> NifResult<String> {
+    let checkpoints = resource.store.blocking_read().checkpoint_store();
+    let executions = match checkpoints {
+        Some(checkpoints) => checkpoints
+            .list()
+            .map_err(|e| rustler::Error::Term(Box::new(format!("Recover error: {}", e))))?,
+        None => Vec::new(),
+    };
+    serde_json::to_string(&executions)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Serialization error: {}", e))))
+}
+
+/// Delete the checkpoint of an execution the host chose not to resume.
+pub(crate) fn discard_execution(
+    resource: ResourceArc<OrchestrationStoreResource>,
+    execution_id: String,
+) -> NifResult<bool> {
+    let Some(checkpoints) = resource.store.blocking_read().checkpoint_store() else {
+        return Ok(false);
+    };
+    checkpoints
+        .remove(&execution_id)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Discard error: {}", e))))
+}
@@ -1,10 +1,17 @@
-use std::path::PathBuf;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use node_engine::config::LibraryRootConfig;
+use node_engine::ModelUsageLog;
+use pantograph_model_downloads::{
+    ModelDownloadRequest, ModelDownloader, PumasImporter, STAGING_DIR_NAME,
+};
 use rustler::{Atom, NifResult, ResourceArc};
+use workflow_nodes::{ModelLibraryRoots, ModelLibraryView};
 
 use crate::atoms;
-use crate::resources::{PumasApiResource, WorkflowExecutorResource};
+use crate::resources::{OrchestrationStoreResource, PumasApiResource, WorkflowExecutorResource};
 
 pub(crate) fn api_discover() -> NifResult<ResourceArc<PumasApiResource>> {
     let runtime = tokio::runtime::Runtime::new()
@@ -14,8 +21,12 @@ pub(crate) fn api_discover() -> NifResult<ResourceArc<PumasApiResource>> {
         .block_on(async { pumas_library::PumasApi::discover().await })
         .map_err(|e| rustler::Error::Term(Box::new(format!("PumasApi discover error: {}", e))))?;
 
+    let api = Arc::new(api);
     Ok(ResourceArc::new(PumasApiResource {
-        api: Arc::new(api),
+        downloads: model_downloader(&std::env::temp_dir(), &api),
+        api,
+        roots: None,
+        usage: Arc::new(ModelUsageLog::new()),
         runtime: Arc::new(runtime),
     }))
 }
@@ -34,13 +45,58 @@ pub(crate) fn api_new(launcher_root_path: String) -> NifResult<ResourceArc<Pumas
                 .await
         })
         .map_err(|e| rustler::Error::Term(Box::new(format!("PumasApi init error: {}", e))))?;
+    let root = PathBuf::from(&launcher_root_path);
+    let usage = load_usage_log(&root)?;
+    let api = Arc::new(api);
 
     Ok(ResourceArc::new(PumasApiResource {
-        api: Arc::new(api),
+        downloads: model_downloader(&root, &api),
+        api,
+        roots: None,
+        usage,
         runtime: Arc::new(runtime),
     }))
 }
 
+pub(crate) fn api_new_with_roots(roots_json: String) -> NifResult<ResourceArc<PumasApiResource>> {
+    let configs: Vec<LibraryRootConfig> = serde_json::from_str(&roots_json)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Parse error: {}", e))))?;
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Runtime error: {}", e))))?;
+
+    let roots = runtime
+        .block_on(async { ModelLibraryRoots::open(&configs, true).await })
+        .map_err(|e| rustler::Error::Term(Box::new(format!("PumasApi init error: {}", e))))?;
+
+    let usage = load_usage_log(&roots.primary().path)?;
+    let downloads = model_downloader(&roots.primary().path, &roots.primary().api);
+
+    Ok(ResourceArc::new(PumasApiResource {
+        api: roots.primary().api.clone(),
+        roots: Some(Arc::new(roots)),
+        usage,
+        downloads,
+        runtime: Arc::new(runtime),
+    }))
+}
+
+/// Verified downloader staging under `root` and importing into `api`.
+fn model_downloader(root: &Path, api: &Arc<pumas_library::PumasApi>) -> Arc<ModelDownloader> {
+    Arc::new(
+        ModelDownloader::new(root.join(STAGING_DIR_NAME))
+            .with_importer(Arc::new(PumasImporter::new(api.clone()))),
+    )
+}
+
+/// Usage log kept next to the library at `root`.
+fn load_usage_log(root: &Path) -> NifResult<Arc<ModelUsageLog>> {
+    let usage = ModelUsageLog::with_persistence(root.join(ModelUsageLog::FILE_NAME));
+    usage
+        .load_from_disk()
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Model usage error: {}", e))))?;
+    Ok(Arc::new(usage))
+}
+
 pub(crate) fn executor_set_pumas_api(
     executor_resource: ResourceArc<WorkflowExecutorResource>,
     pumas_resource: ResourceArc<PumasApiResource>,
@@ -49,9 +105,18 @@ pub(crate) fn executor_set_pumas_api(
 
     rt.block_on(async {
         let mut exec = executor_resource.executor.write().await;
+        match &pumas_resource.roots {
+            Some(roots) => {
+                workflow_nodes::install_library_roots(exec.extensions_mut(), roots.clone())
+            }
+            None => exec.extensions_mut().set(
+                node_engine::extension_keys::PUMAS_API,
+                pumas_resource.api.clone(),
+            ),
+        }
         exec.extensions_mut().set(
-            node_engine::extension_keys::PUMAS_API,
-            pumas_resource.api.clone(),
+            node_engine::extension_keys::MODEL_USAGE,
+            pumas_resource.usage.clone(),
         );
     });
 
@@ -76,6 +141,14 @@ pub(crate) fn executor_set_kv_cache_store(
 }
 
 pub(crate) fn list_models(resource: ResourceArc<PumasApiResource>) -> NifResult<String> {
+    if let Some(roots) = &resource.roots {
+        let models = resource
+            .runtime
+            .block_on(roots.list_models())
+            .map_err(|e| rustler::Error::Term(Box::new(format!("list_models error: {}", e))))?;
+        return serde_json::to_string(&models)
+            .map_err(|e| rustler::Error::Term(Box::new(format!("JSON error: {}", e))));
+    }
     resource
         .runtime
         .block_on(async { resource.api.list_models().await })
@@ -92,6 +165,14 @@ pub(crate) fn search_models(
     limit: usize,
     offset: usize,
 ) -> NifResult<String> {
+    if let Some(roots) = &resource.roots {
+        let result = resource
+            .runtime
+            .block_on(roots.search_models(&query, limit, offset))
+            .map_err(|e| rustler::Error::Term(Box::new(format!("search_models error: {}", e))))?;
+        return serde_json::to_string(&result)
+            .map_err(|e| rustler::Error::Term(Box::new(format!("JSON error: {}", e))));
+    }
     resource
         .runtime
         .block_on(async { resource.api.search_models(&query, limit, offset).await })
@@ -106,6 +187,18 @@ pub(crate) fn get_model(
     resource: ResourceArc<PumasApiResource>,
     model_id: String,
 ) -> NifResult<Option<String>> {
+    if let Some(roots) = &resource.roots {
+        let model = resource
+            .runtime
+            .block_on(roots.get_model(&model_id))
+            .map_err(|e| rustler::Error::Term(Box::new(format!("get_model error: {}", e))))?;
+        return model
+            .map(|m| {
+                serde_json::to_string(&m)
+                    .map_err(|e| rustler::Error::Term(Box::new(format!("JSON error: {}", e))))
+            })
+            .transpose();
+    }
     let model = resource
         .runtime
         .block_on(async { resource.api.get_model(&model_id).await })
@@ -121,6 +214,87 @@ pub(crate) fn get_model(
     }
 }
 
+fn library_view(resource: &PumasApiResource) -> ModelLibraryView<'_> {
+    match &resource.roots {
+        Some(roots) => ModelLibraryView::Merged(roots),
+        None => ModelLibraryView::Single(&resource.api),
+    }
+}
+
+/// Model IDs referenced by the data graphs in `store`.
+fn referenced_models(
+    resource: &PumasApiResource,
+    store: Option<ResourceArc<OrchestrationStoreResource>>,
+) -> BTreeSet<String> {
+    let Some(store) = store else {
+        return BTreeSet::new();
+    };
+    resource.runtime.block_on(async {
+        let store = store.store.read().await;
+        node_engine::referenced_model_ids(store.data_graphs())
+    })
+}
+
+pub(crate) fn unused_models(
+    resource: ResourceArc<PumasApiResource>,
+    store: Option<ResourceArc<OrchestrationStoreResource>>,
+    unused_for_days: u32,
+) -> NifResult<String> {
+    let referenced = referenced_models(&resource, store);
+    let models = resource
+        .runtime
+        .block_on(workflow_nodes::unused_models(
+            library_view(&resource),
+            &resource.usage,
+            &referenced,
+            unused_for_days,
+        ))
+        .map_err(|e| rustler::Error::Term(Box::new(format!("unused_models error: {}", e))))?;
+    serde_json::to_string(&models)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("JSON error: {}", e))))
+}
+
+pub(crate) fn gc(
+    resource: ResourceArc<PumasApiResource>,
+    store: Option<ResourceArc<OrchestrationStoreResource>>,
+    unused_for_days: u32,
+    dry_run: bool,
+) -> NifResult<String> {
+    let referenced = referenced_models(&resource, store);
+    let report = resource
+        .runtime
+        .block_on(workflow_nodes::collect_unused_models(
+            library_view(&resource),
+            &resource.usage,
+            &referenced,
+            unused_for_days,
+            dry_run,
+        ))
+        .map_err(|e| rustler::Error::Term(Box::new(format!("gc error: {}", e))))?;
+    serde_json::to_string(&report)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("JSON error: {}", e))))
+}
+
+pub(crate) fn pin_model(
+    resource: ResourceArc<PumasApiResource>,
+    model_id: String,
+) -> NifResult<bool> {
+    resource
+        .usage
+        .pin(&model_id)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("pin_model error: {}", e))))
+}
+
+pub(crate) fn unpin_model(
+    resource: ResourceArc<PumasApiResource>,
+    model_id: String,
+) -> NifResult<bool> {
+    resource
+        .usage
+        .unpin(&model_id)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("unpin_model error: {}", e))))
+}
+
 pub(crate) fn rebuild_index(resource: ResourceArc<PumasApiResource>) -> NifResult<usize> {
     resource
         .runtime
@@ -205,6 +379,64 @@ pub(crate) fn cancel_download(
         .map_err(|e| rustler::Error::Term(Box::new(format!("cancel_download error: {}", e))))
 }
 
+pub(crate) fn start_verified_download(
+    resource: ResourceArc<PumasApiResource>,
+    request_json: String,
+) -> NifResult<String> {
+    let request: ModelDownloadRequest = serde_json::from_str(&request_json)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("Parse error: {}", e))))?;
+
+    resource
+        .runtime
+        .block_on(resource.downloads.start(request))
+        .map_err(|e| {
+            rustler::Error::Term(Box::new(format!("start_verified_download error: {}", e)))
+        })
+}
+
+pub(crate) fn get_verified_download_progress(
+    resource: ResourceArc<PumasApiResource>,
+    download_id: String,
+) -> NifResult<Option<String>> {
+    match resource.downloads.progress(&download_id) {
+        Some(p) => {
+            let json = serde_json::to_string(&p)
+                .map_err(|e| rustler::Error::Term(Box::new(format!("JSON error: {}", e))))?;
+            Ok(Some(json))
+        }
+        None => Ok(None),
+    }
+}
+
+pub(crate) fn cancel_verified_download(
+    resource: ResourceArc<PumasApiResource>,
+    download_id: String,
+) -> NifResult<bool> {
+    Ok(resource.downloads.cancel(&download_id))
+}
+
+pub(crate) fn inspect_download_url(
+    resource: ResourceArc<PumasApiResource>,
+    url: String,
+) -> NifResult<String> {
+    let inspection = resource
+        .runtime
+        .block_on(resource.downloads.inspect_url(&url))
+        .map_err(|e| {
+            rustler::Error::Term(Box::new(format!("inspect_download_url error: {}", e)))
+        })?;
+    serde_json::to_string(&inspection)
+        .map_err(|e| rustler::Error::Term(Box::new(format!("JSON error: {}", e))))
+}
+
+pub(crate) fn set_download_bandwidth_limit(
+    resource: ResourceArc<PumasApiResource>,
+    bytes_per_sec: Option<u64>,
+) -> NifResult<Atom> {
+    resource.downloads.set_bandwidth_limit(bytes_per_sec);
+    Ok(atoms::ok())
+}
+
 pub(crate) fn import_model(
     resource: ResourceArc<PumasApiResource>,
     spec_json: String,
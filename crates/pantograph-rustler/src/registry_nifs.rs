@@ -103,6 +103,21 @@ pub(crate) fn extensions_setup(
     Ok(atoms::ok())
 }
 
+pub(crate) fn extensions_setup_from_file(
+    resource: ResourceArc<ExtensionsResource>,
+    config_path: String,
+) -> NifResult<Atom> {
+    resource
+        .runtime
+        .block_on(async {
+            let mut ext = resource.extensions.write().await;
+            workflow_nodes::setup_extensions_from_file(&mut ext, &config_path).await
+        })
+        .map_err(|e| rustler::Error::Term(Box::new(format!("extensions config error: {}", e))))?;
+
+    Ok(atoms::ok())
+}
+
 pub(crate) fn node_registry_query_port_options(
     registry_resource: ResourceArc<NodeRegistryResource>,
     extensions_resource: ResourceArc<ExtensionsResource>,
@@ -8,6 +8,11 @@ pub struct WorkflowExecutorResource {
     pub executor: Arc<tokio::sync::RwLock<WorkflowExecutor>>,
     pub task_executor: Arc<dyn TaskExecutor>,
     pub runtime: Arc<tokio::runtime::Runtime>,
+    /// BEAM event channel used for NIF-emitted `graphChanged` patches.
+    pub(crate) graph_events: Arc<crate::callback_bridge::BeamEventSink>,
+    /// Revision of the executor graph, bumped once per emitted patch. The
+    /// lock also serializes graph-mutating NIFs so patches stay ordered.
+    pub(crate) graph_revision: Arc<tokio::sync::Mutex<u64>>,
 }
 impl Resource for WorkflowExecutorResource {}
 
@@ -26,6 +31,15 @@ impl Resource for NodeRegistryResource {}
 /// Wrapper for PumasApi shared via ResourceArc.
 pub struct PumasApiResource {
     pub api: Arc<pumas_library::PumasApi>,
+    /// Merged view when created from several library roots; `api` is then
+    /// the primary root.
+    pub roots: Option<Arc<workflow_nodes::ModelLibraryRoots>>,
+    /// Model usage and pins, persisted next to the (primary) library when
+    /// its root is known.
+    pub usage: Arc<node_engine::ModelUsageLog>,
+    /// Checksum-verified, resumable downloads staged next to the (primary)
+    /// library and imported into `api`.
+    pub downloads: Arc<pantograph_model_downloads::ModelDownloader>,
     pub runtime: Arc<tokio::runtime::Runtime>,
 }
 impl Resource for PumasApiResource {}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use node_engine::{GraphEdge, GraphNode, WorkflowGraph};
 use pantograph_workflow_service::{
     convert_graph_from_node_engine, validate_workflow_graph_contract, NodeRegistry,
@@ -112,3 +114,17 @@ pub(crate) fn workflow_validate_json(graph_json: String) -> NifResult<Vec<String
 
     Ok(validate_workflow_graph_contract(&graph, &registry))
 }
+
+pub(crate) fn workflow_estimate_json(graph_json: String, inputs_json: String) -> NifResult<String> {
+    let graph = parse_graph(&graph_json)?;
+    estimate_graph_json(&graph, &inputs_json)
+}
+
+/// Estimate a graph's run cost, returning JSON `ExecutionEstimate`.
+pub(crate) fn estimate_graph_json(graph: &WorkflowGraph, inputs_json: &str) -> NifResult<String> {
+    let inputs: HashMap<String, serde_json::Value> =
+        serde_json::from_str(inputs_json).map_err(|error| parse_error(error.to_string()))?;
+    let estimate = node_engine::estimate(graph, &inputs)
+        .map_err(|error| Error::Term(Box::new(format!("Estimate error: {}", error))))?;
+    serde_json::to_string(&estimate).map_err(|error| serialization_error(error.to_string()))
+}
@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet};
+
+use node_engine::{EdgeId, EventError, GraphEdge, GraphNode, NodeId, WorkflowGraph};
+use serde::Serialize;
+
+/// Structural difference between two executor graph snapshots.
+///
+/// Nodes and edges are matched by id. An entry whose id exists on both sides
+/// but whose serialized form differs is reported as updated with its new
+/// value. Entries keep the order of the graph they were taken from.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WorkflowGraphPatch {
+    pub added_nodes: Vec<GraphNode>,
+    pub removed_nodes: Vec<NodeId>,
+    pub updated_nodes: Vec<GraphNode>,
+    pub added_edges: Vec<GraphEdge>,
+    pub removed_edges: Vec<EdgeId>,
+    pub updated_edges: Vec<GraphEdge>,
+}
+
+impl WorkflowGraphPatch {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.updated_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.updated_edges.is_empty()
+    }
+}
+
+pub(crate) fn diff_workflow_graphs(
+    before: &WorkflowGraph,
+    after: &WorkflowGraph,
+) -> WorkflowGraphPatch {
+    let (added_nodes, removed_nodes, updated_nodes) =
+        diff_by_id(&before.nodes, &after.nodes, |node| &node.id);
+    let (added_edges, removed_edges, updated_edges) =
+        diff_by_id(&before.edges, &after.edges, |edge| &edge.id);
+
+    WorkflowGraphPatch {
+        added_nodes,
+        removed_nodes,
+        updated_nodes,
+        added_edges,
+        removed_edges,
+        updated_edges,
+    }
+}
+
+fn diff_by_id<T: Clone + Serialize>(
+    before: &[T],
+    after: &[T],
+    id_of: impl Fn(&T) -> &String,
+) -> (Vec<T>, Vec<String>, Vec<T>) {
+    let before_by_id: HashMap<&String, &T> =
+        before.iter().map(|item| (id_of(item), item)).collect();
+    let after_ids: HashSet<&String> = after.iter().map(&id_of).collect();
+
+    let mut added = Vec::new();
+    let mut updated = Vec::new();
+    for item in after {
+        match before_by_id.get(id_of(item)) {
+            None => added.push(item.clone()),
+            Some(previous) if !same_serialized(*previous, item) => updated.push(item.clone()),
+            Some(_) => {}
+        }
+    }
+
+    let removed = before
+        .iter()
+        .filter(|item| !after_ids.contains(id_of(item)))
+        .map(|item| id_of(item).clone())
+        .collect();
+
+    (added, removed, updated)
+}
+
+fn same_serialized<T: Serialize>(a: &T, b: &T) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+/// Serialize a `graphChanged` event for the BEAM workflow-event channel.
+///
+/// `snapshotRevision` increases by one per emitted patch; hosts that observe a
+/// gap resync with `executor_get_graph_snapshot_with_revision`.
+pub(crate) fn graph_changed_event_json(
+    workflow_id: &str,
+    snapshot_revision: u64,
+    patch: &WorkflowGraphPatch,
+) -> std::result::Result<String, EventError> {
+    serde_json::to_string(&serde_json::json!({
+        "type": "graphChanged",
+        "workflowId": workflow_id,
+        "snapshotRevision": snapshot_revision,
+        "patch": patch,
+        "occurredAtMs": unix_timestamp_ms(),
+    }))
+    .map_err(|e| EventError {
+        message: format!("Serialization error: {}", e),
+    })
+}
+
+/// Serialize a graph snapshot together with the revision it corresponds to.
+pub(crate) fn revisioned_snapshot_json(
+    graph: &WorkflowGraph,
+    snapshot_revision: u64,
+) -> std::result::Result<String, serde_json::Error> {
+    serde_json::to_string(&serde_json::json!({
+        "snapshotRevision": snapshot_revision,
+        "graph": graph,
+    }))
+}
+
+fn unix_timestamp_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_millis().min(u128::from(u64::MAX)) as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, data: serde_json::Value) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            node_type: "text-input".to_string(),
+            data,
+            position: (0.0, 0.0),
+        }
+    }
+
+    fn edge(id: &str, source: &str, target: &str) -> GraphEdge {
+        GraphEdge {
+            id: id.to_string(),
+            source: source.to_string(),
+            source_handle: "out".to_string(),
+            target: target.to_string(),
+            target_handle: "in".to_string(),
+        }
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_updated_entries() {
+        let mut before = WorkflowGraph::new("wf-1", "Test");
+        before.nodes = vec![
+            node("a", serde_json::json!({"text": "one"})),
+            node("b", serde_json::Value::Null),
+        ];
+        before.edges = vec![edge("e1", "a", "b")];
+
+        let mut after = WorkflowGraph::new("wf-1", "Test");
+        after.nodes = vec![
+            node("a", serde_json::json!({"text": "two"})),
+            node("c", serde_json::Value::Null),
+        ];
+        after.edges = vec![edge("e2", "a", "c")];
+
+        let patch = diff_workflow_graphs(&before, &after);
+
+        assert_eq!(patch.added_nodes.len(), 1);
+        assert_eq!(patch.added_nodes[0].id, "c");
+        assert_eq!(patch.removed_nodes, vec!["b".to_string()]);
+        assert_eq!(patch.updated_nodes.len(), 1);
+        assert_eq!(patch.updated_nodes[0].data["text"], "two");
+        assert_eq!(patch.added_edges[0].id, "e2");
+        assert_eq!(patch.removed_edges, vec!["e1".to_string()]);
+        assert!(patch.updated_edges.is_empty());
+    }
+
+    #[test]
+    fn diff_of_identical_graphs_is_empty() {
+        let mut graph = WorkflowGraph::new("wf-1", "Test");
+        graph.nodes = vec![node("a", serde_json::Value::Null)];
+
+        assert!(diff_workflow_graphs(&graph, &graph.clone()).is_empty());
+    }
+
+    #[test]
+    fn graph_changed_event_uses_camel_case_contract() {
+        let patch = WorkflowGraphPatch {
+            removed_nodes: vec!["a".to_string()],
+            ..WorkflowGraphPatch::default()
+        };
+        let json = graph_changed_event_json("wf-1", 7, &patch).expect("serialize");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("parse");
+
+        assert_eq!(value["type"], "graphChanged");
+        assert_eq!(value["workflowId"], "wf-1");
+        assert_eq!(value["snapshotRevision"], 7);
+        assert_eq!(value["patch"]["removedNodes"], serde_json::json!(["a"]));
+        assert_eq!(value["patch"]["addedEdges"], serde_json::json!([]));
+    }
+}
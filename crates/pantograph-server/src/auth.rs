@@ -0,0 +1,291 @@
+//! Authentication and scope enforcement for served endpoints.
+//!
+//! Credentials are read from `Authorization: Bearer <token>` or `X-API-Key`
+//! and checked by each configured [`Authenticator`] in order. The first
+//! authenticator that accepts the token yields the request's [`Principal`],
+//! which must hold the scope that [`required_scope`] assigns to the route.
+//! Authorization runs as a route layer, so a rejected request never reaches a
+//! handler or the engine.
+
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::{header, HeaderMap, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApiError;
+use crate::ServerState;
+
+/// Header carrying a static API key.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Permission level of a credential.
+///
+/// Scopes are ordered: `Admin` implies `Execute`, which implies `Read`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scope {
+    /// Read graphs, stores, validation results, port options, and events.
+    Read,
+    /// Demand workflow outputs and run orchestrations.
+    Execute,
+    /// Create, modify, and delete graphs and stored data.
+    Admin,
+}
+
+impl Scope {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Scope::Read => "read",
+            Scope::Execute => "execute",
+            Scope::Admin => "admin",
+        }
+    }
+}
+
+impl FromStr for Scope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read" => Ok(Scope::Read),
+            "execute" => Ok(Scope::Execute),
+            "admin" => Ok(Scope::Admin),
+            other => Err(format!("Unknown scope '{}'", other)),
+        }
+    }
+}
+
+/// Authenticated caller of a request.
+///
+/// Inserted into request extensions for handlers that need the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    pub subject: String,
+    pub scopes: BTreeSet<Scope>,
+}
+
+impl Principal {
+    pub fn new(subject: impl Into<String>, scopes: impl IntoIterator<Item = Scope>) -> Self {
+        Self {
+            subject: subject.into(),
+            scopes: scopes.into_iter().collect(),
+        }
+    }
+
+    /// Whether any held scope implies `scope`.
+    pub fn allows(&self, scope: Scope) -> bool {
+        self.scopes.iter().any(|held| *held >= scope)
+    }
+}
+
+/// Pluggable credential check.
+///
+/// Returns `None` when the token is not a valid credential for this
+/// authenticator, so the next configured authenticator can try it.
+pub trait Authenticator: Send + Sync {
+    fn authenticate(&self, token: &str) -> Option<Principal>;
+}
+
+/// Fixed set of API keys, each mapped to a principal.
+#[derive(Default)]
+pub struct StaticApiKeys {
+    keys: HashMap<String, Principal>,
+}
+
+impl StaticApiKeys {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_key(
+        mut self,
+        key: impl Into<String>,
+        subject: impl Into<String>,
+        scopes: impl IntoIterator<Item = Scope>,
+    ) -> Self {
+        self.keys
+            .insert(key.into(), Principal::new(subject, scopes));
+        self
+    }
+}
+
+impl Authenticator for StaticApiKeys {
+    fn authenticate(&self, token: &str) -> Option<Principal> {
+        // Compare every key so response timing does not reveal prefixes.
+        self.keys
+            .iter()
+            .fold(None, |found, (key, principal)| {
+                if constant_time_eq(key.as_bytes(), token.as_bytes()) {
+                    Some(principal)
+                } else {
+                    found
+                }
+            })
+            .cloned()
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Claims read from a JWT.
+#[derive(Debug, Deserialize)]
+struct JwtClaims {
+    sub: String,
+    /// Space-delimited scope list (RFC 8693 `scope` claim).
+    #[serde(default)]
+    scope: String,
+}
+
+/// Validates JWTs signed by a configured issuer.
+///
+/// Tokens must carry `iss`, `sub`, and `exp`; scopes come from the
+/// space-delimited `scope` claim and unknown scope names are ignored.
+pub struct JwtValidator {
+    key: DecodingKey,
+    validation: Validation,
+}
+
+impl JwtValidator {
+    /// HMAC-SHA256 tokens signed with a shared secret.
+    pub fn hs256(secret: &[u8], issuer: &str) -> Self {
+        Self::new(DecodingKey::from_secret(secret), Algorithm::HS256, issuer)
+    }
+
+    /// RSA-SHA256 tokens verified against a PEM-encoded public key.
+    pub fn rs256_pem(public_key_pem: &[u8], issuer: &str) -> Result<Self, String> {
+        let key = DecodingKey::from_rsa_pem(public_key_pem)
+            .map_err(|e| format!("Invalid RSA public key: {}", e))?;
+        Ok(Self::new(key, Algorithm::RS256, issuer))
+    }
+
+    fn new(key: DecodingKey, algorithm: Algorithm, issuer: &str) -> Self {
+        let mut validation = Validation::new(algorithm);
+        validation.set_issuer(&[issuer]);
+        validation.set_required_spec_claims(&["exp", "iss", "sub"]);
+        Self { key, validation }
+    }
+
+    /// Also require the `aud` claim to contain `audience`.
+    pub fn with_audience(mut self, audience: &str) -> Self {
+        self.validation.set_audience(&[audience]);
+        self
+    }
+}
+
+impl Authenticator for JwtValidator {
+    fn authenticate(&self, token: &str) -> Option<Principal> {
+        let claims = jsonwebtoken::decode::<JwtClaims>(token, &self.key, &self.validation)
+            .ok()?
+            .claims;
+        let scopes = claims
+            .scope
+            .split_whitespace()
+            .filter_map(|scope| scope.parse().ok());
+        Some(Principal::new(claims.sub, scopes))
+    }
+}
+
+/// Ordered list of authenticators guarding the server.
+#[derive(Clone, Default)]
+pub struct AuthConfig {
+    authenticators: Vec<Arc<dyn Authenticator>>,
+}
+
+impl AuthConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_authenticator(mut self, authenticator: impl Authenticator + 'static) -> Self {
+        self.authenticators.push(Arc::new(authenticator));
+        self
+    }
+
+    /// Principal for `token` from the first authenticator that accepts it.
+    pub fn authenticate(&self, token: &str) -> Option<Principal> {
+        self.authenticators
+            .iter()
+            .find_map(|authenticator| authenticator.authenticate(token))
+    }
+}
+
+/// Scope required to call `method` on the route matched as `path`.
+///
+/// Reads and side-effect-free queries need `Read`, running work needs
+/// `Execute`, and every mutation of stored state needs `Admin`.
+pub fn required_scope(method: &Method, path: &str) -> Scope {
+    if *method == Method::GET {
+        return Scope::Read;
+    }
+    match path {
+        "/workflows/validate" | "/port-options/:node_type/:port_id" => Scope::Read,
+        "/workflows/:workflow_id/demand" | "/orchestrations/:graph_id/runs" => Scope::Execute,
+        _ => Scope::Admin,
+    }
+}
+
+fn credential(headers: &HeaderMap) -> Option<&str> {
+    if let Some(token) = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+    {
+        return Some(token.trim());
+    }
+    headers
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+}
+
+/// Route layer rejecting requests without a credential for the route's scope.
+///
+/// Passes every request through when the server has no [`AuthConfig`].
+pub async fn authorize(
+    State(state): State<ServerState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(auth) = state.auth.as_ref() else {
+        return next.run(request).await;
+    };
+
+    let Some(principal) = credential(request.headers()).and_then(|token| auth.authenticate(token))
+    else {
+        let mut response =
+            ApiError::new(StatusCode::UNAUTHORIZED, "Missing or invalid credentials")
+                .into_response();
+        response
+            .headers_mut()
+            .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+        return response;
+    };
+
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+    let scope = required_scope(request.method(), &path);
+    if !principal.allows(scope) {
+        return ApiError::new(
+            StatusCode::FORBIDDEN,
+            format!(
+                "'{}' lacks the '{}' scope",
+                principal.subject,
+                scope.as_str()
+            ),
+        )
+        .into_response();
+    }
+
+    request.extensions_mut().insert(principal);
+    next.run(request).await
+}
@@ -0,0 +1,157 @@
+//! Graceful draining ahead of deploys.
+//!
+//! [`drain`] puts the server into draining mode: new workflows and
+//! orchestration runs are refused with `503`, demands on existing workflows
+//! fail the same way, and demands and orchestration runs already running get
+//! until the timeout to finish. Each workflow's demand cache is then written
+//! to the checkpoint directory so the next instance can import it, and the
+//! task executor is shut down. Call it before resolving the shutdown future
+//! passed to [`crate::serve_with_shutdown`].
+//!
+//! Orchestration runs cannot be cancelled mid-node; runs still going at the
+//! timeout are counted as unfinished and resume from their orchestration
+//! checkpoint when the store persists to disk.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use node_engine::ExecutorDrainReport;
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::ServerState;
+
+/// Outcome of [`drain`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DrainSummary {
+    /// Drain report of every workflow, keyed by workflow ID.
+    pub workflows: BTreeMap<String, ExecutorDrainReport>,
+    /// Demands that finished within the timeout, over all workflows.
+    pub finished: usize,
+    /// Demands cancelled at the timeout, over all workflows.
+    pub cancelled: usize,
+    /// Checkpoint files written, keyed by workflow ID.
+    pub checkpoints: BTreeMap<String, PathBuf>,
+    /// Orchestration runs that finished within the timeout.
+    pub runs_finished: usize,
+    /// Orchestration runs still going when the timeout expired.
+    pub runs_unfinished: usize,
+}
+
+/// Orchestration runs in flight, awaited by [`drain`].
+#[derive(Clone)]
+pub(crate) struct InFlightRuns(Arc<watch::Sender<usize>>);
+
+impl Default for InFlightRuns {
+    fn default() -> Self {
+        Self(Arc::new(watch::channel(0).0))
+    }
+}
+
+impl InFlightRuns {
+    /// Count a run until the returned guard drops.
+    pub(crate) fn start(&self) -> RunGuard {
+        self.0.send_modify(|runs| *runs += 1);
+        RunGuard(self.0.clone())
+    }
+
+    fn count(&self) -> usize {
+        *self.0.borrow()
+    }
+
+    /// Wait up to `timeout` for every run to finish; returns how many remain.
+    async fn wait_idle(&self, timeout: Duration) -> usize {
+        let mut runs = self.0.subscribe();
+        let _ = tokio::time::timeout(timeout, runs.wait_for(|runs| *runs == 0)).await;
+        self.count()
+    }
+}
+
+/// Keeps one orchestration run counted while alive.
+pub(crate) struct RunGuard(Arc<watch::Sender<usize>>);
+
+impl Drop for RunGuard {
+    fn drop(&mut self) {
+        self.0.send_modify(|runs| *runs -= 1);
+    }
+}
+
+/// Drain every workflow executor and orchestration run, then shut down the
+/// task executor.
+///
+/// Workflows and runs drain concurrently, so `timeout` bounds the whole drain.
+/// Checkpoints are written to `<checkpoint_dir>/<workflow_id>.json` when a
+/// directory is given.
+pub async fn drain(
+    state: &ServerState,
+    timeout: Duration,
+    checkpoint_dir: Option<&Path>,
+) -> DrainSummary {
+    state.start_draining();
+    let runs_at_start = state.orchestration_runs.count();
+
+    let executors: Vec<_> = state
+        .workflows
+        .read()
+        .await
+        .iter()
+        .map(|(id, executor)| (id.clone(), executor.clone()))
+        .collect();
+    let workflow_drains = futures_util::future::join_all(executors.into_iter().map(
+        |(workflow_id, executor)| async move {
+            let checkpoint_path =
+                checkpoint_dir.map(|dir| dir.join(checkpoint_file_name(&workflow_id)));
+            let report = executor
+                .drain(timeout, None, checkpoint_path.as_deref())
+                .await;
+            (workflow_id, checkpoint_path, report)
+        },
+    ));
+    let (reports, runs_unfinished) =
+        tokio::join!(workflow_drains, state.orchestration_runs.wait_idle(timeout));
+    state.task_executor.shutdown().await;
+
+    let mut summary = DrainSummary {
+        runs_finished: runs_at_start.saturating_sub(runs_unfinished),
+        runs_unfinished,
+        ..DrainSummary::default()
+    };
+    for (workflow_id, checkpoint_path, report) in reports {
+        summary.finished += report.finished;
+        summary.cancelled += report.shutdown.cancelled_demands;
+        if let (Some(path), Some(_)) = (checkpoint_path, report.shutdown.checkpoint_entries) {
+            summary.checkpoints.insert(workflow_id.clone(), path);
+        }
+        summary.workflows.insert(workflow_id, report);
+    }
+    summary
+}
+
+/// Workflow IDs come from request bodies, so keep only characters that are
+/// safe in a file name.
+fn checkpoint_file_name(workflow_id: &str) -> String {
+    let stem: String = workflow_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}.json", stem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkpoint_file_name_strips_path_characters() {
+        assert_eq!(checkpoint_file_name("wf-1"), "wf-1.json");
+        assert_eq!(checkpoint_file_name("../etc/passwd"), "___etc_passwd.json");
+    }
+}
@@ -0,0 +1,82 @@
+//! JSON error responses shared by REST handlers.
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use node_engine::{GraphLimitError, NodeEngineError};
+
+/// Error returned by REST handlers as `{"error": "..."}` with a status code.
+///
+/// A graph that exceeds the server's graph limits also carries the violated
+/// limit as `{"error": "...", "limit": {"kind": "max_nodes", ...}}`.
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub message: String,
+    pub limit: Option<GraphLimitError>,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+            limit: None,
+        }
+    }
+
+    pub fn not_found(what: &str, id: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            format!("{} '{}' not found", what, id),
+        )
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::CONFLICT, message)
+    }
+
+    pub fn service_unavailable(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::SERVICE_UNAVAILABLE, message)
+    }
+}
+
+/// Engine errors are failures of a well-formed request, so they map to
+/// `422`; a run paused for human input maps to `409`, and a demand refused
+/// or cancelled by a drain maps to `503`.
+impl From<NodeEngineError> for ApiError {
+    fn from(error: NodeEngineError) -> Self {
+        let status = match error {
+            NodeEngineError::WaitingForInput { .. } => StatusCode::CONFLICT,
+            NodeEngineError::Cancelled => StatusCode::SERVICE_UNAVAILABLE,
+            _ => StatusCode::UNPROCESSABLE_ENTITY,
+        };
+        let message = error.to_string();
+        let limit = match error {
+            NodeEngineError::GraphLimitExceeded(limit) => Some(limit),
+            _ => None,
+        };
+        Self {
+            limit,
+            ..Self::new(status, message)
+        }
+    }
+}
+
+impl From<GraphLimitError> for ApiError {
+    fn from(error: GraphLimitError) -> Self {
+        NodeEngineError::from(error).into()
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let mut body = serde_json::json!({ "error": self.message });
+        if let Some(limit) = self.limit {
+            body["limit"] = serde_json::to_value(limit).unwrap_or_default();
+        }
+        (self.status, Json(body)).into_response()
+    }
+}
+
+pub type ApiResult<T> = std::result::Result<T, ApiError>;
@@ -0,0 +1,252 @@
+//! Network server layer for Pantograph.
+//!
+//! Hosts build a [`ServerState`] around the shared [`EventRingBuffer`] and the
+//! task executor that should run nodes, then serve [`router`]. Every
+//! executor the server creates sends its events to the ring buffer, so runs
+//! started over REST can be followed over SSE.
+//!
+//! # Endpoints
+//!
+//! - `GET /openapi.json`: OpenAPI 3 description of every endpoint below.
+//! - `/workflows/...`: workflow graph CRUD, validation, and demand. See
+//!   [`workflows`].
+//! - `/orchestrations/...` and `/data-graphs/...`: orchestration store CRUD
+//!   and runs. See [`orchestrations`].
+//! - `/port-options/...`: dynamic port option queries.
+//! - `GET /executions/{execution_id}/events`: Server-Sent Events stream of the
+//!   execution's workflow events. See [`sse`] for resume semantics.
+//!
+//! Before a deploy, [`drain::drain`] refuses new work, lets running demands
+//! finish, and checkpoints each workflow's demand cache.
+//!
+//! Every endpoint except `/openapi.json` is guarded by [`auth::authorize`]
+//! when the state carries an [`auth::AuthConfig`], then by
+//! [`rate_limit::enforce`] when it carries a [`rate_limit::RateLimiter`].
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use axum::http::header;
+use axum::middleware;
+use axum::routing::{get, post, put};
+use axum::Router;
+use node_engine::{
+    EventRingBuffer, ExecutorExtensions, GraphLimits, NodeRegistry, OrchestrationStore,
+    TaskExecutor, WorkflowExecutor,
+};
+use tokio::sync::RwLock;
+
+pub mod auth;
+pub mod drain;
+pub mod error;
+pub mod orchestrations;
+pub mod port_options;
+pub mod rate_limit;
+#[cfg(feature = "redis")]
+pub mod redis_rate_limit;
+pub mod sse;
+pub mod workflows;
+
+/// OpenAPI description of the REST and SSE endpoints.
+pub const OPENAPI_SPEC: &str = include_str!("openapi.json");
+
+/// Shared state behind every route.
+#[derive(Clone)]
+pub struct ServerState {
+    /// Event history shared by all streaming transports.
+    pub events: Arc<EventRingBuffer>,
+    /// Workflows created over REST, keyed by workflow ID.
+    pub workflows: Arc<RwLock<HashMap<String, Arc<WorkflowExecutor>>>>,
+    pub orchestrations: Arc<RwLock<OrchestrationStore>>,
+    /// Node metadata for validation and port option providers.
+    pub registry: Arc<NodeRegistry>,
+    /// Executor that runs nodes for demand and orchestration runs.
+    pub task_executor: Arc<dyn TaskExecutor>,
+    /// Extensions passed to port option providers.
+    pub extensions: Arc<ExecutorExtensions>,
+    /// Credential checks; `None` serves every endpoint unauthenticated.
+    pub auth: Option<Arc<auth::AuthConfig>>,
+    /// Per-caller limits; `None` serves every request without limits.
+    pub rate_limits: Option<rate_limit::RateLimiter>,
+    /// Size and complexity bounds on submitted graphs; unlimited by default.
+    pub graph_limits: GraphLimits,
+    /// Set by [`drain::drain`]; new workflows and runs are refused.
+    draining: Arc<AtomicBool>,
+    /// Orchestration runs [`drain::drain`] waits for.
+    orchestration_runs: drain::InFlightRuns,
+}
+
+impl ServerState {
+    /// State with an in-memory orchestration store, the built-in node
+    /// registry, and `CoreTaskExecutor` for host-independent nodes.
+    pub fn new(events: Arc<EventRingBuffer>) -> Self {
+        Self {
+            events,
+            workflows: Arc::new(RwLock::new(HashMap::new())),
+            orchestrations: Arc::new(RwLock::new(OrchestrationStore::new())),
+            registry: Arc::new(NodeRegistry::with_builtins()),
+            task_executor: Arc::new(node_engine::CoreTaskExecutor::new()),
+            extensions: Arc::new(ExecutorExtensions::new()),
+            auth: None,
+            rate_limits: None,
+            graph_limits: GraphLimits::default(),
+            draining: Arc::new(AtomicBool::new(false)),
+            orchestration_runs: drain::InFlightRuns::default(),
+        }
+    }
+
+    /// Run nodes with a host-provided executor.
+    pub fn with_task_executor(mut self, task_executor: Arc<dyn TaskExecutor>) -> Self {
+        self.task_executor = task_executor;
+        self
+    }
+
+    pub fn with_registry(mut self, registry: Arc<NodeRegistry>) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Serve an existing (e.g. persistent) orchestration store.
+    pub fn with_orchestration_store(mut self, store: Arc<RwLock<OrchestrationStore>>) -> Self {
+        self.orchestrations = store;
+        self
+    }
+
+    pub fn with_extensions(mut self, extensions: ExecutorExtensions) -> Self {
+        self.extensions = Arc::new(extensions);
+        self
+    }
+
+    /// Require credentials with the route's scope on every endpoint.
+    pub fn with_auth(mut self, auth: auth::AuthConfig) -> Self {
+        self.auth = Some(Arc::new(auth));
+        self
+    }
+
+    /// Apply per-caller rate limits, concurrency caps, and daily quotas.
+    pub fn with_rate_limits(mut self, limiter: rate_limit::RateLimiter) -> Self {
+        self.rate_limits = Some(limiter);
+        self
+    }
+
+    /// Reject workflows, data graphs, and orchestrations that exceed
+    /// `limits`, and mutations that would make a workflow exceed them.
+    pub fn with_graph_limits(mut self, limits: GraphLimits) -> Self {
+        self.graph_limits = limits;
+        self
+    }
+
+    /// Whether [`drain::drain`] has been called.
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    fn start_draining(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+    }
+
+    /// Refuse new work while draining.
+    fn ensure_accepting(&self) -> error::ApiResult<()> {
+        if self.is_draining() {
+            return Err(error::ApiError::service_unavailable(
+                "Server is draining; retry against the next instance",
+            ));
+        }
+        Ok(())
+    }
+}
+
+async fn openapi_spec() -> ([(header::HeaderName, &'static str); 1], &'static str) {
+    ([(header::CONTENT_TYPE, "application/json")], OPENAPI_SPEC)
+}
+
+/// Build the router for all server endpoints.
+pub fn router(state: ServerState) -> Router {
+    Router::new()
+        .route("/workflows", get(workflows::list).post(workflows::create))
+        .route("/workflows/validate", post(workflows::validate_graph))
+        .route(
+            "/workflows/:workflow_id",
+            get(workflows::get)
+                .put(workflows::replace)
+                .delete(workflows::delete),
+        )
+        .route("/workflows/:workflow_id/nodes", post(workflows::add_node))
+        .route(
+            "/workflows/:workflow_id/nodes/:node_id",
+            axum::routing::delete(workflows::remove_node),
+        )
+        .route(
+            "/workflows/:workflow_id/nodes/:node_id/data",
+            put(workflows::update_node_data),
+        )
+        .route("/workflows/:workflow_id/edges", post(workflows::add_edge))
+        .route(
+            "/workflows/:workflow_id/edges/:edge_id",
+            axum::routing::delete(workflows::remove_edge),
+        )
+        .route(
+            "/workflows/:workflow_id/validation",
+            get(workflows::validate_stored),
+        )
+        .route("/workflows/:workflow_id/demand", post(workflows::demand))
+        .route(
+            "/orchestrations",
+            get(orchestrations::list).post(orchestrations::upsert),
+        )
+        .route(
+            "/orchestrations/:graph_id",
+            get(orchestrations::get).delete(orchestrations::delete),
+        )
+        .route("/orchestrations/:graph_id/runs", post(orchestrations::run))
+        .route(
+            "/data-graphs/:graph_id",
+            get(orchestrations::get_data_graph)
+                .put(orchestrations::put_data_graph)
+                .delete(orchestrations::delete_data_graph),
+        )
+        .route("/port-options", get(port_options::list))
+        .route(
+            "/port-options/:node_type/:port_id",
+            post(port_options::query),
+        )
+        .route(
+            "/executions/:execution_id/events",
+            get(sse::execution_events),
+        )
+        // The last route layer runs first: authenticate, then rate limit.
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit::enforce,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::authorize,
+        ))
+        .route("/openapi.json", get(openapi_spec))
+        .with_state(state)
+}
+
+/// Serve [`router`] on `listener` until the server fails.
+pub async fn serve(listener: tokio::net::TcpListener, state: ServerState) -> std::io::Result<()> {
+    axum::serve(listener, router(state)).await
+}
+
+/// Serve [`router`] on `listener` until `shutdown` resolves, then stop
+/// accepting connections and wait for in-flight requests to finish.
+///
+/// Open SSE streams count as in-flight requests; clients should reconnect
+/// with `Last-Event-ID` against the next instance.
+pub async fn serve_with_shutdown(
+    listener: tokio::net::TcpListener,
+    state: ServerState,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+) -> std::io::Result<()> {
+    axum::serve(listener, router(state))
+        .with_graceful_shutdown(shutdown)
+        .await
+}
+
+#[cfg(test)]
+mod tests;
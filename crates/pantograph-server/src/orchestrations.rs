@@ -0,0 +1,215 @@
+//! REST handlers for the orchestration store and orchestration runs.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use node_engine::{
+    EventSink, OrchestrationExecutor, OrchestrationGraph, OrchestrationGraphMetadata,
+    OrchestrationResult, OrchestrationStore, TaskExecutor, WorkflowGraph,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::error::{ApiError, ApiResult};
+use crate::ServerState;
+
+/// Body of `POST /orchestrations/{id}/runs`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunRequest {
+    #[serde(default)]
+    pub initial_data: HashMap<String, serde_json::Value>,
+    /// Execution ID to run under, so clients can open the event stream before
+    /// starting the run. Generated when omitted.
+    #[serde(default)]
+    pub execution_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunResponse {
+    pub execution_id: String,
+    pub result: OrchestrationResult,
+}
+
+/// Runs data graphs from the orchestration store with the server's task
+/// executor, forwarding their events to the shared event history.
+struct StoreDataGraphExecutor {
+    store: Arc<RwLock<OrchestrationStore>>,
+    task_executor: Arc<dyn TaskExecutor>,
+    event_sink: Arc<dyn EventSink>,
+}
+
+#[async_trait::async_trait]
+impl node_engine::DataGraphExecutor for StoreDataGraphExecutor {
+    async fn execute_data_graph(
+        &self,
+        graph_id: &str,
+        inputs: HashMap<String, serde_json::Value>,
+        _event_sink: &dyn EventSink,
+    ) -> node_engine::Result<HashMap<String, serde_json::Value>> {
+        let graph = self
+            .store
+            .read()
+            .await
+            .get_data_graph(graph_id)
+            .cloned()
+            .ok_or_else(|| {
+                node_engine::NodeEngineError::failed(format!("Data graph '{}' not found", graph_id))
+            })?;
+
+        node_engine::execute_data_graph(
+            &graph,
+            &inputs,
+            self.event_sink.clone(),
+            self.task_executor.as_ref(),
+        )
+        .await
+    }
+
+    fn get_data_graph(&self, graph_id: &str) -> Option<WorkflowGraph> {
+        self.store
+            .try_read()
+            .ok()
+            .and_then(|store| store.get_data_graph(graph_id).cloned())
+    }
+}
+
+/// `GET /orchestrations`
+pub async fn list(State(state): State<ServerState>) -> Json<Vec<OrchestrationGraphMetadata>> {
+    Json(state.orchestrations.read().await.list_graphs())
+}
+
+/// `POST /orchestrations`: insert or overwrite an orchestration graph.
+pub async fn upsert(
+    State(state): State<ServerState>,
+    Json(graph): Json<OrchestrationGraph>,
+) -> ApiResult<(StatusCode, Json<OrchestrationGraph>)> {
+    state.graph_limits.check_orchestration(&graph)?;
+    state
+        .orchestrations
+        .write()
+        .await
+        .insert_graph(graph.clone())?;
+    Ok((StatusCode::CREATED, Json(graph)))
+}
+
+/// `GET /orchestrations/{id}`
+pub async fn get(
+    State(state): State<ServerState>,
+    Path(graph_id): Path<String>,
+) -> ApiResult<Json<OrchestrationGraph>> {
+    state
+        .orchestrations
+        .read()
+        .await
+        .get_graph(&graph_id)
+        .cloned()
+        .map(Json)
+        .ok_or_else(|| ApiError::not_found("Orchestration", &graph_id))
+}
+
+/// `DELETE /orchestrations/{id}`
+pub async fn delete(
+    State(state): State<ServerState>,
+    Path(graph_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .orchestrations
+        .write()
+        .await
+        .remove_graph(&graph_id)?
+        .map(|_| StatusCode::NO_CONTENT)
+        .ok_or_else(|| ApiError::not_found("Orchestration", &graph_id))
+}
+
+/// `PUT /data-graphs/{id}`: store a data graph referenced by orchestrations.
+pub async fn put_data_graph(
+    State(state): State<ServerState>,
+    Path(graph_id): Path<String>,
+    Json(graph): Json<WorkflowGraph>,
+) -> ApiResult<StatusCode> {
+    state.graph_limits.check_graph(&graph)?;
+    state
+        .orchestrations
+        .write()
+        .await
+        .insert_data_graph(graph_id, graph);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `GET /data-graphs/{id}`
+pub async fn get_data_graph(
+    State(state): State<ServerState>,
+    Path(graph_id): Path<String>,
+) -> ApiResult<Json<WorkflowGraph>> {
+    state
+        .orchestrations
+        .read()
+        .await
+        .get_data_graph(&graph_id)
+        .cloned()
+        .map(Json)
+        .ok_or_else(|| ApiError::not_found("Data graph", &graph_id))
+}
+
+/// `DELETE /data-graphs/{id}`
+pub async fn delete_data_graph(
+    State(state): State<ServerState>,
+    Path(graph_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .orchestrations
+        .write()
+        .await
+        .remove_data_graph(&graph_id)
+        .map(|_| StatusCode::NO_CONTENT)
+        .ok_or_else(|| ApiError::not_found("Data graph", &graph_id))
+}
+
+/// `POST /orchestrations/{id}/runs`: run an orchestration to completion.
+///
+/// Persistent stores checkpoint the run like every other host does.
+pub async fn run(
+    State(state): State<ServerState>,
+    Path(graph_id): Path<String>,
+    Json(request): Json<RunRequest>,
+) -> ApiResult<Json<RunResponse>> {
+    // Counted before the draining check so a drain that starts in between
+    // still waits for this run.
+    let _run = state.orchestration_runs.start();
+    state.ensure_accepting()?;
+    let (graph, checkpoints) = {
+        let store = state.orchestrations.read().await;
+        let graph = store
+            .get_graph(&graph_id)
+            .cloned()
+            .ok_or_else(|| ApiError::not_found("Orchestration", &graph_id))?;
+        (graph, store.checkpoint_store())
+    };
+
+    let event_sink: Arc<dyn EventSink> = state.events.clone();
+    let mut executor = OrchestrationExecutor::new(StoreDataGraphExecutor {
+        store: state.orchestrations.clone(),
+        task_executor: state.task_executor.clone(),
+        event_sink: event_sink.clone(),
+    });
+    if let Some(execution_id) = request.execution_id {
+        executor = executor.with_execution_id(execution_id);
+    }
+    if let Some(checkpoints) = checkpoints {
+        executor = executor.with_checkpoints(checkpoints);
+    }
+    executor = executor.with_graph_limits(state.graph_limits);
+
+    let result = executor
+        .execute(&graph, request.initial_data, event_sink.as_ref())
+        .await?;
+    Ok(Json(RunResponse {
+        execution_id: executor.execution_id().to_string(),
+        result,
+    }))
+}
@@ -0,0 +1,45 @@
+//! REST handlers for dynamic port option queries.
+
+use axum::extract::{Path, State};
+use axum::Json;
+use node_engine::{PortOptionsQuery, PortOptionsResult};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApiResult;
+use crate::ServerState;
+
+/// A port whose options can be queried.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryablePort {
+    pub node_type: String,
+    pub port_id: String,
+}
+
+/// `GET /port-options`
+pub async fn list(State(state): State<ServerState>) -> Json<Vec<QueryablePort>> {
+    let mut ports: Vec<QueryablePort> = state
+        .registry
+        .queryable_ports()
+        .into_iter()
+        .map(|(node_type, port_id)| QueryablePort {
+            node_type: node_type.to_string(),
+            port_id: port_id.to_string(),
+        })
+        .collect();
+    ports.sort_by(|a, b| (&a.node_type, &a.port_id).cmp(&(&b.node_type, &b.port_id)));
+    Json(ports)
+}
+
+/// `POST /port-options/{node_type}/{port_id}`
+pub async fn query(
+    State(state): State<ServerState>,
+    Path((node_type, port_id)): Path<(String, String)>,
+    Json(query): Json<PortOptionsQuery>,
+) -> ApiResult<Json<PortOptionsResult>> {
+    let result = state
+        .registry
+        .query_port_options(&node_type, &port_id, &query, &state.extensions)
+        .await?;
+    Ok(Json(result))
+}
@@ -0,0 +1,378 @@
+//! Per-caller rate limits, concurrent execution caps, and daily quotas.
+//!
+//! Callers are keyed by the authenticated [`Principal`] subject, or
+//! [`ANONYMOUS_KEY`] when auth is disabled. Counters live behind
+//! [`RateLimitStore`] so multi-instance deployments can share them:
+//! [`InMemoryRateLimitStore`] serves single instances, and the `redis`
+//! feature adds `RedisRateLimitStore` for counters shared across instances.
+//! Rejections are `429` responses with a `Retry-After` header.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+
+use crate::auth::{self, Principal, Scope};
+use crate::error::ApiError;
+use crate::ServerState;
+
+#[cfg(feature = "redis")]
+pub use crate::redis_rate_limit::RedisRateLimitStore;
+
+/// Key shared by all requests when auth is disabled.
+pub const ANONYMOUS_KEY: &str = "anonymous";
+
+const SECONDS_PER_DAY: u64 = 86_400;
+/// Idle time after which an in-memory entry is dropped. Every token bucket
+/// refills completely within a minute, so an evicted caller comes back with
+/// the same bucket it would have had.
+const IDLE_EVICTION_AFTER: Duration = Duration::from_secs(60);
+
+/// Limits applied to one caller. `None` leaves that dimension unlimited.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimits {
+    /// Token bucket size and refill rate for all requests.
+    pub requests_per_minute: Option<u32>,
+    /// Demand and orchestration run requests in flight at once.
+    pub max_concurrent_executions: Option<u32>,
+    /// Requests allowed per UTC day.
+    pub daily_quota: Option<u64>,
+}
+
+/// Default limits plus per-subject overrides.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitConfig {
+    #[serde(default)]
+    pub default: RateLimits,
+    /// Overrides keyed by principal subject.
+    #[serde(default)]
+    pub per_subject: HashMap<String, RateLimits>,
+}
+
+impl RateLimitConfig {
+    pub fn limits_for(&self, key: &str) -> &RateLimits {
+        self.per_subject.get(key).unwrap_or(&self.default)
+    }
+}
+
+/// Why a request was rejected and when to retry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimited {
+    pub reason: &'static str,
+    pub retry_after: Duration,
+}
+
+impl IntoResponse for RateLimited {
+    fn into_response(self) -> Response {
+        let mut response =
+            ApiError::new(StatusCode::TOO_MANY_REQUESTS, self.reason).into_response();
+        let seconds = self.retry_after.as_secs_f64().ceil().max(1.0) as u64;
+        response.headers_mut().insert(
+            header::RETRY_AFTER,
+            HeaderValue::from_str(&seconds.to_string()).expect("digits are a valid header"),
+        );
+        response
+    }
+}
+
+/// Shared counters behind the limiter.
+#[async_trait]
+pub trait RateLimitStore: Send + Sync {
+    /// Take one request token and one unit of daily quota for `key`.
+    async fn acquire_request(&self, key: &str, limits: &RateLimits) -> Result<(), RateLimited>;
+
+    /// Reserve a concurrent execution slot for `key`.
+    async fn acquire_execution(&self, key: &str, limits: &RateLimits) -> Result<(), RateLimited>;
+
+    /// Release a slot taken by [`RateLimitStore::acquire_execution`].
+    async fn release_execution(&self, key: &str);
+}
+
+#[derive(Debug)]
+struct KeyState {
+    tokens: f64,
+    refilled_at: Instant,
+    day: u64,
+    day_count: u64,
+    executions: u32,
+    last_seen: Instant,
+}
+
+#[derive(Debug)]
+struct Keys {
+    states: HashMap<String, KeyState>,
+    swept_at: Instant,
+}
+
+/// Single-instance store with token buckets refilled on access.
+///
+/// Entries idle for a minute are evicted on a later access unless they hold
+/// an execution slot or today's quota usage, so the map stays bounded by the
+/// callers active in the last minute plus those with quota usage today.
+pub struct InMemoryRateLimitStore {
+    keys: Mutex<Keys>,
+}
+
+impl Default for InMemoryRateLimitStore {
+    fn default() -> Self {
+        Self {
+            keys: Mutex::new(Keys {
+                states: HashMap::new(),
+                swept_at: Instant::now(),
+            }),
+        }
+    }
+}
+
+impl InMemoryRateLimitStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Keys {
+    /// State for `key`, after evicting idle entries at most once per
+    /// [`IDLE_EVICTION_AFTER`].
+    fn touch(&mut self, key: &str, limits: &RateLimits, now: Instant) -> &mut KeyState {
+        if now.duration_since(self.swept_at) >= IDLE_EVICTION_AFTER {
+            let today = seconds_since_epoch() / SECONDS_PER_DAY;
+            self.states
+                .retain(|_, state| !state.is_evictable(now, today));
+            self.swept_at = now;
+        }
+        let state = self
+            .states
+            .entry(key.to_string())
+            .or_insert_with(|| KeyState::new(limits));
+        state.last_seen = now;
+        state
+    }
+}
+
+impl KeyState {
+    /// Fresh caller with a full token bucket.
+    fn new(limits: &RateLimits) -> Self {
+        Self {
+            tokens: limits.requests_per_minute.unwrap_or(0) as f64,
+            refilled_at: Instant::now(),
+            day: seconds_since_epoch() / SECONDS_PER_DAY,
+            day_count: 0,
+            executions: 0,
+            last_seen: Instant::now(),
+        }
+    }
+
+    /// Whether dropping this entry is indistinguishable from keeping it.
+    fn is_evictable(&self, now: Instant, today: u64) -> bool {
+        self.executions == 0
+            && now.duration_since(self.last_seen) >= IDLE_EVICTION_AFTER
+            && (self.day_count == 0 || self.day != today)
+    }
+}
+
+fn seconds_since_epoch() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+#[async_trait]
+impl RateLimitStore for InMemoryRateLimitStore {
+    async fn acquire_request(&self, key: &str, limits: &RateLimits) -> Result<(), RateLimited> {
+        let now = Instant::now();
+        let epoch_seconds = seconds_since_epoch();
+        let today = epoch_seconds / SECONDS_PER_DAY;
+        let mut keys = self.keys.lock().expect("rate limit lock poisoned");
+        let state = keys.touch(key, limits, now);
+
+        if state.day != today {
+            state.day = today;
+            state.day_count = 0;
+        }
+        if let Some(quota) = limits.daily_quota {
+            if state.day_count >= quota {
+                let until_midnight = SECONDS_PER_DAY - epoch_seconds % SECONDS_PER_DAY;
+                return Err(RateLimited {
+                    reason: "Daily quota exhausted",
+                    retry_after: Duration::from_secs(until_midnight),
+                });
+            }
+        }
+
+        if let Some(per_minute) = limits.requests_per_minute {
+            let capacity = per_minute as f64;
+            let per_second = capacity / 60.0;
+            let elapsed = now.duration_since(state.refilled_at).as_secs_f64();
+            state.tokens = (state.tokens + elapsed * per_second).min(capacity);
+            state.refilled_at = now;
+            if state.tokens < 1.0 {
+                let wait = if per_second > 0.0 {
+                    (1.0 - state.tokens) / per_second
+                } else {
+                    SECONDS_PER_DAY as f64
+                };
+                return Err(RateLimited {
+                    reason: "Request rate limit exceeded",
+                    retry_after: Duration::from_secs_f64(wait),
+                });
+            }
+            state.tokens -= 1.0;
+        }
+
+        if limits.daily_quota.is_some() {
+            state.day_count += 1;
+        }
+        Ok(())
+    }
+
+    async fn acquire_execution(&self, key: &str, limits: &RateLimits) -> Result<(), RateLimited> {
+        let mut keys = self.keys.lock().expect("rate limit lock poisoned");
+        let state = keys.touch(key, limits, Instant::now());
+        if let Some(max) = limits.max_concurrent_executions {
+            if state.executions >= max {
+                return Err(RateLimited {
+                    reason: "Too many concurrent executions",
+                    retry_after: Duration::from_secs(1),
+                });
+            }
+        }
+        state.executions += 1;
+        Ok(())
+    }
+
+    async fn release_execution(&self, key: &str) {
+        let mut keys = self.keys.lock().expect("rate limit lock poisoned");
+        if let Some(state) = keys.states.get_mut(key) {
+            state.executions = state.executions.saturating_sub(1);
+        }
+    }
+}
+
+/// Limits and the store enforcing them.
+#[derive(Clone)]
+pub struct RateLimiter {
+    pub config: Arc<RateLimitConfig>,
+    pub store: Arc<dyn RateLimitStore>,
+}
+
+impl RateLimiter {
+    pub fn in_memory(config: RateLimitConfig) -> Self {
+        Self::with_store(config, Arc::new(InMemoryRateLimitStore::new()))
+    }
+
+    pub fn with_store(config: RateLimitConfig, store: Arc<dyn RateLimitStore>) -> Self {
+        Self {
+            config: Arc::new(config),
+            store,
+        }
+    }
+}
+
+/// Execution slot that is released even when the client disconnects mid-run.
+struct ExecutionSlot {
+    store: Arc<dyn RateLimitStore>,
+    key: Option<String>,
+}
+
+impl ExecutionSlot {
+    async fn release(mut self) {
+        if let Some(key) = self.key.take() {
+            self.store.release_execution(&key).await;
+        }
+    }
+}
+
+impl Drop for ExecutionSlot {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            let store = self.store.clone();
+            tokio::spawn(async move { store.release_execution(&key).await });
+        }
+    }
+}
+
+/// Route layer applying the caller's limits. Runs after [`auth::authorize`].
+///
+/// Passes every request through when the server has no [`RateLimiter`].
+pub async fn enforce(State(state): State<ServerState>, request: Request, next: Next) -> Response {
+    let Some(limiter) = state.rate_limits.as_ref() else {
+        return next.run(request).await;
+    };
+
+    let key = request
+        .extensions()
+        .get::<Principal>()
+        .map(|principal| principal.subject.clone())
+        .unwrap_or_else(|| ANONYMOUS_KEY.to_string());
+    let limits = limiter.config.limits_for(&key);
+
+    if let Err(limited) = limiter.store.acquire_request(&key, limits).await {
+        return limited.into_response();
+    }
+
+    let is_execution = request
+        .extensions()
+        .get::<MatchedPath>()
+        .is_some_and(|path| {
+            auth::required_scope(request.method(), path.as_str()) == Scope::Execute
+        });
+    if !is_execution {
+        return next.run(request).await;
+    }
+
+    if let Err(limited) = limiter.store.acquire_execution(&key, limits).await {
+        return limited.into_response();
+    }
+    let slot = ExecutionSlot {
+        store: limiter.store.clone(),
+        key: Some(key),
+    };
+    let response = next.run(request).await;
+    slot.release().await;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits() -> RateLimits {
+        RateLimits {
+            requests_per_minute: Some(60),
+            daily_quota: Some(100),
+            ..RateLimits::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn idle_entries_are_evicted_unless_they_hold_slots_or_quota() {
+        let store = InMemoryRateLimitStore::new();
+        let no_quota = RateLimits {
+            requests_per_minute: Some(60),
+            ..RateLimits::default()
+        };
+        store.acquire_request("idle", &no_quota).await.unwrap();
+        store
+            .acquire_execution("running", &RateLimits::default())
+            .await
+            .unwrap();
+        store.acquire_request("quota", &limits()).await.unwrap();
+
+        let later = Instant::now() + IDLE_EVICTION_AFTER;
+        store.keys.lock().unwrap().touch("fresh", &limits(), later);
+
+        let keys = store.keys.lock().unwrap();
+        let mut tracked: Vec<&str> = keys.states.keys().map(String::as_str).collect();
+        tracked.sort_unstable();
+        assert_eq!(tracked, vec!["fresh", "quota", "running"]);
+    }
+}
@@ -0,0 +1,254 @@
+//! Redis-backed [`RateLimitStore`] for limits shared across server instances.
+//!
+//! Each check runs as one Lua script, so concurrent instances see a single
+//! token bucket, daily counter, and execution count per caller. Keys expire on
+//! their own: buckets a minute after last use (when they would be full again),
+//! daily counters at UTC midnight, and execution counters after
+//! `execution_slot_ttl` so slots held by a crashed instance are reclaimed.
+//!
+//! A store that cannot reach Redis rejects requests rather than letting them
+//! through unlimited.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use redis::Script;
+
+use crate::rate_limit::{RateLimitStore, RateLimited, RateLimits};
+
+/// Default prefix for every key the store writes.
+pub const DEFAULT_KEY_PREFIX: &str = "pantograph:rate";
+/// Default lifetime of an execution counter without new acquisitions.
+pub const DEFAULT_EXECUTION_SLOT_TTL: Duration = Duration::from_secs(3600);
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Returns `{0, 0}` when admitted, `{1, retry_ms}` when the daily quota is
+/// spent, and `{2, retry_ms}` when the token bucket is empty. Negative limits
+/// mean unlimited.
+const ACQUIRE_REQUEST: &str = r#"
+local per_minute = tonumber(ARGV[1])
+local quota = tonumber(ARGV[2])
+local now_ms = tonumber(ARGV[3])
+local day_ttl = tonumber(ARGV[4])
+if quota >= 0 then
+  local used = tonumber(redis.call('GET', KEYS[2]) or '0')
+  if used >= quota then
+    return {1, day_ttl * 1000}
+  end
+end
+if per_minute >= 0 then
+  local state = redis.call('HMGET', KEYS[1], 'tokens', 'ts')
+  local tokens = tonumber(state[1]) or per_minute
+  local ts = tonumber(state[2]) or now_ms
+  local per_ms = per_minute / 60000
+  tokens = math.min(per_minute, tokens + math.max(0, now_ms - ts) * per_ms)
+  if tokens < 1 then
+    redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'ts', now_ms)
+    redis.call('PEXPIRE', KEYS[1], 60000)
+    if per_ms > 0 then
+      return {2, math.ceil((1 - tokens) / per_ms)}
+    end
+    return {2, 86400000}
+  end
+  redis.call('HSET', KEYS[1], 'tokens', tostring(tokens - 1), 'ts', now_ms)
+  redis.call('PEXPIRE', KEYS[1], 60000)
+end
+if quota >= 0 then
+  redis.call('INCR', KEYS[2])
+  redis.call('EXPIRE', KEYS[2], day_ttl)
+end
+return {0, 0}
+"#;
+
+/// Returns 1 when a slot was taken and 0 when the caller is at its limit.
+const ACQUIRE_EXECUTION: &str = r#"
+local max = tonumber(ARGV[1])
+local current = tonumber(redis.call('GET', KEYS[1]) or '0')
+if max >= 0 and current >= max then
+  return 0
+end
+redis.call('INCR', KEYS[1])
+redis.call('EXPIRE', KEYS[1], ARGV[2])
+return 1
+"#;
+
+const RELEASE_EXECUTION: &str = r#"
+local current = tonumber(redis.call('GET', KEYS[1]) or '0')
+if current <= 1 then
+  redis.call('DEL', KEYS[1])
+else
+  redis.call('DECR', KEYS[1])
+end
+return 0
+"#;
+
+/// Rate limit counters stored in Redis.
+#[derive(Clone)]
+pub struct RedisRateLimitStore {
+    connection: ConnectionManager,
+    key_prefix: String,
+    execution_slot_ttl: Duration,
+    acquire_request: Script,
+    acquire_execution: Script,
+    release_execution: Script,
+}
+
+impl RedisRateLimitStore {
+    /// Connect to `url` (e.g. `redis://cache:6379/0`).
+    pub async fn connect(url: &str) -> redis::RedisResult<Self> {
+        let client = redis::Client::open(url)?;
+        Ok(Self::new(ConnectionManager::new(client).await?))
+    }
+
+    /// Wrap an existing connection manager.
+    pub fn new(connection: ConnectionManager) -> Self {
+        Self {
+            connection,
+            key_prefix: DEFAULT_KEY_PREFIX.to_string(),
+            execution_slot_ttl: DEFAULT_EXECUTION_SLOT_TTL,
+            acquire_request: Script::new(ACQUIRE_REQUEST),
+            acquire_execution: Script::new(ACQUIRE_EXECUTION),
+            release_execution: Script::new(RELEASE_EXECUTION),
+        }
+    }
+
+    /// Namespace keys, e.g. per deployment sharing one Redis.
+    pub fn with_key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.key_prefix = key_prefix.into();
+        self
+    }
+
+    /// Lifetime of an execution counter that sees no new acquisitions.
+    pub fn with_execution_slot_ttl(mut self, ttl: Duration) -> Self {
+        self.execution_slot_ttl = ttl;
+        self
+    }
+
+    fn key(&self, caller: &str, suffix: &str) -> String {
+        format!("{}:{}:{}", self.key_prefix, caller, suffix)
+    }
+}
+
+fn unavailable() -> RateLimited {
+    RateLimited {
+        reason: "Rate limit store unavailable",
+        retry_after: Duration::from_secs(1),
+    }
+}
+
+fn limit_arg(limit: Option<u64>) -> i64 {
+    limit.map_or(-1, |limit| limit.min(i64::MAX as u64) as i64)
+}
+
+#[async_trait]
+impl RateLimitStore for RedisRateLimitStore {
+    async fn acquire_request(&self, key: &str, limits: &RateLimits) -> Result<(), RateLimited> {
+        let elapsed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let epoch_seconds = elapsed.as_secs();
+        let today = epoch_seconds / SECONDS_PER_DAY;
+        let until_midnight = SECONDS_PER_DAY - epoch_seconds % SECONDS_PER_DAY;
+
+        let (outcome, retry_ms): (i64, u64) = self
+            .acquire_request
+            .key(self.key(key, "bucket"))
+            .key(self.key(key, &format!("day:{}", today)))
+            .arg(limit_arg(limits.requests_per_minute.map(u64::from)))
+            .arg(limit_arg(limits.daily_quota))
+            .arg(elapsed.as_millis() as u64)
+            .arg(until_midnight)
+            .invoke_async(&mut self.connection.clone())
+            .await
+            .map_err(|_| unavailable())?;
+
+        let retry_after = Duration::from_millis(retry_ms);
+        match outcome {
+            0 => Ok(()),
+            1 => Err(RateLimited {
+                reason: "Daily quota exhausted",
+                retry_after,
+            }),
+            _ => Err(RateLimited {
+                reason: "Request rate limit exceeded",
+                retry_after,
+            }),
+        }
+    }
+
+    async fn acquire_execution(&self, key: &str, limits: &RateLimits) -> Result<(), RateLimited> {
+        let acquired: i64 = self
+            .acquire_execution
+            .key(self.key(key, "executions"))
+            .arg(limit_arg(limits.max_concurrent_executions.map(u64::from)))
+            .arg(self.execution_slot_ttl.as_secs().max(1))
+            .invoke_async(&mut self.connection.clone())
+            .await
+            .map_err(|_| unavailable())?;
+        if acquired == 1 {
+            Ok(())
+        } else {
+            Err(RateLimited {
+                reason: "Too many concurrent executions",
+                retry_after: Duration::from_secs(1),
+            })
+        }
+    }
+
+    async fn release_execution(&self, key: &str) {
+        // A failed release is reclaimed when the counter expires.
+        let _: redis::RedisResult<i64> = self
+            .release_execution
+            .key(self.key(key, "executions"))
+            .invoke_async(&mut self.connection.clone())
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs against the Redis at `PANTOGRAPH_TEST_REDIS_URL`; run it with
+    /// `--include-ignored` (the `rust-redis` CI job does).
+    #[tokio::test]
+    #[ignore = "needs a live Redis at PANTOGRAPH_TEST_REDIS_URL"]
+    async fn redis_store_enforces_buckets_quotas_and_slots() {
+        let url = std::env::var("PANTOGRAPH_TEST_REDIS_URL")
+            .expect("PANTOGRAPH_TEST_REDIS_URL must point at a Redis server");
+        let prefix = format!("pantograph:test:{}", std::process::id());
+        let store = RedisRateLimitStore::connect(&url)
+            .await
+            .expect("connect to redis")
+            .with_key_prefix(&prefix);
+
+        let bucket = RateLimits {
+            requests_per_minute: Some(2),
+            ..RateLimits::default()
+        };
+        store.acquire_request("bucket", &bucket).await.unwrap();
+        store.acquire_request("bucket", &bucket).await.unwrap();
+        let limited = store.acquire_request("bucket", &bucket).await.unwrap_err();
+        assert_eq!(limited.reason, "Request rate limit exceeded");
+
+        let quota = RateLimits {
+            daily_quota: Some(1),
+            ..RateLimits::default()
+        };
+        store.acquire_request("quota", &quota).await.unwrap();
+        let limited = store.acquire_request("quota", &quota).await.unwrap_err();
+        assert_eq!(limited.reason, "Daily quota exhausted");
+
+        let slots = RateLimits {
+            max_concurrent_executions: Some(1),
+            ..RateLimits::default()
+        };
+        store.acquire_execution("slots", &slots).await.unwrap();
+        assert!(store.acquire_execution("slots", &slots).await.is_err());
+        store.release_execution("slots").await;
+        store.acquire_execution("slots", &slots).await.unwrap();
+        store.release_execution("slots").await;
+    }
+}
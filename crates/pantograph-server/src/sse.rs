@@ -0,0 +1,172 @@
+//! Server-Sent Events streams of execution events.
+//!
+//! Each workflow or orchestration event is sent as `event: workflow` with its
+//! [`EventRingBuffer`](node_engine::EventRingBuffer) ID as the SSE `id` and the
+//! JSON `WorkflowEvent` as data. Clients resume by reconnecting with the
+//! standard `Last-Event-ID` header, or a `lastEventId` query parameter when
+//! they cannot set headers; retained events after that ID are replayed before
+//! live delivery continues.
+//!
+//! Two control events signal gaps:
+//!
+//! - `truncated` is sent first when events after the cursor were already
+//!   evicted from the buffer.
+//! - `lagged` (data: number of skipped events) is sent when the client fell
+//!   behind live delivery. The stream then ends so the client reconnects and
+//!   replays from its last ID.
+
+use std::convert::Infallible;
+
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures_util::stream::{self, Stream, StreamExt};
+use node_engine::{EventReplay, SequencedEvent};
+use serde::Deserialize;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::ServerState;
+
+/// Standard SSE resume header.
+pub const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+const WORKFLOW_EVENT: &str = "workflow";
+const TRUNCATED_EVENT: &str = "truncated";
+const LAGGED_EVENT: &str = "lagged";
+const ERROR_EVENT: &str = "error";
+
+/// Query parameters of the event stream endpoint.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventStreamQuery {
+    /// Resume cursor for clients that cannot send `Last-Event-ID`.
+    pub last_event_id: Option<u64>,
+}
+
+/// `GET /executions/{execution_id}/events`
+pub async fn execution_events(
+    State(state): State<ServerState>,
+    Path(execution_id): Path<String>,
+    Query(query): Query<EventStreamQuery>,
+    headers: HeaderMap,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let last_event_id = resume_cursor(&headers, &query);
+    let replay = state
+        .events
+        .replay_after(Some(&execution_id), last_event_id);
+    Sse::new(event_stream(execution_id, replay)).keep_alive(KeepAlive::default())
+}
+
+/// The `Last-Event-ID` header wins over the query parameter; no cursor means
+/// "replay everything retained".
+fn resume_cursor(headers: &HeaderMap, query: &EventStreamQuery) -> u64 {
+    headers
+        .get(LAST_EVENT_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+        .or(query.last_event_id)
+        .unwrap_or(0)
+}
+
+fn workflow_event(event: &SequencedEvent) -> Event {
+    let (kind, data) = match serde_json::to_string(&event.event) {
+        Ok(data) => (WORKFLOW_EVENT, data),
+        Err(error) => (ERROR_EVENT, error.to_string()),
+    };
+    Event::default()
+        .id(event.id.to_string())
+        .event(kind)
+        .data(data)
+}
+
+fn event_stream(
+    execution_id: String,
+    replay: EventReplay,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    let EventReplay {
+        events,
+        truncated,
+        receiver,
+    } = replay;
+
+    let truncated = truncated.then(|| {
+        Event::default()
+            .event(TRUNCATED_EVENT)
+            .data("events after the requested ID were evicted")
+    });
+    let replayed: Vec<Result<Event, Infallible>> = truncated
+        .into_iter()
+        .chain(events.iter().map(workflow_event))
+        .map(Ok)
+        .collect();
+
+    let live = stream::unfold(Some((execution_id, receiver)), |state| async move {
+        let (execution_id, mut receiver) = state?;
+        loop {
+            match receiver.recv().await {
+                Ok(event) if event.execution_id.as_deref() == Some(execution_id.as_str()) => {
+                    let item = Ok(workflow_event(&event));
+                    return Some((item, Some((execution_id, receiver))));
+                }
+                Ok(_) => {}
+                Err(RecvError::Lagged(skipped)) => {
+                    let item = Ok(Event::default()
+                        .event(LAGGED_EVENT)
+                        .data(skipped.to_string()));
+                    return Some((item, None));
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    stream::iter(replayed).chain(live)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::http::HeaderValue;
+    use node_engine::{EventRingBuffer, EventSink, WorkflowEvent};
+
+    use super::*;
+
+    fn progress(execution_id: &str) -> WorkflowEvent {
+        WorkflowEvent::task_progress("task1", execution_id, 0.5, None)
+    }
+
+    #[test]
+    fn header_cursor_wins_over_query() {
+        let mut headers = HeaderMap::new();
+        let query = EventStreamQuery {
+            last_event_id: Some(3),
+        };
+        assert_eq!(resume_cursor(&headers, &query), 3);
+
+        headers.insert(LAST_EVENT_ID_HEADER, HeaderValue::from_static("7"));
+        assert_eq!(resume_cursor(&headers, &query), 7);
+        assert_eq!(
+            resume_cursor(&HeaderMap::new(), &EventStreamQuery::default()),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_replays_then_continues_with_live_events() {
+        let buffer = EventRingBuffer::new(16);
+        for execution_id in ["exec1", "exec2", "exec1", "exec1"] {
+            buffer.send(progress(execution_id)).unwrap();
+        }
+
+        let stream = event_stream("exec1".to_string(), buffer.replay_after(Some("exec1"), 1));
+        buffer.send(progress("exec2")).unwrap();
+        buffer.send(progress("exec1")).unwrap();
+
+        let events: Vec<_> =
+            tokio::time::timeout(Duration::from_secs(1), stream.take(3).collect::<Vec<_>>())
+                .await
+                .expect("replayed and live events arrive");
+        assert_eq!(events.len(), 3);
+    }
+}
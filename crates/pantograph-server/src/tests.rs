@@ -0,0 +1,515 @@
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode};
+use axum::Router;
+use node_engine::{
+    EventRingBuffer, OrchestrationEdge, OrchestrationGraph, OrchestrationNode,
+    OrchestrationNodeType, SimulatedTaskExecutor, SimulationConfig,
+};
+use tower::ServiceExt;
+
+use super::*;
+
+fn app() -> Router {
+    let state = ServerState::new(Arc::new(EventRingBuffer::new(64))).with_task_executor(Arc::new(
+        SimulatedTaskExecutor::new(SimulationConfig::default()),
+    ));
+    router(state)
+}
+
+async fn call(
+    app: &Router,
+    method: Method,
+    uri: &str,
+    body: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json");
+    let body = body.map_or_else(Body::empty, |body| Body::from(body.to_string()));
+    let response = app
+        .clone()
+        .oneshot(request.body(body).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json = serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null);
+    (status, json)
+}
+
+#[test]
+fn openapi_spec_documents_every_route() {
+    let spec: serde_json::Value = serde_json::from_str(OPENAPI_SPEC).expect("valid JSON");
+    let paths = spec["paths"].as_object().expect("paths object");
+
+    for path in [
+        "/workflows",
+        "/workflows/validate",
+        "/workflows/{workflowId}",
+        "/workflows/{workflowId}/nodes",
+        "/workflows/{workflowId}/nodes/{nodeId}",
+        "/workflows/{workflowId}/nodes/{nodeId}/data",
+        "/workflows/{workflowId}/edges",
+        "/workflows/{workflowId}/edges/{edgeId}",
+        "/workflows/{workflowId}/validation",
+        "/workflows/{workflowId}/demand",
+        "/orchestrations",
+        "/orchestrations/{graphId}",
+        "/orchestrations/{graphId}/runs",
+        "/data-graphs/{graphId}",
+        "/port-options",
+        "/port-options/{nodeType}/{portId}",
+        "/executions/{executionId}/events",
+    ] {
+        assert!(paths.contains_key(path), "missing {path}");
+    }
+}
+
+#[tokio::test]
+async fn workflow_crud_validation_and_demand() {
+    let app = app();
+    let graph = serde_json::json!({
+        "id": "wf-1",
+        "name": "Test",
+        "nodes": [],
+        "edges": []
+    });
+
+    let (status, _) = call(&app, Method::POST, "/workflows", Some(graph.clone())).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = call(&app, Method::POST, "/workflows", Some(graph)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let node = serde_json::json!({
+        "id": "n1",
+        "nodeType": "custom",
+        "data": {},
+        "position": [0.0, 0.0]
+    });
+    let (status, _) = call(&app, Method::POST, "/workflows/wf-1/nodes", Some(node)).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (_, stored) = call(&app, Method::GET, "/workflows/wf-1", None).await;
+    assert_eq!(stored["nodes"][0]["id"], "n1");
+
+    let (status, validation) = call(&app, Method::GET, "/workflows/wf-1/validation", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(validation["errors"].is_array());
+
+    let (status, demand) = call(
+        &app,
+        Method::POST,
+        "/workflows/wf-1/demand",
+        Some(serde_json::json!({ "nodeIds": ["n1"] })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(demand["executionId"], "wf-1");
+    assert!(demand["outputs"]["n1"].is_object());
+
+    let (status, _) = call(&app, Method::DELETE, "/workflows/wf-1/nodes/n1", None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = call(&app, Method::DELETE, "/workflows/wf-1", None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, error) = call(&app, Method::GET, "/workflows/wf-1", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(error["error"].as_str().unwrap().contains("wf-1"));
+}
+
+#[tokio::test]
+async fn graph_limits_reject_oversized_graphs_and_mutations() {
+    let state = ServerState::new(Arc::new(EventRingBuffer::new(64))).with_graph_limits(
+        node_engine::GraphLimits {
+            max_nodes: Some(1),
+            ..Default::default()
+        },
+    );
+    let app = router(state);
+    let node = |id: &str| {
+        serde_json::json!({
+            "id": id,
+            "nodeType": "custom",
+            "data": {},
+            "position": [0.0, 0.0]
+        })
+    };
+
+    let oversized = serde_json::json!({
+        "id": "wf-big",
+        "name": "Big",
+        "nodes": [node("a"), node("b")],
+        "edges": []
+    });
+    let (status, error) = call(&app, Method::POST, "/workflows", Some(oversized)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        error["limit"],
+        serde_json::json!({"kind": "max_nodes", "count": 2, "limit": 1})
+    );
+    let (status, _) = call(&app, Method::GET, "/workflows/wf-big", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let graph = serde_json::json!({
+        "id": "wf-1",
+        "name": "Test",
+        "nodes": [node("a")],
+        "edges": []
+    });
+    let (status, _) = call(&app, Method::POST, "/workflows", Some(graph)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, error) = call(&app, Method::POST, "/workflows/wf-1/nodes", Some(node("b"))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(error["limit"]["kind"], "max_nodes");
+    let (_, stored) = call(&app, Method::GET, "/workflows/wf-1", None).await;
+    assert_eq!(stored["nodes"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn orchestration_store_and_run() {
+    let app = app();
+    let mut graph = OrchestrationGraph::new("orch-1", "Orchestration");
+    graph.nodes.push(OrchestrationNode::new(
+        "start",
+        OrchestrationNodeType::Start,
+        (0.0, 0.0),
+    ));
+    graph.nodes.push(OrchestrationNode::new(
+        "end",
+        OrchestrationNodeType::End,
+        (200.0, 0.0),
+    ));
+    graph.edges.push(OrchestrationEdge::new(
+        "e1", "start", "next", "end", "input",
+    ));
+
+    let (status, _) = call(
+        &app,
+        Method::POST,
+        "/orchestrations",
+        Some(serde_json::to_value(&graph).unwrap()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (_, listed) = call(&app, Method::GET, "/orchestrations", None).await;
+    assert_eq!(listed[0]["id"], "orch-1");
+
+    let (status, run) = call(
+        &app,
+        Method::POST,
+        "/orchestrations/orch-1/runs",
+        Some(serde_json::json!({ "executionId": "run-1" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(run["executionId"], "run-1");
+    assert_eq!(run["result"]["success"], true);
+
+    let (status, _) = call(
+        &app,
+        Method::POST,
+        "/orchestrations/missing/runs",
+        Some(serde_json::json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+fn authed_app() -> Router {
+    let keys = auth::StaticApiKeys::new()
+        .with_key("read-key", "reader", [auth::Scope::Read])
+        .with_key("exec-key", "runner", [auth::Scope::Execute])
+        .with_key("admin-key", "admin", [auth::Scope::Admin]);
+    let auth = auth::AuthConfig::new()
+        .with_authenticator(keys)
+        .with_authenticator(auth::JwtValidator::hs256(b"secret", "https://issuer.test"));
+    router(ServerState::new(Arc::new(EventRingBuffer::new(64))).with_auth(auth))
+}
+
+async fn status_with(
+    app: &Router,
+    method: Method,
+    uri: &str,
+    credential: Option<(&str, &str)>,
+) -> StatusCode {
+    let mut request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json");
+    if let Some((name, value)) = credential {
+        request = request.header(name, value);
+    }
+    let body = Body::from(r#"{"id":"wf-1","name":"Test","nodes":[],"edges":[]}"#);
+    app.clone()
+        .oneshot(request.body(body).unwrap())
+        .await
+        .unwrap()
+        .status()
+}
+
+fn jwt(issuer: &str, scope: &str) -> String {
+    let claims = serde_json::json!({
+        "iss": issuer,
+        "sub": "service",
+        "scope": scope,
+        "exp": 4_102_444_800u64,
+    });
+    jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(b"secret"),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn auth_rejects_missing_and_unknown_credentials() {
+    let app = authed_app();
+
+    assert_eq!(
+        status_with(&app, Method::GET, "/workflows", None).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        status_with(&app, Method::GET, "/workflows", Some(("x-api-key", "nope"))).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        status_with(&app, Method::GET, "/openapi.json", None).await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn auth_enforces_scopes_before_handlers() {
+    let app = authed_app();
+    let read = Some(("x-api-key", "read-key"));
+    let exec = Some(("authorization", "Bearer exec-key"));
+    let admin = Some(("x-api-key", "admin-key"));
+
+    assert_eq!(
+        status_with(&app, Method::GET, "/workflows", read).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status_with(&app, Method::POST, "/workflows", read).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status_with(&app, Method::POST, "/workflows", exec).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status_with(&app, Method::POST, "/workflows", admin).await,
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        status_with(&app, Method::POST, "/workflows/wf-1/demand", read).await,
+        StatusCode::FORBIDDEN
+    );
+    // Authorized, then rejected by the handler for the body shape.
+    assert_ne!(
+        status_with(&app, Method::POST, "/workflows/wf-1/demand", exec).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status_with(&app, Method::POST, "/workflows/validate", read).await,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn auth_accepts_jwts_from_the_configured_issuer() {
+    let app = authed_app();
+    let token = format!("Bearer {}", jwt("https://issuer.test", "read execute"));
+    let foreign = format!("Bearer {}", jwt("https://other.test", "admin"));
+
+    assert_eq!(
+        status_with(
+            &app,
+            Method::GET,
+            "/workflows",
+            Some(("authorization", &token))
+        )
+        .await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status_with(
+            &app,
+            Method::POST,
+            "/workflows",
+            Some(("authorization", &token))
+        )
+        .await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status_with(
+            &app,
+            Method::GET,
+            "/workflows",
+            Some(("authorization", &foreign))
+        )
+        .await,
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+fn limited_app(limits: rate_limit::RateLimits) -> Router {
+    let executor = SimulatedTaskExecutor::new(SimulationConfig {
+        latency_ms: 200,
+        ..SimulationConfig::default()
+    });
+    let config = rate_limit::RateLimitConfig {
+        default: limits,
+        ..rate_limit::RateLimitConfig::default()
+    };
+    router(
+        ServerState::new(Arc::new(EventRingBuffer::new(64)))
+            .with_task_executor(Arc::new(executor))
+            .with_rate_limits(rate_limit::RateLimiter::in_memory(config)),
+    )
+}
+
+async fn retry_after(app: &Router, uri: &str) -> Option<u64> {
+    let response = app
+        .clone()
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    (response.status() == StatusCode::TOO_MANY_REQUESTS).then(|| {
+        response.headers()[header::RETRY_AFTER]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap()
+    })
+}
+
+#[tokio::test]
+async fn rate_limit_rejects_requests_beyond_the_bucket() {
+    let app = limited_app(rate_limit::RateLimits {
+        requests_per_minute: Some(2),
+        ..rate_limit::RateLimits::default()
+    });
+
+    assert_eq!(retry_after(&app, "/workflows").await, None);
+    assert_eq!(retry_after(&app, "/workflows").await, None);
+    let wait = retry_after(&app, "/workflows").await.expect("limited");
+    assert!((1..=30).contains(&wait), "retry after {wait}");
+}
+
+#[tokio::test]
+async fn daily_quota_retries_after_midnight() {
+    let app = limited_app(rate_limit::RateLimits {
+        daily_quota: Some(1),
+        ..rate_limit::RateLimits::default()
+    });
+
+    assert_eq!(retry_after(&app, "/workflows").await, None);
+    let wait = retry_after(&app, "/workflows")
+        .await
+        .expect("quota exhausted");
+    assert!((1..=86_400).contains(&wait));
+}
+
+#[tokio::test]
+async fn concurrent_executions_are_capped_and_released() {
+    let app = limited_app(rate_limit::RateLimits {
+        max_concurrent_executions: Some(1),
+        ..rate_limit::RateLimits::default()
+    });
+    let graph = serde_json::json!({
+        "id": "wf-1",
+        "name": "Test",
+        "nodes": [{ "id": "n1", "nodeType": "custom", "data": {}, "position": [0.0, 0.0] }],
+        "edges": []
+    });
+    call(&app, Method::POST, "/workflows", Some(graph)).await;
+    let demand = || {
+        call(
+            &app,
+            Method::POST,
+            "/workflows/wf-1/demand",
+            Some(serde_json::json!({ "nodeIds": ["n1"] })),
+        )
+    };
+
+    let ((first, _), (second, _)) = tokio::join!(demand(), demand());
+    assert_eq!(first, StatusCode::OK);
+    assert_eq!(second, StatusCode::TOO_MANY_REQUESTS);
+
+    let (third, _) = demand().await;
+    assert_eq!(third, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn drain_refuses_new_work_and_reports_each_workflow() {
+    let state = ServerState::new(Arc::new(EventRingBuffer::new(64))).with_task_executor(Arc::new(
+        SimulatedTaskExecutor::new(SimulationConfig::default()),
+    ));
+    let app = router(state.clone());
+    let graph = serde_json::json!({
+        "id": "wf-1",
+        "name": "Test",
+        "nodes": [{ "id": "n1", "nodeType": "custom", "data": {}, "position": [0.0, 0.0] }],
+        "edges": []
+    });
+    let (status, _) = call(&app, Method::POST, "/workflows", Some(graph)).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let summary = drain::drain(&state, std::time::Duration::from_secs(1), None).await;
+    assert!(state.is_draining());
+    assert_eq!(summary.cancelled, 0);
+    assert!(summary.workflows.contains_key("wf-1"));
+
+    let (status, _) = call(
+        &app,
+        Method::POST,
+        "/workflows/wf-1/demand",
+        Some(serde_json::json!({ "nodeIds": ["n1"] })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    let graph = serde_json::json!({ "id": "wf-2", "name": "Test", "nodes": [], "edges": [] });
+    let (status, _) = call(&app, Method::POST, "/workflows", Some(graph)).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+}
+
+#[tokio::test]
+async fn drain_waits_for_orchestration_runs_up_to_the_timeout() {
+    let state = ServerState::new(Arc::new(EventRingBuffer::new(16)));
+    let finishing = state.orchestration_runs.start();
+    let _stuck = state.orchestration_runs.start();
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        drop(finishing);
+    });
+
+    let summary = drain::drain(&state, std::time::Duration::from_millis(300), None).await;
+
+    assert_eq!(summary.runs_finished, 1);
+    assert_eq!(summary.runs_unfinished, 1);
+}
+
+#[tokio::test]
+async fn serve_with_shutdown_returns_after_the_signal() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let state = ServerState::new(Arc::new(EventRingBuffer::new(16)));
+    let server = tokio::spawn(serve_with_shutdown(listener, state, async {
+        let _ = stopped.await;
+    }));
+
+    stop.send(()).unwrap();
+    let result = tokio::time::timeout(std::time::Duration::from_secs(5), server)
+        .await
+        .expect("server did not shut down");
+    assert!(result.unwrap().is_ok());
+}
@@ -0,0 +1,218 @@
+//! REST handlers for workflow graphs and demand-driven execution.
+//!
+//! Each workflow is held by a `WorkflowExecutor` whose execution ID is the
+//! workflow ID, so its events stream from
+//! `GET /executions/{workflow_id}/events`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use node_engine::{EventSink, GraphEdge, GraphNode, NodeId, WorkflowExecutor, WorkflowGraph};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ApiError, ApiResult};
+use crate::ServerState;
+
+/// Validation result for a workflow graph.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationResponse {
+    pub valid: bool,
+    pub errors: Vec<String>,
+}
+
+/// Body of `POST /workflows/{id}/demand`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemandRequest {
+    pub node_ids: Vec<NodeId>,
+}
+
+/// Outputs of every demanded node, keyed by node ID and then port.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemandResponse {
+    pub execution_id: String,
+    pub outputs: HashMap<NodeId, HashMap<String, serde_json::Value>>,
+}
+
+async fn executor(state: &ServerState, workflow_id: &str) -> ApiResult<Arc<WorkflowExecutor>> {
+    state
+        .workflows
+        .read()
+        .await
+        .get(workflow_id)
+        .cloned()
+        .ok_or_else(|| ApiError::not_found("Workflow", workflow_id))
+}
+
+fn validate(state: &ServerState, graph: &WorkflowGraph) -> ValidationResponse {
+    let errors: Vec<String> = node_engine::validate_workflow(graph, Some(&state.registry))
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    ValidationResponse {
+        valid: errors.is_empty(),
+        errors,
+    }
+}
+
+/// `GET /workflows`
+pub async fn list(State(state): State<ServerState>) -> Json<Vec<String>> {
+    let mut ids: Vec<String> = state.workflows.read().await.keys().cloned().collect();
+    ids.sort();
+    Json(ids)
+}
+
+/// `POST /workflows`
+pub async fn create(
+    State(state): State<ServerState>,
+    Json(graph): Json<WorkflowGraph>,
+) -> ApiResult<(StatusCode, Json<WorkflowGraph>)> {
+    state.ensure_accepting()?;
+    let mut workflows = state.workflows.write().await;
+    if workflows.contains_key(&graph.id) {
+        return Err(ApiError::conflict(format!(
+            "Workflow '{}' already exists",
+            graph.id
+        )));
+    }
+    let event_sink: Arc<dyn EventSink> = state.events.clone();
+    let mut executor = WorkflowExecutor::new(graph.id.clone(), graph.clone(), event_sink);
+    executor.set_graph_limits(state.graph_limits).await?;
+    workflows.insert(graph.id.clone(), Arc::new(executor));
+    Ok((StatusCode::CREATED, Json(graph)))
+}
+
+/// `GET /workflows/{id}`
+pub async fn get(
+    State(state): State<ServerState>,
+    Path(workflow_id): Path<String>,
+) -> ApiResult<Json<WorkflowGraph>> {
+    let executor = executor(&state, &workflow_id).await?;
+    Ok(Json(executor.get_graph_snapshot().await))
+}
+
+/// `PUT /workflows/{id}`: replace the graph (clears the demand cache).
+pub async fn replace(
+    State(state): State<ServerState>,
+    Path(workflow_id): Path<String>,
+    Json(mut graph): Json<WorkflowGraph>,
+) -> ApiResult<Json<WorkflowGraph>> {
+    let executor = executor(&state, &workflow_id).await?;
+    graph.id = workflow_id;
+    executor.replace_graph(graph.clone()).await?;
+    Ok(Json(graph))
+}
+
+/// `DELETE /workflows/{id}`
+pub async fn delete(
+    State(state): State<ServerState>,
+    Path(workflow_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .workflows
+        .write()
+        .await
+        .remove(&workflow_id)
+        .map(|_| StatusCode::NO_CONTENT)
+        .ok_or_else(|| ApiError::not_found("Workflow", &workflow_id))
+}
+
+/// `POST /workflows/{id}/nodes`
+pub async fn add_node(
+    State(state): State<ServerState>,
+    Path(workflow_id): Path<String>,
+    Json(node): Json<GraphNode>,
+) -> ApiResult<StatusCode> {
+    let executor = executor(&state, &workflow_id).await?;
+    executor.add_node(node).await?;
+    Ok(StatusCode::CREATED)
+}
+
+/// `DELETE /workflows/{id}/nodes/{node_id}`: remove a node and its edges.
+pub async fn remove_node(
+    State(state): State<ServerState>,
+    Path((workflow_id, node_id)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    let executor = executor(&state, &workflow_id).await?;
+    let mut graph = executor.get_graph_snapshot().await;
+    if graph.find_node(&node_id).is_none() {
+        return Err(ApiError::not_found("Node", &node_id));
+    }
+    graph.nodes.retain(|node| node.id != node_id);
+    graph
+        .edges
+        .retain(|edge| edge.source != node_id && edge.target != node_id);
+    executor.restore_graph_snapshot(graph).await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `PUT /workflows/{id}/nodes/{node_id}/data`
+pub async fn update_node_data(
+    State(state): State<ServerState>,
+    Path((workflow_id, node_id)): Path<(String, String)>,
+    Json(data): Json<serde_json::Value>,
+) -> ApiResult<StatusCode> {
+    let executor = executor(&state, &workflow_id).await?;
+    executor.update_node_data(&node_id, data).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `POST /workflows/{id}/edges`
+pub async fn add_edge(
+    State(state): State<ServerState>,
+    Path(workflow_id): Path<String>,
+    Json(edge): Json<GraphEdge>,
+) -> ApiResult<StatusCode> {
+    let executor = executor(&state, &workflow_id).await?;
+    executor.add_edge(edge).await?;
+    Ok(StatusCode::CREATED)
+}
+
+/// `DELETE /workflows/{id}/edges/{edge_id}`
+pub async fn remove_edge(
+    State(state): State<ServerState>,
+    Path((workflow_id, edge_id)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    let executor = executor(&state, &workflow_id).await?;
+    executor.remove_edge(&edge_id).await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `GET /workflows/{id}/validation`
+pub async fn validate_stored(
+    State(state): State<ServerState>,
+    Path(workflow_id): Path<String>,
+) -> ApiResult<Json<ValidationResponse>> {
+    let executor = executor(&state, &workflow_id).await?;
+    let graph = executor.get_graph_snapshot().await;
+    Ok(Json(validate(&state, &graph)))
+}
+
+/// `POST /workflows/validate`: validate a graph without storing it.
+pub async fn validate_graph(
+    State(state): State<ServerState>,
+    Json(graph): Json<WorkflowGraph>,
+) -> Json<ValidationResponse> {
+    Json(validate(&state, &graph))
+}
+
+/// `POST /workflows/{id}/demand`
+pub async fn demand(
+    State(state): State<ServerState>,
+    Path(workflow_id): Path<String>,
+    Json(request): Json<DemandRequest>,
+) -> ApiResult<Json<DemandResponse>> {
+    let executor = executor(&state, &workflow_id).await?;
+    let outputs = executor
+        .demand_multiple(&request.node_ids, state.task_executor.as_ref())
+        .await?;
+    Ok(Json(DemandResponse {
+        execution_id: executor.execution_id().to_string(),
+        outputs,
+    }))
+}
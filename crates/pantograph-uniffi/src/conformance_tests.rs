@@ -0,0 +1,88 @@
+//! Runs the node-engine conformance vectors through `FfiWorkflowEngine`.
+//!
+//! UniFFI exposes no data-graph entry point yet, so the harness runs the
+//! canonical `DataGraphRun` on the engine's executor directly. What it pins
+//! is the UniFFI side of the contract: graphs loaded through `from_json`,
+//! events as buffered for `drain_events`, and errors as mapped onto
+//! `FfiError`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use node_engine::conformance::{check_suite, ConformanceHarness, ConformanceObservation};
+use node_engine::{DataGraphRun, TaskExecutor, WorkflowGraph};
+
+use crate::{FfiError, FfiWorkflowEngine};
+
+/// Vectors the UniFFI engine does not satisfy yet.
+const KNOWN_DIVERGENCES: &[&str] = &[];
+
+/// Error kind a host reads from an `FfiError` variant.
+fn ffi_error_kind(error: &FfiError) -> &'static str {
+    match error {
+        FfiError::GraphFlow { .. } => "graph_flow",
+        FfiError::MissingInput { .. } => "missing_input",
+        FfiError::InvalidInputType { .. } => "invalid_input_type",
+        FfiError::ExecutionFailed { .. } => "execution_failed",
+        FfiError::ContextNotFound { .. } => "context_not_found",
+        FfiError::Serialization { .. } => "serialization",
+        FfiError::Compression { .. } => "compression",
+        FfiError::Cancelled => "cancelled",
+        FfiError::WaitingForInput { .. } => "waiting_for_input",
+        FfiError::Gateway { .. } => "gateway",
+        FfiError::Rag { .. } => "rag",
+        FfiError::Io { .. } => "io",
+        FfiError::NodeFailure { .. } => "node_failure",
+        FfiError::DemandNotFound { .. } => "demand_not_found",
+        FfiError::Other { .. } => "other",
+    }
+}
+
+struct UniffiHarness;
+
+#[async_trait::async_trait]
+impl ConformanceHarness for UniffiHarness {
+    fn host(&self) -> &str {
+        "uniffi"
+    }
+
+    async fn run(
+        &self,
+        graph: &WorkflowGraph,
+        inputs: &HashMap<String, serde_json::Value>,
+        task_executor: Arc<dyn TaskExecutor>,
+    ) -> ConformanceObservation {
+        let run = match DataGraphRun::prepare(graph, inputs) {
+            Ok(run) => run,
+            Err(error) => {
+                return ConformanceObservation {
+                    result: Err(ffi_error_kind(&FfiError::from(error)).to_string()),
+                    events: Vec::new(),
+                }
+            }
+        };
+        let graph_json = serde_json::to_string(run.graph()).expect("serialize vector graph");
+        let engine = FfiWorkflowEngine::from_json(graph_json).expect("load vector graph");
+
+        let result = {
+            let exec = engine.executor.read().await;
+            run.execute(&exec, task_executor.as_ref()).await
+        }
+        .map_err(|error| ffi_error_kind(&FfiError::from(error)).to_string());
+
+        let events = engine
+            .drain_events()
+            .await
+            .into_iter()
+            .map(|event| serde_json::from_str(&event.event_json).expect("event json"))
+            .collect();
+        ConformanceObservation { result, events }
+    }
+}
+
+#[tokio::test]
+async fn uniffi_engine_matches_conformance_vectors() {
+    check_suite(&UniffiHarness, KNOWN_DIVERGENCES)
+        .await
+        .unwrap_or_else(|report| panic!("{}", report));
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use node_engine::{Clock, NodeEngineError, TaskExecutor, WorkflowExecutor};
+use tokio::sync::RwLock;
+
+use crate::{FfiDemandPoll, FfiError};
+
+/// How long a finished demand result is kept for a poll before it is dropped.
+const DEFAULT_DEMAND_RESULT_TTL: Duration = Duration::from_secs(300);
+
+type DemandOutputs = HashMap<String, serde_json::Value>;
+
+/// Runtime that runs token demands, so hosts can start and poll them from
+/// plain blocking calls without an async executor of their own.
+fn demand_runtime() -> &'static tokio::runtime::Runtime {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .thread_name("pantograph-demand")
+            .enable_all()
+            .build()
+            .expect("build demand runtime")
+    })
+}
+
+enum DemandState {
+    Pending,
+    Finished {
+        at: Instant,
+        result: Result<DemandOutputs, NodeEngineError>,
+    },
+}
+
+struct DemandSlot {
+    state: Mutex<DemandState>,
+    ready: Condvar,
+}
+
+impl DemandSlot {
+    fn finish(&self, at: Instant, result: Result<DemandOutputs, NodeEngineError>) {
+        *self.state.lock().unwrap() = DemandState::Finished { at, result };
+        self.ready.notify_all();
+    }
+}
+
+/// Token-addressed demands of one workflow engine.
+///
+/// A demand result is handed out by the first poll that sees it finished and
+/// is then forgotten. Results nobody polls within the TTL are dropped the next
+/// time any demand is started or polled. Result age is measured on `clock`.
+pub(crate) struct DemandTokens {
+    slots: Mutex<HashMap<String, Arc<DemandSlot>>>,
+    next_id: AtomicU64,
+    ttl: Mutex<Duration>,
+    clock: Arc<dyn Clock>,
+}
+
+impl DemandTokens {
+    pub(crate) fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            slots: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+            ttl: Mutex::new(DEFAULT_DEMAND_RESULT_TTL),
+            clock,
+        }
+    }
+
+    pub(crate) fn set_ttl(&self, ttl: Duration) {
+        *self.ttl.lock().unwrap() = ttl;
+    }
+
+    /// Start demanding `node_id` in the background and return its demand ID.
+    pub(crate) fn start(
+        &self,
+        executor: Arc<RwLock<WorkflowExecutor>>,
+        task_executor: Arc<dyn TaskExecutor>,
+        node_id: String,
+    ) -> String {
+        self.expire_abandoned();
+
+        let demand_id = format!("demand-{}", self.next_id.fetch_add(1, Ordering::Relaxed));
+        let slot = Arc::new(DemandSlot {
+            state: Mutex::new(DemandState::Pending),
+            ready: Condvar::new(),
+        });
+        self.slots
+            .lock()
+            .unwrap()
+            .insert(demand_id.clone(), slot.clone());
+
+        let clock = self.clock.clone();
+        demand_runtime().spawn(async move {
+            let exec = executor.read().await;
+            let result = exec.demand(&node_id, task_executor.as_ref()).await;
+            slot.finish(clock.now(), result);
+        });
+        demand_id
+    }
+
+    /// Block for up to `timeout` until the demand finishes.
+    pub(crate) fn poll(
+        &self,
+        demand_id: &str,
+        timeout: Duration,
+    ) -> Result<FfiDemandPoll, FfiError> {
+        self.expire_abandoned();
+
+        let slot = self
+            .slots
+            .lock()
+            .unwrap()
+            .get(demand_id)
+            .cloned()
+            .ok_or_else(|| FfiError::DemandNotFound {
+                demand_id: demand_id.to_string(),
+            })?;
+
+        let state = slot.state.lock().unwrap();
+        let (mut state, _) = slot
+            .ready
+            .wait_timeout_while(state, timeout, |state| {
+                matches!(state, DemandState::Pending)
+            })
+            .unwrap();
+        let result = match std::mem::replace(&mut *state, DemandState::Pending) {
+            DemandState::Pending => return Ok(FfiDemandPoll::Pending),
+            DemandState::Finished { result, .. } => result,
+        };
+        drop(state);
+        self.slots.lock().unwrap().remove(demand_id);
+
+        Ok(match result {
+            Ok(outputs) => FfiDemandPoll::Complete {
+                outputs_json: serde_json::to_string(&outputs).map_err(|e| {
+                    FfiError::Serialization {
+                        message: e.to_string(),
+                    }
+                })?,
+            },
+            Err(error) => FfiDemandPoll::Failed {
+                kind: error.kind().to_string(),
+                message: error.to_string(),
+            },
+        })
+    }
+
+    /// Drop finished results that have waited longer than the TTL.
+    fn expire_abandoned(&self) {
+        let ttl = *self.ttl.lock().unwrap();
+        let now = self.clock.now();
+        self.slots
+            .lock()
+            .unwrap()
+            .retain(|_, slot| match &*slot.state.lock().unwrap() {
+                DemandState::Pending => true,
+                DemandState::Finished { at, .. } => now.duration_since(*at) < ttl,
+            });
+    }
+
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.slots.lock().unwrap().len()
+    }
+}
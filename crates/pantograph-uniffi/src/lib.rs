@@ -20,10 +20,12 @@
 //!     --out-dir ./bindings/python target/release/libpantograph_headless.so
 //! ```
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use node_engine::{
-    EventSink, OrchestrationGraph, OrchestrationStore, WorkflowExecutor, WorkflowGraph,
+    CoreTaskExecutor, EventSink, OrchestrationGraph, OrchestrationStore, TaskExecutor,
+    WorkflowExecutor, WorkflowGraph,
 };
 use pantograph_workflow_service::{
     convert_graph_from_node_engine, validate_workflow_graph_contract, NodeRegistry,
@@ -38,8 +40,14 @@ pub use runtime::{FfiEmbeddedRuntimeConfig, FfiPantographRuntime};
 mod frontend_http;
 #[cfg(feature = "frontend-http")]
 pub use frontend_http::*;
+mod log_bridge;
+pub use log_bridge::{
+    clear_log_listener, install_log_listener, set_log_listener_level, FfiLogListener, FfiLogRecord,
+};
 mod workflow_event_bridge;
 use workflow_event_bridge::BufferedEventSink;
+mod demand_poll;
+use demand_poll::DemandTokens;
 
 // UniFFI scaffolding
 uniffi::setup_scaffolding!();
@@ -90,6 +98,26 @@ pub enum FfiError {
     #[error("IO error: {message}")]
     Io { message: String },
 
+    #[error("Node '{node_id}' failed ({kind}): {message}")]
+    NodeFailure {
+        node_id: String,
+        /// Failure kind label, e.g. `panic`
+        kind: String,
+        message: String,
+    },
+
+    #[error("Graph limit exceeded: {message}")]
+    GraphLimitExceeded {
+        /// Limit label, e.g. `max_nodes`
+        kind: String,
+        /// JSON object with the limit and the count that exceeded it
+        details_json: String,
+        message: String,
+    },
+
+    #[error("Demand '{demand_id}' not found; it was never started, already collected, or expired")]
+    DemandNotFound { demand_id: String },
+
     #[error("{message}")]
     Other { message: String },
 }
@@ -118,6 +146,19 @@ impl From<node_engine::NodeEngineError> for FfiError {
             NodeEngineError::Io(err) => FfiError::Io {
                 message: err.to_string(),
             },
+            NodeEngineError::NodeFailure(failure) => FfiError::NodeFailure {
+                node_id: failure.node_id,
+                kind: failure.kind.as_str().to_string(),
+                message: failure.message,
+            },
+            NodeEngineError::GraphLimitExceeded(error) => {
+                let details = serde_json::to_value(&error).unwrap_or_default();
+                FfiError::GraphLimitExceeded {
+                    kind: details["kind"].as_str().unwrap_or_default().to_string(),
+                    details_json: details.to_string(),
+                    message: error.to_string(),
+                }
+            }
         }
     }
 }
@@ -195,6 +236,56 @@ pub struct FfiCacheStats {
     pub cached_nodes: u64,
     pub total_versions: u64,
     pub global_version: u64,
+    /// Nodes whose last execution panicked
+    pub poisoned_nodes: Vec<String>,
+}
+
+/// FFI-safe executor shutdown report.
+#[derive(uniffi::Record)]
+pub struct FfiExecutorShutdownReport {
+    /// The engine had already been shut down
+    pub already_shut_down: bool,
+    pub cancelled_demands: u64,
+    pub event_flush_error: Option<String>,
+    /// Demand-cache entries written to the checkpoint path
+    pub checkpoint_entries: Option<u64>,
+    pub checkpoint_error: Option<String>,
+    pub checkpointed_session_id: Option<String>,
+}
+
+/// FFI-safe executor drain report.
+#[derive(uniffi::Record)]
+pub struct FfiExecutorDrainReport {
+    /// Demands running when the drain began
+    pub running_at_start: u64,
+    /// Of those, demands that finished before the timeout
+    pub finished: u64,
+    pub timed_out: bool,
+    pub shutdown: FfiExecutorShutdownReport,
+}
+
+impl From<node_engine::ExecutorDrainReport> for FfiExecutorDrainReport {
+    fn from(report: node_engine::ExecutorDrainReport) -> Self {
+        Self {
+            running_at_start: report.running_at_start as u64,
+            finished: report.finished as u64,
+            timed_out: report.timed_out,
+            shutdown: report.shutdown.into(),
+        }
+    }
+}
+
+impl From<node_engine::ExecutorShutdownReport> for FfiExecutorShutdownReport {
+    fn from(report: node_engine::ExecutorShutdownReport) -> Self {
+        Self {
+            already_shut_down: report.already_shut_down,
+            cancelled_demands: report.cancelled_demands as u64,
+            event_flush_error: report.event_flush_error,
+            checkpoint_entries: report.checkpoint_entries.map(|entries| entries as u64),
+            checkpoint_error: report.checkpoint_error,
+            checkpointed_session_id: report.checkpointed_session_id,
+        }
+    }
 }
 
 /// FFI-safe orchestration metadata.
@@ -215,6 +306,18 @@ pub struct FfiWorkflowEvent {
     pub event_json: String,
 }
 
+/// Result of polling a demand started with `FfiWorkflowEngine::start_demand`.
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum FfiDemandPoll {
+    /// The demand is still running; poll again.
+    Pending,
+    /// The demand finished; outputs are a JSON object keyed by port.
+    Complete { outputs_json: String },
+    /// The demand failed. `kind` is the engine error label, e.g.
+    /// `execution_failed` or `waiting_for_input`.
+    Failed { kind: String, message: String },
+}
+
 /// Get the version of the Pantograph headless binding surface.
 #[uniffi::export]
 pub fn version() -> String {
@@ -233,6 +336,30 @@ pub fn validate_workflow_json(graph_json: String) -> Result<Vec<String>, FfiErro
     Ok(validate_workflow_graph_contract(&graph, &registry))
 }
 
+/// Estimate token usage, API calls, and wall time of running a workflow.
+///
+/// `inputs_json` is an object of run inputs keyed `"{node_id}.{port}"`.
+/// Returns JSON `ExecutionEstimate` with per-node and total predictions.
+#[uniffi::export]
+pub fn estimate_workflow_json(graph_json: String, inputs_json: String) -> Result<String, FfiError> {
+    let graph: WorkflowGraph =
+        serde_json::from_str(&graph_json).map_err(|e| FfiError::Serialization {
+            message: e.to_string(),
+        })?;
+    estimate_graph_json(&graph, &inputs_json)
+}
+
+fn estimate_graph_json(graph: &WorkflowGraph, inputs_json: &str) -> Result<String, FfiError> {
+    let inputs: HashMap<String, serde_json::Value> =
+        serde_json::from_str(inputs_json).map_err(|e| FfiError::Serialization {
+            message: e.to_string(),
+        })?;
+    let estimate = node_engine::estimate(graph, &inputs)?;
+    serde_json::to_string(&estimate).map_err(|e| FfiError::Serialization {
+        message: e.to_string(),
+    })
+}
+
 /// Validate an orchestration graph JSON string, returning error messages.
 #[uniffi::export]
 pub fn validate_orchestration_json(graph_json: String) -> Result<Vec<String>, FfiError> {
@@ -265,7 +392,9 @@ pub fn validate_orchestration_json(graph_json: String) -> Result<Vec<String>, Ff
 #[derive(uniffi::Object)]
 pub struct FfiWorkflowEngine {
     executor: Arc<RwLock<WorkflowExecutor>>,
+    task_executor: Arc<dyn TaskExecutor>,
     event_buffer: Arc<RwLock<Vec<FfiWorkflowEvent>>>,
+    demands: DemandTokens,
 }
 
 #[uniffi::export(async_runtime = "tokio")]
@@ -273,15 +402,7 @@ impl FfiWorkflowEngine {
     /// Create a new workflow engine with an empty graph.
     #[uniffi::constructor]
     pub fn new(id: String, name: String) -> Arc<Self> {
-        let graph = WorkflowGraph::new(&id, &name);
-        let event_buffer = Arc::new(RwLock::new(Vec::new()));
-        let event_sink: Arc<dyn EventSink> = Arc::new(BufferedEventSink::new(event_buffer.clone()));
-        let executor = WorkflowExecutor::new("uniffi-execution", graph, event_sink);
-
-        Arc::new(Self {
-            executor: Arc::new(RwLock::new(executor)),
-            event_buffer,
-        })
+        Self::with_graph(WorkflowGraph::new(&id, &name))
     }
 
     /// Create from a JSON-serialized workflow graph.
@@ -291,14 +412,7 @@ impl FfiWorkflowEngine {
             serde_json::from_str(&graph_json).map_err(|e| FfiError::Serialization {
                 message: e.to_string(),
             })?;
-        let event_buffer = Arc::new(RwLock::new(Vec::new()));
-        let event_sink: Arc<dyn EventSink> = Arc::new(BufferedEventSink::new(event_buffer.clone()));
-        let executor = WorkflowExecutor::new("uniffi-execution", graph, event_sink);
-
-        Ok(Arc::new(Self {
-            executor: Arc::new(RwLock::new(executor)),
-            event_buffer,
-        }))
+        Ok(Self::with_graph(graph))
     }
 
     // ============================
@@ -325,7 +439,7 @@ impl FfiWorkflowEngine {
         };
 
         let exec = self.executor.read().await;
-        exec.add_node(node).await;
+        exec.add_node(node).await?;
         Ok(())
     }
 
@@ -350,7 +464,7 @@ impl FfiWorkflowEngine {
         };
 
         let exec = self.executor.read().await;
-        exec.add_edge(edge).await;
+        exec.add_edge(edge).await?;
         Ok(())
     }
 
@@ -396,6 +510,36 @@ impl FfiWorkflowEngine {
         })
     }
 
+    /// Estimate the cost of running the current graph before executing it.
+    ///
+    /// See [`estimate_workflow_json`] for the input and result formats.
+    pub async fn estimate(&self, inputs_json: String) -> Result<String, FfiError> {
+        let exec = self.executor.read().await;
+        let snapshot = exec.get_graph_snapshot().await;
+        estimate_graph_json(&snapshot, &inputs_json)
+    }
+
+    /// Enable deterministic execution with the given seed, or disable it
+    /// with `None`. Changing the mode clears the cache.
+    pub async fn set_deterministic_mode(&self, seed: Option<i64>) {
+        let exec = self.executor.read().await;
+        exec.set_deterministic_mode(seed.map(|seed| node_engine::DeterministicMode { seed }))
+            .await;
+    }
+
+    /// Set hard limits on graph size and complexity from a camelCase JSON
+    /// object such as `{"maxNodes": 200}`. Fails, keeping the old limits, when the
+    /// current graph already exceeds them.
+    pub async fn set_graph_limits(&self, limits_json: String) -> FfiResult<()> {
+        let limits: node_engine::GraphLimits =
+            serde_json::from_str(&limits_json).map_err(|e| FfiError::Serialization {
+                message: e.to_string(),
+            })?;
+        let mut exec = self.executor.write().await;
+        exec.set_graph_limits(limits).await?;
+        Ok(())
+    }
+
     /// Get cache statistics.
     pub async fn cache_stats(&self) -> FfiCacheStats {
         let exec = self.executor.read().await;
@@ -404,6 +548,7 @@ impl FfiWorkflowEngine {
             cached_nodes: stats.cached_nodes as u64,
             total_versions: stats.total_versions as u64,
             global_version: stats.global_version,
+            poisoned_nodes: stats.poisoned_nodes,
         }
     }
 
@@ -417,6 +562,33 @@ impl FfiWorkflowEngine {
         exec.mark_modified(&node_id).await;
     }
 
+    /// Start demanding `node_id` in the background and return a demand ID.
+    ///
+    /// For hosts that cannot await async calls: collect the result with
+    /// [`Self::poll_demand`]. Results not polled within the result TTL are
+    /// dropped.
+    pub fn start_demand(&self, node_id: String) -> String {
+        self.demands
+            .start(self.executor.clone(), self.task_executor.clone(), node_id)
+    }
+
+    /// Block for up to `timeout_ms` waiting for a started demand.
+    ///
+    /// Returns `Pending` if it is still running. A `Complete` or `Failed`
+    /// result is returned once; later polls of the same ID fail with
+    /// `DemandNotFound`, as do polls of expired IDs.
+    pub fn poll_demand(&self, demand_id: String, timeout_ms: u64) -> FfiResult<FfiDemandPoll> {
+        self.demands
+            .poll(&demand_id, std::time::Duration::from_millis(timeout_ms))
+    }
+
+    /// Set how long finished demand results wait for a poll before they are
+    /// dropped. Defaults to five minutes.
+    pub fn set_demand_result_ttl(&self, ttl_ms: u64) {
+        self.demands
+            .set_ttl(std::time::Duration::from_millis(ttl_ms));
+    }
+
     // ============================
     // Events
     // ============================
@@ -426,6 +598,64 @@ impl FfiWorkflowEngine {
         let mut buffer = self.event_buffer.write().await;
         std::mem::take(&mut *buffer)
     }
+
+    // ============================
+    // Lifecycle
+    // ============================
+
+    /// Shut the engine down.
+    ///
+    /// Cancels running demands, writes the demand cache to `checkpoint_path`
+    /// when given, and stops the task executor's backend processes. Later
+    /// demands fail with `Cancelled`.
+    pub async fn shutdown(&self, checkpoint_path: Option<String>) -> FfiExecutorShutdownReport {
+        let checkpoint_path = checkpoint_path.map(std::path::PathBuf::from);
+        let exec = self.executor.read().await;
+        exec.shutdown(
+            Some(self.task_executor.as_ref()),
+            checkpoint_path.as_deref(),
+        )
+        .await
+        .into()
+    }
+
+    /// Drain the engine: reject new demands, give running ones `timeout_ms`
+    /// to finish, then shut down as by [`Self::shutdown`].
+    pub async fn drain(
+        &self,
+        timeout_ms: u64,
+        checkpoint_path: Option<String>,
+    ) -> FfiExecutorDrainReport {
+        let checkpoint_path = checkpoint_path.map(std::path::PathBuf::from);
+        let exec = self.executor.read().await;
+        exec.drain(
+            std::time::Duration::from_millis(timeout_ms),
+            Some(self.task_executor.as_ref()),
+            checkpoint_path.as_deref(),
+        )
+        .await
+        .into()
+    }
+}
+
+impl FfiWorkflowEngine {
+    fn with_graph(graph: WorkflowGraph) -> Arc<Self> {
+        let event_buffer = Arc::new(RwLock::new(Vec::new()));
+        let event_sink: Arc<dyn EventSink> = Arc::new(BufferedEventSink::new(event_buffer.clone()));
+        let task_executor = Arc::new(
+            CoreTaskExecutor::new()
+                .with_event_sink(event_sink.clone())
+                .with_execution_id("uniffi-execution".to_string()),
+        );
+        let executor = WorkflowExecutor::new("uniffi-execution", graph, event_sink);
+
+        Arc::new(Self {
+            executor: Arc::new(RwLock::new(executor)),
+            task_executor,
+            event_buffer,
+            demands: DemandTokens::new(Arc::new(node_engine::SystemClock)),
+        })
+    }
 }
 
 // ============================================================================
@@ -503,11 +733,29 @@ impl FfiOrchestrationStore {
 // FfiPumasApi - Model Library API
 // ============================================================================
 
+/// One root of a merged model library.
+#[derive(uniffi::Record)]
+pub struct FfiLibraryRoot {
+    pub path: String,
+    /// Never receives downloads, imports, or index rebuilds.
+    pub read_only: bool,
+    /// Higher priorities win when roots hold the same model ID.
+    pub priority: i32,
+}
+
 /// Pumas model library API for model management, HuggingFace search,
 /// downloads, and imports.
 #[derive(uniffi::Object)]
 pub struct FfiPumasApi {
     api: Arc<pumas_library::PumasApi>,
+    /// Merged view when created with [`FfiPumasApi::with_roots`]; `api` is
+    /// then the primary root.
+    roots: Option<Arc<workflow_nodes::ModelLibraryRoots>>,
+    /// Model usage and pins, persisted next to the (primary) library.
+    usage: Arc<node_engine::ModelUsageLog>,
+    /// Checksum-verified, resumable downloads staged next to the (primary)
+    /// library and imported into `api`.
+    downloads: Arc<pantograph_model_downloads::ModelDownloader>,
 }
 
 #[uniffi::export(async_runtime = "tokio")]
@@ -527,13 +775,62 @@ impl FfiPumasApi {
                 message: format!("PumasApi init error: {}", e),
             })?;
 
-        Ok(Arc::new(Self { api: Arc::new(api) }))
+        let usage = load_usage_log(std::path::Path::new(&launcher_root))?;
+        let api = Arc::new(api);
+
+        Ok(Arc::new(Self {
+            downloads: model_downloader(std::path::Path::new(&launcher_root), &api),
+            api,
+            roots: None,
+            usage,
+        }))
+    }
+
+    /// Create a view merging several library roots.
+    ///
+    /// Listing, search, and lookup span every root, preferring higher
+    /// priority on duplicate model IDs; other calls use the highest-priority
+    /// writable root.
+    #[uniffi::constructor]
+    pub async fn with_roots(roots: Vec<FfiLibraryRoot>) -> Result<Arc<Self>, FfiError> {
+        let configs: Vec<node_engine::config::LibraryRootConfig> = roots
+            .into_iter()
+            .map(|root| node_engine::config::LibraryRootConfig {
+                path: root.path.into(),
+                read_only: root.read_only,
+                priority: root.priority,
+            })
+            .collect();
+        let roots = workflow_nodes::ModelLibraryRoots::open(&configs, true)
+            .await
+            .map_err(|e| FfiError::Other {
+                message: format!("PumasApi init error: {}", e),
+            })?;
+
+        let usage = load_usage_log(&roots.primary().path)?;
+        let downloads = model_downloader(&roots.primary().path, &roots.primary().api);
+
+        Ok(Arc::new(Self {
+            api: roots.primary().api.clone(),
+            roots: Some(Arc::new(roots)),
+            usage,
+            downloads,
+        }))
     }
 
     // --- Local library ---
 
     /// List all models in the local library. Returns JSON array of ModelRecord.
     pub async fn list_models(&self) -> Result<String, FfiError> {
+        if let Some(roots) = &self.roots {
+            let models = roots
+                .list_models()
+                .await
+                .map_err(|message| FfiError::Other { message })?;
+            return serde_json::to_string(&models).map_err(|e| FfiError::Serialization {
+                message: e.to_string(),
+            });
+        }
         let models = self.api.list_models().await.map_err(|e| FfiError::Other {
             message: e.to_string(),
         })?;
@@ -549,6 +846,15 @@ impl FfiPumasApi {
         limit: u32,
         offset: u32,
     ) -> Result<String, FfiError> {
+        if let Some(roots) = &self.roots {
+            let result = roots
+                .search_models(&query, limit as usize, offset as usize)
+                .await
+                .map_err(|message| FfiError::Other { message })?;
+            return serde_json::to_string(&result).map_err(|e| FfiError::Serialization {
+                message: e.to_string(),
+            });
+        }
         let result = self
             .api
             .search_models(&query, limit as usize, offset as usize)
@@ -563,6 +869,19 @@ impl FfiPumasApi {
 
     /// Get a single model by ID. Returns JSON ModelRecord or None.
     pub async fn get_model(&self, model_id: String) -> Result<Option<String>, FfiError> {
+        if let Some(roots) = &self.roots {
+            let model = roots
+                .get_model(&model_id)
+                .await
+                .map_err(|message| FfiError::Other { message })?;
+            return model
+                .map(|m| {
+                    serde_json::to_string(&m).map_err(|e| FfiError::Serialization {
+                        message: e.to_string(),
+                    })
+                })
+                .transpose();
+        }
         let model = self
             .api
             .get_model(&model_id)
@@ -581,6 +900,67 @@ impl FfiPumasApi {
         }
     }
 
+    // --- Garbage collection ---
+
+    /// List models unused for `unused_for_days`. Returns JSON array of
+    /// UnusedModel.
+    ///
+    /// Pinned models and models referenced by the data graphs in `store` are
+    /// never listed. Uses are recorded by engines given this API through
+    /// `set_pumas_api`.
+    pub async fn unused_models(
+        &self,
+        store: Option<Arc<FfiOrchestrationStore>>,
+        unused_for_days: u32,
+    ) -> Result<String, FfiError> {
+        let referenced = referenced_models(store).await;
+        let models = workflow_nodes::unused_models(
+            self.library_view(),
+            &self.usage,
+            &referenced,
+            unused_for_days,
+        )
+        .await
+        .map_err(|message| FfiError::Other { message })?;
+        serde_json::to_string(&models).map_err(|e| FfiError::Serialization {
+            message: e.to_string(),
+        })
+    }
+
+    /// Delete models unused for `unused_for_days`, or only report them when
+    /// `dry_run` is true. Returns JSON ModelGcReport.
+    pub async fn gc(
+        &self,
+        store: Option<Arc<FfiOrchestrationStore>>,
+        unused_for_days: u32,
+        dry_run: bool,
+    ) -> Result<String, FfiError> {
+        let referenced = referenced_models(store).await;
+        let report = workflow_nodes::collect_unused_models(
+            self.library_view(),
+            &self.usage,
+            &referenced,
+            unused_for_days,
+            dry_run,
+        )
+        .await
+        .map_err(|message| FfiError::Other { message })?;
+        serde_json::to_string(&report).map_err(|e| FfiError::Serialization {
+            message: e.to_string(),
+        })
+    }
+
+    /// Pin a model so garbage collection never deletes it. Returns false if
+    /// it was already pinned.
+    pub fn pin_model(&self, model_id: String) -> Result<bool, FfiError> {
+        self.usage.pin(&model_id).map_err(FfiError::from)
+    }
+
+    /// Remove a model pin. Returns false if the model was not pinned.
+    pub fn unpin_model(&self, model_id: String) -> Result<bool, FfiError> {
+        self.usage.unpin(&model_id).map_err(FfiError::from)
+    }
+
     // --- HuggingFace ---
 
     /// Search HuggingFace for models. Returns JSON array of HuggingFaceModel.
@@ -660,6 +1040,73 @@ impl FfiPumasApi {
             })
     }
 
+    /// Start a checksum-verified, resumable download. Returns the download ID.
+    ///
+    /// Takes the same JSON as [`FfiPumasApi::start_download`], plus optional
+    /// `revision` and `max_concurrent_files`. Set `download_url` (with
+    /// `family`, `official_name`, and optionally `sha256`) to import a plain
+    /// URL, or `civitai_model_version_id` to download a Civitai model version.
+    /// Files are staged next to the library, checked against their sha256,
+    /// then imported. Starting the same request again after a cancel or
+    /// failure resumes its partial files.
+    pub async fn start_verified_download(&self, request_json: String) -> Result<String, FfiError> {
+        let request: pantograph_model_downloads::ModelDownloadRequest =
+            serde_json::from_str(&request_json).map_err(|e| FfiError::Serialization {
+                message: e.to_string(),
+            })?;
+        self.downloads
+            .start(request)
+            .await
+            .map_err(|e| FfiError::Other {
+                message: e.to_string(),
+            })
+    }
+
+    /// Get verified download progress. Returns JSON DownloadProgress (with
+    /// per-file `state` and `error_kind`) or None.
+    pub fn get_verified_download_progress(
+        &self,
+        download_id: String,
+    ) -> Result<Option<String>, FfiError> {
+        match self.downloads.progress(&download_id) {
+            Some(p) => {
+                let json = serde_json::to_string(&p).map_err(|e| FfiError::Serialization {
+                    message: e.to_string(),
+                })?;
+                Ok(Some(json))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Cancel a verified download, keeping its partial files. Returns true if
+    /// it was running.
+    pub fn cancel_verified_download(&self, download_id: String) -> bool {
+        self.downloads.cancel(&download_id)
+    }
+
+    /// Describe what a URL serves before downloading it. Returns JSON
+    /// UrlInspection with the file name, size, and the request fields still
+    /// to prompt for.
+    pub async fn inspect_download_url(&self, url: String) -> Result<String, FfiError> {
+        let inspection = self
+            .downloads
+            .inspect_url(&url)
+            .await
+            .map_err(|e| FfiError::Other {
+                message: e.to_string(),
+            })?;
+        serde_json::to_string(&inspection).map_err(|e| FfiError::Serialization {
+            message: e.to_string(),
+        })
+    }
+
+    /// Cap the combined rate of all verified downloads in bytes per second,
+    /// or lift the cap with None.
+    pub fn set_download_bandwidth_limit(&self, bytes_per_sec: Option<u64>) {
+        self.downloads.set_bandwidth_limit(bytes_per_sec);
+    }
+
     // --- Import ---
 
     /// Import a model. `spec_json` is a JSON ModelImportSpec.
@@ -707,6 +1154,57 @@ impl FfiPumasApi {
     fn api_arc(&self) -> Arc<pumas_library::PumasApi> {
         self.api.clone()
     }
+
+    fn library_view(&self) -> workflow_nodes::ModelLibraryView<'_> {
+        match &self.roots {
+            Some(roots) => workflow_nodes::ModelLibraryView::Merged(roots),
+            None => workflow_nodes::ModelLibraryView::Single(&self.api),
+        }
+    }
+
+    /// Install this API, its merged roots when present, and its usage log
+    /// into `extensions`.
+    fn install_into(&self, extensions: &mut node_engine::ExecutorExtensions) {
+        match &self.roots {
+            Some(roots) => workflow_nodes::install_library_roots(extensions, roots.clone()),
+            None => extensions.set(node_engine::extension_keys::PUMAS_API, self.api_arc()),
+        }
+        extensions.set(node_engine::extension_keys::MODEL_USAGE, self.usage.clone());
+    }
+}
+
+/// Verified downloader staging under `root` and importing into `api`.
+fn model_downloader(
+    root: &std::path::Path,
+    api: &Arc<pumas_library::PumasApi>,
+) -> Arc<pantograph_model_downloads::ModelDownloader> {
+    Arc::new(
+        pantograph_model_downloads::ModelDownloader::new(
+            root.join(pantograph_model_downloads::STAGING_DIR_NAME),
+        )
+        .with_importer(Arc::new(pantograph_model_downloads::PumasImporter::new(
+            api.clone(),
+        ))),
+    )
+}
+
+/// Usage log kept next to the library at `root`.
+fn load_usage_log(root: &std::path::Path) -> Result<Arc<node_engine::ModelUsageLog>, FfiError> {
+    let usage = node_engine::ModelUsageLog::with_persistence(
+        root.join(node_engine::ModelUsageLog::FILE_NAME),
+    );
+    usage.load_from_disk()?;
+    Ok(Arc::new(usage))
+}
+
+/// Model IDs referenced by the data graphs in `store`.
+async fn referenced_models(
+    store: Option<Arc<FfiOrchestrationStore>>,
+) -> std::collections::BTreeSet<String> {
+    match store {
+        Some(store) => node_engine::referenced_model_ids(store.store.read().await.data_graphs()),
+        None => std::collections::BTreeSet::new(),
+    }
 }
 
 /// Inject PumasApi into a workflow engine's extensions.
@@ -715,10 +1213,22 @@ impl FfiWorkflowEngine {
     /// Set a PumasApi on this engine for model resolution in workflow nodes.
     pub async fn set_pumas_api(&self, api: Arc<FfiPumasApi>) {
         let mut exec = self.executor.write().await;
-        exec.extensions_mut()
-            .set(node_engine::extension_keys::PUMAS_API, api.api_arc());
+        api.install_into(exec.extensions_mut());
+    }
+
+    /// Apply a TOML or JSON extensions config file to this engine: secrets,
+    /// filesystem policy, backend endpoints, KV cache, and PumasApi.
+    pub async fn load_extensions_config(&self, config_path: String) -> FfiResult<()> {
+        let mut exec = self.executor.write().await;
+        workflow_nodes::setup_extensions_from_file(exec.extensions_mut(), &config_path)
+            .await
+            .map_err(|e| FfiError::Other {
+                message: e.to_string(),
+            })
     }
 }
 
+#[cfg(test)]
+mod conformance_tests;
 #[cfg(test)]
 mod lib_tests;
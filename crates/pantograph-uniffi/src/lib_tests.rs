@@ -4,7 +4,8 @@ use node_engine::{EventSink, WorkflowEvent, WorkflowGraph};
 use tokio::sync::RwLock;
 
 use crate::{
-    validate_workflow_json, version, workflow_event_bridge::BufferedEventSink, FfiError,
+    estimate_workflow_json, set_log_listener_level, validate_workflow_json, version,
+    workflow_event_bridge::BufferedEventSink, FfiDemandPoll, FfiError, FfiLogRecord,
     FfiOrchestrationStore, FfiWorkflowEngine, FfiWorkflowGraph,
 };
 
@@ -54,6 +55,58 @@ fn test_ffi_error_waiting_for_input() {
     ));
 }
 
+#[test]
+fn test_ffi_error_node_failure() {
+    let err = node_engine::NodeEngineError::panicked("llm-1", "index out of bounds");
+    let ffi_err: FfiError = err.into();
+    assert!(matches!(
+        ffi_err,
+        FfiError::NodeFailure { node_id, kind, message }
+            if node_id == "llm-1" && kind == "panic" && message == "index out of bounds"
+    ));
+}
+
+#[test]
+fn test_ffi_error_graph_limit_exceeded() {
+    let err: node_engine::NodeEngineError =
+        node_engine::GraphLimitError::MaxNodes { count: 3, limit: 2 }.into();
+    let ffi_err: FfiError = err.into();
+    let FfiError::GraphLimitExceeded {
+        kind, details_json, ..
+    } = ffi_err
+    else {
+        panic!("expected GraphLimitExceeded");
+    };
+    assert_eq!(kind, "max_nodes");
+    let details: serde_json::Value = serde_json::from_str(&details_json).unwrap();
+    assert_eq!(details["limit"], 2);
+}
+
+#[test]
+fn test_ffi_log_record_conversion() {
+    let record: FfiLogRecord = node_engine::LogRecord {
+        level: node_engine::LogLevel::Warn,
+        target: "node_engine::engine".to_string(),
+        message: "cache miss".to_string(),
+        module_path: None,
+        file: Some("engine.rs".to_string()),
+        line: Some(42),
+        occurred_at_ms: 7,
+    }
+    .into();
+    assert_eq!(record.level, "warn");
+    assert_eq!(record.target, "node_engine::engine");
+    assert_eq!(record.line, Some(42));
+}
+
+#[test]
+fn test_log_listener_level_rejects_unknown_label() {
+    assert!(matches!(
+        set_log_listener_level("verbose".to_string()),
+        Err(FfiError::Other { .. })
+    ));
+}
+
 #[test]
 fn test_ffi_graph_conversion() {
     let graph = WorkflowGraph::new("test", "Test Graph");
@@ -72,6 +125,25 @@ fn test_validate_empty_workflow() {
     assert!(errors.is_empty());
 }
 
+#[test]
+fn test_estimate_workflow_json() {
+    let mut graph = WorkflowGraph::new("test", "Test");
+    graph.nodes.push(node_engine::GraphNode {
+        id: "prompt".to_string(),
+        node_type: "text-input".to_string(),
+        data: serde_json::json!({ "text": "abcdefgh" }),
+        position: (0.0, 0.0),
+    });
+    let json = serde_json::to_string(&graph).unwrap();
+
+    let estimate: node_engine::ExecutionEstimate =
+        serde_json::from_str(&estimate_workflow_json(json, "{}".to_string()).unwrap()).unwrap();
+    assert_eq!(estimate.nodes.len(), 1);
+    assert_eq!(estimate.nodes[0].input_tokens, 2);
+    assert_eq!(estimate.total_input_tokens, 0);
+    assert_eq!(estimate.total_api_calls, 0);
+}
+
 #[tokio::test]
 async fn test_workflow_engine_new() {
     let engine = FfiWorkflowEngine::new("wf-1".to_string(), "Test".to_string());
@@ -99,6 +171,33 @@ async fn test_workflow_engine_add_node() {
     assert_eq!(graph.nodes[0].id, "n1");
 }
 
+#[tokio::test]
+async fn test_workflow_engine_graph_limits_reject_extra_node() {
+    let engine = FfiWorkflowEngine::new("wf-1".to_string(), "Test".to_string());
+    engine
+        .set_graph_limits(r#"{"maxNodes": 1}"#.to_string())
+        .await
+        .unwrap();
+    let add = |id: &str| {
+        engine.add_node(
+            id.to_string(),
+            "text-input".to_string(),
+            0.0,
+            0.0,
+            "{}".to_string(),
+        )
+    };
+    add("n1").await.unwrap();
+
+    let err = add("n2").await.unwrap_err();
+    assert!(matches!(err, FfiError::GraphLimitExceeded { kind, .. } if kind == "max_nodes"));
+    assert_eq!(engine.get_graph().await.nodes.len(), 1);
+    assert!(engine
+        .set_graph_limits(r#"{"maxNodes": 0}"#.to_string())
+        .await
+        .is_err());
+}
+
 #[tokio::test]
 async fn test_workflow_engine_export_json() {
     let engine = FfiWorkflowEngine::new("wf-1".to_string(), "Test".to_string());
@@ -106,6 +205,188 @@ async fn test_workflow_engine_export_json() {
     assert!(json.contains("wf-1"));
 }
 
+#[tokio::test]
+async fn test_workflow_engine_deterministic_mode_round_trip() {
+    let engine = FfiWorkflowEngine::new("wf-1".to_string(), "Test".to_string());
+    engine.set_deterministic_mode(Some(42)).await;
+    assert_eq!(
+        engine.executor.read().await.deterministic_mode().await,
+        Some(node_engine::DeterministicMode { seed: 42 })
+    );
+
+    engine.set_deterministic_mode(None).await;
+    assert_eq!(
+        engine.executor.read().await.deterministic_mode().await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_workflow_engine_shutdown_is_idempotent() {
+    let engine = FfiWorkflowEngine::new("wf-1".to_string(), "Test".to_string());
+    let report = engine.shutdown(None).await;
+    assert!(!report.already_shut_down);
+    assert_eq!(report.cancelled_demands, 0);
+    assert!(engine.executor.read().await.is_shut_down());
+
+    assert!(engine.shutdown(None).await.already_shut_down);
+}
+
+#[tokio::test]
+async fn test_workflow_engine_drain_without_running_demands() {
+    let engine = FfiWorkflowEngine::new("wf-1".to_string(), "Test".to_string());
+    let report = engine.drain(1_000, None).await;
+    assert_eq!(report.running_at_start, 0);
+    assert!(!report.timed_out);
+    assert!(!report.shutdown.already_shut_down);
+    assert!(engine.executor.read().await.is_draining());
+}
+
+struct ShutdownRecordingExecutor {
+    shutdowns: std::sync::atomic::AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl node_engine::TaskExecutor for ShutdownRecordingExecutor {
+    async fn execute_task(
+        &self,
+        task_id: &str,
+        _inputs: std::collections::HashMap<String, serde_json::Value>,
+        _context: &graph_flow::Context,
+        _extensions: &node_engine::ExecutorExtensions,
+    ) -> node_engine::Result<std::collections::HashMap<String, serde_json::Value>> {
+        Err(node_engine::NodeEngineError::failed(format!(
+            "unexpected task '{}'",
+            task_id
+        )))
+    }
+
+    async fn shutdown(&self) {
+        self.shutdowns
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn test_workflow_engine_shutdown_and_drain_stop_the_task_executor() {
+    for drain in [false, true] {
+        let engine = FfiWorkflowEngine::new("wf-1".to_string(), "Test".to_string());
+        let recorder = Arc::new(ShutdownRecordingExecutor {
+            shutdowns: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let engine = Arc::new(FfiWorkflowEngine {
+            executor: engine.executor.clone(),
+            task_executor: recorder.clone(),
+            event_buffer: engine.event_buffer.clone(),
+            demands: crate::DemandTokens::new(Arc::new(node_engine::SystemClock)),
+        });
+
+        if drain {
+            engine.drain(1_000, None).await;
+        } else {
+            engine.shutdown(None).await;
+        }
+
+        assert_eq!(
+            recorder.shutdowns.load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+}
+
+fn text_input_engine() -> Arc<FfiWorkflowEngine> {
+    let mut graph = WorkflowGraph::new("wf-1", "Test");
+    graph.nodes.push(node_engine::GraphNode {
+        id: "prompt".to_string(),
+        node_type: "text-input".to_string(),
+        data: serde_json::json!({ "text": "hello" }),
+        position: (0.0, 0.0),
+    });
+    FfiWorkflowEngine::from_json(serde_json::to_string(&graph).unwrap()).unwrap()
+}
+
+#[test]
+fn test_workflow_engine_demand_token_completes_once() {
+    let engine = text_input_engine();
+    let demand_id = engine.start_demand("prompt".to_string());
+
+    let FfiDemandPoll::Complete { outputs_json } =
+        engine.poll_demand(demand_id.clone(), 5_000).unwrap()
+    else {
+        panic!("demand should complete");
+    };
+    let outputs: serde_json::Value = serde_json::from_str(&outputs_json).unwrap();
+    assert_eq!(outputs["text"], "hello");
+
+    assert!(matches!(
+        engine.poll_demand(demand_id, 0),
+        Err(FfiError::DemandNotFound { .. })
+    ));
+    assert_eq!(engine.demands.len(), 0);
+}
+
+#[test]
+fn test_workflow_engine_demand_token_reports_failure() {
+    let engine = text_input_engine();
+    let demand_id = engine.start_demand("missing".to_string());
+
+    assert!(matches!(
+        engine.poll_demand(demand_id, 5_000).unwrap(),
+        FfiDemandPoll::Failed { kind, .. } if kind == "execution_failed"
+    ));
+}
+
+#[test]
+fn test_workflow_engine_unknown_demand_token_is_not_found() {
+    let engine = text_input_engine();
+    assert!(matches!(
+        engine.poll_demand("demand-404".to_string(), 0),
+        Err(FfiError::DemandNotFound { demand_id }) if demand_id == "demand-404"
+    ));
+}
+
+#[test]
+fn test_workflow_engine_abandoned_demand_result_expires() {
+    let engine = text_input_engine();
+    engine.set_demand_result_ttl(0);
+    let demand_id = engine.start_demand("prompt".to_string());
+    std::thread::sleep(std::time::Duration::from_millis(200));
+
+    assert!(matches!(
+        engine.poll_demand(demand_id, 0),
+        Err(FfiError::DemandNotFound { .. })
+    ));
+}
+
+#[test]
+fn test_demand_result_expires_on_simulated_clock() {
+    let engine = text_input_engine();
+    let clock = Arc::new(node_engine::SimulatedClock::new());
+    let demands = crate::demand_poll::DemandTokens::new(clock.clone());
+    let stale = demands.start(
+        engine.executor.clone(),
+        engine.task_executor.clone(),
+        "prompt".to_string(),
+    );
+    let fresh = demands.start(
+        engine.executor.clone(),
+        engine.task_executor.clone(),
+        "prompt".to_string(),
+    );
+    std::thread::sleep(std::time::Duration::from_millis(200));
+
+    clock.advance(std::time::Duration::from_secs(299));
+    assert!(matches!(
+        demands.poll(&fresh, 0),
+        Ok(FfiDemandPoll::Complete { .. })
+    ));
+    clock.advance(std::time::Duration::from_secs(1));
+    assert!(matches!(
+        demands.poll(&stale, 0),
+        Err(FfiError::DemandNotFound { .. })
+    ));
+}
+
 #[tokio::test]
 async fn test_orchestration_store() {
     let store = FfiOrchestrationStore::new();
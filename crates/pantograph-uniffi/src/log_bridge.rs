@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use node_engine::{LogLevel, LogRecord, LogSink};
+
+use crate::FfiError;
+
+/// FFI-safe forwarded log record.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct FfiLogRecord {
+    /// One of `error`, `warn`, `info`, `debug`, `trace`
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    pub module_path: Option<String>,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub occurred_at_ms: u64,
+}
+
+impl From<LogRecord> for FfiLogRecord {
+    fn from(record: LogRecord) -> Self {
+        Self {
+            level: record.level.as_str().to_string(),
+            target: record.target,
+            message: record.message,
+            module_path: record.module_path,
+            file: record.file,
+            line: record.line,
+            occurred_at_ms: record.occurred_at_ms,
+        }
+    }
+}
+
+/// Host-implemented log receiver, e.g. a Python adapter that calls
+/// `logging.getLogger(record.target).log(...)`.
+///
+/// Called synchronously on the thread that logged; implementations should
+/// not block.
+#[uniffi::export(with_foreign)]
+pub trait FfiLogListener: Send + Sync {
+    fn on_log(&self, record: FfiLogRecord);
+}
+
+struct ForeignLogSink {
+    listener: Arc<dyn FfiLogListener>,
+}
+
+impl LogSink for ForeignLogSink {
+    fn log(&self, record: LogRecord) {
+        self.listener.on_log(record.into());
+    }
+}
+
+fn parse_level(level: &str) -> Result<LogLevel, FfiError> {
+    LogLevel::parse(level).ok_or_else(|| FfiError::Other {
+        message: format!(
+            "Invalid log level '{}': expected error, warn, info, debug, or trace",
+            level
+        ),
+    })
+}
+
+/// Forward engine log records at or above `level` to `listener`.
+///
+/// Replaces any previously installed listener.
+#[uniffi::export]
+pub fn install_log_listener(
+    listener: Arc<dyn FfiLogListener>,
+    level: String,
+) -> Result<(), FfiError> {
+    node_engine::install_log_sink(Arc::new(ForeignLogSink { listener }), parse_level(&level)?)
+        .map_err(|e| FfiError::Other {
+            message: e.to_string(),
+        })
+}
+
+/// Change the forwarding level of the installed log listener.
+#[uniffi::export]
+pub fn set_log_listener_level(level: String) -> Result<(), FfiError> {
+    node_engine::set_log_sink_level(parse_level(&level)?);
+    Ok(())
+}
+
+/// Stop forwarding engine log records.
+#[uniffi::export]
+pub fn clear_log_listener() {
+    node_engine::clear_log_sink();
+}
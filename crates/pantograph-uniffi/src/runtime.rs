@@ -83,10 +83,7 @@ impl FfiPantographRuntime {
 
         let extensions = Arc::new(RwLock::new(ExecutorExtensions::new()));
         if let Some(api) = pumas_api {
-            extensions
-                .write()
-                .await
-                .set(node_engine::extension_keys::PUMAS_API, api.api_arc());
+            api.install_into(&mut *extensions.write().await);
         }
 
         let workflow_service = Arc::new(
@@ -117,6 +114,16 @@ impl FfiPantographRuntime {
         self.runtime.shutdown().await;
     }
 
+    /// Apply a TOML or JSON extensions config file to this runtime's
+    /// extensions: secrets, filesystem policy, backend endpoints, KV cache,
+    /// and PumasApi from `pumas_library_path`.
+    pub async fn load_extensions_config(&self, config_path: String) -> Result<(), FfiError> {
+        let mut extensions = self.extensions.write().await;
+        workflow_nodes::setup_extensions_from_file(&mut extensions, &config_path)
+            .await
+            .map_err(|e| workflow_adapter_error(WorkflowErrorCode::InvalidRequest, e.to_string()))
+    }
+
     /// Register an attribution client and return ClientRegistrationResponse JSON.
     pub fn workflow_register_attribution_client(
         &self,
@@ -613,6 +613,20 @@ async fn direct_runtime_exposes_backend_owned_graph_authoring_discovery() {
         .expect("outputs")
         .iter()
         .any(|port| port["id"] == "text"));
+    assert!(text_input.get("documentation").is_none());
+
+    let sort_json = runtime
+        .workflow_graph_get_node_definition("sort".to_string())
+        .expect("get sort definition");
+    let sort: serde_json::Value = serde_json::from_str(&sort_json).expect("parse sort definition");
+    assert_eq!(
+        sort["documentation"]["examples"][0]["data"]["order"],
+        "desc"
+    );
+    assert_eq!(sort["ui"]["icon"], "arrow-down-up");
+    assert_eq!(sort["ui"]["color_token"], "category-processing");
+    assert_eq!(sort["inputs"][0]["ui"]["color_token"], "data-json");
+    assert_eq!(sort["inputs"][0]["ui"]["shape"], "square");
 
     let grouped_json = runtime
         .workflow_graph_get_node_definitions_by_category()
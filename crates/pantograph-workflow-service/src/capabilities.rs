@@ -61,6 +61,7 @@ impl StoredWorkflowFile {
                 })
                 .collect(),
             groups: Vec::new(),
+            ports: node_engine::GraphPorts::default(),
         }
     }
 
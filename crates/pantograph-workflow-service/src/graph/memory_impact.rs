@@ -541,6 +541,7 @@ mod tests {
                 target_handle: "text".to_string(),
             }],
             groups: Vec::new(),
+            ports: node_engine::GraphPorts::default(),
         };
         let after = node_engine::WorkflowGraph {
             id: "wf".to_string(),
@@ -572,6 +573,7 @@ mod tests {
                 target_handle: "text".to_string(),
             }],
             groups: Vec::new(),
+            ports: node_engine::GraphPorts::default(),
         };
 
         let impact = graph_memory_impact_from_node_engine_graph_change(&before, &after)
@@ -74,6 +74,6 @@ pub use types::{
     EdgeInsertionBridge, EdgeInsertionPreviewResponse, ExecutionMode, GraphEdge, GraphNode,
     InsertNodeConnectionResponse, InsertNodeOnEdgeResponse, InsertNodePositionHint,
     InsertableNodeTypeCandidate, IoBindingOrigin, NodeCategory, NodeDefinition, NodeGroup,
-    PortDataType, PortDefinition, PortMapping, Position, Viewport, WorkflowDerivedGraph,
-    WorkflowFile, WorkflowGraph, WorkflowGraphMetadata,
+    NodeUiHints, PortDataType, PortDefinition, PortMapping, Position, Viewport,
+    WorkflowDerivedGraph, WorkflowFile, WorkflowGraph, WorkflowGraphMetadata,
 };
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
 use super::types::{
-    ExecutionMode, IoBindingOrigin, NodeCategory, NodeDefinition, PortDataType, PortDefinition,
+    ExecutionMode, IoBindingOrigin, NodeCategory, NodeDefinition, NodeUiHints, PortDataType,
+    PortDefinition,
 };
 
 fn convert_contract(contract: &pantograph_node_contracts::NodeTypeContract) -> NodeDefinition {
@@ -15,9 +16,21 @@ fn convert_contract(contract: &pantograph_node_contracts::NodeTypeContract) -> N
         inputs: contract.inputs.iter().map(convert_port).collect(),
         outputs: contract.outputs.iter().map(convert_port).collect(),
         execution_mode: convert_execution_mode(contract.execution_semantics),
+        documentation: contract.authoring.documentation.clone(),
+        ui: node_ui_hints(&contract.authoring),
     }
 }
 
+fn node_ui_hints(
+    authoring: &pantograph_node_contracts::NodeAuthoringMetadata,
+) -> Option<NodeUiHints> {
+    Some(NodeUiHints {
+        icon: authoring.icon.clone()?,
+        color_token: authoring.color_token.clone()?,
+        color: authoring.color.clone()?,
+    })
+}
+
 fn determine_io_binding_origin(node_type: &str, category: &NodeCategory) -> IoBindingOrigin {
     if !matches!(category, NodeCategory::Input | NodeCategory::Output) {
         return IoBindingOrigin::Integrated;
@@ -70,6 +83,7 @@ pub(super) fn convert_port(port: &pantograph_node_contracts::PortContract) -> Po
             port.cardinality,
             pantograph_node_contracts::PortCardinality::Multiple
         ),
+        ui: port.ui.clone(),
     }
 }
 
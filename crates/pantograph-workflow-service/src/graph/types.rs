@@ -107,6 +107,8 @@ pub struct PortDefinition {
     pub required: bool,
     #[serde(default)]
     pub multiple: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui: Option<pantograph_node_contracts::PortUiHints>,
 }
 
 impl PortDefinition {
@@ -133,6 +135,7 @@ impl PortDefinition {
             visibility: pantograph_node_contracts::PortVisibility::Public,
             constraints: Vec::new(),
             editor_hints: Vec::new(),
+            ui: self.ui.clone(),
         })
     }
 }
@@ -174,6 +177,21 @@ pub struct NodeDefinition {
     pub outputs: Vec<PortDefinition>,
     #[serde(default)]
     pub execution_mode: ExecutionMode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<pantograph_node_contracts::NodeDocumentation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui: Option<NodeUiHints>,
+}
+
+/// Standard node rendering hints projected from the node contract.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NodeUiHints {
+    /// Lucide icon name.
+    pub icon: String,
+    /// Semantic color token that themes may remap.
+    pub color_token: String,
+    /// Default hex color for `color_token`.
+    pub color: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -29,11 +29,11 @@ pub use graph::{
     EdgeInsertionPreviewResponse, ExecutionMode, FileSystemWorkflowGraphStore, GraphEdge,
     GraphNode, InsertNodeConnectionResponse, InsertNodeOnEdgeResponse, InsertNodePositionHint,
     InsertableNodeTypeCandidate, IoBindingOrigin, NodeCategory, NodeDefinition, NodeGroup,
-    NodeRegistry, PortDataType, PortDefinition, PortMapping, Position, UndoRedoState, Viewport,
-    WorkflowExecutableTopology, WorkflowExecutableTopologyEdge, WorkflowExecutableTopologyNode,
-    WorkflowFile, WorkflowGraph, WorkflowGraphAddEdgeRequest, WorkflowGraphAddNodeRequest,
-    WorkflowGraphConnectRequest, WorkflowGraphCreateGroupRequest, WorkflowGraphDeleteRequest,
-    WorkflowGraphDeleteResponse, WorkflowGraphDeleteSelectionRequest,
+    NodeRegistry, NodeUiHints, PortDataType, PortDefinition, PortMapping, Position, UndoRedoState,
+    Viewport, WorkflowExecutableTopology, WorkflowExecutableTopologyEdge,
+    WorkflowExecutableTopologyNode, WorkflowFile, WorkflowGraph, WorkflowGraphAddEdgeRequest,
+    WorkflowGraphAddNodeRequest, WorkflowGraphConnectRequest, WorkflowGraphCreateGroupRequest,
+    WorkflowGraphDeleteRequest, WorkflowGraphDeleteResponse, WorkflowGraphDeleteSelectionRequest,
     WorkflowGraphEditSessionCloseRequest, WorkflowGraphEditSessionCloseResponse,
     WorkflowGraphEditSessionCreateRequest, WorkflowGraphEditSessionCreateResponse,
     WorkflowGraphEditSessionGraphRequest, WorkflowGraphEditSessionGraphResponse,
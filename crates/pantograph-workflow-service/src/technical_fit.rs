@@ -144,6 +144,7 @@ pub enum WorkflowTechnicalFitReasonCode {
     WarmupCost,
     BudgetPressure,
     QueuePressure,
+    MeasuredThroughput,
     MissingCandidateData,
     MissingRuntimeState,
     DeterministicTieBreak,
@@ -7,9 +7,10 @@
 use pantograph_node_contracts::{
     ComposedInternalEdge, ComposedInternalGraph, ComposedInternalNode, ComposedNodeContract,
     ComposedPortMapping, ComposedPortMappings, ComposedTracePolicy, NodeAuthoringMetadata,
-    NodeCapabilityRequirement, NodeCategory, NodeContractError, NodeExecutionSemantics,
-    NodeInstanceId, NodeTypeContract, NodeTypeId, PortCardinality, PortContract, PortId, PortKind,
-    PortRequirement, PortValueType, PortVisibility,
+    NodeCapabilityRequirement, NodeCategory, NodeContractError, NodeDocumentation,
+    NodeDocumentationExample, NodeDocumentationLink, NodeExecutionSemantics, NodeInstanceId,
+    NodeTypeContract, NodeTypeId, PortCardinality, PortContract, PortId, PortKind, PortRequirement,
+    PortShape, PortUiHints, PortValueType, PortVisibility,
 };
 
 pub fn builtin_node_contracts() -> Result<Vec<NodeTypeContract>, NodeContractError> {
@@ -169,11 +170,30 @@ fn port_metadata_to_contract(
         visibility: PortVisibility::Public,
         constraints: Vec::new(),
         editor_hints: Vec::new(),
+        ui: Some(port_ui_hints(metadata)),
     };
     contract.validate()?;
     Ok(contract)
 }
 
+fn port_ui_hints(metadata: &node_engine::PortMetadata) -> PortUiHints {
+    let hints = if metadata.ui.is_unset() {
+        node_engine::PortUiHints::for_data_type(metadata.data_type)
+    } else {
+        metadata.ui.clone()
+    };
+    PortUiHints {
+        color_token: hints.color_token,
+        color: hints.color,
+        shape: match hints.shape {
+            node_engine::PortShape::Circle => PortShape::Circle,
+            node_engine::PortShape::Square => PortShape::Square,
+            node_engine::PortShape::Diamond => PortShape::Diamond,
+            node_engine::PortShape::Hexagon => PortShape::Hexagon,
+        },
+    }
+}
+
 fn convert_category(category: node_engine::NodeCategory) -> NodeCategory {
     match category {
         node_engine::NodeCategory::Input => NodeCategory::Input,
@@ -235,14 +255,45 @@ fn capability_requirements(metadata: &node_engine::TaskMetadata) -> Vec<NodeCapa
 }
 
 fn authoring_metadata(metadata: &node_engine::TaskMetadata) -> NodeAuthoringMetadata {
+    let mut ui = metadata.ui.clone();
+    ui.resolve(metadata.category);
     NodeAuthoringMetadata {
         tags: vec![format!("{:?}", metadata.category).to_lowercase()],
-        icon: None,
-        color: None,
-        documentation_url: None,
+        icon: Some(ui.icon),
+        color: Some(ui.color),
+        color_token: Some(ui.color_token),
+        documentation_url: metadata.docs.links.first().map(|link| link.url.clone()),
+        documentation: documentation(&metadata.docs),
     }
 }
 
+fn documentation(docs: &node_engine::NodeDocs) -> Option<NodeDocumentation> {
+    if docs.is_empty() {
+        return None;
+    }
+    Some(NodeDocumentation {
+        body: docs.body.clone(),
+        examples: docs
+            .examples
+            .iter()
+            .map(|example| NodeDocumentationExample {
+                title: example.title.clone(),
+                data: example.data.clone(),
+                inputs: example.inputs.clone(),
+                outputs: example.outputs.clone(),
+            })
+            .collect(),
+        links: docs
+            .links
+            .iter()
+            .map(|link| NodeDocumentationLink {
+                label: link.label.clone(),
+                url: link.url.clone(),
+            })
+            .collect(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -331,6 +382,68 @@ mod tests {
         assert_eq!(response.value_type, PortValueType::String);
     }
 
+    #[test]
+    fn projection_carries_descriptor_documentation() {
+        let contracts = builtin_node_contracts().expect("canonical contracts");
+        let schema = contracts
+            .iter()
+            .find(|contract| contract.node_type.as_str() == "json-schema-validate")
+            .expect("json-schema-validate contract");
+
+        let documentation = schema
+            .authoring
+            .documentation
+            .as_ref()
+            .expect("documentation");
+        assert!(!documentation.body.is_empty());
+        assert_eq!(documentation.examples[0].outputs["valid"], false);
+        assert_eq!(
+            schema.authoring.documentation_url.as_deref(),
+            Some(documentation.links[0].url.as_str())
+        );
+
+        let llm = contracts
+            .iter()
+            .find(|contract| contract.node_type.as_str() == "llm-inference")
+            .expect("llm-inference contract");
+        let llm_docs = llm
+            .authoring
+            .documentation
+            .as_ref()
+            .expect("llm-inference documentation");
+        assert!(llm_docs.examples.is_empty());
+        assert!(llm.authoring.documentation_url.is_none());
+    }
+
+    #[test]
+    fn projection_carries_standard_ui_hints() {
+        let contracts = builtin_node_contracts().expect("canonical contracts");
+        let delay = contracts
+            .iter()
+            .find(|contract| contract.node_type.as_str() == "delay")
+            .expect("delay contract");
+
+        assert_eq!(delay.authoring.icon.as_deref(), Some("timer"));
+        assert_eq!(
+            delay.authoring.color_token.as_deref(),
+            Some("category-control")
+        );
+        let duration = delay
+            .inputs
+            .iter()
+            .find(|port| port.id.as_str() == "duration_ms")
+            .and_then(|port| port.ui.as_ref())
+            .expect("duration_ms hints");
+        assert_eq!(duration.color_token, "data-number");
+        assert_eq!(duration.shape, PortShape::Circle);
+
+        let text_input = contracts
+            .iter()
+            .find(|contract| contract.node_type.as_str() == "text-input")
+            .expect("text-input contract");
+        assert_eq!(text_input.authoring.icon.as_deref(), Some("log-in"));
+    }
+
     #[test]
     fn projection_preserves_extended_engine_value_types() {
         let metadata = node_engine::TaskMetadata {
@@ -349,6 +462,8 @@ mod tests {
                 node_engine::PortDataType::Tensor,
             )],
             execution_mode: node_engine::ExecutionMode::Batch,
+            docs: node_engine::NodeDocs::default(),
+            ui: node_engine::NodeUiHints::default(),
         };
 
         let contract = task_metadata_to_contract(&metadata).expect("contract");
@@ -372,6 +487,8 @@ mod tests {
                 node_engine::PortDataType::String,
             )],
             execution_mode: node_engine::ExecutionMode::Reactive,
+            docs: node_engine::NodeDocs::default(),
+            ui: node_engine::NodeUiHints::default(),
         };
 
         assert_eq!(
@@ -7,9 +7,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Conditional Task
 ///
@@ -69,6 +70,20 @@ impl TaskDescriptor for ConditionalTask {
                 PortMetadata::optional(Self::PORT_FALSE_OUT, "False", PortDataType::Any),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Routes `value` to `true_out` when `condition` is `true` and to \
+                 `false_out` otherwise; the other port gets null. Only a JSON \
+                 boolean counts as true, so the string `\"true\"` routes to \
+                 `false_out`.",
+            )
+            .example(
+                NodeExample::new("Valid code goes on")
+                    .input(Self::PORT_CONDITION, json!(true))
+                    .input(Self::PORT_VALUE, json!("<h1>Hi</h1>"))
+                    .output(Self::PORT_TRUE_OUT, json!("<h1>Hi</h1>"))
+                    .output(Self::PORT_FALSE_OUT, json!(null)),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -0,0 +1,108 @@
+//! Delay Task
+//!
+//! Passes a value through after waiting a fixed time on the executor clock.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+/// Delay Task
+///
+/// # Node Data
+/// - `duration_ms` - Wait in milliseconds (default 0); the `duration_ms`
+///   input port wins when connected
+///
+/// The wait uses the clock installed in the executor extensions, so a
+/// simulated clock completes it immediately and advances virtual time.
+///
+/// Execution is handled by `CoreTaskExecutor`.
+#[derive(Clone)]
+pub struct DelayTask {
+    /// Unique identifier for this task instance
+    task_id: String,
+}
+
+impl DelayTask {
+    /// Port ID for value input and delayed value output
+    pub const PORT_VALUE: &'static str = "value";
+    /// Port ID for duration input
+    pub const PORT_DURATION_MS: &'static str = "duration_ms";
+
+    /// Create a new delay task
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for DelayTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "delay".to_string(),
+            category: NodeCategory::Control,
+            label: "Delay".to_string(),
+            description: "Passes a value through after a wait".to_string(),
+            inputs: vec![
+                PortMetadata::optional(Self::PORT_VALUE, "Value", PortDataType::Any),
+                PortMetadata::optional(
+                    Self::PORT_DURATION_MS,
+                    "Duration (ms)",
+                    PortDataType::Number,
+                ),
+            ],
+            outputs: vec![PortMetadata::optional(
+                Self::PORT_VALUE,
+                "Value",
+                PortDataType::Any,
+            )],
+            execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Waits `duration_ms` milliseconds, then passes `value` through \
+                 unchanged. The wait runs on the executor clock, so simulated and \
+                 replayed runs finish it instantly while still advancing time.",
+            )
+            .example(
+                NodeExample::new("Wait one second")
+                    .data(json!({"duration_ms": 1000}))
+                    .input(Self::PORT_VALUE, json!("ready"))
+                    .output(Self::PORT_VALUE, json!("ready")),
+            ),
+            ui: NodeUiHints::icon("timer"),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(DelayTask::descriptor));
+
+#[async_trait]
+impl Task for DelayTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        // Execution handled by CoreTaskExecutor::execute_delay
+        Err(GraphError::TaskExecutionFailed(
+            "delay requires CoreTaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor() {
+        let meta = DelayTask::descriptor();
+        assert_eq!(meta.node_type, "delay");
+        assert_eq!(meta.category, NodeCategory::Control);
+        assert_eq!(meta.inputs.len(), 2);
+        assert_eq!(meta.outputs[0].id, "value");
+    }
+}
@@ -7,10 +7,11 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 /// Configuration for the merge task
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,6 +96,21 @@ impl TaskDescriptor for MergeTask {
                 PortMetadata::optional(Self::PORT_COUNT, "Count", PortDataType::Number),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Joins the strings connected to `inputs` into `merged`, one per \
+                 line, and reports how many were joined on `count`. Connect several \
+                 edges to `inputs`; blank strings and non-string values are skipped.",
+            )
+            .example(
+                NodeExample::new("Prompt and feedback")
+                    .input(
+                        Self::PORT_INPUTS,
+                        json!(["Write a button.", " ", "Use $props()."]),
+                    )
+                    .output(Self::PORT_MERGED, json!("Write a button.\nUse $props()."))
+                    .output(Self::PORT_COUNT, json!(2)),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
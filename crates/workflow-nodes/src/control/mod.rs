@@ -3,11 +3,15 @@
 //! Nodes for control flow, loops, and agent-style execution.
 
 mod conditional;
+mod delay;
 mod merge;
+mod throttle;
 mod tool_executor;
 mod tool_loop;
 
 pub use conditional::ConditionalTask;
+pub use delay::DelayTask;
 pub use merge::{MergeConfig, MergeTask};
+pub use throttle::ThrottleTask;
 pub use tool_executor::{ToolCallRequest, ToolCallResult, ToolExecutorTask};
 pub use tool_loop::{ToolCall, ToolDefinition, ToolLoopConfig, ToolLoopTask};
@@ -0,0 +1,113 @@
+//! Throttle Task
+//!
+//! Passes a value through no sooner than a minimum interval after the same
+//! node's previous pass, measured on the executor clock.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+/// Throttle Task
+///
+/// # Node Data
+/// - `interval_ms` - Minimum spacing between passes in milliseconds
+///   (default 0); the `interval_ms` input port wins when connected
+///
+/// The first pass of a node is immediate. Later passes wait on the clock
+/// installed in the executor extensions until `interval_ms` has elapsed
+/// since the previous one, so a simulated clock completes them immediately
+/// and advances virtual time.
+///
+/// Execution is handled by `CoreTaskExecutor`.
+#[derive(Clone)]
+pub struct ThrottleTask {
+    /// Unique identifier for this task instance
+    task_id: String,
+}
+
+impl ThrottleTask {
+    /// Port ID for value input and throttled value output
+    pub const PORT_VALUE: &'static str = "value";
+    /// Port ID for interval input
+    pub const PORT_INTERVAL_MS: &'static str = "interval_ms";
+
+    /// Create a new throttle task
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for ThrottleTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "throttle".to_string(),
+            category: NodeCategory::Control,
+            label: "Throttle".to_string(),
+            description: "Spaces out repeated passes of a value".to_string(),
+            inputs: vec![
+                PortMetadata::optional(Self::PORT_VALUE, "Value", PortDataType::Any),
+                PortMetadata::optional(
+                    Self::PORT_INTERVAL_MS,
+                    "Interval (ms)",
+                    PortDataType::Number,
+                ),
+            ],
+            outputs: vec![PortMetadata::optional(
+                Self::PORT_VALUE,
+                "Value",
+                PortDataType::Any,
+            )],
+            execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Passes `value` through unchanged, waiting first if this node \
+                 passed a value less than `interval_ms` milliseconds ago. Use it \
+                 to keep re-run or looped graphs under an API rate limit. The \
+                 wait runs on the executor clock, so simulated and replayed runs \
+                 finish it instantly while still advancing time.",
+            )
+            .example(
+                NodeExample::new("At most one call per second")
+                    .data(json!({"interval_ms": 1000}))
+                    .input(Self::PORT_VALUE, json!("request"))
+                    .output(Self::PORT_VALUE, json!("request")),
+            ),
+            ui: NodeUiHints::icon("gauge"),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(ThrottleTask::descriptor));
+
+#[async_trait]
+impl Task for ThrottleTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        // Execution handled by CoreTaskExecutor::execute_throttle
+        Err(GraphError::TaskExecutionFailed(
+            "throttle requires CoreTaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor() {
+        let meta = ThrottleTask::descriptor();
+        assert_eq!(meta.node_type, "throttle");
+        assert_eq!(meta.category, NodeCategory::Control);
+        assert_eq!(meta.inputs.len(), 2);
+        assert_eq!(meta.outputs[0].id, "value");
+    }
+}
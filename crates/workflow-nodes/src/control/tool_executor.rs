@@ -8,8 +8,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
 
@@ -99,6 +99,12 @@ impl TaskDescriptor for ToolExecutorTask {
                 ),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Disabled: every run fails until backend-owned tool execution \
+                 exists. The node stays registered so saved workflows that use it \
+                 still load.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
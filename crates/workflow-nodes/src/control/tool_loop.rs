@@ -8,8 +8,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
 
@@ -151,6 +151,14 @@ impl TaskDescriptor for ToolLoopTask {
                 PortMetadata::optional(Self::PORT_TURNS, "Turns", PortDataType::Number),
             ],
             execution_mode: ExecutionMode::Stream,
+            docs: NodeDocs::new(
+                "Sends `prompt` (with `context` appended and `system_prompt` as the \
+                 system message) to an OpenAI-compatible chat endpoint once and \
+                 emits the `response`. Tool calls are not executed yet: when the \
+                 model requests one, the node fails instead of inventing a result. \
+                 `max_turns` is reserved for multi-turn continuation.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
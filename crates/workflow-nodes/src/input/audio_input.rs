@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Audio Input Task
 ///
@@ -64,6 +65,17 @@ impl TaskDescriptor for AudioInputTask {
                 PortDataType::Audio,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Emits the loaded audio (`audio_data` in node data, or the connected \
+                 `audio_data` input) on `audio`, or null when nothing is loaded. \
+                 Audio travels as a base64 string, typically WAV.",
+            )
+            .example(
+                NodeExample::new("Loaded clip")
+                    .data(json!({"audio_data": "UklGRiQAAABXQVZF"}))
+                    .output(Self::PORT_AUDIO, json!("UklGRiQAAABXQVZF")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
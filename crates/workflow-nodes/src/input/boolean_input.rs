@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Boolean Input Task
 #[derive(Clone)]
@@ -46,6 +47,17 @@ impl TaskDescriptor for BooleanInputTask {
                 PortDataType::Boolean,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Emits `value` from node data, falling back to the connected `value` \
+                 input. Besides JSON booleans, the strings `true` and `false` are \
+                 accepted; anything else emits nothing.",
+            )
+            .example(
+                NodeExample::new("Enable streaming")
+                    .data(json!({"value": "true"}))
+                    .output(Self::PORT_VALUE, json!(true)),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -6,10 +6,11 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 /// Human Input Task
 ///
@@ -85,6 +86,25 @@ impl TaskDescriptor for HumanInputTask {
                 PortDataType::String,
             )],
             execution_mode: ExecutionMode::Manual,
+            docs: NodeDocs::new(
+                "Pauses the run and asks a person for a value. The workflow waits, \
+                 showing `prompt` (port, then node data), until `user_response` is \
+                 provided, then emits the answer on `value`.\n\n\
+                 With `auto_accept` true and a `default`, the node completes at once \
+                 with the default instead of waiting.",
+            )
+            .example(
+                NodeExample::new("Approval answered")
+                    .data(json!({"prompt": "Deploy to production?"}))
+                    .input(Self::PORT_USER_RESPONSE, json!("yes"))
+                    .output(Self::PORT_VALUE, json!("yes")),
+            )
+            .example(
+                NodeExample::new("Auto-accept a default")
+                    .data(json!({"default": "approved", "auto_accept": true}))
+                    .output(Self::PORT_VALUE, json!("approved")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -6,10 +6,11 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 /// Capture bounds for image input
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -75,6 +76,21 @@ impl TaskDescriptor for ImageInputTask {
                 PortMetadata::optional(Self::PORT_BOUNDS, "Bounds", PortDataType::Json),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Emits the image held in node data as `image` (a base64 data URL, \
+                 as the editor's image picker stores it) on `image`, or null when \
+                 no image is set. `bounds` is reserved for region selection and is \
+                 not emitted yet.",
+            )
+            .example(
+                NodeExample::new("Embedded PNG")
+                    .data(json!({"image": "data:image/png;base64,iVBORw0KGgo="}))
+                    .output(
+                        Self::PORT_IMAGE,
+                        json!("data:image/png;base64,iVBORw0KGgo="),
+                    ),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -7,9 +7,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Linked Input Task
 ///
@@ -58,6 +59,18 @@ impl TaskDescriptor for LinkedInputTask {
                 PortDataType::String,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Emits the current value of the editor element this node is linked \
+                 to, which the editor stores in node data as `linked_value`. It has \
+                 no input ports; an unlinked node or a non-text value emits an \
+                 empty string.",
+            )
+            .example(
+                NodeExample::new("Linked theme picker")
+                    .data(json!({"linked_value": "dark"}))
+                    .output(Self::PORT_VALUE, json!("dark")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
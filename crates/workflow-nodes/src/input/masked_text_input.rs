@@ -13,10 +13,11 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 /// A segment of text that is either masked (regenerated) or anchored (preserved).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +84,28 @@ impl TaskDescriptor for MaskedTextInputTask {
                 PortDataType::Prompt,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Builds a `masked_prompt` for diffusion LLMs that regenerate only \
+                 masked regions. Node data `segments` is a list of `{text, masked}`; \
+                 unmasked segments are kept verbatim as anchors.\n\n\
+                 Without segments, `text` (node data, then the input) becomes one \
+                 fully masked segment.",
+            )
+            .example(
+                NodeExample::new("Regenerate the body only")
+                    .data(json!({"segments": [
+                        {"text": "Dear team,", "masked": false},
+                        {"text": "Body to rewrite.", "masked": true}
+                    ]}))
+                    .output(
+                        Self::PORT_MASKED_PROMPT,
+                        json!({"type": "masked_prompt", "segments": [
+                            {"text": "Dear team,", "masked": false},
+                            {"text": "Body to rewrite.", "masked": true}
+                        ]}),
+                    ),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
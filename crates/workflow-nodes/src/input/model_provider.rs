@@ -7,10 +7,11 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 /// Model information output by the Model Provider
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,6 +102,22 @@ impl TaskDescriptor for ModelProviderTask {
                 ),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Names the model that downstream inference nodes such as `ollama-inference` \
+                 should use. The name comes from the node's `model_name` setting, then the \
+                 `model_name` input, and falls back to `llama2`. `model_info` describes the \
+                 selection for nodes that need more than the name.",
+            )
+            .example(
+                NodeExample::new("Pick an Ollama model")
+                    .data(json!({"model_name": "llama3.2"}))
+                    .output(PORT_MODEL_NAME_OUT, json!("llama3.2"))
+                    .output(
+                        PORT_MODEL_INFO,
+                        json!({"name": "llama3.2", "model_type": "llm"}),
+                    ),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Number Input Task
 #[derive(Clone)]
@@ -46,6 +47,17 @@ impl TaskDescriptor for NumberInputTask {
                 PortDataType::Number,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Emits `value` from node data, falling back to the connected `value` \
+                 input. Numeric strings are parsed. A missing, non-numeric, or \
+                 non-finite value emits nothing, so downstream ports stay unset.",
+            )
+            .example(
+                NodeExample::new("Temperature")
+                    .data(json!({"value": "0.7"}))
+                    .output(Self::PORT_VALUE, json!(0.7)),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
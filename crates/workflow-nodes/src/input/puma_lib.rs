@@ -12,8 +12,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 const PORT_MODEL_PATH: &str = "model_path";
 const PORT_MODEL_ID: &str = "model_id";
@@ -27,6 +29,7 @@ const PORT_DEPENDENCY_BINDINGS: &str = "dependency_bindings";
 const PORT_DEPENDENCY_REQUIREMENTS_ID: &str = "dependency_requirements_id";
 const PORT_INFERENCE_SETTINGS: &str = "inference_settings";
 const PORT_DEPENDENCY_REQUIREMENTS: &str = "dependency_requirements";
+const PORT_MMPROJ_PATH: &str = "mmproj_path";
 
 /// Stub task for the puma-lib node.
 ///
@@ -95,8 +98,34 @@ impl TaskDescriptor for PumaLibTask {
                     "Dependency Requirements",
                     PortDataType::Json,
                 ),
+                PortMetadata::optional(
+                    PORT_MMPROJ_PATH,
+                    "Vision Projector Path",
+                    PortDataType::String,
+                ),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Emits a model picked from the Pumas model library. `model_path` feeds \
+                 inference nodes, while the model type, backend, and dependency outputs let \
+                 `dependency-environment` and the backend nodes prepare a matching runtime. \
+                 `inference_settings` carries the model's recommended settings, and \
+                 `mmproj_path` points at its vision projector when one sits next to the \
+                 model file.",
+            )
+            .example(
+                NodeExample::new("Library GGUF model")
+                    .data(json!({
+                        "modelPath": "/models/qwen2.5-7b-instruct-q4_k_m.gguf",
+                        "model_id": "llm/qwen/qwen2_5-7b-instruct",
+                    }))
+                    .output(
+                        PORT_MODEL_PATH,
+                        json!("/models/qwen2.5-7b-instruct-q4_k_m.gguf"),
+                    )
+                    .output(PORT_MODEL_ID, json!("llm/qwen/qwen2_5-7b-instruct")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -115,8 +144,12 @@ mod options_provider {
         PortOptionsQuery, PortOptionsResult,
     };
     use pumas_library::models::ModelExecutionDescriptor;
+    use pumas_library::{ModelRecord, PumasApi};
+    use std::path::PathBuf;
     use std::sync::Arc;
 
+    use crate::model_library::ModelLibraryRoots;
+
     /// Provides available models from pumas-library for the `model_path` port.
     pub struct PumaLibOptionsProvider;
 
@@ -202,26 +235,61 @@ mod options_provider {
             query: &PortOptionsQuery,
             extensions: &ExecutorExtensions,
         ) -> node_engine::Result<PortOptionsResult> {
-            let api = extensions
-                .get::<Arc<pumas_library::PumasApi>>(extension_keys::PUMAS_API)
-                .ok_or_else(|| {
-                    NodeEngineError::ExecutionFailed("Model library not available".to_string())
-                })?;
-
-            let records = if let Some(ref search) = query.search {
-                let result = api
-                    .search_models(search, query.limit.unwrap_or(50), query.offset.unwrap_or(0))
-                    .await
-                    .map_err(|e| NodeEngineError::ExecutionFailed(e.to_string()))?;
-                result.models
-            } else {
-                api.list_models()
-                    .await
-                    .map_err(|e| NodeEngineError::ExecutionFailed(e.to_string()))?
-            };
-
-            let mut options = Vec::with_capacity(records.len());
-            for m in &records {
+            let limit = query.limit.unwrap_or(50);
+            let offset = query.offset.unwrap_or(0);
+            // Each entry is (owning API, record, (root path, read-only)); the
+            // root is only known when several libraries are merged.
+            let entries: Vec<(Arc<PumasApi>, ModelRecord, Option<(PathBuf, bool)>)> =
+                if let Some(roots) =
+                    extensions.get::<Arc<ModelLibraryRoots>>(extension_keys::PUMAS_LIBRARY_ROOTS)
+                {
+                    let models = if let Some(ref search) = query.search {
+                        roots
+                            .search_models(search, limit, offset)
+                            .await
+                            .map(|result| result.models)
+                    } else {
+                        roots.list_models().await
+                    }
+                    .map_err(NodeEngineError::ExecutionFailed)?;
+                    models
+                        .into_iter()
+                        .map(|model| {
+                            let api = roots.roots()[model.root_index].api.clone();
+                            (
+                                api,
+                                model.record,
+                                Some((model.library_root, model.read_only)),
+                            )
+                        })
+                        .collect()
+                } else {
+                    let api = extensions
+                        .get::<Arc<PumasApi>>(extension_keys::PUMAS_API)
+                        .ok_or_else(|| {
+                            NodeEngineError::ExecutionFailed(
+                                "Model library not available".to_string(),
+                            )
+                        })?;
+                    let records = if let Some(ref search) = query.search {
+                        let result = api
+                            .search_models(search, limit, offset)
+                            .await
+                            .map_err(|e| NodeEngineError::ExecutionFailed(e.to_string()))?;
+                        result.models
+                    } else {
+                        api.list_models()
+                            .await
+                            .map_err(|e| NodeEngineError::ExecutionFailed(e.to_string()))?
+                    };
+                    records
+                        .into_iter()
+                        .map(|record| (api.clone(), record, None))
+                        .collect()
+                };
+
+            let mut options = Vec::with_capacity(entries.len());
+            for (api, m, root) in &entries {
                 // Prefer the Pumas execution descriptor whenever the record can
                 // resolve one so runtime-facing paths come from the executable
                 // contract rather than projected metadata.
@@ -295,6 +363,11 @@ mod options_provider {
                     .as_ref()
                     .map(|descriptor| descriptor.entry_path.clone())
                     .unwrap_or_else(|| m.path.clone());
+                let mmproj_path =
+                    metadata_string(m, &["mmproj_path", "mmprojPath"]).or_else(|| {
+                        node_engine::find_mmproj(std::path::Path::new(&execution_path))
+                            .map(|path| path.to_string_lossy().into_owned())
+                    });
 
                 options.push(PortOption {
                     value: serde_json::json!(execution_path),
@@ -318,6 +391,9 @@ mod options_provider {
                         "dependency_bindings": dependency_bindings,
                         "review_reasons": review_reasons,
                         "inference_settings": inference_settings,
+                        "mmproj_path": mmproj_path,
+                        "library_root": root.as_ref().map(|(path, _)| path),
+                        "read_only": root.as_ref().map(|(_, read_only)| read_only),
                     })),
                 });
             }
@@ -367,7 +443,7 @@ mod tests {
         let meta = PumaLibTask::descriptor();
 
         assert!(meta.inputs.is_empty());
-        assert_eq!(meta.outputs.len(), 12);
+        assert_eq!(meta.outputs.len(), 13);
 
         assert!(meta.outputs.iter().any(|p| p.id == "model_path"));
         assert!(meta.outputs.iter().any(|p| p.id == "model_id"));
@@ -399,6 +475,10 @@ mod tests {
             .any(|p| p.id == "dependency_requirements"
                 && p.data_type == PortDataType::Json
                 && !p.required));
+        assert!(meta
+            .outputs
+            .iter()
+            .any(|p| p.id == "mmproj_path" && p.data_type == PortDataType::String && !p.required));
     }
 
     #[tokio::test]
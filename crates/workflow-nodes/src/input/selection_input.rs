@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Selection Input Task
 ///
@@ -48,6 +49,17 @@ impl TaskDescriptor for SelectionInputTask {
                 PortDataType::Any,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Emits the selected `value` from node data unchanged, falling back to \
+                 the connected `value` input, or null when nothing is selected. \
+                 Selections may be strings, numbers, or objects.",
+            )
+            .example(
+                NodeExample::new("Quantization choice")
+                    .data(json!({"value": "q4_k_m"}))
+                    .output(Self::PORT_VALUE, json!("q4_k_m")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
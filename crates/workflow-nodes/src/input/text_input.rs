@@ -7,9 +7,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Text Input Task
 ///
@@ -62,6 +63,18 @@ impl TaskDescriptor for TextInputTask {
                 PortDataType::String,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Emits the text typed into the node (`text` in node data). When the \
+                 node has no text of its own, a connected `text` input is used \
+                 instead; with neither it emits an empty string.\n\n\
+                 A data-graph input bound to this node replaces the typed text.",
+            )
+            .example(
+                NodeExample::new("Fixed prompt")
+                    .data(json!({"text": "Summarize this article."}))
+                    .output(Self::PORT_TEXT, json!("Summarize this article.")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -5,9 +5,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Vector Input Task
 ///
@@ -53,6 +54,18 @@ impl TaskDescriptor for VectorInputTask {
                 PortDataType::Embedding,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Emits `vector` from node data (or the connected `vector` input) as \
+                 an array of numbers. A string holding a JSON array is parsed. Any \
+                 element that is not a finite number fails the node; an empty node \
+                 emits `[]`.",
+            )
+            .example(
+                NodeExample::new("Pasted embedding")
+                    .data(json!({"vector": "[0.1, 0.2, 0.3]"}))
+                    .output(Self::PORT_VECTOR, json!([0.1, 0.2, 0.3])),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
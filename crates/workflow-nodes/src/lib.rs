@@ -10,10 +10,19 @@
 //! - **Processing**: Nodes that transform data (LLM, embedding, etc.)
 //! - **Storage**: Nodes for file and database operations
 //! - **Control**: Nodes for control flow (loops, conditionals)
+//! - **Mock**: Canned stand-ins for model and HTTP backends (`mock` feature)
 
 pub mod contracts;
 pub mod control;
 pub mod input;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(feature = "model-library")]
+pub mod model_benchmark;
+#[cfg(feature = "model-library")]
+pub mod model_gc;
+#[cfg(feature = "model-library")]
+pub mod model_library;
 pub mod output;
 pub mod processing;
 pub mod setup;
@@ -27,9 +36,24 @@ pub use contracts::{
 };
 pub use control::*;
 pub use input::*;
+#[cfg(feature = "mock")]
+pub use mock::*;
+#[cfg(feature = "model-library")]
+pub use model_benchmark::PumasBenchmarkStore;
+#[cfg(feature = "model-library")]
+pub use model_gc::{
+    collect_unused_models, unused_models, ModelGcFailure, ModelGcReport, ModelLibraryView,
+};
+#[cfg(feature = "model-library")]
+pub use model_library::{
+    install_library_roots, LibraryRoot, MergedSearchResult, ModelLibraryRoots, RootedModel,
+};
 pub use output::*;
 pub use processing::*;
-pub use setup::{setup_extensions, setup_extensions_with_path};
+pub use setup::{
+    setup_extensions, setup_extensions_from_config, setup_extensions_from_file,
+    setup_extensions_with_path,
+};
 pub use storage::*;
 pub use system::*;
 pub use tool::*;
@@ -42,18 +66,19 @@ mod tests {
     fn test_inventory_collects_all_builtins() {
         let registry = NodeRegistry::with_builtins();
         let all = registry.all_metadata();
+        let mock_nodes = if cfg!(feature = "mock") { 3 } else { 0 };
 
         #[cfg(feature = "desktop")]
         assert_eq!(
             all.len(),
-            45,
-            "Expected 45 built-in nodes with desktop feature"
+            55 + mock_nodes,
+            "Expected 55 built-in nodes with desktop feature, plus mock nodes"
         );
         #[cfg(not(feature = "desktop"))]
         assert_eq!(
             all.len(),
-            42,
-            "Expected 42 built-in nodes without desktop feature"
+            52 + mock_nodes,
+            "Expected 52 built-in nodes without desktop feature, plus mock nodes"
         );
 
         // Spot-check known types
@@ -85,8 +110,161 @@ mod tests {
         assert!(registry.has_node_type("masked-text-input"));
         assert!(registry.has_node_type("expand-settings"));
         assert!(registry.has_node_type("dependency-environment"));
+        assert!(registry.has_node_type("json-schema-validate"));
+        assert!(registry.has_node_type("aggregate"));
+        assert!(registry.has_node_type("sort"));
+        assert!(registry.has_node_type("filter"));
+        assert!(registry.has_node_type("join"));
+        assert!(registry.has_node_type("delay"));
+        assert!(registry.has_node_type("throttle"));
+        assert!(registry.has_node_type("random"));
+        assert!(registry.has_node_type("model-convert"));
+        assert!(registry.has_node_type("model-benchmark"));
 
         #[cfg(feature = "desktop")]
         assert!(registry.has_node_type("point-cloud-output"));
+        #[cfg(feature = "mock")]
+        {
+            assert!(registry.has_node_type("mock-llm"));
+            assert!(registry.has_node_type("mock-embedding"));
+            assert!(registry.has_node_type("mock-http"));
+        }
+    }
+
+    /// Built-in nodes that must ship documentation for editor help panels.
+    const CORE_DOCUMENTED_NODES: &[&str] = &[
+        "text-input",
+        "number-input",
+        "boolean-input",
+        "selection-input",
+        "vector-input",
+        "masked-text-input",
+        "image-input",
+        "audio-input",
+        "human-input",
+        "text-output",
+        "vector-output",
+        "image-output",
+        "audio-output",
+        "conditional",
+        "merge",
+        "delay",
+        "throttle",
+        "tool-loop",
+        "tool-executor",
+        "validator",
+        "json-filter",
+        "json-schema-validate",
+        "aggregate",
+        "sort",
+        "filter",
+        "join",
+        "expand-settings",
+        "random",
+        "read-file",
+        "write-file",
+        "kv-cache-save",
+        "kv-cache-load",
+        "kv-cache-truncate",
+        "model-provider",
+        "puma-lib",
+        "llm-inference",
+        "llamacpp-inference",
+        "ollama-inference",
+        "pytorch-inference",
+        "onnx-inference",
+        "diffusion-inference",
+        "audio-generation",
+        "depth-estimation",
+        "vision-analysis",
+        "embedding",
+        "reranker",
+        "dependency-environment",
+        "model-convert",
+        "model-benchmark",
+        "unload-model",
+        "agent-tools",
+        "process",
+    ];
+
+    #[test]
+    fn test_core_nodes_are_documented() {
+        let registry = NodeRegistry::with_builtins();
+        let mut node_types = CORE_DOCUMENTED_NODES.to_vec();
+        if cfg!(feature = "desktop") {
+            node_types.extend(["linked-input", "component-preview", "point-cloud-output"]);
+        }
+        for node_type in node_types {
+            let metadata = registry
+                .get_metadata(node_type)
+                .unwrap_or_else(|| panic!("{} is not registered", node_type));
+            assert!(
+                !metadata.docs.body.is_empty(),
+                "{} has no documentation",
+                node_type
+            );
+        }
+    }
+
+    /// Documented examples are run through `CoreTaskExecutor` so published
+    /// outputs cannot drift from what the node actually returns.
+    #[tokio::test]
+    async fn test_documented_examples_match_core_executor() {
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        use node_engine::{
+            extension_keys, Clock, CoreTaskExecutor, ExecutorExtensions, SimulatedClock,
+            TaskExecutor,
+        };
+
+        let registry = NodeRegistry::with_builtins();
+        let executor = CoreTaskExecutor::new();
+        let mut extensions = ExecutorExtensions::new();
+        extensions.set(
+            extension_keys::CLOCK,
+            Arc::new(SimulatedClock::new()) as Arc<dyn Clock>,
+        );
+
+        let mut checked = 0;
+        for metadata in registry.all_metadata() {
+            for example in &metadata.docs.examples {
+                if example.outputs.is_empty() {
+                    continue;
+                }
+                let mut inputs: HashMap<String, serde_json::Value> =
+                    example.inputs.clone().into_iter().collect();
+                let mut data = example.data.clone();
+                if !data.is_object() {
+                    data = serde_json::json!({});
+                }
+                data["node_type"] = serde_json::json!(metadata.node_type);
+                inputs.insert("_data".to_string(), data);
+
+                let outputs = executor
+                    .execute_task(
+                        &format!("{}-example", metadata.node_type),
+                        inputs,
+                        &graph_flow::Context::new(),
+                        &extensions,
+                    )
+                    .await
+                    .unwrap_or_else(|e| {
+                        panic!("{} example '{}': {}", metadata.node_type, example.title, e)
+                    });
+                for (port, expected) in &example.outputs {
+                    assert_eq!(
+                        outputs.get(port),
+                        Some(expected),
+                        "{} example '{}' output '{}'",
+                        metadata.node_type,
+                        example.title,
+                        port
+                    );
+                }
+                checked += 1;
+            }
+        }
+        assert!(checked > 0);
     }
 }
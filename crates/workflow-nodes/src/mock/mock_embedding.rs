@@ -0,0 +1,109 @@
+//! Mock Embedding Task
+//!
+//! Embeds text as a repeatable hash-derived vector instead of a model.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+/// Mock Embedding Task
+///
+/// # Node Data
+/// - `dimensions` - Vector length, 1 to 4096 (default 8)
+/// - `embedding` - Fixed vector to return for every input
+/// - `latency_ms` - Simulated embedding time on the executor clock
+///
+/// Ports match `embedding`, so the node can stand in for it in example
+/// graphs.
+///
+/// Execution is handled by `CoreTaskExecutor`.
+#[derive(Clone)]
+pub struct MockEmbeddingTask {
+    /// Unique identifier for this task instance
+    task_id: String,
+}
+
+impl MockEmbeddingTask {
+    /// Port ID for text input
+    pub const PORT_TEXT: &'static str = "text";
+    /// Port ID for embedding output
+    pub const PORT_EMBEDDING: &'static str = "embedding";
+    /// Port ID for metadata output
+    pub const PORT_METADATA: &'static str = "metadata";
+
+    /// Create a new mock embedding task
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for MockEmbeddingTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "mock-embedding".to_string(),
+            category: NodeCategory::Processing,
+            label: "Mock Embedding".to_string(),
+            description: "Embeds text as a repeatable vector instead of a model".to_string(),
+            inputs: vec![PortMetadata::required(
+                Self::PORT_TEXT,
+                "Text",
+                PortDataType::String,
+            )],
+            outputs: vec![
+                PortMetadata::optional(Self::PORT_EMBEDDING, "Embedding", PortDataType::Embedding),
+                PortMetadata::optional(Self::PORT_METADATA, "Metadata", PortDataType::Json),
+            ],
+            execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Stands in for `embedding` without a model. Returns a unit vector \
+                 of `dimensions` numbers derived from a hash of `text`, so the same \
+                 text always gets the same vector and retrieval examples are \
+                 repeatable. Similar texts are not close; only identical texts are.\n\n\
+                 Set `embedding` to return a fixed vector instead, and `latency_ms` \
+                 to wait on the executor clock.",
+            )
+            .example(
+                NodeExample::new("Fixed vector")
+                    .data(json!({"embedding": [0.6, 0.8]}))
+                    .input(Self::PORT_TEXT, json!("hello"))
+                    .output(Self::PORT_EMBEDDING, json!([0.6, 0.8])),
+            ),
+            ui: NodeUiHints::icon("bot"),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(MockEmbeddingTask::descriptor));
+
+#[async_trait]
+impl Task for MockEmbeddingTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        // Execution handled by CoreTaskExecutor::execute_mock_embedding
+        Err(GraphError::TaskExecutionFailed(
+            "mock-embedding requires CoreTaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor() {
+        let meta = MockEmbeddingTask::descriptor();
+        assert_eq!(meta.node_type, "mock-embedding");
+        assert_eq!(meta.inputs[0].id, "text");
+        assert_eq!(meta.outputs[0].data_type, PortDataType::Embedding);
+    }
+}
@@ -0,0 +1,120 @@
+//! Mock HTTP Task
+//!
+//! Returns a canned HTTP response without making a request.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+/// Mock HTTP Task
+///
+/// # Node Data
+/// - `status` - Response status code (default 200)
+/// - `response_body` - Response body; strings are templates over the inputs
+/// - `headers` - Response headers object (default empty)
+/// - `latency_ms` - Simulated round-trip time on the executor clock
+///
+/// Execution is handled by `CoreTaskExecutor`.
+#[derive(Clone)]
+pub struct MockHttpTask {
+    /// Unique identifier for this task instance
+    task_id: String,
+}
+
+impl MockHttpTask {
+    /// Port ID for URL input
+    pub const PORT_URL: &'static str = "url";
+    /// Port ID for request body input and response body output
+    pub const PORT_BODY: &'static str = "body";
+    /// Port ID for status output
+    pub const PORT_STATUS: &'static str = "status";
+    /// Port ID for success flag output
+    pub const PORT_OK: &'static str = "ok";
+    /// Port ID for headers output
+    pub const PORT_HEADERS: &'static str = "headers";
+
+    /// Create a new mock HTTP task
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for MockHttpTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "mock-http".to_string(),
+            category: NodeCategory::Tool,
+            label: "Mock HTTP".to_string(),
+            description: "Returns a canned HTTP response without a request".to_string(),
+            inputs: vec![
+                PortMetadata::optional(Self::PORT_URL, "URL", PortDataType::String),
+                PortMetadata::optional(Self::PORT_BODY, "Request Body", PortDataType::Any),
+            ],
+            outputs: vec![
+                PortMetadata::optional(Self::PORT_STATUS, "Status", PortDataType::Number),
+                PortMetadata::optional(Self::PORT_OK, "OK", PortDataType::Boolean),
+                PortMetadata::optional(Self::PORT_BODY, "Body", PortDataType::Any),
+                PortMetadata::optional(Self::PORT_HEADERS, "Headers", PortDataType::Json),
+            ],
+            execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Returns `status`, `headers`, and `response_body` without touching \
+                 the network; `ok` is true for 2xx statuses. A string body is a \
+                 template whose `{{url}}`, `{{body}}`, and other `{{name}}` \
+                 placeholders are filled from the inputs or node data; JSON bodies \
+                 are returned unchanged.\n\n\
+                 `latency_ms` waits on the executor clock to mimic a round trip.",
+            )
+            .example(
+                NodeExample::new("Canned JSON API")
+                    .data(json!({"response_body": {"temperature": 21}}))
+                    .input(Self::PORT_URL, json!("https://api.example.com/weather"))
+                    .output(Self::PORT_STATUS, json!(200))
+                    .output(Self::PORT_BODY, json!({"temperature": 21})),
+            )
+            .example(
+                NodeExample::new("Not found")
+                    .data(json!({"status": 404, "response_body": "No route for {{url}}"}))
+                    .input(Self::PORT_URL, json!("/missing"))
+                    .output(Self::PORT_OK, json!(false))
+                    .output(Self::PORT_BODY, json!("No route for /missing")),
+            ),
+            ui: NodeUiHints::icon("globe"),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(MockHttpTask::descriptor));
+
+#[async_trait]
+impl Task for MockHttpTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        // Execution handled by CoreTaskExecutor::execute_mock_http
+        Err(GraphError::TaskExecutionFailed(
+            "mock-http requires CoreTaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor() {
+        let meta = MockHttpTask::descriptor();
+        assert_eq!(meta.node_type, "mock-http");
+        assert_eq!(meta.category, NodeCategory::Tool);
+        assert_eq!(meta.outputs.len(), 4);
+    }
+}
@@ -0,0 +1,132 @@
+//! Mock LLM Task
+//!
+//! Answers prompts with canned or templated text instead of a model.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+/// Mock LLM Task
+///
+/// # Node Data
+/// - `response` - Reply template (default `Mock response to: {{prompt}}`)
+/// - `responses` - Object mapping exact prompts to reply templates
+/// - `tool_calls` - Tool calls to return (default none)
+/// - `latency_ms` - Simulated generation time on the executor clock
+///
+/// Templates fill `{{name}}` from the inputs or node data. Ports match
+/// `llm-inference`, so the node can stand in for it in example graphs.
+///
+/// Execution is handled by `CoreTaskExecutor`.
+#[derive(Clone)]
+pub struct MockLlmTask {
+    /// Unique identifier for this task instance
+    task_id: String,
+}
+
+impl MockLlmTask {
+    /// Port ID for prompt input
+    pub const PORT_PROMPT: &'static str = "prompt";
+    /// Port ID for system prompt input
+    pub const PORT_SYSTEM_PROMPT: &'static str = "system_prompt";
+    /// Port ID for context input
+    pub const PORT_CONTEXT: &'static str = "context";
+    /// Port ID for response output
+    pub const PORT_RESPONSE: &'static str = "response";
+    /// Port ID for tool calls output
+    pub const PORT_TOOL_CALLS: &'static str = "tool_calls";
+    /// Port ID for has-tool-calls output
+    pub const PORT_HAS_TOOL_CALLS: &'static str = "has_tool_calls";
+
+    /// Create a new mock LLM task
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for MockLlmTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "mock-llm".to_string(),
+            category: NodeCategory::Processing,
+            label: "Mock LLM".to_string(),
+            description: "Answers prompts with canned text instead of a model".to_string(),
+            inputs: vec![
+                PortMetadata::required(Self::PORT_PROMPT, "Prompt", PortDataType::Prompt),
+                PortMetadata::optional(
+                    Self::PORT_SYSTEM_PROMPT,
+                    "System Prompt",
+                    PortDataType::String,
+                ),
+                PortMetadata::optional(Self::PORT_CONTEXT, "Context", PortDataType::String),
+            ],
+            outputs: vec![
+                PortMetadata::optional(Self::PORT_RESPONSE, "Response", PortDataType::String),
+                PortMetadata::optional(Self::PORT_TOOL_CALLS, "Tool Calls", PortDataType::Json),
+                PortMetadata::optional(
+                    Self::PORT_HAS_TOOL_CALLS,
+                    "Has Tool Calls",
+                    PortDataType::Boolean,
+                ),
+            ],
+            execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Stands in for `llm-inference` without a model. Replies with the \
+                 `responses` entry for the exact prompt, otherwise with the \
+                 `response` template; `{{prompt}}`, `{{context}}`, and any other \
+                 `{{name}}` are filled from the inputs or node data.\n\n\
+                 `tool_calls` returns fixed tool calls for testing agent loops, and \
+                 `latency_ms` waits on the executor clock to mimic generation time.",
+            )
+            .example(
+                NodeExample::new("Echo the prompt")
+                    .data(json!({"response": "You asked: {{prompt}}"}))
+                    .input(Self::PORT_PROMPT, json!("What is Pantograph?"))
+                    .output(Self::PORT_RESPONSE, json!("You asked: What is Pantograph?")),
+            )
+            .example(
+                NodeExample::new("Canned answer per prompt")
+                    .data(json!({"responses": {"ping": "pong"}}))
+                    .input(Self::PORT_PROMPT, json!("ping"))
+                    .output(Self::PORT_RESPONSE, json!("pong")),
+            ),
+            ui: NodeUiHints::icon("bot"),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(MockLlmTask::descriptor));
+
+#[async_trait]
+impl Task for MockLlmTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        // Execution handled by CoreTaskExecutor::execute_mock_llm
+        Err(GraphError::TaskExecutionFailed(
+            "mock-llm requires CoreTaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor() {
+        let meta = MockLlmTask::descriptor();
+        assert_eq!(meta.node_type, "mock-llm");
+        assert_eq!(meta.category, NodeCategory::Processing);
+        assert_eq!(meta.inputs[0].id, "prompt");
+        assert_eq!(meta.outputs[0].id, "response");
+    }
+}
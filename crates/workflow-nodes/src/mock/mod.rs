@@ -0,0 +1,13 @@
+//! Mock nodes
+//!
+//! Stand-ins for model and network backends that return canned or templated
+//! responses, so example graphs and tutorials run without model downloads.
+//! Compiled with the `mock` feature.
+
+mod mock_embedding;
+mod mock_http;
+mod mock_llm;
+
+pub use mock_embedding::MockEmbeddingTask;
+pub use mock_http::MockHttpTask;
+pub use mock_llm::MockLlmTask;
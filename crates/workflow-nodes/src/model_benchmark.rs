@@ -0,0 +1,92 @@
+//! Benchmark result storage in Pumas model libraries.
+//!
+//! [`PumasBenchmarkStore`] backs `model-benchmark` nodes. It merges each
+//! result into the model's `benchmarks` metadata, keyed by backend, and
+//! writes the merged map back as a model review patch so earlier results for
+//! other backends are kept. Models held by a read-only root are not written.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use node_engine::model_benchmark::BENCHMARKS_METADATA_KEY;
+use node_engine::{extension_keys, ExecutorExtensions, ModelBenchmarkResult, ModelBenchmarkStore};
+use pumas_library::PumasApi;
+
+use crate::model_library::ModelLibraryRoots;
+
+/// Reviewer recorded on benchmark metadata patches.
+pub const BENCHMARK_REVIEWER: &str = "pantograph-benchmark";
+
+/// The library benchmark results are written to.
+#[derive(Clone)]
+pub enum PumasBenchmarkStore {
+    Single(Arc<PumasApi>),
+    Merged(Arc<ModelLibraryRoots>),
+}
+
+impl PumasBenchmarkStore {
+    /// Install this store under [`extension_keys::MODEL_BENCHMARK_STORE`].
+    pub fn install(self, extensions: &mut ExecutorExtensions) {
+        let store: Arc<dyn ModelBenchmarkStore> = Arc::new(self);
+        extensions.set(extension_keys::MODEL_BENCHMARK_STORE, store);
+    }
+
+    /// The API of the library holding `model_id` and the model's metadata.
+    async fn locate(&self, model_id: &str) -> Result<(Arc<PumasApi>, serde_json::Value), String> {
+        match self {
+            Self::Single(api) => {
+                let record = api
+                    .get_model(model_id)
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .ok_or_else(|| format!("Model '{}' not found in library", model_id))?;
+                Ok((api.clone(), record.metadata))
+            }
+            Self::Merged(roots) => {
+                let model = roots
+                    .get_model(model_id)
+                    .await?
+                    .ok_or_else(|| format!("Model '{}' not found in any library", model_id))?;
+                if model.read_only {
+                    return Err(format!(
+                        "Model '{}' is held by read-only library {:?}",
+                        model_id, model.library_root
+                    ));
+                }
+                Ok((
+                    roots.roots()[model.root_index].api.clone(),
+                    model.record.metadata,
+                ))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ModelBenchmarkStore for PumasBenchmarkStore {
+    async fn store_benchmark(
+        &self,
+        model_id: &str,
+        result: &ModelBenchmarkResult,
+    ) -> Result<(), String> {
+        let (api, metadata) = self.locate(model_id).await?;
+        let patch = serde_json::json!({
+            BENCHMARKS_METADATA_KEY: result.merged_benchmarks(&metadata),
+        });
+        api.submit_model_review(
+            model_id,
+            patch,
+            BENCHMARK_REVIEWER,
+            Some("model-benchmark run"),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        log::info!(
+            "Stored {} benchmark for '{}': {:.1} tok/s",
+            result.backend,
+            model_id,
+            result.tokens_per_second
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+//! Garbage collection for Pumas model libraries.
+//!
+//! Candidates come from [`ModelUsageLog::unused_models`]: library models that
+//! are unpinned, unreferenced by the stored graphs the host passes in, and idle
+//! for the requested number of days. Collection deletes them through
+//! `PumasApi::delete_model_with_cascade`, never touching read-only roots.
+
+use std::collections::{BTreeSet, HashMap};
+
+use node_engine::model_usage::now_ms;
+use node_engine::{ModelUsageLog, UnusedModel};
+use pumas_library::PumasApi;
+use serde::Serialize;
+
+use crate::model_library::ModelLibraryRoots;
+
+/// The library a collection runs against.
+#[derive(Clone, Copy)]
+pub enum ModelLibraryView<'a> {
+    Single(&'a PumasApi),
+    Merged(&'a ModelLibraryRoots),
+}
+
+impl ModelLibraryView<'_> {
+    /// Model IDs with the index of the root holding them, `None` for a
+    /// single library.
+    async fn model_roots(&self) -> Result<HashMap<String, Option<usize>>, String> {
+        Ok(match self {
+            Self::Single(api) => api
+                .list_models()
+                .await
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .map(|record| (record.id, None))
+                .collect(),
+            Self::Merged(roots) => roots
+                .list_models()
+                .await?
+                .into_iter()
+                .map(|model| (model.record.id, Some(model.root_index)))
+                .collect(),
+        })
+    }
+}
+
+/// A model that could not be deleted.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelGcFailure {
+    pub model_id: String,
+    pub error: String,
+}
+
+/// Outcome of [`collect_unused_models`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModelGcReport {
+    pub dry_run: bool,
+    /// Every eligible model, including ones a dry run would delete.
+    pub candidates: Vec<UnusedModel>,
+    pub deleted: Vec<String>,
+    /// Candidates held by a read-only library root.
+    pub skipped_read_only: Vec<String>,
+    pub failed: Vec<ModelGcFailure>,
+}
+
+/// Library models eligible for collection, sorted by model ID.
+pub async fn unused_models(
+    library: ModelLibraryView<'_>,
+    usage: &ModelUsageLog,
+    referenced: &BTreeSet<String>,
+    unused_for_days: u32,
+) -> Result<Vec<UnusedModel>, String> {
+    let mut ids: Vec<String> = library.model_roots().await?.into_keys().collect();
+    ids.sort();
+    usage
+        .unused_models(&ids, referenced, unused_for_days, now_ms())
+        .map_err(|e| e.to_string())
+}
+
+/// Delete unused models, or only report them when `dry_run` is set.
+pub async fn collect_unused_models(
+    library: ModelLibraryView<'_>,
+    usage: &ModelUsageLog,
+    referenced: &BTreeSet<String>,
+    unused_for_days: u32,
+    dry_run: bool,
+) -> Result<ModelGcReport, String> {
+    let model_roots = library.model_roots().await?;
+    let mut ids: Vec<String> = model_roots.keys().cloned().collect();
+    ids.sort();
+    let candidates = usage
+        .unused_models(&ids, referenced, unused_for_days, now_ms())
+        .map_err(|e| e.to_string())?;
+
+    let mut report = ModelGcReport {
+        dry_run,
+        ..ModelGcReport::default()
+    };
+    for candidate in &candidates {
+        let model_id = &candidate.model_id;
+        let api = match (library, model_roots.get(model_id).copied().flatten()) {
+            (ModelLibraryView::Merged(roots), Some(index)) => {
+                let root = &roots.roots()[index];
+                if root.read_only {
+                    report.skipped_read_only.push(model_id.clone());
+                    continue;
+                }
+                root.api.as_ref()
+            }
+            (ModelLibraryView::Single(api), _) => api,
+            (ModelLibraryView::Merged(roots), None) => roots.primary().api.as_ref(),
+        };
+        if dry_run {
+            continue;
+        }
+
+        match api.delete_model_with_cascade(model_id).await {
+            Ok(result) if result.success => {
+                log::info!("Deleted unused model '{}'", model_id);
+                if let Err(e) = usage.forget(model_id) {
+                    log::warn!("Failed to update model usage for '{}': {}", model_id, e);
+                }
+                report.deleted.push(model_id.clone());
+            }
+            Ok(result) => report.failed.push(ModelGcFailure {
+                model_id: model_id.clone(),
+                error: result
+                    .error
+                    .unwrap_or_else(|| "Delete was not successful".to_string()),
+            }),
+            Err(e) => report.failed.push(ModelGcFailure {
+                model_id: model_id.clone(),
+                error: e.to_string(),
+            }),
+        }
+    }
+    report.candidates = candidates;
+    Ok(report)
+}
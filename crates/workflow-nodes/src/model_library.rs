@@ -0,0 +1,218 @@
+//! Merged view over several Pumas model library roots.
+//!
+//! `PumasApi` serves one library root. Deployments that keep models on a
+//! shared network volume plus a local cache open one API per root and merge
+//! them here: listings and searches combine every root, and when two roots
+//! hold the same model ID the higher-priority root's record wins.
+//! Downloads, imports, and index rebuilds go to [`ModelLibraryRoots::primary`],
+//! the highest-priority writable root.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use node_engine::config::LibraryRootConfig;
+use node_engine::{extension_keys, ExecutorExtensions};
+use pumas_library::{ModelRecord, PumasApi};
+use serde::Serialize;
+
+/// One opened library root.
+pub struct LibraryRoot {
+    pub path: PathBuf,
+    pub read_only: bool,
+    pub priority: i32,
+    pub api: Arc<PumasApi>,
+}
+
+/// A model record and the root it was read from.
+#[derive(Serialize)]
+pub struct RootedModel {
+    #[serde(flatten)]
+    pub record: ModelRecord,
+    pub library_root: PathBuf,
+    pub read_only: bool,
+    /// Index into [`ModelLibraryRoots::roots`].
+    #[serde(skip)]
+    pub root_index: usize,
+}
+
+/// Merged search results.
+#[derive(Serialize)]
+pub struct MergedSearchResult {
+    pub models: Vec<RootedModel>,
+    /// Distinct models found across roots before `offset` and `limit`,
+    /// counting at most `offset + limit` from each root.
+    pub total_count: usize,
+}
+
+/// Library roots ordered by descending priority.
+pub struct ModelLibraryRoots {
+    roots: Vec<LibraryRoot>,
+}
+
+impl ModelLibraryRoots {
+    /// Order `roots` by descending priority, keeping the given order for ties.
+    pub fn new(mut roots: Vec<LibraryRoot>) -> Result<Self, String> {
+        if roots.is_empty() {
+            return Err("At least one model library root is required".to_string());
+        }
+        roots.sort_by_key(|root| std::cmp::Reverse(root.priority));
+        Ok(Self { roots })
+    }
+
+    /// Open a `PumasApi` for every configured root.
+    ///
+    /// Writable roots create missing directories; read-only roots must
+    /// already exist. Only the primary root gets a HuggingFace client, and
+    /// only when `with_hf_client` is set, since it is the only download target.
+    pub async fn open(configs: &[LibraryRootConfig], with_hf_client: bool) -> Result<Self, String> {
+        let mut ordered: Vec<&LibraryRootConfig> = configs.iter().collect();
+        ordered.sort_by_key(|config| std::cmp::Reverse(config.priority));
+        let primary = ordered
+            .iter()
+            .position(|config| !config.read_only)
+            .unwrap_or(0);
+
+        let mut roots = Vec::with_capacity(ordered.len());
+        for (index, config) in ordered.into_iter().enumerate() {
+            let api = PumasApi::builder(&config.path)
+                .auto_create_dirs(!config.read_only)
+                .with_hf_client(with_hf_client && index == primary)
+                .with_process_manager(false)
+                .build()
+                .await
+                .map_err(|e| format!("Cannot open model library {:?}: {}", config.path, e))?;
+            log::info!(
+                "Opened model library root {:?} (priority {}, read_only {})",
+                config.path,
+                config.priority,
+                config.read_only
+            );
+            roots.push(LibraryRoot {
+                path: config.path.clone(),
+                read_only: config.read_only,
+                priority: config.priority,
+                api: Arc::new(api),
+            });
+        }
+        Self::new(roots)
+    }
+
+    /// Roots in priority order.
+    pub fn roots(&self) -> &[LibraryRoot] {
+        &self.roots
+    }
+
+    /// The highest-priority writable root, or the highest-priority root when
+    /// every root is read-only. Single-library callers use its API.
+    pub fn primary(&self) -> &LibraryRoot {
+        self.roots
+            .iter()
+            .find(|root| !root.read_only)
+            .unwrap_or(&self.roots[0])
+    }
+
+    fn rooted(&self, root_index: usize, record: ModelRecord) -> RootedModel {
+        let root = &self.roots[root_index];
+        RootedModel {
+            record,
+            library_root: root.path.clone(),
+            read_only: root.read_only,
+            root_index,
+        }
+    }
+
+    /// Every model across roots, deduplicated by ID in priority order.
+    pub async fn list_models(&self) -> Result<Vec<RootedModel>, String> {
+        let mut per_root = Vec::with_capacity(self.roots.len());
+        for root in &self.roots {
+            per_root.push(root.api.list_models().await.map_err(|e| e.to_string())?);
+        }
+        Ok(merge_by_priority(per_root, |record| record.id.as_str())
+            .into_iter()
+            .map(|(root_index, record)| self.rooted(root_index, record))
+            .collect())
+    }
+
+    /// Search every root and page through the merged results.
+    pub async fn search_models(
+        &self,
+        query: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<MergedSearchResult, String> {
+        let mut per_root = Vec::with_capacity(self.roots.len());
+        for root in &self.roots {
+            let result = root
+                .api
+                .search_models(query, offset + limit, 0)
+                .await
+                .map_err(|e| e.to_string())?;
+            per_root.push(result.models);
+        }
+        let merged = merge_by_priority(per_root, |record| record.id.as_str());
+        let total_count = merged.len();
+        Ok(MergedSearchResult {
+            models: merged
+                .into_iter()
+                .skip(offset)
+                .take(limit)
+                .map(|(root_index, record)| self.rooted(root_index, record))
+                .collect(),
+            total_count,
+        })
+    }
+
+    /// The model from the highest-priority root that holds `model_id`.
+    pub async fn get_model(&self, model_id: &str) -> Result<Option<RootedModel>, String> {
+        for (root_index, root) in self.roots.iter().enumerate() {
+            if let Some(record) = root
+                .api
+                .get_model(model_id)
+                .await
+                .map_err(|e| e.to_string())?
+            {
+                return Ok(Some(self.rooted(root_index, record)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Install `roots` and its primary root's API, so single-library consumers
+/// keep working unchanged, plus a benchmark store writing to whichever root
+/// holds each model.
+pub fn install_library_roots(extensions: &mut ExecutorExtensions, roots: Arc<ModelLibraryRoots>) {
+    crate::model_benchmark::PumasBenchmarkStore::Merged(roots.clone()).install(extensions);
+    extensions.set(extension_keys::PUMAS_API, roots.primary().api.clone());
+    extensions.set(extension_keys::PUMAS_LIBRARY_ROOTS, roots);
+}
+
+/// Concatenate per-root items in root order, dropping items whose ID an
+/// earlier root already produced.
+fn merge_by_priority<T>(per_root: Vec<Vec<T>>, id: impl Fn(&T) -> &str) -> Vec<(usize, T)> {
+    let mut seen = HashSet::new();
+    let mut merged = Vec::new();
+    for (root_index, items) in per_root.into_iter().enumerate() {
+        for item in items {
+            if seen.insert(id(&item).to_string()) {
+                merged.push((root_index, item));
+            }
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_keeps_the_first_root_for_duplicate_ids() {
+        let merged =
+            merge_by_priority(vec![vec!["a", "b"], vec!["b", "c"], vec!["a", "d"]], |id| {
+                id
+            });
+        assert_eq!(merged, vec![(0, "a"), (0, "b"), (1, "c"), (2, "d")]);
+    }
+}
@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Audio Output Task
 ///
@@ -70,6 +71,16 @@ impl TaskDescriptor for AudioOutputTask {
                 PortDataType::Audio,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Plays the connected `audio` and passes it through on `audio` \
+                 unchanged, or null when nothing is connected.",
+            )
+            .example(
+                NodeExample::new("Generated clip")
+                    .input(Self::PORT_AUDIO, json!("UklGRiQAAABXQVZF"))
+                    .output(Self::PORT_AUDIO, json!("UklGRiQAAABXQVZF")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
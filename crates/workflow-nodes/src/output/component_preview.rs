@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Component Preview Task
 ///
@@ -68,6 +69,21 @@ impl TaskDescriptor for ComponentPreviewTask {
                 PortDataType::Component,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Renders the connected Svelte `component` with `props` (default \
+                 `{}`) in the editor, and emits both on `rendered` as \
+                 `{component, props}`.",
+            )
+            .example(
+                NodeExample::new("Heading with a prop")
+                    .input(Self::PORT_COMPONENT, json!("<h1>{title}</h1>"))
+                    .input(Self::PORT_PROPS, json!({"title": "Hi"}))
+                    .output(
+                        Self::PORT_RENDERED,
+                        json!({"component": "<h1>{title}</h1>", "props": {"title": "Hi"}}),
+                    ),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Image Output Task
 ///
@@ -66,6 +67,22 @@ impl TaskDescriptor for ImageOutputTask {
                 PortDataType::Image,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Shows the connected `image` and passes it through on `image` \
+                 unchanged, or null when nothing is connected.",
+            )
+            .example(
+                NodeExample::new("Generated image")
+                    .input(
+                        Self::PORT_IMAGE,
+                        json!("data:image/png;base64,iVBORw0KGgo="),
+                    )
+                    .output(
+                        Self::PORT_IMAGE,
+                        json!("data:image/png;base64,iVBORw0KGgo="),
+                    ),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
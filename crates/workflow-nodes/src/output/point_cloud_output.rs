@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Point Cloud Output Task
 ///
@@ -64,6 +65,17 @@ impl TaskDescriptor for PointCloudOutputTask {
             ],
             outputs: vec![],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Renders the connected `point_cloud` JSON as a 3D view and passes it \
+                 through unchanged. `source_image` optionally carries the image the \
+                 cloud was derived from, such as a depth-estimation input.",
+            )
+            .example(
+                NodeExample::new("Single point")
+                    .input(Self::PORT_POINT_CLOUD, json!({"points": [[0.0, 0.0, 1.0]]}))
+                    .output(Self::PORT_POINT_CLOUD, json!({"points": [[0.0, 0.0, 1.0]]})),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Text Output Task
 ///
@@ -65,6 +66,17 @@ impl TaskDescriptor for TextOutputTask {
                 PortDataType::String,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Shows the connected `text` in the editor and passes it through on \
+                 `text`, so a run's result is both visible and returned to callers. \
+                 A missing or non-string input shows as empty text.",
+            )
+            .example(
+                NodeExample::new("Final answer")
+                    .input(Self::PORT_TEXT, json!("Done."))
+                    .output(Self::PORT_TEXT, json!("Done.")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -5,9 +5,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Vector Output Task
 ///
@@ -53,6 +54,17 @@ impl TaskDescriptor for VectorOutputTask {
                 PortDataType::Embedding,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Shows the connected `vector` and passes it through. A string \
+                 holding a JSON array is parsed. A malformed vector becomes null and \
+                 logs a warning instead of failing the run.",
+            )
+            .example(
+                NodeExample::new("Embedding result")
+                    .input(Self::PORT_VECTOR, json!([0.5, -0.5]))
+                    .output(Self::PORT_VECTOR, json!([0.5, -0.5])),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -0,0 +1,130 @@
+//! Aggregate Task
+//!
+//! Reduces a JSON array to a single value: count, sum/avg over a field,
+//! min/max, distinct values, group-by, or string concatenation. Together with
+//! fan-out nodes this covers fan-in data handling without host callbacks.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+/// Aggregate Task
+///
+/// # Node Data
+/// - `operation` - One of `count` (default), `sum`, `avg`, `min`, `max`,
+///   `distinct`, `group-by`, or `concat`
+/// - `field` - Element field in `json-filter` path notation; empty selects the
+///   element itself
+/// - `separator` - Separator for `concat` (default `", "`)
+///
+/// # Outputs
+/// - `result` - Aggregated value; `group-by` emits `[{ key, count, items }]`
+/// - `count` - Number of input elements
+///
+/// Execution is handled by `CoreTaskExecutor`.
+#[derive(Clone)]
+pub struct AggregateTask {
+    /// Unique identifier for this task instance
+    task_id: String,
+}
+
+impl AggregateTask {
+    /// Port ID for items input
+    pub const PORT_ITEMS: &'static str = "items";
+    /// Port ID for result output
+    pub const PORT_RESULT: &'static str = "result";
+    /// Port ID for count output
+    pub const PORT_COUNT: &'static str = "count";
+
+    /// Create a new aggregate task
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for AggregateTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "aggregate".to_string(),
+            category: NodeCategory::Processing,
+            label: "Aggregate".to_string(),
+            description: "Counts, sums, groups, or concatenates values from a JSON array"
+                .to_string(),
+            inputs: vec![PortMetadata::required(
+                Self::PORT_ITEMS,
+                "Items",
+                PortDataType::Json,
+            )],
+            outputs: vec![
+                PortMetadata::required(Self::PORT_RESULT, "Result", PortDataType::Any),
+                PortMetadata::optional(Self::PORT_COUNT, "Count", PortDataType::Number),
+            ],
+            execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Reduces the `items` array with `operation`: `count` (default), \
+                 `sum`, `avg`, `min`, `max`, `distinct`, `group-by`, or `concat` \
+                 (joined with `separator`, default `, `). `field` is a `json-filter` \
+                 path into each element; empty uses the element itself.\n\n\
+                 Elements without the field, or with null, are skipped; `count` \
+                 always reports the input length. `sum` and `avg` fail on \
+                 non-numeric values.",
+            )
+            .example(
+                NodeExample::new("Total price")
+                    .data(json!({"operation": "sum", "field": "price"}))
+                    .input(
+                        Self::PORT_ITEMS,
+                        json!([{"price": 2}, {"price": 3.5}, {"name": "gift"}]),
+                    )
+                    .output(Self::PORT_RESULT, json!(5.5))
+                    .output(Self::PORT_COUNT, json!(3)),
+            ),
+            ui: NodeUiHints::default(),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(AggregateTask::descriptor));
+
+#[async_trait]
+impl Task for AggregateTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        // Execution handled by CoreTaskExecutor::execute_aggregate
+        Err(GraphError::TaskExecutionFailed(
+            "aggregate requires CoreTaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor_node_type() {
+        let meta = AggregateTask::descriptor();
+        assert_eq!(meta.node_type, "aggregate");
+        assert_eq!(meta.category, NodeCategory::Processing);
+        assert_eq!(meta.execution_mode, ExecutionMode::Reactive);
+    }
+
+    #[test]
+    fn test_descriptor_ports() {
+        let meta = AggregateTask::descriptor();
+        assert_eq!(meta.inputs.len(), 1);
+        assert_eq!(meta.inputs[0].id, "items");
+        assert_eq!(meta.outputs.len(), 2);
+        assert!(meta.outputs.iter().any(|p| p.id == "result"));
+        assert!(meta.outputs.iter().any(|p| p.id == "count"));
+    }
+}
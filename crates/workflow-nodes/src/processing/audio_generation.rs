@@ -8,7 +8,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 const PORT_MODEL_PATH: &str = "model_path";
@@ -71,6 +72,13 @@ impl TaskDescriptor for AudioGenerationTask {
                 PortMetadata::optional(PORT_MODEL_REF, "Model Reference", PortDataType::Json),
             ],
             execution_mode: ExecutionMode::Batch,
+            docs: NodeDocs::new(
+                "Generates a clip from a text `prompt` with a Stable Audio model at \
+                 `model_path`. `duration`, `num_inference_steps`, `guidance_scale`, and `seed` \
+                 tune the sampler. The node runs to completion before emitting `audio` along \
+                 with its duration and sample rate.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
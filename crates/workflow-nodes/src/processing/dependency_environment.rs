@@ -8,7 +8,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 const PORT_MODEL_PATH: &str = "model_path";
@@ -85,6 +86,14 @@ impl TaskDescriptor for DependencyEnvironmentTask {
                 ),
             ],
             execution_mode: ExecutionMode::Batch,
+            docs: NodeDocs::new(
+                "Resolves the Python dependencies a model needs and emits an \
+                 `environment_ref` that the PyTorch, ONNX, diffusion, and audio nodes run in. \
+                 Wire the model outputs of `puma-lib` into it. In `auto` mode missing \
+                 dependencies are installed; `manual` mode only checks them and reports \
+                 `dependency_status`.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
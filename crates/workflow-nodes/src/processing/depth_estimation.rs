@@ -8,7 +8,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 const PORT_IMAGE: &str = "image";
@@ -54,6 +55,12 @@ impl TaskDescriptor for DepthEstimationTask {
                 PortMetadata::optional(PORT_FOCAL_LENGTH, "Focal Length", PortDataType::Number),
             ],
             execution_mode: ExecutionMode::Batch,
+            docs: NodeDocs::new(
+                "Estimates per-pixel depth for `image` with an Apple DepthPro model at \
+                 `model_path`. Emits the `depth_map` as an image, a `point_cloud` for 3D \
+                 previews, and the estimated `focal_length`.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -8,7 +8,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 const PORT_MODEL_PATH: &str = "model_path";
@@ -77,6 +78,13 @@ impl TaskDescriptor for DiffusionInferenceTask {
                 PortMetadata::optional(PORT_STREAM, "Stream", PortDataType::Stream),
             ],
             execution_mode: ExecutionMode::Stream,
+            docs: NodeDocs::new(
+                "Generates an image from `prompt` with the diffusion model at \
+                 `model_path` (Stable Diffusion, SDXL, or Flux). `negative_prompt`, `steps`, \
+                 `cfg_scale`, `width`, and `height` tune the sampler; previews stream while it \
+                 runs. `seed_used` reports the seed so a result can be reproduced.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -6,8 +6,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
 
@@ -99,11 +99,24 @@ impl TaskDescriptor for EmbeddingTask {
                 PortMetadata::optional(Self::PORT_METADATA, "Metadata", PortDataType::Json),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Embeds `text` with the llama.cpp server's embedding endpoint and emits \
+                 the vector on `embedding`. `metadata` reports the model and vector size. The \
+                 server must be running an embedding model; empty text fails the node.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
 
 inventory::submit!(node_engine::DescriptorFn(EmbeddingTask::descriptor));
+inventory::submit!(node_engine::NodeCostAnnotation {
+    node_type: "embedding",
+    api_calls: 1,
+    min_wall_time_ms: 50,
+    max_wall_time_ms: 5_000,
+    default_output_tokens: None,
+});
 
 #[async_trait]
 impl Task for EmbeddingTask {
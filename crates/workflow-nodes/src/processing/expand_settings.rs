@@ -8,8 +8,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 /// Expand Settings Task
 ///
@@ -62,6 +64,27 @@ impl TaskDescriptor for ExpandSettingsTask {
                 // syncExpandPorts()
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Passes the `inference_settings` schema from a model provider \
+                 through unchanged and gives each parameter its own output port, \
+                 named by its `key`. A port carries the connected or node-data \
+                 override, else the parameter's `default`; option labels resolve \
+                 to their `value`, and null values emit nothing.",
+            )
+            .example(
+                NodeExample::new("Override temperature")
+                    .data(json!({"temperature": 0.2}))
+                    .input(
+                        Self::PORT_INFERENCE_SETTINGS,
+                        json!([
+                            {"key": "temperature", "default": 0.7},
+                            {"key": "top_k", "default": 40}
+                        ]),
+                    )
+                    .output("temperature", json!(0.2))
+                    .output("top_k", json!(40)),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -0,0 +1,124 @@
+//! Filter Task
+//!
+//! Keeps the elements of a JSON array that satisfy a predicate expression
+//! such as `score >= 0.5 && label == 'relevant'`.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+/// Filter Task
+///
+/// # Node Data
+/// - `expression` - Predicate evaluated per element. Supports `==`, `!=`,
+///   `<`, `<=`, `>`, `>=`, `contains`, `&&`/`and`, `||`/`or`, `!`/`not`,
+///   parentheses, and number/string/boolean/null literals. Field paths use
+///   `json-filter` notation relative to the element; `@` is the element
+///   itself. Missing fields evaluate to `null`.
+///
+/// # Outputs
+/// - `items` - Elements that matched, in input order
+/// - `rejected` - Elements that did not match, in input order
+/// - `count` - Number of matched elements
+///
+/// Execution is handled by `CoreTaskExecutor`.
+#[derive(Clone)]
+pub struct FilterTask {
+    /// Unique identifier for this task instance
+    task_id: String,
+}
+
+impl FilterTask {
+    /// Port ID for items input and matched items output
+    pub const PORT_ITEMS: &'static str = "items";
+    /// Port ID for rejected items output
+    pub const PORT_REJECTED: &'static str = "rejected";
+    /// Port ID for count output
+    pub const PORT_COUNT: &'static str = "count";
+
+    /// Create a new filter task
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for FilterTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "filter".to_string(),
+            category: NodeCategory::Processing,
+            label: "Filter".to_string(),
+            description: "Keeps array elements that match a predicate expression".to_string(),
+            inputs: vec![PortMetadata::required(
+                Self::PORT_ITEMS,
+                "Items",
+                PortDataType::Json,
+            )],
+            outputs: vec![
+                PortMetadata::required(Self::PORT_ITEMS, "Items", PortDataType::Json),
+                PortMetadata::optional(Self::PORT_REJECTED, "Rejected", PortDataType::Json),
+                PortMetadata::optional(Self::PORT_COUNT, "Count", PortDataType::Number),
+            ],
+            execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Evaluates `expression` against every element of `items`. Supports \
+                 `==`, `!=`, `<`, `<=`, `>`, `>=`, `contains`, `&&`/`and`, \
+                 `||`/`or`, `!`/`not`, parentheses, and number, string, boolean, and \
+                 null literals. Field paths use `json-filter` notation relative to \
+                 the element, `@` is the element itself, and missing fields are \
+                 `null`.\n\n\
+                 Matches go to `items` and the rest to `rejected`, both in input \
+                 order.",
+            )
+            .example(
+                NodeExample::new("Keep relevant results")
+                    .data(json!({"expression": "score >= 0.5"}))
+                    .input(
+                        Self::PORT_ITEMS,
+                        json!([{"id": "a", "score": 0.9}, {"id": "b", "score": 0.4}]),
+                    )
+                    .output(Self::PORT_ITEMS, json!([{"id": "a", "score": 0.9}]))
+                    .output(Self::PORT_REJECTED, json!([{"id": "b", "score": 0.4}]))
+                    .output(Self::PORT_COUNT, json!(1)),
+            ),
+            ui: NodeUiHints::icon("filter"),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(FilterTask::descriptor));
+
+#[async_trait]
+impl Task for FilterTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        // Execution handled by CoreTaskExecutor::execute_filter
+        Err(GraphError::TaskExecutionFailed(
+            "filter requires CoreTaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor() {
+        let meta = FilterTask::descriptor();
+        assert_eq!(meta.node_type, "filter");
+        assert_eq!(meta.category, NodeCategory::Processing);
+        assert_eq!(meta.inputs.len(), 1);
+        assert_eq!(meta.outputs.len(), 3);
+        assert!(meta.outputs.iter().any(|p| p.id == "rejected"));
+    }
+}
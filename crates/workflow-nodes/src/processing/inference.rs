@@ -6,8 +6,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
 
@@ -177,11 +177,27 @@ impl TaskDescriptor for InferenceTask {
                 PortMetadata::optional(Self::PORT_STREAM, "Stream", PortDataType::Stream),
             ],
             execution_mode: ExecutionMode::Stream,
+            docs: NodeDocs::new(
+                "Runs `prompt`, with an optional `system_prompt` and extra `context`, \
+                 through the model loaded in the inference gateway and streams the reply; the \
+                 full text lands on `response`. Connected `tools` are offered to the model, and \
+                 any calls it makes appear on `tool_calls` with `has_tool_calls` set so a \
+                 `tool-executor` or `tool-loop` can run them. Requires the \
+                 `inference-nodes` feature and a started gateway.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
 
 inventory::submit!(node_engine::DescriptorFn(InferenceTask::descriptor));
+inventory::submit!(node_engine::NodeCostAnnotation {
+    node_type: "llm-inference",
+    api_calls: 1,
+    min_wall_time_ms: 500,
+    max_wall_time_ms: 60_000,
+    default_output_tokens: Some(1_024),
+});
 
 #[async_trait]
 impl Task for InferenceTask {
@@ -0,0 +1,126 @@
+//! Join Task
+//!
+//! Combines two JSON arrays, either element-wise (zip) or by matching a key
+//! field (inner/left join). Typical use is correlating retrieval results with
+//! scores produced by a reranker.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+/// Join Task
+///
+/// # Node Data
+/// - `mode` - `zip` (default), `inner`, or `left`
+/// - `key` - Key field used on both sides for `inner`/`left` joins
+/// - `left_key` / `right_key` - Per-side key fields overriding `key`
+///
+/// Zip pairs elements by position and stops at the shorter array. Two objects
+/// merge into one object with right-hand fields winning; any other pairing is
+/// emitted as `{ "left": .., "right": .. }`. Key joins preserve left order and
+/// emit one row per matching right element; `left` mode also keeps unmatched
+/// left elements unchanged.
+///
+/// Execution is handled by `CoreTaskExecutor`.
+#[derive(Clone)]
+pub struct JoinTask {
+    /// Unique identifier for this task instance
+    task_id: String,
+}
+
+impl JoinTask {
+    /// Port ID for left array input
+    pub const PORT_LEFT: &'static str = "left";
+    /// Port ID for right array input
+    pub const PORT_RIGHT: &'static str = "right";
+    /// Port ID for merged items output
+    pub const PORT_ITEMS: &'static str = "items";
+    /// Port ID for count output
+    pub const PORT_COUNT: &'static str = "count";
+
+    /// Create a new join task
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for JoinTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "join".to_string(),
+            category: NodeCategory::Processing,
+            label: "Zip / Join".to_string(),
+            description: "Combines two arrays element-wise or joins them on a key field"
+                .to_string(),
+            inputs: vec![
+                PortMetadata::required(Self::PORT_LEFT, "Left", PortDataType::Json),
+                PortMetadata::required(Self::PORT_RIGHT, "Right", PortDataType::Json),
+            ],
+            outputs: vec![
+                PortMetadata::required(Self::PORT_ITEMS, "Items", PortDataType::Json),
+                PortMetadata::optional(Self::PORT_COUNT, "Count", PortDataType::Number),
+            ],
+            execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Combines `left` and `right` arrays. `mode` `zip` (default) pairs \
+                 elements by position and stops at the shorter array; `inner` and \
+                 `left` join on `key` (or `left_key`/`right_key`), one row per \
+                 match, and `left` keeps unmatched left rows.\n\n\
+                 Paired objects merge with right-hand fields winning; other pairs \
+                 become `{left, right}`.",
+            )
+            .example(
+                NodeExample::new("Attach scores by id")
+                    .data(json!({"mode": "inner", "key": "id"}))
+                    .input(
+                        Self::PORT_LEFT,
+                        json!([{"id": 1, "name": "a"}, {"id": 2, "name": "b"}]),
+                    )
+                    .input(Self::PORT_RIGHT, json!([{"id": 2, "score": 0.9}]))
+                    .output(
+                        Self::PORT_ITEMS,
+                        json!([{"id": 2, "name": "b", "score": 0.9}]),
+                    )
+                    .output(Self::PORT_COUNT, json!(1)),
+            ),
+            ui: NodeUiHints::default(),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(JoinTask::descriptor));
+
+#[async_trait]
+impl Task for JoinTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        // Execution handled by CoreTaskExecutor::execute_join
+        Err(GraphError::TaskExecutionFailed(
+            "join requires CoreTaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor() {
+        let meta = JoinTask::descriptor();
+        assert_eq!(meta.node_type, "join");
+        assert_eq!(meta.category, NodeCategory::Processing);
+        assert_eq!(meta.inputs.len(), 2);
+        assert!(meta.inputs.iter().all(|p| p.required));
+        assert_eq!(meta.outputs.len(), 2);
+    }
+}
@@ -6,10 +6,11 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 /// Configuration for the JSON filter task
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -152,6 +153,23 @@ impl TaskDescriptor for JsonFilterTask {
                 PortMetadata::optional(Self::PORT_FOUND, "Found", PortDataType::Boolean),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Extracts the value at `path` (node data) from the `json` input and \
+                 reports on `found` whether it exists. Paths use dots for fields and \
+                 `[n]` for array indexes; an empty path returns the whole input. A \
+                 missing path emits null with `found` false.",
+            )
+            .example(
+                NodeExample::new("Chat completion content")
+                    .data(json!({"path": "choices[0].message.content"}))
+                    .input(
+                        Self::PORT_JSON,
+                        json!({"choices": [{"message": {"content": "Hi"}}]}),
+                    )
+                    .output(Self::PORT_VALUE, json!("Hi"))
+                    .output(Self::PORT_FOUND, json!(true)),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
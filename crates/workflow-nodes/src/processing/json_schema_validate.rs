@@ -0,0 +1,139 @@
+//! JSON Schema Validate Task
+//!
+//! Validates a JSON payload against a JSON Schema and reports every violation
+//! with a path into the payload. Used to guard downstream nodes from malformed
+//! model output and to score structured responses in eval pipelines.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+/// JSON Schema Validate Task
+///
+/// The schema comes from the optional `schema` input port when connected,
+/// otherwise from the `schema` field in node data. Either form may be a JSON
+/// value or a JSON-encoded string.
+///
+/// # Outputs
+/// - `valid` - Whether the payload satisfies the schema
+/// - `errors` - Array of `{ path, keyword, message }` objects; `path` uses the
+///   same dot/bracket notation as `json-filter`
+/// - `error_count` - Number of reported violations
+///
+/// Execution is handled by `CoreTaskExecutor`.
+#[derive(Clone)]
+pub struct JsonSchemaValidateTask {
+    /// Unique identifier for this task instance
+    task_id: String,
+}
+
+impl JsonSchemaValidateTask {
+    /// Port ID for the payload to validate
+    pub const PORT_JSON: &'static str = "json";
+    /// Port ID for the optional schema input
+    pub const PORT_SCHEMA: &'static str = "schema";
+    /// Port ID for valid output
+    pub const PORT_VALID: &'static str = "valid";
+    /// Port ID for errors output
+    pub const PORT_ERRORS: &'static str = "errors";
+    /// Port ID for error count output
+    pub const PORT_ERROR_COUNT: &'static str = "error_count";
+
+    /// Create a new JSON schema validate task
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for JsonSchemaValidateTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "json-schema-validate".to_string(),
+            category: NodeCategory::Processing,
+            label: "JSON Schema Validate".to_string(),
+            description: "Validates JSON against a JSON Schema and reports error paths".to_string(),
+            inputs: vec![
+                PortMetadata::required(Self::PORT_JSON, "JSON", PortDataType::Json),
+                PortMetadata::optional(Self::PORT_SCHEMA, "Schema", PortDataType::Json),
+            ],
+            outputs: vec![
+                PortMetadata::required(Self::PORT_VALID, "Valid", PortDataType::Boolean),
+                PortMetadata::optional(Self::PORT_ERRORS, "Errors", PortDataType::Json),
+                PortMetadata::optional(Self::PORT_ERROR_COUNT, "Error Count", PortDataType::Number),
+            ],
+            execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Checks `json` against a JSON Schema taken from the `schema` input \
+                 when connected, otherwise from the `schema` field in node data. \
+                 Either may be a JSON value or a JSON-encoded string.\n\n\
+                 `errors` lists every violation as `{ path, keyword, message }`, \
+                 with `path` in `json-filter` notation.",
+            )
+            .example(
+                NodeExample::new("Missing required field")
+                    .data(json!({"schema": {"type": "object", "required": ["name"]}}))
+                    .input(Self::PORT_JSON, json!({"age": 3}))
+                    .output(Self::PORT_VALID, json!(false))
+                    .output(Self::PORT_ERROR_COUNT, json!(1)),
+            )
+            .link("JSON Schema", "https://json-schema.org/"),
+            ui: NodeUiHints::icon("shield-check"),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(
+    JsonSchemaValidateTask::descriptor
+));
+
+#[async_trait]
+impl Task for JsonSchemaValidateTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        // Execution handled by CoreTaskExecutor::execute_json_schema_validate
+        Err(GraphError::TaskExecutionFailed(
+            "json-schema-validate requires CoreTaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor_node_type() {
+        let meta = JsonSchemaValidateTask::descriptor();
+        assert_eq!(meta.node_type, "json-schema-validate");
+        assert_eq!(meta.category, NodeCategory::Processing);
+        assert_eq!(meta.execution_mode, ExecutionMode::Reactive);
+    }
+
+    #[test]
+    fn test_descriptor_ports() {
+        let meta = JsonSchemaValidateTask::descriptor();
+        assert_eq!(meta.inputs.len(), 2);
+        assert!(meta.inputs.iter().any(|p| p.id == "json" && p.required));
+        assert!(meta.inputs.iter().any(|p| p.id == "schema" && !p.required));
+        assert_eq!(meta.outputs.len(), 3);
+        assert!(meta.outputs.iter().any(|p| p.id == "valid"));
+        assert!(meta.outputs.iter().any(|p| p.id == "errors"));
+        assert!(meta.outputs.iter().any(|p| p.id == "error_count"));
+    }
+
+    #[tokio::test]
+    async fn test_run_requires_core_executor() {
+        let task = JsonSchemaValidateTask::new("schema-1");
+        let result = task.run(Context::new()).await;
+        assert!(result.is_err());
+    }
+}
@@ -4,21 +4,28 @@
 //! `llamacpp-inference` node type. Actual execution is delegated to
 //! the host application via the callback bridge, so `run()` always
 //! returns an error directing callers to that path.
+//!
+//! When the `model-library` feature is enabled, this module also registers
+//! a `PortOptionsProvider` for the `lora` port that lists LoRA adapters
+//! from the pumas-library.
 
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 // Port name constants
 const PORT_MODEL_PATH: &str = "model_path";
+const PORT_MMPROJ_PATH: &str = "mmproj_path";
 const PORT_PROMPT: &str = "prompt";
 const PORT_SYSTEM_PROMPT: &str = "system_prompt";
 const PORT_TEMPERATURE: &str = "temperature";
 const PORT_MAX_TOKENS: &str = "max_tokens";
 const PORT_TOOLS: &str = "tools";
 const PORT_KV_CACHE_IN: &str = "kv_cache_in";
+const PORT_LORA: &str = "lora";
 const PORT_RESPONSE: &str = "response";
 const PORT_TOOL_CALLS: &str = "tool_calls";
 const PORT_HAS_TOOL_CALLS: &str = "has_tool_calls";
@@ -53,12 +60,18 @@ impl TaskDescriptor for LlamaCppInferenceTask {
             description: "Run inference via llama.cpp server (no model duplication)".to_string(),
             inputs: vec![
                 PortMetadata::required(PORT_MODEL_PATH, "Model Path", PortDataType::String),
+                PortMetadata::optional(
+                    PORT_MMPROJ_PATH,
+                    "Vision Projector Path",
+                    PortDataType::String,
+                ),
                 PortMetadata::required(PORT_PROMPT, "Prompt", PortDataType::Prompt),
                 PortMetadata::optional(PORT_SYSTEM_PROMPT, "System Prompt", PortDataType::String),
                 PortMetadata::optional(PORT_TEMPERATURE, "Temperature", PortDataType::Number),
                 PortMetadata::optional(PORT_MAX_TOKENS, "Max Tokens", PortDataType::Number),
                 PortMetadata::optional(PORT_TOOLS, "Tools", PortDataType::Tools).multiple(),
                 PortMetadata::optional(PORT_KV_CACHE_IN, "KV Cache In", PortDataType::KvCache),
+                PortMetadata::optional(PORT_LORA, "LoRA Adapters", PortDataType::String).multiple(),
                 PortMetadata::optional(
                     "inference_settings",
                     "Inference Settings",
@@ -79,11 +92,132 @@ impl TaskDescriptor for LlamaCppInferenceTask {
                 PortMetadata::optional(PORT_STREAM, "Stream", PortDataType::Stream),
             ],
             execution_mode: ExecutionMode::Stream,
+            docs: NodeDocs::new(
+                "Runs `prompt` against the GGUF at `model_path` on the shared llama.cpp \
+                 server and streams the reply into `response`. Pass \
+                 `mmproj_path` for vision models and `lora` adapters from the model library. \
+                 `model_ref` identifies the loaded model so `unload-model` can free it later, \
+                 and `kv_cache_out` can be saved with `kv-cache-save` to resume the prompt \
+                 prefix.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
 
 inventory::submit!(node_engine::DescriptorFn(LlamaCppInferenceTask::descriptor));
+inventory::submit!(node_engine::NodeCostAnnotation {
+    node_type: "llamacpp-inference",
+    api_calls: 1,
+    min_wall_time_ms: 500,
+    max_wall_time_ms: 120_000,
+    default_output_tokens: Some(512),
+});
+
+// ---------------------------------------------------------------------------
+// LoRA options provider (model-library feature)
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "model-library")]
+mod lora_options_provider {
+    use async_trait::async_trait;
+    use node_engine::{
+        extension_keys, ExecutorExtensions, NodeEngineError, PortOption, PortOptionsProvider,
+        PortOptionsQuery, PortOptionsResult,
+    };
+    use pumas_library::{ModelRecord, PumasApi};
+    use std::sync::Arc;
+
+    use crate::model_library::ModelLibraryRoots;
+
+    /// Lists LoRA adapters from pumas-library for the `lora` port.
+    ///
+    /// Adapters are library records whose model type or tags say `lora`;
+    /// option values are record paths, which execution resolves to the
+    /// adapter's `.gguf` file.
+    pub struct LoraOptionsProvider;
+
+    fn is_lora(record: &ModelRecord) -> bool {
+        record.model_type.eq_ignore_ascii_case("lora")
+            || record
+                .tags
+                .iter()
+                .any(|tag| tag.eq_ignore_ascii_case("lora"))
+    }
+
+    #[async_trait]
+    impl PortOptionsProvider for LoraOptionsProvider {
+        async fn query_options(
+            &self,
+            query: &PortOptionsQuery,
+            extensions: &ExecutorExtensions,
+        ) -> node_engine::Result<PortOptionsResult> {
+            let records: Vec<ModelRecord> = if let Some(roots) =
+                extensions.get::<Arc<ModelLibraryRoots>>(extension_keys::PUMAS_LIBRARY_ROOTS)
+            {
+                roots
+                    .list_models()
+                    .await
+                    .map_err(NodeEngineError::ExecutionFailed)?
+                    .into_iter()
+                    .map(|model| model.record)
+                    .collect()
+            } else {
+                extensions
+                    .get::<Arc<PumasApi>>(extension_keys::PUMAS_API)
+                    .ok_or_else(|| {
+                        NodeEngineError::ExecutionFailed("Model library not available".to_string())
+                    })?
+                    .list_models()
+                    .await
+                    .map_err(|e| NodeEngineError::ExecutionFailed(e.to_string()))?
+            };
+
+            let search = query.search.as_deref().map(str::to_lowercase);
+            let adapters: Vec<ModelRecord> = records
+                .into_iter()
+                .filter(is_lora)
+                .filter(|record| match search.as_deref() {
+                    Some(search) => {
+                        record.official_name.to_lowercase().contains(search)
+                            || record.id.to_lowercase().contains(search)
+                    }
+                    None => true,
+                })
+                .collect();
+            let total_count = adapters.len();
+            let options = adapters
+                .into_iter()
+                .skip(query.offset.unwrap_or(0))
+                .take(query.limit.unwrap_or(50))
+                .map(|record| PortOption {
+                    value: serde_json::json!(record.path),
+                    label: record.official_name.clone(),
+                    description: Some(record.tags.join(", ")),
+                    metadata: Some(serde_json::json!({
+                        "id": record.id,
+                        "model_type": record.model_type,
+                        "family": record.metadata.get("family"),
+                        "base_model": record.metadata.get("base_model"),
+                    })),
+                })
+                .collect();
+
+            Ok(PortOptionsResult {
+                options,
+                total_count,
+                searchable: true,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "model-library")]
+inventory::submit!(node_engine::PortQueryFn {
+    node_type: "llamacpp-inference",
+    port_id: "lora",
+    provider: || Box::new(lora_options_provider::LoraOptionsProvider),
+});
 
 #[async_trait]
 impl Task for LlamaCppInferenceTask {
@@ -113,16 +247,24 @@ mod tests {
     fn test_descriptor_has_correct_ports() {
         let meta = LlamaCppInferenceTask::descriptor();
 
-        // 8 inputs: model_path, prompt, system_prompt, temperature, max_tokens,
-        // tools, kv_cache_in, inference_settings
-        assert_eq!(meta.inputs.len(), 8);
+        // 10 inputs: model_path, mmproj_path, prompt, system_prompt, temperature,
+        // max_tokens, tools, kv_cache_in, lora, inference_settings
+        assert_eq!(meta.inputs.len(), 10);
         assert!(meta.inputs.iter().any(|p| p.id == "model_path"));
+        assert!(meta
+            .inputs
+            .iter()
+            .any(|p| p.id == "mmproj_path" && !p.required));
         assert!(meta.inputs.iter().any(|p| p.id == "prompt"));
         assert!(meta.inputs.iter().any(|p| p.id == "system_prompt"));
         assert!(meta.inputs.iter().any(|p| p.id == "temperature"));
         assert!(meta.inputs.iter().any(|p| p.id == "max_tokens"));
         assert!(meta.inputs.iter().any(|p| p.id == "tools"));
         assert!(meta.inputs.iter().any(|p| p.id == "kv_cache_in"));
+        assert!(meta
+            .inputs
+            .iter()
+            .any(|p| p.id == "lora" && p.multiple && !p.required));
         assert!(meta.inputs.iter().any(|p| p.id == "inference_settings"));
 
         // 7 outputs: response, model_path, model_ref, tool_calls,
@@ -2,39 +2,55 @@
 //!
 //! Nodes that transform, analyze, or generate data.
 
+mod aggregate;
 mod audio_generation;
 mod dependency_environment;
 mod depth_estimation;
 mod diffusion_inference;
 mod embedding;
 mod expand_settings;
+mod filter;
 mod inference;
+mod join;
 mod json_filter;
+mod json_schema_validate;
 mod llamacpp_inference;
+mod model_benchmark;
+mod model_convert;
 mod ollama_inference;
 mod onnx_inference;
 mod pytorch_inference;
+mod random;
 mod reranker;
+mod sort;
 mod unload_model;
 mod validator;
 mod vision_analysis;
 
+pub use aggregate::AggregateTask;
 pub use audio_generation::AudioGenerationTask;
 pub use dependency_environment::DependencyEnvironmentTask;
 pub use depth_estimation::DepthEstimationTask;
 pub use diffusion_inference::DiffusionInferenceTask;
 pub use embedding::{EmbeddingConfig, EmbeddingTask};
 pub use expand_settings::ExpandSettingsTask;
+pub use filter::FilterTask;
 pub use inference::{
     InferenceConfig, InferenceTask, ToolCall as InferenceToolCall,
     ToolDefinition as InferenceToolDefinition,
 };
+pub use join::JoinTask;
 pub use json_filter::{JsonFilterConfig, JsonFilterTask};
+pub use json_schema_validate::JsonSchemaValidateTask;
 pub use llamacpp_inference::LlamaCppInferenceTask;
+pub use model_benchmark::ModelBenchmarkTask;
+pub use model_convert::ModelConvertTask;
 pub use ollama_inference::OllamaInferenceTask;
 pub use onnx_inference::OnnxInferenceTask;
 pub use pytorch_inference::PyTorchInferenceTask;
+pub use random::RandomTask;
 pub use reranker::RerankerTask;
+pub use sort::SortTask;
 pub use unload_model::UnloadModelTask;
 pub use validator::{ValidationResult, ValidatorConfig, ValidatorTask};
 pub use vision_analysis::{VisionAnalysisTask, VisionConfig};
@@ -0,0 +1,116 @@
+//! Model Benchmark Task - Stub Descriptor
+//!
+//! Provides metadata so that `register_builtins()` discovers the
+//! `model-benchmark` node type. Actual execution is handled by the core
+//! executor, which loads the model through the inference gateway, runs the
+//! prompt set and stores the results in the model's library metadata.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
+};
+
+const PORT_MODEL_PATH: &str = "model_path";
+const PORT_MODEL_ID: &str = "model_id";
+const PORT_BACKEND: &str = "backend";
+const PORT_PROMPTS: &str = "prompts";
+const PORT_MAX_TOKENS: &str = "max_tokens";
+
+const PORT_TOKENS_PER_SECOND: &str = "tokens_per_second";
+const PORT_TIME_TO_FIRST_TOKEN_MS: &str = "time_to_first_token_ms";
+const PORT_MEMORY_MB: &str = "memory_mb";
+const PORT_BENCHMARK: &str = "benchmark";
+const PORT_STORED: &str = "stored";
+
+#[derive(Clone)]
+pub struct ModelBenchmarkTask {
+    task_id: String,
+}
+
+impl ModelBenchmarkTask {
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for ModelBenchmarkTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "model-benchmark".to_string(),
+            category: NodeCategory::Processing,
+            label: "Model Benchmark".to_string(),
+            description:
+                "Measure tokens/sec, time-to-first-token and memory use of a model on a backend"
+                    .to_string(),
+            inputs: vec![
+                PortMetadata::required(PORT_MODEL_PATH, "Model Path", PortDataType::String),
+                PortMetadata::optional(PORT_MODEL_ID, "Model ID", PortDataType::String),
+                PortMetadata::optional(PORT_BACKEND, "Backend", PortDataType::String),
+                PortMetadata::optional(PORT_PROMPTS, "Prompts", PortDataType::Json),
+                PortMetadata::optional(PORT_MAX_TOKENS, "Max Tokens", PortDataType::Number),
+            ],
+            outputs: vec![
+                PortMetadata::optional(PORT_TOKENS_PER_SECOND, "Tokens/sec", PortDataType::Number),
+                PortMetadata::optional(
+                    PORT_TIME_TO_FIRST_TOKEN_MS,
+                    "Time to First Token (ms)",
+                    PortDataType::Number,
+                ),
+                PortMetadata::optional(PORT_MEMORY_MB, "Memory (MiB)", PortDataType::Number),
+                PortMetadata::optional(PORT_BENCHMARK, "Benchmark", PortDataType::Json),
+                PortMetadata::optional(PORT_STORED, "Stored", PortDataType::Boolean),
+            ],
+            execution_mode: ExecutionMode::Batch,
+            docs: NodeDocs::new(
+                "Loads the model at `model_path` on `backend`, runs the `prompts`, and \
+                 reports throughput, time to first token, and the RAM the load added. \
+                 The full result is stored in the model's library metadata when `model_id` \
+                 is known, and `stored` says whether that happened.",
+            ),
+            ui: NodeUiHints::default(),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(ModelBenchmarkTask::descriptor));
+
+#[async_trait]
+impl Task for ModelBenchmarkTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        Err(GraphError::TaskExecutionFailed(
+            "model-benchmark requires execution via CoreTaskExecutor with an inference gateway"
+                .into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor_has_correct_node_type() {
+        let meta = ModelBenchmarkTask::descriptor();
+        assert_eq!(meta.node_type, "model-benchmark");
+    }
+
+    #[test]
+    fn test_descriptor_ports() {
+        let meta = ModelBenchmarkTask::descriptor();
+        assert_eq!(meta.inputs.len(), 5);
+        assert_eq!(meta.outputs.len(), 5);
+        assert!(meta
+            .inputs
+            .iter()
+            .any(|p| p.id == "model_path" && p.required));
+        assert!(meta.outputs.iter().any(|p| p.id == "tokens_per_second"));
+    }
+}
@@ -0,0 +1,120 @@
+//! Model Convert Task - Stub Descriptor
+//!
+//! Provides metadata so that `register_builtins()` discovers the
+//! `model-convert` node type. Actual execution is delegated to the host task
+//! executor, which runs llama.cpp's `llama-quantize` or
+//! `convert_hf_to_gguf.py` and imports the result into the model library.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+const PORT_MODEL_PATH: &str = "model_path";
+const PORT_MODEL_ID: &str = "model_id";
+const PORT_MODE: &str = "mode";
+const PORT_QUANTIZATION: &str = "quantization";
+const PORT_OUTPUT_DIR: &str = "output_dir";
+const PORT_MODEL_NAME: &str = "model_name";
+const PORT_IMPORT_TO_LIBRARY: &str = "import_to_library";
+
+const PORT_OUTPUT_PATH: &str = "output_path";
+
+#[derive(Clone)]
+pub struct ModelConvertTask {
+    task_id: String,
+}
+
+impl ModelConvertTask {
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for ModelConvertTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "model-convert".to_string(),
+            category: NodeCategory::Processing,
+            label: "Model Convert".to_string(),
+            description:
+                "Quantize a GGUF or convert safetensors to GGUF and import the result into the library"
+                    .to_string(),
+            inputs: vec![
+                PortMetadata::required(PORT_MODEL_PATH, "Model Path", PortDataType::String),
+                PortMetadata::optional(PORT_MODEL_ID, "Model ID", PortDataType::String),
+                PortMetadata::optional(PORT_MODE, "Mode", PortDataType::String),
+                PortMetadata::optional(PORT_QUANTIZATION, "Quantization", PortDataType::String),
+                PortMetadata::optional(PORT_OUTPUT_DIR, "Output Directory", PortDataType::String),
+                PortMetadata::optional(PORT_MODEL_NAME, "Model Name", PortDataType::String),
+                PortMetadata::optional(
+                    PORT_IMPORT_TO_LIBRARY,
+                    "Import to Library",
+                    PortDataType::Boolean,
+                ),
+            ],
+            outputs: vec![
+                PortMetadata::optional(PORT_MODEL_PATH, "Model Path", PortDataType::String),
+                PortMetadata::optional(PORT_MODEL_ID, "Model ID", PortDataType::String),
+                PortMetadata::optional(PORT_OUTPUT_PATH, "Output Path", PortDataType::String),
+            ],
+            execution_mode: ExecutionMode::Batch,
+            docs: NodeDocs::new(
+                "Quantizes a GGUF with `llama-quantize` or converts a safetensors \
+                 checkpoint to GGUF with `convert_hf_to_gguf.py`. `mode` defaults to `auto`, \
+                 which picks by the source file; `quantization` is a llama.cpp type such as \
+                 `Q4_K_M`. The result is imported into the model library unless \
+                 `import_to_library` is false.",
+            )
+            .example(
+                NodeExample::new("Requantize to Q5_K_M")
+                    .input(PORT_MODEL_PATH, json!("/models/llama-3.1-8b-instruct-f16.gguf"))
+                    .input(PORT_QUANTIZATION, json!("Q5_K_M")),
+            ),
+            ui: NodeUiHints::default(),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(ModelConvertTask::descriptor));
+
+#[async_trait]
+impl Task for ModelConvertTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        Err(GraphError::TaskExecutionFailed(
+            "model-convert requires execution via host TaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor_has_correct_node_type() {
+        let meta = ModelConvertTask::descriptor();
+        assert_eq!(meta.node_type, "model-convert");
+    }
+
+    #[test]
+    fn test_descriptor_ports() {
+        let meta = ModelConvertTask::descriptor();
+        assert_eq!(meta.inputs.len(), 7);
+        assert_eq!(meta.outputs.len(), 3);
+        assert!(meta
+            .inputs
+            .iter()
+            .any(|p| p.id == "model_path" && p.required));
+        assert!(meta.outputs.iter().any(|p| p.id == "model_id"));
+    }
+}
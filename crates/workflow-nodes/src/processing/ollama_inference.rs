@@ -6,8 +6,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
 };
 use serde::Deserialize;
 
@@ -114,11 +114,25 @@ impl TaskDescriptor for OllamaInferenceTask {
                 PortMetadata::optional(Self::PORT_STREAM, "Stream", PortDataType::Stream),
             ],
             execution_mode: ExecutionMode::Stream,
+            docs: NodeDocs::new(
+                "Sends `prompt` to the local Ollama server and streams the reply into \
+                 `response`. `model` is an Ollama model name, usually from a `model-provider` \
+                 node; the model must already be pulled. `model_ref` identifies the model so \
+                 `unload-model` can release it from VRAM.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
 
 inventory::submit!(node_engine::DescriptorFn(OllamaInferenceTask::descriptor));
+inventory::submit!(node_engine::NodeCostAnnotation {
+    node_type: "ollama-inference",
+    api_calls: 1,
+    min_wall_time_ms: 500,
+    max_wall_time_ms: 120_000,
+    default_output_tokens: Some(1_024),
+});
 
 #[async_trait]
 impl Task for OllamaInferenceTask {
@@ -8,7 +8,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 const PORT_MODEL_PATH: &str = "model_path";
@@ -64,6 +65,13 @@ impl TaskDescriptor for OnnxInferenceTask {
                 PortMetadata::optional(PORT_STREAM, "Audio Stream", PortDataType::AudioStream),
             ],
             execution_mode: ExecutionMode::Stream,
+            docs: NodeDocs::new(
+                "Runs an ONNX model at `model_path` in the Python ONNX Runtime sidecar. \
+                 Text-to-audio pipelines turn `prompt` into `audio`, streaming chunks on \
+                 `stream` as they are produced. Connect `environment_ref` from \
+                 `dependency-environment` so the sidecar has the model's packages.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -8,7 +8,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 const PORT_MODEL_PATH: &str = "model_path";
@@ -77,6 +78,15 @@ impl TaskDescriptor for PyTorchInferenceTask {
                 PortMetadata::optional(PORT_STREAM, "Stream", PortDataType::Stream),
             ],
             execution_mode: ExecutionMode::Stream,
+            docs: NodeDocs::new(
+                "Runs a HuggingFace-format model at `model_path` through the PyTorch \
+                 worker: text generation from `prompt`, or speech recognition when `audio` is \
+                 connected. `device` picks the compute device and `model_type` overrides \
+                 the detected pipeline. Connect `environment_ref` from \
+                 `dependency-environment` so the worker runs in the model's Python \
+                 environment.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
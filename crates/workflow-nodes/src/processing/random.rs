@@ -0,0 +1,107 @@
+//! Random Task
+//!
+//! Draws a uniform random number, reproducibly when seeded.
+
+use async_trait::async_trait;
+use graph_flow::{Context, GraphError, Task, TaskResult};
+use node_engine::{
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
+};
+use serde_json::json;
+
+/// Random Task
+///
+/// # Node Data
+/// - `mode` - `float` (default, `[min, max)`) or `integer` (`[min, max]`)
+/// - `min` / `max` - Range bounds; default `0`/`1` for floats and `0`/`100`
+///   for integers
+///
+/// A non-negative `seed` input draws from a generator seeded with it, so
+/// deterministic mode pins this node like any other seeded node. Without a
+/// seed, the generator installed in the executor extensions is used.
+///
+/// Execution is handled by `CoreTaskExecutor`.
+#[derive(Clone)]
+pub struct RandomTask {
+    /// Unique identifier for this task instance
+    task_id: String,
+}
+
+impl RandomTask {
+    /// Port ID for seed input
+    pub const PORT_SEED: &'static str = "seed";
+    /// Port ID for value output
+    pub const PORT_VALUE: &'static str = "value";
+
+    /// Create a new random task
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+        }
+    }
+}
+
+impl TaskDescriptor for RandomTask {
+    fn descriptor() -> TaskMetadata {
+        TaskMetadata {
+            node_type: "random".to_string(),
+            category: NodeCategory::Processing,
+            label: "Random".to_string(),
+            description: "Draws a uniform random number".to_string(),
+            inputs: vec![PortMetadata::optional(
+                Self::PORT_SEED,
+                "Seed",
+                PortDataType::Number,
+            )],
+            outputs: vec![PortMetadata::required(
+                Self::PORT_VALUE,
+                "Value",
+                PortDataType::Number,
+            )],
+            execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Draws a uniform number. `mode` is `float` (`[min, max)`, default \
+                 `0` to `1`) or `integer` (`[min, max]` inclusive, default `0` to \
+                 `100`).\n\n\
+                 Connect a non-negative `seed` for a repeatable draw; deterministic \
+                 mode pins it automatically. Unseeded draws use the executor's \
+                 generator.",
+            )
+            .example(
+                NodeExample::new("Roll a die").data(json!({"mode": "integer", "min": 1, "max": 6})),
+            ),
+            ui: NodeUiHints::icon("dices"),
+        }
+    }
+}
+
+inventory::submit!(node_engine::DescriptorFn(RandomTask::descriptor));
+
+#[async_trait]
+impl Task for RandomTask {
+    fn id(&self) -> &str {
+        &self.task_id
+    }
+
+    async fn run(&self, _context: Context) -> graph_flow::Result<TaskResult> {
+        // Execution handled by CoreTaskExecutor::execute_random
+        Err(GraphError::TaskExecutionFailed(
+            "random requires CoreTaskExecutor".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptor() {
+        let meta = RandomTask::descriptor();
+        assert_eq!(meta.node_type, "random");
+        assert_eq!(meta.category, NodeCategory::Processing);
+        assert_eq!(meta.inputs[0].id, "seed");
+        assert_eq!(meta.outputs[0].id, "value");
+    }
+}
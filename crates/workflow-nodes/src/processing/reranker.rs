@@ -7,8 +7,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 const PORT_MODEL_PATH: &str = "model_path";
 const PORT_QUERY: &str = "query";
@@ -68,11 +70,39 @@ impl TaskDescriptor for RerankerTask {
                 PortMetadata::optional(PORT_MODEL_REF, "Model Reference", PortDataType::Json),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Scores each entry of `documents` against `query` with a GGUF reranker \
+                 model served by llama.cpp. `results` lists the documents from best to worst \
+                 with their scores, trimmed to `top_k` when set; `top_score` and, with \
+                 `return_documents`, `top_document` carry the best match. Use \
+                 `documents_json` to pass the list as a JSON string instead.",
+            )
+            .example(
+                NodeExample::new("Rank search hits")
+                    .input(PORT_MODEL_PATH, json!("/models/bge-reranker-v2-m3-q8_0.gguf"))
+                    .input(PORT_QUERY, json!("How do I resume a download?"))
+                    .input(
+                        PORT_DOCUMENTS,
+                        json!([
+                            "Interrupted files resume from their .part file.",
+                            "Downloads are capped by a shared bandwidth limit.",
+                        ]),
+                    )
+                    .input(PORT_TOP_K, json!(1)),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
 
 inventory::submit!(node_engine::DescriptorFn(RerankerTask::descriptor));
+inventory::submit!(node_engine::NodeCostAnnotation {
+    node_type: "reranker",
+    api_calls: 1,
+    min_wall_time_ms: 50,
+    max_wall_time_ms: 5_000,
+    default_output_tokens: None,
+});
 
 #[async_trait]
 impl Task for RerankerTask {
@@ -14,7 +14,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 const PORT_MODEL_REF: &str = "model_ref";
@@ -56,6 +57,13 @@ impl TaskDescriptor for UnloadModelTask {
                 PortMetadata::optional(PORT_TRIGGER_PASSTHROUGH, "Trigger Data", PortDataType::Any),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Frees a model from its engine once `trigger` fires. Connect `model_ref` \
+                 to an inference node's Model Reference output and `trigger` to whatever \
+                 must finish first. `trigger` is passed through so later nodes can chain on \
+                 the unload.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -10,10 +10,11 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 /// Validation result details
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -223,6 +224,34 @@ impl TaskDescriptor for ValidatorTask {
                 PortMetadata::optional(Self::PORT_CATEGORY, "Category", PortDataType::String),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Checks Svelte component `code` for Svelte 4 syntax that Svelte 5 \
+                 rejects (`export let` props and `on:` event directives) and for \
+                 unbalanced `<script>` tags. Emits `valid`, the first problem on \
+                 `error` (empty when valid), and its `category` (`SveltePattern` or \
+                 `SvelteCompiler`).\n\n\
+                 Text after `//` on a line is ignored. `timeout_ms` is reserved for \
+                 compiler-backed validation.",
+            )
+            .example(
+                NodeExample::new("Svelte 5 props")
+                    .input(
+                        Self::PORT_CODE,
+                        json!("<script>let { name } = $props();</script>"),
+                    )
+                    .output(Self::PORT_VALID, json!(true))
+                    .output(Self::PORT_ERROR, json!("")),
+            )
+            .example(
+                NodeExample::new("Legacy event directive")
+                    .input(
+                        Self::PORT_CODE,
+                        json!("<button on:click={save}>Save</button>"),
+                    )
+                    .output(Self::PORT_VALID, json!(false))
+                    .output(Self::PORT_CATEGORY, json!("SveltePattern")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
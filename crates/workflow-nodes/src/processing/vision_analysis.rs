@@ -6,8 +6,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
 };
 use serde::{Deserialize, Serialize};
 
@@ -100,11 +100,24 @@ impl TaskDescriptor for VisionAnalysisTask {
                 PortDataType::String,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Sends `image` and `prompt` to the vision-capable model served by the \
+                 inference gateway and emits its text `analysis`. The gateway must have a \
+                 vision model loaded. Requires the `inference-nodes` feature.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
 
 inventory::submit!(node_engine::DescriptorFn(VisionAnalysisTask::descriptor));
+inventory::submit!(node_engine::NodeCostAnnotation {
+    node_type: "vision-analysis",
+    api_calls: 1,
+    min_wall_time_ms: 1_000,
+    max_wall_time_ms: 120_000,
+    default_output_tokens: Some(4_096),
+});
 
 #[async_trait]
 impl Task for VisionAnalysisTask {
@@ -5,6 +5,7 @@
 //! keeps host crates decoupled from the underlying libraries — they don't
 //! need to import `pumas-library` directly.
 
+use node_engine::config::{ConfigError, ExtensionsConfig};
 use node_engine::ExecutorExtensions;
 
 /// Initialize optional runtime dependencies in `ExecutorExtensions`.
@@ -13,6 +14,8 @@ use node_engine::ExecutorExtensions;
 /// - **PumasApi** (`model-library` feature): Tries explicit/local paths first
 ///   (`library_path`, then `PUMAS_LIBRARY_PATH`) and falls back to
 ///   `PumasApi::discover()` (global registry at `~/.config/pumas/registry.db`).
+///   A [`crate::PumasBenchmarkStore`] over the same library is installed
+///   alongside it.
 ///
 /// # Example
 ///
@@ -161,7 +164,9 @@ pub async fn setup_extensions_with_path(
     }
 
     if let Some(api) = api {
-        extensions.set(node_engine::extension_keys::PUMAS_API, Arc::new(api));
+        let api = Arc::new(api);
+        crate::model_benchmark::PumasBenchmarkStore::Single(api.clone()).install(extensions);
+        extensions.set(node_engine::extension_keys::PUMAS_API, api);
     }
 }
 
@@ -176,3 +181,42 @@ pub async fn setup_extensions_with_path(
     _library_path: Option<&std::path::Path>,
 ) {
 }
+
+/// Initialize extensions from an [`ExtensionsConfig`].
+///
+/// Installs the config's node-engine extensions, then either the merged
+/// `pumas_library_roots` or the PumasApi using `pumas_library_path` with the
+/// same fallbacks as [`setup_extensions_with_path`].
+pub async fn setup_extensions_from_config(
+    extensions: &mut ExecutorExtensions,
+    config: &ExtensionsConfig,
+) -> Result<(), ConfigError> {
+    config.apply_to(extensions)?;
+    if config.pumas_library_roots.is_empty() {
+        setup_extensions_with_path(extensions, config.pumas_library_path.as_deref()).await;
+        return Ok(());
+    }
+
+    #[cfg(feature = "model-library")]
+    {
+        let roots =
+            crate::model_library::ModelLibraryRoots::open(&config.pumas_library_roots, false)
+                .await
+                .map_err(ConfigError::ModelLibrary)?;
+        crate::model_library::install_library_roots(extensions, std::sync::Arc::new(roots));
+        Ok(())
+    }
+    #[cfg(not(feature = "model-library"))]
+    Err(ConfigError::Unsupported(
+        "pumas_library_roots requires the model-library feature".to_string(),
+    ))
+}
+
+/// Load an extensions config file and initialize extensions from it.
+pub async fn setup_extensions_from_file(
+    extensions: &mut ExecutorExtensions,
+    path: impl AsRef<std::path::Path>,
+) -> Result<(), ConfigError> {
+    let config = ExtensionsConfig::load(path)?;
+    setup_extensions_from_config(extensions, &config).await
+}
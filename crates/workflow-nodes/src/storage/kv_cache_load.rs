@@ -8,7 +8,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 const PORT_CACHE_ID: &str = "cache_id";
@@ -52,6 +53,14 @@ impl TaskDescriptor for KvCacheLoadTask {
                 PortMetadata::required(PORT_VALID, "Valid", PortDataType::Boolean),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Looks up a saved KV cache by `cache_id` and checks it against \
+                 `model_fingerprint`. When the cache matches the active runtime, \
+                 `cache_data` can be wired into an inference node's KV Cache In. Otherwise \
+                 `valid` is false and `cache_data` is null, so the prompt is processed from \
+                 scratch.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -8,7 +8,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 const PORT_CACHE_DATA: &str = "cache_data";
@@ -60,6 +61,13 @@ impl TaskDescriptor for KvCacheSaveTask {
                 PortMetadata::required(PORT_METADATA, "Metadata", PortDataType::Json),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Stores an inference node's `kv_cache_out` and emits its `cache_id`. \
+                 `storage_policy` is `memory` (the default), `disk`, or `both`; `cache_dir` \
+                 overrides where disk copies go. Named `markers` record token positions that \
+                 `kv-cache-truncate` can rewind to.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -8,8 +8,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType, PortMetadata,
+    TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 
 const PORT_CACHE_ID: &str = "cache_id";
 const PORT_MARKER_NAME: &str = "marker_name";
@@ -47,6 +49,18 @@ impl TaskDescriptor for KvCacheTruncateTask {
                 PortMetadata::required(PORT_METADATA, "Metadata", PortDataType::Json),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Rewinds a saved KV cache to a named marker or a token position, \
+                 so a conversation can branch from an earlier turn without reprocessing the \
+                 shared prefix. Set `marker_name` or `token_position`; the truncated cache \
+                 keeps the same `cache_id`.",
+            )
+            .example(
+                NodeExample::new("Back to the system prompt")
+                    .input(PORT_CACHE_ID, json!("chat-session"))
+                    .input(PORT_MARKER_NAME, json!("system")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
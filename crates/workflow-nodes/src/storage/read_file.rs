@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    resolve_path_within_root, ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata,
-    TaskDescriptor, TaskMetadata,
+    resolve_path_within_root, ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample,
+    NodeUiHints, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 use std::path::PathBuf;
 use tokio::fs;
 
@@ -83,6 +84,16 @@ impl TaskDescriptor for ReadFileTask {
                 PortMetadata::optional(Self::PORT_EXISTS, "Exists", PortDataType::Boolean),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Reads the UTF-8 text file at `path` and emits its `content`. \
+                 Relative paths resolve against the filesystem policy root or the \
+                 project root; paths that escape it, including through symlinks, \
+                 are rejected. A missing file fails the node.",
+            )
+            .example(
+                NodeExample::new("Project notes").input(Self::PORT_PATH, json!("notes/todo.md")),
+            ),
+            ui: NodeUiHints::icon("file-input"),
         }
     }
 }
@@ -6,9 +6,10 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    resolve_path_within_root, ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata,
-    TaskDescriptor, TaskMetadata,
+    resolve_path_within_root, ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample,
+    NodeUiHints, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 use std::path::PathBuf;
 use tokio::fs;
 
@@ -86,6 +87,17 @@ impl TaskDescriptor for WriteFileTask {
                 PortMetadata::optional(Self::PORT_PATH, "Path", PortDataType::String),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Writes `content` to `path`, creating parent directories, and emits \
+                 `success`. Paths resolve and are confined like `read-file`. The \
+                 node fails when the filesystem policy is read-only.",
+            )
+            .example(
+                NodeExample::new("Save generated code")
+                    .input(Self::PORT_PATH, json!("out/Button.svelte"))
+                    .input(Self::PORT_CONTENT, json!("<button>Save</button>")),
+            ),
+            ui: NodeUiHints::icon("file-output"),
         }
     }
 }
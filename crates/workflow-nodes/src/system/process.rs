@@ -10,9 +10,10 @@ use std::time::Duration;
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, NextAction, Task, TaskResult};
 use node_engine::{
-    ContextKeys, ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor,
-    TaskMetadata,
+    ContextKeys, ExecutionMode, NodeCategory, NodeDocs, NodeExample, NodeUiHints, PortDataType,
+    PortMetadata, TaskDescriptor, TaskMetadata,
 };
+use serde_json::json;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::process::Command;
 
@@ -179,11 +180,25 @@ impl TaskDescriptor for ProcessTask {
                 PortMetadata::optional(Self::PORT_SUCCESS, "Success", PortDataType::Boolean),
             ],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Runs `command` with `args`, optional `stdin`, working directory, and \
+                 environment, then emits its `exit_code`, `stdout`, and `stderr`. Commands \
+                 must be allowed by the host's process policy, which is off by default. The \
+                 process is killed after `timeout_secs` (300 by default).",
+            )
+            .example(
+                NodeExample::new("List a directory")
+                    .input(Self::PORT_COMMAND, json!("ls"))
+                    .input(Self::PORT_ARGS, json!(["-la"]))
+                    .input(Self::PORT_CWD, json!("src")),
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
 
 inventory::submit!(node_engine::DescriptorFn(ProcessTask::descriptor));
+inventory::submit!(node_engine::NondeterministicNode("process"));
 
 #[async_trait]
 impl Task for ProcessTask {
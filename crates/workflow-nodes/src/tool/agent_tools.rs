@@ -7,7 +7,8 @@
 use async_trait::async_trait;
 use graph_flow::{Context, GraphError, Task, TaskResult};
 use node_engine::{
-    ExecutionMode, NodeCategory, PortDataType, PortMetadata, TaskDescriptor, TaskMetadata,
+    ExecutionMode, NodeCategory, NodeDocs, NodeUiHints, PortDataType, PortMetadata, TaskDescriptor,
+    TaskMetadata,
 };
 
 const PORT_TOOLS: &str = "tools";
@@ -43,6 +44,13 @@ impl TaskDescriptor for AgentToolsTask {
                 PortDataType::Tools,
             )],
             execution_mode: ExecutionMode::Reactive,
+            docs: NodeDocs::new(
+                "Chooses which built-in tools an agent may call, such as reading and \
+                 writing files or running shell commands. Connect `tools` to an inference \
+                 node's Tools input. The host builds the tool definitions, so the node only \
+                 runs where a host executor is attached.",
+            ),
+            ui: NodeUiHints::default(),
         }
     }
 }
@@ -1,5 +1,7 @@
-// Canonical port type color mapping
-// Consolidates the typeColors constant previously duplicated across 5+ component files
+// Fallback port type color mapping
+// Consolidates the typeColors constant previously duplicated across 5+ component files.
+// Port definitions from the backend carry `ui.color`, which takes precedence;
+// these values mirror node-engine's standard palette for ports without hints.
 
 import type { PortDataType } from '../types/workflow.js';
 
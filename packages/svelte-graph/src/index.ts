@@ -4,9 +4,11 @@
 export type {
   PortDataType,
   PortDefinition,
+  PortUiHints,
   NodeCategory,
   ExecutionMode,
   NodeDefinition,
+  NodeUiHints,
   GraphNode,
   GraphEdge,
   ConnectionAnchor,
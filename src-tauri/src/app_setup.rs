@@ -190,6 +190,11 @@ pub fn run_app() -> AppStartupResult<()> {
                 let rag_manager = create_rag_manager(project_data_dir);
                 app.manage(rag_manager);
 
+                app.manage(workflow::puma_lib_commands::create_model_downloader(
+                    &app_data_dir,
+                    shared_extensions.clone(),
+                ));
+
                 let kv_cache_dir = app_data_dir.join("kv_cache");
                 let config = tauri::async_runtime::block_on(async {
                     match AppConfig::load(&app_data_dir).await {
@@ -433,6 +438,11 @@ pub fn run_app() -> AppStartupResult<()> {
             crate::workflow::commands::delete_pumas_model_with_audit,
             crate::workflow::commands::search_hf_models_with_audit,
             crate::workflow::commands::start_hf_download_with_audit,
+            crate::workflow::commands::start_verified_model_download_with_audit,
+            crate::workflow::commands::inspect_model_download_url,
+            crate::workflow::commands::get_verified_model_download_progress,
+            crate::workflow::commands::cancel_verified_model_download,
+            crate::workflow::commands::set_model_download_bandwidth_limit,
             crate::workflow::commands::run_dependency_environment_action,
             crate::workflow::commands::resolve_model_dependency_requirements,
             crate::workflow::commands::check_model_dependencies,
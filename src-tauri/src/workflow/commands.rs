@@ -29,6 +29,8 @@ pub type SharedWorkflowExecutionSessionStaleCleanupWorker =
 pub type SharedWorkflowDiagnosticsStore = Arc<super::diagnostics::WorkflowDiagnosticsStore>;
 /// Shared filesystem-backed workflow graph store.
 pub type SharedWorkflowGraphStore = Arc<FileSystemWorkflowGraphStore>;
+/// Shared checksum-verified, resumable model downloader.
+pub type SharedModelDownloader = Arc<pantograph_model_downloads::ModelDownloader>;
 
 #[command]
 pub fn validate_workflow_connection(source_type: PortDataType, target_type: PortDataType) -> bool {
@@ -596,6 +598,52 @@ pub async fn start_hf_download_with_audit(
         .await
 }
 
+#[command]
+pub async fn start_verified_model_download_with_audit(
+    downloader: State<'_, SharedModelDownloader>,
+    workflow_service: State<'_, SharedWorkflowService>,
+    request: pantograph_model_downloads::ModelDownloadRequest,
+) -> Result<super::puma_lib_commands::PumaHfDownloadStartAuditResponse, String> {
+    super::puma_lib_commands::start_verified_model_download_with_audit(
+        downloader,
+        workflow_service,
+        request,
+    )
+    .await
+}
+
+#[command]
+pub async fn inspect_model_download_url(
+    downloader: State<'_, SharedModelDownloader>,
+    url: String,
+) -> Result<pantograph_model_downloads::UrlInspection, String> {
+    super::puma_lib_commands::inspect_model_download_url(downloader, url).await
+}
+
+#[command]
+pub fn get_verified_model_download_progress(
+    downloader: State<'_, SharedModelDownloader>,
+    download_id: String,
+) -> Option<pantograph_model_downloads::DownloadProgress> {
+    downloader.progress(&download_id)
+}
+
+#[command]
+pub fn cancel_verified_model_download(
+    downloader: State<'_, SharedModelDownloader>,
+    download_id: String,
+) -> bool {
+    downloader.cancel(&download_id)
+}
+
+#[command]
+pub fn set_model_download_bandwidth_limit(
+    downloader: State<'_, SharedModelDownloader>,
+    bytes_per_sec: Option<u64>,
+) {
+    downloader.set_bandwidth_limit(bytes_per_sec);
+}
+
 #[command]
 pub async fn run_dependency_environment_action(
     resolver: State<'_, super::model_dependencies::SharedModelDependencyResolver>,
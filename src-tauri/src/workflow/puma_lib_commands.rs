@@ -1,10 +1,13 @@
+use pantograph_model_downloads::DownloadSource;
 use pantograph_runtime_identity::canonical_engine_backend_key;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
 use std::sync::Arc;
 use tauri::State;
 
-use super::commands::{SharedExtensions, SharedNodeRegistry, SharedWorkflowService};
+use super::commands::{
+    SharedExtensions, SharedModelDownloader, SharedNodeRegistry, SharedWorkflowService,
+};
 use super::model_dependencies::SharedModelDependencyResolver;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -146,6 +149,97 @@ pub async fn start_hf_download_with_audit(
     })
 }
 
+/// Imports verified downloads through whichever Pumas API the executor
+/// extensions hold when the download finishes, since the API is installed
+/// after app setup.
+struct ExtensionsPumasImporter {
+    extensions: SharedExtensions,
+}
+
+#[async_trait::async_trait]
+impl pantograph_model_downloads::ModelImporter for ExtensionsPumasImporter {
+    async fn import(
+        &self,
+        model: &pantograph_model_downloads::ModelImport,
+    ) -> pantograph_model_downloads::Result<pantograph_model_downloads::ImportedModel> {
+        let api = self
+            .extensions
+            .read()
+            .await
+            .get::<Arc<pumas_library::PumasApi>>(node_engine::extension_keys::PUMAS_API)
+            .cloned()
+            .ok_or_else(|| {
+                pantograph_model_downloads::DownloadError::Import(
+                    "Pumas API not available in executor extensions".to_string(),
+                )
+            })?;
+        pantograph_model_downloads::PumasImporter::new(api)
+            .import(model)
+            .await
+    }
+}
+
+/// Verified downloader staging under `app_data_dir` and importing into the
+/// executor's Pumas API.
+pub fn create_model_downloader(
+    app_data_dir: &std::path::Path,
+    extensions: SharedExtensions,
+) -> SharedModelDownloader {
+    Arc::new(
+        pantograph_model_downloads::ModelDownloader::new(
+            app_data_dir.join(pantograph_model_downloads::STAGING_DIR_NAME),
+        )
+        .with_importer(Arc::new(ExtensionsPumasImporter { extensions })),
+    )
+}
+
+pub async fn start_verified_model_download_with_audit(
+    downloader: State<'_, SharedModelDownloader>,
+    workflow_service: State<'_, SharedWorkflowService>,
+    request: pantograph_model_downloads::ModelDownloadRequest,
+) -> Result<PumaHfDownloadStartAuditResponse, String> {
+    let source = request.source();
+    if source == DownloadSource::HuggingFace {
+        validate_hf_repo_id_for_audit(&request.repo_id)?;
+    }
+    let repo_id = request.repo_id.clone();
+    let civitai_version_id = request.civitai_model_version_id;
+    let download_id = downloader
+        .start(request)
+        .await
+        .map_err(|error| error.to_string())?;
+    let audit_event_seq = match (source, civitai_version_id) {
+        (DownloadSource::HuggingFace, _) => {
+            record_hf_model_download_audit(&workflow_service, &repo_id)
+        }
+        (DownloadSource::Civitai, Some(version_id)) => record_model_download_audit(
+            &workflow_service,
+            format!("pantograph://civitai/model-versions/{version_id}"),
+            "civitai-download",
+        ),
+        _ => record_model_download_audit(
+            &workflow_service,
+            format!("pantograph://url-downloads/{download_id}"),
+            "url-download",
+        ),
+    };
+
+    Ok(PumaHfDownloadStartAuditResponse {
+        download_id,
+        audit_event_seq,
+    })
+}
+
+pub async fn inspect_model_download_url(
+    downloader: State<'_, SharedModelDownloader>,
+    url: String,
+) -> Result<pantograph_model_downloads::UrlInspection, String> {
+    downloader
+        .inspect_url(&url)
+        .await
+        .map_err(|error| error.to_string())
+}
+
 fn record_pumas_model_delete_audit(
     workflow_service: &SharedWorkflowService,
     model_id: &str,
@@ -170,19 +264,31 @@ fn record_pumas_model_delete_audit(
 fn record_hf_model_download_audit(
     workflow_service: &SharedWorkflowService,
     repo_id: &str,
+) -> Option<i64> {
+    record_model_download_audit(
+        workflow_service,
+        format!("hf://models/{repo_id}"),
+        "pumas-hf-download",
+    )
+}
+
+fn record_model_download_audit(
+    workflow_service: &SharedWorkflowService,
+    asset_id: String,
+    source_instance_id: &str,
 ) -> Option<i64> {
     match workflow_service.workflow_library_asset_access_record(
         pantograph_workflow_service::WorkflowLibraryAssetAccessRecordRequest {
-            asset_id: format!("hf://models/{repo_id}"),
+            asset_id,
             operation: pantograph_workflow_service::LibraryAssetOperation::Download,
             cache_status: Some(pantograph_workflow_service::LibraryAssetCacheStatus::Unknown),
             network_bytes: None,
-            source_instance_id: Some("pumas-hf-download".to_string()),
+            source_instance_id: Some(source_instance_id.to_string()),
         },
     ) {
         Ok(response) => response.event_seq,
         Err(error) => {
-            log::warn!("Failed to record Pumas HuggingFace download audit event: {error}");
+            log::warn!("Failed to record {source_instance_id} audit event: {error}");
             None
         }
     }
@@ -288,6 +394,7 @@ fn build_hydrated_node_data(
         "review_reasons": metadata.get("review_reasons").cloned().unwrap_or_else(|| Value::Array(Vec::new())),
         "selected_binding_ids": sanitize_selected_binding_ids(selected_binding_ids),
         "inference_settings": metadata.get("inference_settings").cloned().unwrap_or_else(|| Value::Array(Vec::new())),
+        "mmproj_path": metadata_string(metadata, &["mmproj_path", "mmprojPath"]),
         "dependency_requirements_id": Value::Null,
         "dependency_requirements": Value::Null,
     });
@@ -617,6 +724,7 @@ mod tests {
         assert_eq!(node_data["selected_binding_ids"], json!(["binding-a"]));
         assert_eq!(node_data["inference_settings"], json!([{ "key": "steps" }]));
         assert!(node_data["dependency_requirements"].is_null());
+        assert!(node_data["mmproj_path"].is_null());
     }
 
     #[test]
@@ -459,6 +459,72 @@ export interface PumasHfDownloadStartAuditResponse {
   auditEventSeq?: number | null;
 }
 
+export type VerifiedDownloadSource = 'huggingface' | 'url' | 'civitai';
+
+export interface VerifiedModelDownloadRequest {
+  source?: VerifiedDownloadSource | null;
+  repo_id?: string;
+  family?: string;
+  official_name?: string;
+  revision?: string | null;
+  filename?: string | null;
+  filenames?: string[] | null;
+  max_concurrent_files?: number | null;
+  download_url?: string | null;
+  sha256?: string | null;
+  civitai_model_version_id?: number | null;
+}
+
+export interface ModelDownloadUrlInspection {
+  url: string;
+  filename: string | null;
+  size: number | null;
+  content_type: string | null;
+  suggested_official_name: string | null;
+  required_fields: string[];
+}
+
+export type VerifiedDownloadStatus =
+  | 'queued'
+  | 'resolving'
+  | 'downloading'
+  | 'importing'
+  | 'completed'
+  | 'failed'
+  | 'cancelled';
+
+export type VerifiedDownloadFileState =
+  | 'queued'
+  | 'downloading'
+  | 'verifying'
+  | 'verified'
+  | 'unverified'
+  | 'failed';
+
+export interface VerifiedDownloadFileProgress {
+  name: string;
+  bytes_done: number;
+  bytes_total: number | null;
+  resumed_from_bytes: number;
+  state: VerifiedDownloadFileState;
+  expected_sha256: string | null;
+}
+
+export interface VerifiedDownloadProgress {
+  download_id: string;
+  source: VerifiedDownloadSource;
+  repo_id: string;
+  status: VerifiedDownloadStatus;
+  bytes_done: number;
+  bytes_total: number | null;
+  resumed_from_bytes: number;
+  files: VerifiedDownloadFileProgress[];
+  error: string | null;
+  error_kind: string | null;
+  model_id: string | null;
+  model_path: string | null;
+}
+
 export interface WorkflowSchedulerTimelineQueryRequest {
   workflow_run_id?: string | null;
   workflow_id?: string | null;
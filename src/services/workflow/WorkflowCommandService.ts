@@ -1,10 +1,13 @@
 import type {
   DiagnosticsRetentionPolicy,
+  ModelDownloadUrlInspection,
   PumasHfDownloadRequest,
   PumasHfDownloadStartAuditResponse,
   PumasHfModelSearchAuditRequest,
   PumasHfModelSearchAuditResponse,
   PumasModelDeleteAuditResponse,
+  VerifiedDownloadProgress,
+  VerifiedModelDownloadRequest,
   WorkflowRetentionPolicyQueryRequest,
   WorkflowRetentionPolicyQueryResponse,
   WorkflowRetentionCleanupRequest,
@@ -271,6 +274,66 @@ export class WorkflowCommandService extends WorkflowProjectionService {
       request,
     });
   }
+
+  async startVerifiedModelDownloadWithAudit(
+    request: VerifiedModelDownloadRequest,
+  ): Promise<PumasHfDownloadStartAuditResponse> {
+    if (USE_WORKFLOW_MOCKS) {
+      return {
+        downloadId: 'mock-verified-download',
+        auditEventSeq: null,
+      };
+    }
+
+    return invokeWorkflowCommand<PumasHfDownloadStartAuditResponse>(
+      'start_verified_model_download_with_audit',
+      { request },
+    );
+  }
+
+  async inspectModelDownloadUrl(url: string): Promise<ModelDownloadUrlInspection> {
+    if (USE_WORKFLOW_MOCKS) {
+      return {
+        url,
+        filename: null,
+        size: null,
+        content_type: null,
+        suggested_official_name: null,
+        required_fields: ['family', 'official_name'],
+      };
+    }
+
+    return invokeWorkflowCommand<ModelDownloadUrlInspection>('inspect_model_download_url', { url });
+  }
+
+  async getVerifiedModelDownloadProgress(
+    downloadId: string,
+  ): Promise<VerifiedDownloadProgress | null> {
+    if (USE_WORKFLOW_MOCKS) {
+      return null;
+    }
+
+    return invokeWorkflowCommand<VerifiedDownloadProgress | null>(
+      'get_verified_model_download_progress',
+      { downloadId },
+    );
+  }
+
+  async cancelVerifiedModelDownload(downloadId: string): Promise<boolean> {
+    if (USE_WORKFLOW_MOCKS) {
+      return false;
+    }
+
+    return invokeWorkflowCommand<boolean>('cancel_verified_model_download', { downloadId });
+  }
+
+  async setModelDownloadBandwidthLimit(bytesPerSec: number | null): Promise<void> {
+    if (USE_WORKFLOW_MOCKS) {
+      return;
+    }
+
+    await invokeWorkflowCommand<void>('set_model_download_bandwidth_limit', { bytesPerSec });
+  }
 }
 
 function standardRetentionPolicySettings(retentionDays: number): DiagnosticsRetentionPolicy['settings'] {
@@ -454,3 +454,55 @@ test('startHfDownloadWithAudit forwards download request and preserves result',
     clearMocks();
   }
 });
+test('verified download commands forward camelCase arguments', async () => {
+  installWindowMock();
+  const calls: Array<{ cmd: string; args: unknown }> = [];
+  const request = {
+    download_url: 'https://example.test/model-a-Q4_K_M.gguf',
+    family: 'llama',
+    official_name: 'Model A',
+    max_concurrent_files: 2,
+  };
+  mockIPC((cmd, args) => {
+    calls.push({ cmd, args });
+    if (cmd === 'inspect_model_download_url') {
+      return {
+        url: request.download_url,
+        filename: 'model-a-Q4_K_M.gguf',
+        size: 1024,
+        content_type: null,
+        suggested_official_name: 'model-a-Q4_K_M',
+        required_fields: ['family', 'official_name'],
+      };
+    }
+    if (cmd === 'start_verified_model_download_with_audit') {
+      return { downloadId: 'download-1', auditEventSeq: 90 };
+    }
+    if (cmd === 'cancel_verified_model_download') {
+      return true;
+    }
+    return null;
+  });
+
+  try {
+    const service = new WorkflowCommandService();
+    const inspection = await service.inspectModelDownloadUrl(request.download_url);
+    const started = await service.startVerifiedModelDownloadWithAudit(request);
+    await service.getVerifiedModelDownloadProgress(started.downloadId);
+    await service.setModelDownloadBandwidthLimit(1_000_000);
+    const cancelled = await service.cancelVerifiedModelDownload(started.downloadId);
+
+    assert.equal(inspection.filename, 'model-a-Q4_K_M.gguf');
+    assert.equal(cancelled, true);
+    assert.deepEqual(calls, [
+      { cmd: 'inspect_model_download_url', args: { url: request.download_url } },
+      { cmd: 'start_verified_model_download_with_audit', args: { request } },
+      { cmd: 'get_verified_model_download_progress', args: { downloadId: 'download-1' } },
+      { cmd: 'set_model_download_bandwidth_limit', args: { bytesPerSec: 1_000_000 } },
+      { cmd: 'cancel_verified_model_download', args: { downloadId: 'download-1' } },
+    ]);
+  } finally {
+    clearMocks();
+  }
+});
+
@@ -7,7 +7,7 @@
 
 import { invoke } from '@tauri-apps/api/core';
 import type { OrchestrationGraph } from '../../stores/orchestrationStore';
-import type { WorkflowGraph, GraphNode } from './types';
+import type { WorkflowGraph, WorkflowGraphPorts, GraphNode } from './types';
 import type { NodeGroup, PortMapping } from './groupTypes';
 
 // Import templates statically (bundled with the app)
@@ -62,6 +62,7 @@ export interface DataGraphTemplate {
     target: string;
     target_handle: string;
   }>;
+  ports?: WorkflowGraphPorts;
 }
 
 export interface NodeGroupTemplate {
@@ -173,6 +174,7 @@ export async function loadTemplate(templateId: string): Promise<{
         target: `${edge.target}-${instanceId}`,
         target_handle: edge.target_handle,
       })),
+      ports: suffixGraphPorts(graphTemplate.ports, `-${instanceId}`),
     };
 
     // Register with backend
@@ -211,9 +213,23 @@ export function dataGraphTemplateToWorkflowGraph(
       target: `${edge.target}${suffix}`,
       target_handle: edge.target_handle,
     })),
+    ports: suffixGraphPorts(template.ports, suffix),
   };
 }
 
+/**
+ * Rebind a data graph template's declared ports to its suffixed node IDs
+ */
+function suffixGraphPorts(
+  ports: WorkflowGraphPorts | undefined,
+  suffix: string
+): WorkflowGraphPorts | undefined {
+  if (!ports) return undefined;
+  const rebind = (list: WorkflowGraphPorts['inputs']) =>
+    list?.map((entry) => ({ ...entry, nodeId: `${entry.nodeId}${suffix}` }));
+  return { inputs: rebind(ports.inputs), outputs: rebind(ports.outputs) };
+}
+
 /**
  * Convert a node group template to a NodeGroup
  */
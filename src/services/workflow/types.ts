@@ -35,6 +35,16 @@ export interface PortDefinition {
     max?: number;
     allowed_values?: unknown[];
   };
+  ui?: PortUiHints;
+}
+
+/** Standard port rendering hints; prefer these over local palettes. */
+export interface PortUiHints {
+  /** Semantic color token a theme may remap, e.g. `data-kv-cache` */
+  color_token: string;
+  /** Default hex color for the token */
+  color: string;
+  shape: 'circle' | 'square' | 'diamond' | 'hexagon';
 }
 
 export type NodeCategory = 'input' | 'processing' | 'tool' | 'output' | 'control';
@@ -50,6 +60,33 @@ export interface NodeDefinition {
   inputs: PortDefinition[];
   outputs: PortDefinition[];
   execution_mode: ExecutionMode;
+  documentation?: NodeDocumentation;
+  ui?: NodeUiHints;
+}
+
+/** Standard node rendering hints; prefer these over local palettes. */
+export interface NodeUiHints {
+  /** Lucide icon name */
+  icon: string;
+  /** Semantic color token a theme may remap, e.g. `category-control` */
+  color_token: string;
+  /** Default hex color for the token */
+  color: string;
+}
+
+export interface NodeDocumentation {
+  /** Markdown body */
+  body?: string;
+  examples?: NodeDocumentationExample[];
+  links?: { label: string; url: string }[];
+}
+
+export interface NodeDocumentationExample {
+  title: string;
+  /** Node data to autofill from this example */
+  data?: Record<string, unknown>;
+  inputs?: Record<string, unknown>;
+  outputs?: Record<string, unknown>;
 }
 
 export interface GraphNode {
@@ -168,10 +205,23 @@ export interface InsertNodeOnEdgeResponse {
   rejection?: ConnectionRejection;
 }
 
+/** A named input or output of a whole data graph, bound to one node port. */
+export interface WorkflowGraphPort {
+  name: string;
+  nodeId: string;
+  portId: string;
+}
+
+export interface WorkflowGraphPorts {
+  inputs?: WorkflowGraphPort[];
+  outputs?: WorkflowGraphPort[];
+}
+
 export interface WorkflowGraph {
   nodes: GraphNode[];
   edges: GraphEdge[];
   derived_graph?: WorkflowDerivedGraph;
+  ports?: WorkflowGraphPorts;
 }
 
 export interface WorkflowRunGraphQueryRequest {